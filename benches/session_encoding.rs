@@ -0,0 +1,153 @@
+//! Compares the JSON and compact session encodings on a large board.
+//!
+//! Run with `cargo bench --bench session_encoding`. File sizes are printed once per encoding
+//! before the timings.
+
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use std::hint::black_box;
+use wayscriber::draw::{BoardPages, Color, FontDescriptor, Frame, Shape};
+use wayscriber::input::BoardMode;
+use wayscriber::session::{
+    CompressionMode, SessionEncoding, SessionOptions, SessionSnapshot, load_snapshot, save_snapshot,
+};
+
+const STROKES: usize = 2_000;
+const POINTS_PER_STROKE: usize = 150;
+
+const PALETTE: [Color; 4] = [
+    Color {
+        r: 1.0,
+        g: 0.0,
+        b: 0.0,
+        a: 1.0,
+    },
+    Color {
+        r: 0.0,
+        g: 0.4,
+        b: 1.0,
+        a: 1.0,
+    },
+    Color {
+        r: 0.1,
+        g: 0.7,
+        b: 0.2,
+        a: 1.0,
+    },
+    Color {
+        r: 1.0,
+        g: 0.9,
+        b: 0.0,
+        a: 0.35,
+    },
+];
+
+/// A board full of freehand strokes (half with pressure widths) and a few text labels.
+fn large_snapshot() -> SessionSnapshot {
+    let mut frame = Frame::new();
+    for stroke in 0..STROKES {
+        let origin_x = (stroke * 37 % 1920) as i32;
+        let origin_y = (stroke * 53 % 1080) as i32;
+        let points: Vec<(i32, i32)> = (0..POINTS_PER_STROKE as i32)
+            .map(|i| {
+                let wobble = ((i * 7 + stroke as i32) % 5) - 2;
+                (origin_x + i * 3, origin_y + i / 2 + wobble)
+            })
+            .collect();
+        let per_point_widths = (stroke % 2 == 0).then(|| {
+            (0..POINTS_PER_STROKE)
+                .map(|i| 2.0 + (i % 10) as f64 * 0.25)
+                .collect()
+        });
+        frame.add_shape(Shape::Freehand {
+            points,
+            color: PALETTE[stroke % PALETTE.len()],
+            thick: 3.0,
+            per_point_colors: None,
+            per_point_widths,
+        });
+        if stroke % 100 == 0 {
+            frame.add_shape(Shape::Text {
+                x: origin_x,
+                y: origin_y,
+                text: format!("Step {}", stroke / 100),
+                color: PALETTE[0],
+                size: 32.0,
+                font_descriptor: FontDescriptor::new(
+                    "Sans".to_string(),
+                    "bold".to_string(),
+                    "normal".to_string(),
+                ),
+                background_enabled: true,
+            });
+        }
+    }
+
+    SessionSnapshot {
+        active_mode: BoardMode::Whiteboard,
+        transparent: None,
+        whiteboard: Some(BoardPages::from_pages(vec![frame], 0)),
+        blackboard: None,
+        tool_state: None,
+    }
+}
+
+fn variants(base_dir: &std::path::Path) -> Vec<(&'static str, SessionOptions)> {
+    [
+        ("json", SessionEncoding::Json, CompressionMode::Off),
+        ("json+gzip", SessionEncoding::Json, CompressionMode::On),
+        ("compact", SessionEncoding::Compact, CompressionMode::Off),
+        (
+            "compact+gzip",
+            SessionEncoding::Compact,
+            CompressionMode::On,
+        ),
+    ]
+    .into_iter()
+    .map(|(name, encoding, compression)| {
+        let mut options = SessionOptions::new(base_dir.to_path_buf(), name);
+        options.persist_whiteboard = true;
+        options.encoding = encoding;
+        options.compression = compression;
+        options.backup_retention = 0;
+        options.max_file_size_bytes = u64::MAX;
+        options.max_shapes_per_frame = usize::MAX;
+        (name, options)
+    })
+    .collect()
+}
+
+fn session_encoding(c: &mut Criterion) {
+    let snapshot = large_snapshot();
+    let temp = tempfile::tempdir().expect("temp dir");
+    let variants = variants(temp.path());
+
+    for (name, options) in &variants {
+        save_snapshot(&snapshot, options).expect("save session");
+        let size = std::fs::metadata(options.session_file_path())
+            .expect("session file")
+            .len();
+        println!("{name:>12}: {:>10} bytes", size);
+    }
+
+    let mut save = c.benchmark_group("session_save");
+    save.sample_size(10);
+    for (name, options) in &variants {
+        save.bench_with_input(BenchmarkId::from_parameter(name), options, |b, options| {
+            b.iter(|| save_snapshot(black_box(&snapshot), options).expect("save session"));
+        });
+    }
+    save.finish();
+
+    let mut load = c.benchmark_group("session_load");
+    load.sample_size(10);
+    for (name, options) in &variants {
+        save_snapshot(&snapshot, options).expect("save session");
+        load.bench_with_input(BenchmarkId::from_parameter(name), options, |b, options| {
+            b.iter(|| load_snapshot(black_box(options)).expect("load session"));
+        });
+    }
+    load.finish();
+}
+
+criterion_group!(benches, session_encoding);
+criterion_main!(benches);
@@ -15,7 +15,7 @@ use wayscriber::config::Config;
 use crate::messages::Message;
 use crate::models::{
     BoardModeOption, ColorMode, ColorQuadInput, ColorTripletInput, ConfigDraft, FontStyleOption,
-    FontWeightOption, NamedColorOption, QuadField, SessionCompressionOption,
+    FontWeightOption, NamedColorOption, QuadField, SessionCompressionOption, SessionFormatOption,
     SessionStorageModeOption, StatusPositionOption, TabId, TextField, ToggleField, TripletField,
 };
 
@@ -254,6 +254,11 @@ impl Application for ConfiguratorApp {
                 self.draft.session_compression = option;
                 self.refresh_dirty_flag();
             }
+            Message::SessionFormatChanged(option) => {
+                self.status = StatusMessage::idle();
+                self.draft.session_format = option;
+                self.refresh_dirty_flag();
+            }
             Message::BufferCountChanged(count) => {
                 self.status = StatusMessage::idle();
                 self.draft.performance_buffer_count = count;
@@ -949,6 +954,11 @@ impl ConfiguratorApp {
             Some(self.draft.session_compression),
             Message::SessionCompressionChanged,
         );
+        let format_pick = pick_list(
+            SessionFormatOption::list(),
+            Some(self.draft.session_format),
+            Message::SessionFormatChanged,
+        );
 
         let mut column = column![
             text("Session Persistence").size(20),
@@ -1019,6 +1029,19 @@ impl ConfiguratorApp {
                 .spacing(12)
                 .align_items(iced::Alignment::Center),
             )
+            .push(
+                row![
+                    text("File format:"),
+                    format_pick,
+                    Space::with_width(Length::Fill),
+                    default_value_text(
+                        self.defaults.session_format.label().to_string(),
+                        self.draft.session_format != self.defaults.session_format,
+                    )
+                ]
+                .spacing(12)
+                .align_items(iced::Alignment::Center),
+            )
             .push(labeled_input(
                 "Max shapes per frame",
                 &self.draft.session_max_shapes_per_frame,
@@ -1042,6 +1065,18 @@ impl ConfiguratorApp {
                 &self.draft.session_backup_retention,
                 &self.defaults.session_backup_retention,
                 TextField::SessionBackupRetention,
+            ))
+            .push(labeled_input(
+                "Autosave interval (seconds)",
+                &self.draft.session_autosave_interval_secs,
+                &self.defaults.session_autosave_interval_secs,
+                TextField::SessionAutosaveIntervalSecs,
+            ))
+            .push(labeled_input(
+                "Autosave after N actions",
+                &self.draft.session_autosave_after_actions,
+                &self.defaults.session_autosave_after_actions,
+                TextField::SessionAutosaveAfterActions,
             ));
 
         scrollable(column).into()
@@ -5,8 +5,8 @@ use wayscriber::config::Config;
 
 use crate::models::{
     BoardModeOption, ColorMode, FontStyleOption, FontWeightOption, KeybindingField,
-    NamedColorOption, QuadField, SessionCompressionOption, SessionStorageModeOption,
-    StatusPositionOption, TabId, TextField, ToggleField, TripletField,
+    NamedColorOption, QuadField, SessionCompressionOption, SessionFormatOption,
+    SessionStorageModeOption, StatusPositionOption, TabId, TextField, ToggleField, TripletField,
 };
 
 #[derive(Debug, Clone)]
@@ -27,6 +27,7 @@ pub enum Message {
     BoardModeChanged(BoardModeOption),
     SessionStorageModeChanged(SessionStorageModeOption),
     SessionCompressionChanged(SessionCompressionOption),
+    SessionFormatChanged(SessionFormatOption),
     BufferCountChanged(u32),
     KeybindingChanged(KeybindingField, String),
     FontStyleOptionSelected(FontStyleOption),
@@ -4,7 +4,8 @@ use super::color::{ColorInput, ColorQuadInput, ColorTripletInput};
 use super::error::FormError;
 use super::fields::{
     BoardModeOption, FontStyleOption, FontWeightOption, QuadField, SessionCompressionOption,
-    SessionStorageModeOption, StatusPositionOption, TextField, ToggleField, TripletField,
+    SessionFormatOption, SessionStorageModeOption, StatusPositionOption, TextField, ToggleField,
+    TripletField,
 };
 use super::keybindings::KeybindingsDraft;
 use super::util::{format_float, parse_f64};
@@ -76,8 +77,11 @@ pub struct ConfigDraft {
     pub session_max_shapes_per_frame: String,
     pub session_max_file_size_mb: String,
     pub session_compression: SessionCompressionOption,
+    pub session_format: SessionFormatOption,
     pub session_auto_compress_threshold_kb: String,
     pub session_backup_retention: String,
+    pub session_autosave_interval_secs: String,
+    pub session_autosave_after_actions: String,
 
     pub keybindings: KeybindingsDraft,
 }
@@ -164,11 +168,14 @@ impl ConfigDraft {
             session_compression: SessionCompressionOption::from_compression(
                 config.session.compress.clone(),
             ),
+            session_format: SessionFormatOption::from_format(config.session.format.clone()),
             session_auto_compress_threshold_kb: config
                 .session
                 .auto_compress_threshold_kb
                 .to_string(),
             session_backup_retention: config.session.backup_retention.to_string(),
+            session_autosave_interval_secs: config.session.autosave_interval_secs.to_string(),
+            session_autosave_after_actions: config.session.autosave_after_actions.to_string(),
 
             keybindings: KeybindingsDraft::from_config(&config.keybindings),
         }
@@ -392,6 +399,7 @@ impl ConfigDraft {
             |value| config.session.max_file_size_mb = value,
         );
         config.session.compress = self.session_compression.to_compression();
+        config.session.format = self.session_format.to_format();
         parse_u64_field(
             &self.session_auto_compress_threshold_kb,
             "session.auto_compress_threshold_kb",
@@ -404,6 +412,18 @@ impl ConfigDraft {
             &mut errors,
             |value| config.session.backup_retention = value,
         );
+        parse_u64_field(
+            &self.session_autosave_interval_secs,
+            "session.autosave_interval_secs",
+            &mut errors,
+            |value| config.session.autosave_interval_secs = value,
+        );
+        parse_usize_field(
+            &self.session_autosave_after_actions,
+            "session.autosave_after_actions",
+            &mut errors,
+            |value| config.session.autosave_after_actions = value,
+        );
 
         match self.keybindings.to_config() {
             Ok(cfg) => config.keybindings = cfg,
@@ -488,6 +508,8 @@ impl ConfigDraft {
                 self.session_auto_compress_threshold_kb = value
             }
             TextField::SessionBackupRetention => self.session_backup_retention = value,
+            TextField::SessionAutosaveIntervalSecs => self.session_autosave_interval_secs = value,
+            TextField::SessionAutosaveAfterActions => self.session_autosave_after_actions = value,
         }
     }
 
@@ -1,4 +1,4 @@
-use wayscriber::config::{SessionCompression, SessionStorageMode, StatusPosition};
+use wayscriber::config::{SessionCompression, SessionFormat, SessionStorageMode, StatusPosition};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum FontStyleOption {
@@ -264,6 +264,8 @@ pub enum TextField {
     SessionMaxFileSizeMb,
     SessionAutoCompressThresholdKb,
     SessionBackupRetention,
+    SessionAutosaveIntervalSecs,
+    SessionAutosaveAfterActions,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -373,3 +375,43 @@ impl std::fmt::Display for SessionCompressionOption {
         f.write_str(self.label())
     }
 }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SessionFormatOption {
+    Json,
+    Compact,
+}
+
+impl SessionFormatOption {
+    pub fn list() -> Vec<Self> {
+        vec![Self::Json, Self::Compact]
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Json => "JSON",
+            Self::Compact => "Compact",
+        }
+    }
+
+    #[allow(clippy::wrong_self_convention)]
+    pub fn to_format(&self) -> SessionFormat {
+        match self {
+            Self::Json => SessionFormat::Json,
+            Self::Compact => SessionFormat::Compact,
+        }
+    }
+
+    pub fn from_format(format: SessionFormat) -> Self {
+        match format {
+            SessionFormat::Json => Self::Json,
+            SessionFormat::Compact => Self::Compact,
+        }
+    }
+}
+
+impl std::fmt::Display for SessionFormatOption {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.label())
+    }
+}
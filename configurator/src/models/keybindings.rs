@@ -48,6 +48,10 @@ pub enum KeybindingField {
     CaptureFileSelection,
     CaptureClipboardRegion,
     CaptureFileRegion,
+    CaptureAnnotatedClipboardFull,
+    CaptureAnnotatedFileFull,
+    CaptureAnnotatedClipboardRegion,
+    CaptureAnnotatedFileRegion,
 }
 
 impl KeybindingsDraft {
@@ -133,6 +137,10 @@ impl KeybindingField {
             Self::CaptureFileSelection,
             Self::CaptureClipboardRegion,
             Self::CaptureFileRegion,
+            Self::CaptureAnnotatedClipboardFull,
+            Self::CaptureAnnotatedFileFull,
+            Self::CaptureAnnotatedClipboardRegion,
+            Self::CaptureAnnotatedFileRegion,
         ]
     }
 
@@ -171,6 +179,10 @@ impl KeybindingField {
             Self::CaptureFileSelection => "File selection",
             Self::CaptureClipboardRegion => "Clipboard region",
             Self::CaptureFileRegion => "File region",
+            Self::CaptureAnnotatedClipboardFull => "Annotated clipboard full screen",
+            Self::CaptureAnnotatedFileFull => "Annotated file full screen",
+            Self::CaptureAnnotatedClipboardRegion => "Annotated clipboard region",
+            Self::CaptureAnnotatedFileRegion => "Annotated file region",
         }
     }
 
@@ -209,6 +221,10 @@ impl KeybindingField {
             Self::CaptureFileSelection => "capture_file_selection",
             Self::CaptureClipboardRegion => "capture_clipboard_region",
             Self::CaptureFileRegion => "capture_file_region",
+            Self::CaptureAnnotatedClipboardFull => "capture_annotated_clipboard_full",
+            Self::CaptureAnnotatedFileFull => "capture_annotated_file_full",
+            Self::CaptureAnnotatedClipboardRegion => "capture_annotated_clipboard_region",
+            Self::CaptureAnnotatedFileRegion => "capture_annotated_file_region",
         }
     }
 
@@ -247,6 +263,10 @@ impl KeybindingField {
             Self::CaptureFileSelection => &config.capture_file_selection,
             Self::CaptureClipboardRegion => &config.capture_clipboard_region,
             Self::CaptureFileRegion => &config.capture_file_region,
+            Self::CaptureAnnotatedClipboardFull => &config.capture_annotated_clipboard_full,
+            Self::CaptureAnnotatedFileFull => &config.capture_annotated_file_full,
+            Self::CaptureAnnotatedClipboardRegion => &config.capture_annotated_clipboard_region,
+            Self::CaptureAnnotatedFileRegion => &config.capture_annotated_file_region,
         }
     }
 
@@ -285,6 +305,12 @@ impl KeybindingField {
             Self::CaptureFileSelection => config.capture_file_selection = value,
             Self::CaptureClipboardRegion => config.capture_clipboard_region = value,
             Self::CaptureFileRegion => config.capture_file_region = value,
+            Self::CaptureAnnotatedClipboardFull => config.capture_annotated_clipboard_full = value,
+            Self::CaptureAnnotatedFileFull => config.capture_annotated_file_full = value,
+            Self::CaptureAnnotatedClipboardRegion => {
+                config.capture_annotated_clipboard_region = value
+            }
+            Self::CaptureAnnotatedFileRegion => config.capture_annotated_file_region = value,
         }
     }
 }
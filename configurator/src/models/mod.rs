@@ -10,7 +10,8 @@ pub use color::{ColorMode, ColorQuadInput, ColorTripletInput, NamedColorOption};
 pub use config::ConfigDraft;
 pub use fields::{
     BoardModeOption, FontStyleOption, FontWeightOption, QuadField, SessionCompressionOption,
-    SessionStorageModeOption, StatusPositionOption, TextField, ToggleField, TripletField,
+    SessionFormatOption, SessionStorageModeOption, StatusPositionOption, TextField, ToggleField,
+    TripletField,
 };
 pub use keybindings::KeybindingField;
 pub use tab::TabId;
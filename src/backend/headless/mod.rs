@@ -0,0 +1,391 @@
+//! Headless backend that replays scripted input without a compositor.
+//!
+//! Feeds [`ScriptEvent`]s into `InputState` the way the Wayland handlers do, renders every frame
+//! into an in-memory Cairo surface through the same scene code as the overlay, and records the
+//! requests (captures, exports, freeze toggles) a real backend would act on. End-to-end tests
+//! drive it to check toolbar events, context menus, capture actions and session saves without a
+//! Wayland connection, and `--replay-input` runs recordings made with `--record-input` through it.
+
+mod record;
+mod script;
+
+use std::time::{Duration, Instant};
+
+use anyhow::{Context, Result};
+use log::{debug, warn};
+
+use super::input_state_from_config;
+use super::scene::{SceneView, render_scene};
+use crate::config::{Action, Config};
+use crate::draw::EraserReplayContext;
+#[cfg(tablet)]
+use crate::input::tablet::{TabletSettings, apply_pressure_to_state};
+use crate::input::{InputState, MouseButton, Tool};
+use crate::session::{self, SessionOptions};
+use crate::util::Rect;
+
+pub use record::InputRecorder;
+pub use script::{ScriptEvent, parse_script};
+
+/// Work the overlay asked its backend to do, recorded instead of performed.
+#[derive(Debug, Clone, PartialEq)]
+pub enum BackendRequest {
+    /// Screenshot capture, with the region selected on the overlay (if any).
+    Capture {
+        action: Action,
+        region: Option<Rect>,
+    },
+    ToggleFreeze,
+    ExportSvg,
+    ExportPdf,
+    SaveDocument,
+    OpenDocument,
+}
+
+/// Offscreen backend driven by scripted events.
+pub struct HeadlessBackend {
+    pub input_state: InputState,
+    config: Config,
+    width: u32,
+    height: u32,
+    /// Clock used for animations and delayed history. `Wait` events move it forward without
+    /// sleeping; it never falls behind the wall clock `InputState` stamps events with.
+    now: Instant,
+    pointer: (i32, i32),
+    session_options: Option<SessionOptions>,
+    requests: Vec<BackendRequest>,
+    frame: Option<cairo::ImageSurface>,
+    frames_rendered: usize,
+    stylus_tip_down: bool,
+    #[cfg(tablet)]
+    stylus_peak_thickness: Option<f64>,
+    #[cfg(tablet)]
+    tablet_settings: TabletSettings,
+}
+
+impl HeadlessBackend {
+    /// Creates a backend with a `width` x `height` logical output and no session persistence.
+    pub fn new(config: Config, width: u32, height: u32) -> Self {
+        let mut input_state = input_state_from_config(&config);
+        input_state.update_screen_dimensions(width, height);
+        input_state.needs_redraw = true;
+        #[cfg(tablet)]
+        let tablet_settings = TabletSettings {
+            // Stylus events in a script imply a tablet, whatever the config says.
+            enabled: true,
+            pressure_enabled: config.tablet.pressure_enabled,
+            min_thickness: config.tablet.min_thickness,
+            max_thickness: config.tablet.max_thickness,
+        };
+        Self {
+            input_state,
+            config,
+            width,
+            height,
+            now: Instant::now(),
+            pointer: (0, 0),
+            session_options: None,
+            requests: Vec::new(),
+            frame: None,
+            frames_rendered: 0,
+            stylus_tip_down: false,
+            #[cfg(tablet)]
+            stylus_peak_thickness: None,
+            #[cfg(tablet)]
+            tablet_settings,
+        }
+    }
+
+    /// Restores the session described by `options` (if one was saved) and saves back to it.
+    // Only the end-to-end tests persist sessions; replays start from a blank overlay.
+    #[allow(dead_code)]
+    pub fn with_session(mut self, options: SessionOptions) -> Result<Self> {
+        if let Some(snapshot) = session::load_snapshot(&options)
+            .with_context(|| format!("failed to load {}", options.session_file_path().display()))?
+        {
+            session::apply_snapshot(&mut self.input_state, snapshot, &options);
+            self.input_state.needs_redraw = true;
+        }
+        self.session_options = Some(options);
+        Ok(self)
+    }
+
+    /// Dispatches every event in order, rendering whenever the state asks for a redraw.
+    ///
+    /// Stops early once an event requests exit, like the Wayland event loop.
+    pub fn run<'a>(&mut self, events: impl IntoIterator<Item = &'a ScriptEvent>) -> Result<()> {
+        self.process()?;
+        for event in events {
+            if self.input_state.should_exit {
+                debug!("Exit requested; ignoring remaining scripted events");
+                break;
+            }
+            self.dispatch(event);
+            self.process()?;
+        }
+        Ok(())
+    }
+
+    /// Feeds a single event into the input state.
+    pub fn dispatch(&mut self, event: &ScriptEvent) {
+        debug!("Replaying {:?}", event);
+        match *event {
+            ScriptEvent::Resize { width, height } => {
+                self.width = width;
+                self.height = height;
+                self.input_state.update_screen_dimensions(width, height);
+                self.input_state.needs_redraw = true;
+            }
+            ScriptEvent::KeyPress { key } => {
+                self.input_state.on_key_press(key);
+                self.input_state.needs_redraw = true;
+            }
+            ScriptEvent::KeyRelease { key } => self.input_state.on_key_release(key),
+            ScriptEvent::Modifiers { shift, ctrl, alt } => {
+                self.input_state.sync_modifiers(shift, ctrl, alt);
+            }
+            ScriptEvent::PointerMotion { x, y } | ScriptEvent::StylusMotion { x, y } => {
+                self.pointer = (x, y);
+                let (x, y) = self.input_state.zoom_pointer_motion(x, y);
+                self.input_state.update_pointer_position(x, y);
+                self.input_state.on_mouse_motion(x, y);
+            }
+            ScriptEvent::PointerPress { button, x, y } => {
+                self.pointer = (x, y);
+                let (x, y) = self.input_state.zoom_canvas_point(x, y);
+                self.input_state.on_mouse_press(button, x, y);
+                self.input_state.needs_redraw = true;
+            }
+            ScriptEvent::PointerRelease { button, x, y } => {
+                self.pointer = (x, y);
+                let (x, y) = self.input_state.zoom_canvas_point(x, y);
+                self.input_state.on_mouse_release(button, x, y);
+                self.input_state.needs_redraw = true;
+            }
+            ScriptEvent::Scroll { steps } => self.scroll(steps),
+            ScriptEvent::StylusDown => {
+                self.stylus_tip_down = true;
+                let (x, y) = self
+                    .input_state
+                    .zoom_canvas_point(self.pointer.0, self.pointer.1);
+                self.input_state.on_mouse_press(MouseButton::Left, x, y);
+                self.input_state.needs_redraw = true;
+            }
+            ScriptEvent::StylusUp => {
+                if !self.stylus_tip_down {
+                    return;
+                }
+                self.stylus_tip_down = false;
+                // Keep the pressure-adjusted (peak) thickness for subsequent strokes
+                #[cfg(tablet)]
+                if let Some(thick) = self.stylus_peak_thickness.take() {
+                    self.input_state.current_thickness = thick;
+                }
+                let (x, y) = self
+                    .input_state
+                    .zoom_canvas_point(self.pointer.0, self.pointer.1);
+                self.input_state.on_mouse_release(MouseButton::Left, x, y);
+                self.input_state.set_pressure_width(None);
+                self.input_state.needs_redraw = true;
+            }
+            ScriptEvent::StylusPressure { pressure } => self.apply_pressure(pressure),
+            ScriptEvent::Toolbar { ref event } => {
+                // Toolbar pin and drawing preferences are not written back to the config here.
+                if self.input_state.apply_toolbar_event(event.clone()) {
+                    self.input_state.needs_redraw = true;
+                }
+            }
+            ScriptEvent::Wait { ms } => {
+                self.now = self.now.max(Instant::now()) + Duration::from_millis(ms);
+                if self.input_state.tick_delayed_history(self.now) {
+                    self.input_state.needs_redraw = true;
+                }
+            }
+            ScriptEvent::Session {
+                ref tool,
+                ref state,
+                ..
+            } => self.restore_session(*tool, state),
+        }
+    }
+
+    /// Mirrors a session load on the overlay: boards, pages, history and tool settings.
+    fn restore_session(&mut self, tool: Option<Tool>, state: &serde_json::Value) {
+        let snapshot = match session::snapshot_from_value(state.clone()) {
+            Ok(snapshot) => snapshot,
+            Err(err) => {
+                warn!("Ignoring recorded session state: {:#}", err);
+                return;
+            }
+        };
+        self.input_state.clear_selection();
+        session::apply_snapshot(
+            &mut self.input_state,
+            snapshot,
+            &session::document_options(usize::MAX),
+        );
+        self.input_state.set_tool_override(tool);
+        self.input_state.dirty_tracker.mark_full();
+        self.input_state.needs_redraw = true;
+    }
+
+    /// Mirrors the Wayland pointer axis handling: Shift+scroll sizes text, scroll sizes strokes
+    /// (or sets the magnification while zoomed, or the spotlight size).
+    fn scroll(&mut self, steps: i32) {
+        if self.input_state.zoom_view().is_some() {
+            self.input_state.adjust_zoom(steps);
+            return;
+        }
+        if self.input_state.spotlight_active() {
+            self.input_state.adjust_spotlight(steps);
+            return;
+        }
+        match steps.cmp(&0) {
+            std::cmp::Ordering::Greater if self.input_state.modifiers.shift => {
+                self.input_state.adjust_font_size(-2.0);
+            }
+            std::cmp::Ordering::Less if self.input_state.modifiers.shift => {
+                self.input_state.adjust_font_size(2.0);
+            }
+            std::cmp::Ordering::Greater | std::cmp::Ordering::Less => {
+                let delta = if steps > 0 { -1.0 } else { 1.0 };
+                if self.input_state.nudge_thickness_for_active_tool(delta) {
+                    self.input_state.needs_redraw = true;
+                }
+            }
+            std::cmp::Ordering::Equal => {}
+        }
+    }
+
+    #[cfg(tablet)]
+    fn apply_pressure(&mut self, pressure: f64) {
+        if !self.stylus_tip_down || pressure <= 0.0 {
+            return;
+        }
+        apply_pressure_to_state(pressure, &mut self.input_state, self.tablet_settings);
+        // Keep thickness monotonic during a stroke to avoid dips near lift.
+        let current = self.input_state.current_thickness;
+        let peak = self.stylus_peak_thickness.unwrap_or(current).max(current);
+        self.input_state.current_thickness = peak;
+        self.stylus_peak_thickness = Some(peak);
+    }
+
+    #[cfg(not(tablet))]
+    fn apply_pressure(&mut self, _pressure: f64) {
+        debug!("Ignoring stylus pressure; built without tablet support");
+    }
+
+    /// Collects backend requests, advances history playback and renders if needed.
+    fn process(&mut self) -> Result<()> {
+        if let Some(action) = self.input_state.take_pending_capture_action() {
+            let region = self.input_state.take_pending_capture_region();
+            self.requests
+                .push(BackendRequest::Capture { action, region });
+        }
+        if self.input_state.take_pending_frozen_toggle() {
+            self.requests.push(BackendRequest::ToggleFreeze);
+            // There is no screen to freeze; like the xdg fallback, a zoom waiting on it gives up.
+            self.input_state.set_frozen_active(false);
+        }
+        let flags = [
+            (
+                self.input_state.take_pending_svg_export(),
+                BackendRequest::ExportSvg,
+            ),
+            (
+                self.input_state.take_pending_pdf_export(),
+                BackendRequest::ExportPdf,
+            ),
+            (
+                self.input_state.take_pending_document_save(),
+                BackendRequest::SaveDocument,
+            ),
+            (
+                self.input_state.take_pending_document_open(),
+                BackendRequest::OpenDocument,
+            ),
+        ];
+        self.requests.extend(
+            flags
+                .into_iter()
+                .filter_map(|(pending, request)| pending.then_some(request)),
+        );
+
+        if self.input_state.has_pending_history() {
+            self.input_state.needs_redraw = true;
+        }
+        if self.input_state.needs_redraw {
+            let keep_rendering = self.render()?;
+            self.input_state.needs_redraw =
+                keep_rendering || self.input_state.has_pending_history();
+        }
+        Ok(())
+    }
+
+    /// Renders the current state; returns true while animations still need frames.
+    pub fn render(&mut self) -> Result<bool> {
+        let surface = cairo::ImageSurface::create(
+            cairo::Format::ARgb32,
+            self.width as i32,
+            self.height as i32,
+        )
+        .context("Failed to create headless surface")?;
+        let highlight_active = self.input_state.advance_click_highlights(self.now);
+        let laser_active = self.input_state.advance_laser(self.now);
+        let vanishing_active = self.input_state.advance_vanishing_ink(self.now);
+        {
+            let ctx = cairo::Context::new(&surface).context("Failed to create Cairo context")?;
+            let mode = self.input_state.board_mode();
+            crate::draw::render_board_background(&ctx, mode, &self.input_state.board_config);
+            let eraser_ctx = EraserReplayContext {
+                pattern: None,
+                bg_color: mode.background_color(&self.input_state.board_config),
+            };
+            let view = SceneView {
+                width: self.width,
+                height: self.height,
+                pointer: self.pointer,
+                frozen_enabled: false,
+                now: self.now,
+            };
+            render_scene(&ctx, &mut self.input_state, &self.config, &eraser_ctx, view);
+        }
+        surface.flush();
+        // Damage hints are only used for diagnostics; drain them like a committed frame.
+        self.input_state.take_dirty_regions();
+        self.frame = Some(surface);
+        self.frames_rendered += 1;
+        Ok(highlight_active || laser_active || vanishing_active)
+    }
+
+    /// Last rendered frame, if any.
+    pub fn frame(&self) -> Option<&cairo::ImageSurface> {
+        self.frame.as_ref()
+    }
+
+    /// Number of frames rendered so far.
+    pub fn frames_rendered(&self) -> usize {
+        self.frames_rendered
+    }
+
+    /// Requests recorded so far, oldest first.
+    pub fn requests(&self) -> &[BackendRequest] {
+        &self.requests
+    }
+
+    /// Saves the session the way the overlay does on exit; returns false without persistence.
+    #[allow(dead_code)]
+    pub fn save_session(&self) -> Result<bool> {
+        let Some(options) = self.session_options.as_ref() else {
+            return Ok(false);
+        };
+        let Some(snapshot) = session::snapshot_from_input(&self.input_state, options) else {
+            return Ok(false);
+        };
+        session::save_snapshot(&snapshot, options)?;
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,59 @@
+//! Writes the overlay's input to a file that `--replay-input` can run back.
+
+use std::fs::File;
+use std::io::{LineWriter, Write};
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+use anyhow::{Context, Result};
+use log::{info, warn};
+
+use super::script::{ScriptEvent, ScriptLine};
+
+/// Appends timestamped [`ScriptEvent`]s to a JSON Lines file.
+///
+/// Every line is flushed as it is written so a recording survives a crash.
+#[derive(Debug)]
+pub struct InputRecorder {
+    path: PathBuf,
+    writer: Option<LineWriter<File>>,
+    started: Instant,
+}
+
+impl InputRecorder {
+    /// Creates (or truncates) the recording at `path`.
+    pub fn create(path: &Path) -> Result<Self> {
+        let file = File::create(path)
+            .with_context(|| format!("failed to create input recording {}", path.display()))?;
+        info!("Recording input to {}", path.display());
+        Ok(Self {
+            path: path.to_path_buf(),
+            writer: Some(LineWriter::new(file)),
+            started: Instant::now(),
+        })
+    }
+
+    /// Records `event` with the time elapsed since the recording started.
+    ///
+    /// A failed write is logged once and stops the recording; drawing carries on regardless.
+    pub fn record(&mut self, event: ScriptEvent) {
+        let Some(writer) = self.writer.as_mut() else {
+            return;
+        };
+        let line = ScriptLine {
+            t_ms: Some(self.started.elapsed().as_millis() as u64),
+            event,
+        };
+        let result = serde_json::to_writer(&mut *writer, &line)
+            .map_err(std::io::Error::from)
+            .and_then(|()| writer.write_all(b"\n"));
+        if let Err(err) = result {
+            warn!(
+                "Stopped recording input to {}: {}",
+                self.path.display(),
+                err
+            );
+            self.writer = None;
+        }
+    }
+}
@@ -0,0 +1,91 @@
+//! Scripted input events understood by the headless backend.
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::input::{Key, MouseButton, Tool};
+use crate::ui::toolbar::ToolbarEvent;
+
+/// One input event, in the coordinates and units the Wayland handlers hand to `InputState`.
+///
+/// Scripts are JSON Lines: one event object per line, tagged by `type`, for example
+/// `{"type":"pointer_press","button":"Left","x":10,"y":20}`. Blank lines are ignored.
+///
+/// Recordings made with `--record-input` use the same format with a `t_ms` field on every line
+/// (milliseconds since recording started); gaps between timestamps replay as [`Self::Wait`].
+/// They open with a [`Self::Session`] line holding the overlay's starting state.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ScriptEvent {
+    /// Overlay surface configured with a new logical size.
+    Resize { width: u32, height: u32 },
+    /// Key pressed (or repeated).
+    KeyPress { key: Key },
+    /// Key released.
+    KeyRelease { key: Key },
+    /// Modifier state reported by the compositor.
+    Modifiers { shift: bool, ctrl: bool, alt: bool },
+    /// Pointer moved over the overlay.
+    PointerMotion { x: i32, y: i32 },
+    /// Pointer button pressed.
+    PointerPress { button: MouseButton, x: i32, y: i32 },
+    /// Pointer button released.
+    PointerRelease { button: MouseButton, x: i32, y: i32 },
+    /// Scroll wheel steps; positive values scroll down.
+    Scroll { steps: i32 },
+    /// Stylus tip touched the overlay at the current position.
+    StylusDown,
+    /// Stylus tip lifted.
+    StylusUp,
+    /// Stylus moved over the overlay.
+    StylusMotion { x: i32, y: i32 },
+    /// Stylus pressure, normalized to 0.0-1.0.
+    StylusPressure { pressure: f64 },
+    /// Event emitted by the floating toolbar.
+    Toolbar { event: ToolbarEvent },
+    /// Lets time pass so delayed undo/redo and animations advance.
+    Wait { ms: u64 },
+    /// Replaces the boards and tool settings, as the overlay does when it loads a session.
+    ///
+    /// `state` is in the session file format; `tool` is the selected tool, if one was picked
+    /// explicitly, and `profile` the `--session` profile the state came from.
+    Session {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        profile: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        tool: Option<Tool>,
+        state: Value,
+    },
+}
+
+/// One line of a script or recording.
+#[derive(Serialize, Deserialize)]
+pub(super) struct ScriptLine {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub t_ms: Option<u64>,
+    #[serde(flatten)]
+    pub event: ScriptEvent,
+}
+
+/// Parses a JSON Lines script or recording.
+pub fn parse_script(text: &str) -> Result<Vec<ScriptEvent>> {
+    let mut events = Vec::new();
+    let mut last_t_ms = None;
+    for (index, line) in text.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let line: ScriptLine = serde_json::from_str(line)
+            .with_context(|| format!("invalid input event on line {}", index + 1))?;
+        if let Some(t_ms) = line.t_ms {
+            let last = last_t_ms.unwrap_or(t_ms);
+            if t_ms > last {
+                events.push(ScriptEvent::Wait { ms: t_ms - last });
+            }
+            last_t_ms = Some(last.max(t_ms));
+        }
+        events.push(line.event);
+    }
+    Ok(events)
+}
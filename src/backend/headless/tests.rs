@@ -0,0 +1,643 @@
+use super::*;
+use crate::draw::{Color, DrawnShape, Shape};
+use crate::input::{BoardMode, Key, Tool};
+use crate::ui::toolbar::ToolbarEvent;
+
+const RED: Color = Color {
+    r: 1.0,
+    g: 0.0,
+    b: 0.0,
+    a: 1.0,
+};
+
+fn backend() -> HeadlessBackend {
+    HeadlessBackend::new(Config::default(), 320, 240)
+}
+
+fn drag(from: (i32, i32), to: (i32, i32)) -> Vec<ScriptEvent> {
+    vec![
+        ScriptEvent::PointerMotion {
+            x: from.0,
+            y: from.1,
+        },
+        ScriptEvent::PointerPress {
+            button: MouseButton::Left,
+            x: from.0,
+            y: from.1,
+        },
+        ScriptEvent::PointerMotion {
+            x: (from.0 + to.0) / 2,
+            y: (from.1 + to.1) / 2,
+        },
+        ScriptEvent::PointerMotion { x: to.0, y: to.1 },
+        ScriptEvent::PointerRelease {
+            button: MouseButton::Left,
+            x: to.0,
+            y: to.1,
+        },
+    ]
+}
+
+fn draw_red_rect() -> Vec<ScriptEvent> {
+    let mut events = vec![
+        ScriptEvent::Toolbar {
+            event: ToolbarEvent::SelectTool(Tool::Rect),
+        },
+        ScriptEvent::Toolbar {
+            event: ToolbarEvent::SetColor(RED),
+        },
+    ];
+    events.extend(drag((40, 40), (140, 120)));
+    events
+}
+
+fn shapes(backend: &HeadlessBackend) -> &[DrawnShape] {
+    &backend.input_state.canvas_set.active_frame().shapes
+}
+
+/// Returns the premultiplied ARGB bytes of one pixel of the last frame, in memory order.
+fn pixel(backend: &HeadlessBackend, x: usize, y: usize) -> [u8; 4] {
+    let surface = backend.frame().expect("a rendered frame");
+    let stride = surface.stride() as usize;
+    let mut out = [0u8; 4];
+    surface
+        .with_data(|data| out.copy_from_slice(&data[y * stride + x * 4..][..4]))
+        .expect("frame data");
+    out
+}
+
+fn key_chord(ctrl: bool, shift: bool, key: Key) -> Vec<ScriptEvent> {
+    vec![
+        ScriptEvent::Modifiers {
+            shift,
+            ctrl,
+            alt: false,
+        },
+        ScriptEvent::KeyPress { key },
+        ScriptEvent::KeyRelease { key },
+        ScriptEvent::Modifiers {
+            shift: false,
+            ctrl: false,
+            alt: false,
+        },
+    ]
+}
+
+#[test]
+fn toolbar_events_and_a_drag_commit_and_render_a_rectangle() {
+    let mut backend = backend();
+    backend.run(&draw_red_rect()).unwrap();
+
+    assert!(matches!(
+        shapes(&backend),
+        [DrawnShape {
+            shape: Shape::Rect {
+                x: 40,
+                y: 40,
+                w: 100,
+                h: 80,
+                ..
+            },
+            ..
+        }]
+    ));
+    assert!(backend.frames_rendered() > 1);
+    let edge = u32::from_ne_bytes(pixel(&backend, 40, 80));
+    assert_eq!(edge >> 24, 0xff, "rectangle outline should be opaque");
+    assert_eq!((edge >> 16) & 0xff, 0xff, "rectangle outline should be red");
+    assert_eq!(
+        pixel(&backend, 90, 80),
+        [0; 4],
+        "outline must not be filled"
+    );
+}
+
+#[test]
+fn context_menu_deletes_the_shape_under_the_pointer() {
+    let mut backend = backend();
+    backend.run(&draw_red_rect()).unwrap();
+    backend
+        .run(&[ScriptEvent::PointerPress {
+            button: MouseButton::Right,
+            x: 40,
+            y: 80,
+        }])
+        .unwrap();
+    assert!(backend.input_state.is_context_menu_open());
+
+    // The layout only exists once the menu has been rendered.
+    let layout = *backend
+        .input_state
+        .context_menu_layout()
+        .expect("menu was laid out");
+    let row = backend
+        .input_state
+        .context_menu_entries()
+        .iter()
+        .position(|entry| entry.label == "Delete")
+        .expect("shape menu has a Delete entry");
+    let x = (layout.origin_x + layout.width / 2.0) as i32;
+    let y = (layout.origin_y + layout.padding_y + layout.row_height * (row as f64 + 0.5)) as i32;
+    backend
+        .run(&[
+            ScriptEvent::PointerMotion { x, y },
+            ScriptEvent::PointerPress {
+                button: MouseButton::Left,
+                x,
+                y,
+            },
+            ScriptEvent::PointerRelease {
+                button: MouseButton::Left,
+                x,
+                y,
+            },
+        ])
+        .unwrap();
+
+    assert!(shapes(&backend).is_empty());
+    assert!(!backend.input_state.is_context_menu_open());
+    assert_eq!(pixel(&backend, 40, 80), [0; 4]);
+}
+
+#[test]
+fn capture_shortcuts_are_handed_to_the_backend() {
+    let mut backend = backend();
+    backend.run(&key_chord(true, true, Key::Char('P'))).unwrap();
+    assert_eq!(
+        backend.requests(),
+        [BackendRequest::Capture {
+            action: Action::CaptureFullScreen,
+            region: None,
+        }]
+    );
+
+    // Region captures wait for a rectangle to be dragged out and confirmed.
+    let mut events = key_chord(true, true, Key::Char('6'));
+    events.extend(drag((10, 20), (110, 70)));
+    events.push(ScriptEvent::KeyPress { key: Key::Return });
+    backend.run(&events).unwrap();
+    assert!(matches!(
+        backend.requests(),
+        [
+            _,
+            BackendRequest::Capture {
+                action: Action::CaptureFileRegion,
+                region: Some(Rect {
+                    x: 10,
+                    y: 20,
+                    width: 100,
+                    height: 50,
+                }),
+            },
+        ]
+    ));
+    assert!(shapes(&backend).is_empty(), "selection drags do not draw");
+}
+
+#[test]
+fn delayed_undo_advances_with_waits() {
+    let mut backend = backend();
+    let mut events = drag((10, 10), (60, 60));
+    events.extend(drag((100, 100), (160, 160)));
+    backend.run(&events).unwrap();
+    assert_eq!(shapes(&backend).len(), 2);
+
+    let delay = Config::default().history.undo_all_delay_ms.max(50);
+    backend
+        .run(&[
+            ScriptEvent::Toolbar {
+                event: ToolbarEvent::UndoAllDelayed,
+            },
+            ScriptEvent::Wait { ms: 0 },
+        ])
+        .unwrap();
+    assert_eq!(shapes(&backend).len(), 1);
+    backend.run(&[ScriptEvent::Wait { ms: delay }]).unwrap();
+    assert!(shapes(&backend).is_empty());
+    assert!(!backend.input_state.has_pending_history());
+}
+
+#[test]
+fn sessions_are_restored_and_saved() {
+    let temp = tempfile::tempdir().unwrap();
+    let mut options = SessionOptions::new(temp.path().to_path_buf(), "headless");
+    options.persist_transparent = true;
+
+    let mut backend = backend().with_session(options.clone()).unwrap();
+    backend.run(&draw_red_rect()).unwrap();
+    assert!(backend.save_session().unwrap());
+
+    let restored = HeadlessBackend::new(Config::default(), 320, 240)
+        .with_session(options)
+        .unwrap();
+    assert_eq!(shapes(&restored).len(), 1);
+    assert!(
+        !HeadlessBackend::new(Config::default(), 10, 10)
+            .save_session()
+            .unwrap()
+    );
+}
+
+#[test]
+fn exit_stops_the_replay() {
+    let mut backend = backend();
+    let mut events = vec![ScriptEvent::KeyPress { key: Key::Escape }];
+    events.extend(drag((10, 10), (60, 60)));
+    backend.run(&events).unwrap();
+    assert!(backend.input_state.should_exit);
+    assert!(shapes(&backend).is_empty());
+}
+
+#[test]
+fn scripts_are_json_lines() {
+    let script = r#"
+{"type":"key_press","key":{"Char":"r"}}
+{"type":"pointer_press","button":"Left","x":1,"y":2}
+
+{"type":"toolbar","event":{"SetThickness":6.0}}
+{"type":"wait","ms":250}
+"#;
+    assert_eq!(
+        parse_script(script).unwrap(),
+        vec![
+            ScriptEvent::KeyPress {
+                key: Key::Char('r')
+            },
+            ScriptEvent::PointerPress {
+                button: MouseButton::Left,
+                x: 1,
+                y: 2,
+            },
+            ScriptEvent::Toolbar {
+                event: ToolbarEvent::SetThickness(6.0)
+            },
+            ScriptEvent::Wait { ms: 250 },
+        ]
+    );
+
+    let err = parse_script("{\"type\":\"wait\",\"ms\":1}\n{\"type\":\"teleport\"}").unwrap_err();
+    assert!(err.to_string().contains("line 2"), "{err}");
+}
+
+#[test]
+fn recording_timestamps_replay_as_waits() {
+    let recording = r#"{"t_ms":0,"type":"resize","width":640,"height":480}
+{"t_ms":0,"type":"pointer_press","button":"Left","x":5,"y":5}
+{"t_ms":120,"type":"pointer_motion","x":9,"y":9}
+{"t_ms":100,"type":"pointer_motion","x":10,"y":10}
+{"t_ms":150,"type":"pointer_release","button":"Left","x":10,"y":10}
+"#;
+    let events = parse_script(recording).unwrap();
+    let waits: Vec<u64> = events
+        .iter()
+        .filter_map(|event| match event {
+            ScriptEvent::Wait { ms } => Some(*ms),
+            _ => None,
+        })
+        .collect();
+    // Out-of-order timestamps never rewind the clock.
+    assert_eq!(waits, vec![120, 30]);
+
+    let mut backend = backend();
+    backend.run(&events).unwrap();
+    let frame = backend.frame().unwrap();
+    assert_eq!((frame.width(), frame.height()), (640, 480));
+    assert_eq!(shapes(&backend).len(), 1);
+}
+
+#[test]
+fn recorder_output_replays_the_same_events() {
+    let temp = tempfile::tempdir().unwrap();
+    let path = temp.path().join("input.jsonl");
+    let mut events = draw_red_rect();
+    events.push(ScriptEvent::Scroll { steps: -1 });
+
+    let mut recorder = InputRecorder::create(&path).unwrap();
+    for event in &events {
+        recorder.record(event.clone());
+    }
+    drop(recorder);
+
+    let replayed: Vec<ScriptEvent> = parse_script(&std::fs::read_to_string(&path).unwrap())
+        .unwrap()
+        .into_iter()
+        .filter(|event| !matches!(event, ScriptEvent::Wait { .. }))
+        .collect();
+    assert_eq!(replayed, events);
+}
+
+#[test]
+fn recorded_session_state_is_restored_before_replaying() {
+    let mut recorded = backend();
+    recorded.run(&draw_red_rect()).unwrap();
+    recorded.input_state.handle_action(Action::ToggleWhiteboard);
+    recorded.input_state.current_thickness = 9.0;
+    let options = session::document_options(usize::MAX);
+    let snapshot = session::snapshot_from_input(&recorded.input_state, &options).unwrap();
+
+    let temp = tempfile::tempdir().unwrap();
+    let path = temp.path().join("input.jsonl");
+    let mut recorder = InputRecorder::create(&path).unwrap();
+    recorder.record(ScriptEvent::Session {
+        profile: Some("work".to_string()),
+        tool: recorded.input_state.tool_override(),
+        state: session::snapshot_to_value(&snapshot).unwrap(),
+    });
+    for event in drag((200, 150), (260, 200)) {
+        recorder.record(event);
+    }
+    drop(recorder);
+
+    let mut replay = backend();
+    replay
+        .run(&parse_script(&std::fs::read_to_string(&path).unwrap()).unwrap())
+        .unwrap();
+    assert_eq!(replay.input_state.board_mode(), BoardMode::Whiteboard);
+    assert!(matches!(
+        shapes(&replay),
+        [DrawnShape {
+            shape: Shape::Rect { thick: 9.0, .. },
+            ..
+        }]
+    ));
+    let transparent = replay
+        .input_state
+        .canvas_set
+        .pages(BoardMode::Transparent)
+        .unwrap();
+    assert_eq!(transparent.active_page().shapes.len(), 1);
+}
+
+#[test]
+fn shapes_drawn_while_zoomed_are_stored_unzoomed() {
+    let mut backend = backend();
+    // The transparent board has nothing to magnify until the screen is frozen.
+    backend
+        .run(&key_chord(true, false, Key::Char('1')))
+        .unwrap();
+    assert_eq!(backend.requests(), [BackendRequest::ToggleFreeze]);
+    assert!(!backend.input_state.zoom_active());
+
+    let mut events = key_chord(true, false, Key::Char('w'));
+    events.push(ScriptEvent::PointerMotion { x: 0, y: 0 });
+    events.extend(key_chord(true, false, Key::Char('1')));
+    backend.run(&events).unwrap();
+    let level =
+        |backend: &HeadlessBackend| backend.input_state.zoom_view().map(|view| view.level());
+    assert_eq!(level(&backend), Some(2.0));
+    backend.run(&[ScriptEvent::Scroll { steps: -1 }]).unwrap();
+    assert_eq!(level(&backend), Some(2.5));
+    backend.run(&[ScriptEvent::Scroll { steps: 1 }]).unwrap();
+
+    // Moving to (40, 40) pans the view to canvas (20, 20); the drag itself does not pan.
+    backend.run(&draw_red_rect()).unwrap();
+    assert!(matches!(
+        shapes(&backend),
+        [DrawnShape {
+            shape: Shape::Rect {
+                x: 40,
+                y: 40,
+                w: 50,
+                h: 40,
+                ..
+            },
+            ..
+        }]
+    ));
+    // The left edge at canvas x = 40 shows up magnified at screen x = 40.
+    let edge = u32::from_ne_bytes(pixel(&backend, 40, 80));
+    assert_eq!(edge >> 8, 0xffff00, "zoomed outline should be opaque red");
+
+    backend
+        .run(&[ScriptEvent::KeyPress { key: Key::Escape }])
+        .unwrap();
+    assert_eq!(level(&backend), None);
+    assert!(!backend.input_state.should_exit, "Escape leaves zoom first");
+}
+
+#[test]
+fn laser_trail_fades_without_drawing_anything() {
+    let mut backend = backend();
+    let mut events = key_chord(false, false, Key::Char('l'));
+    events.extend(drag((50, 50), (150, 50)));
+    backend.run(&events).unwrap();
+    assert_eq!(backend.input_state.active_tool(), Tool::Laser);
+    assert!(shapes(&backend).is_empty(), "the laser never draws");
+    assert_ne!(pixel(&backend, 150, 50), [0; 4], "dot under the pointer");
+    assert_ne!(pixel(&backend, 75, 50), [0; 4], "trail behind the dot");
+
+    let fade = Config::default().ui.laser.trail_duration_ms;
+    backend.run(&[ScriptEvent::Wait { ms: fade }]).unwrap();
+    assert_eq!(pixel(&backend, 75, 50), [0; 4], "trail faded out");
+    assert_ne!(
+        pixel(&backend, 150, 50),
+        [0; 4],
+        "dot stays while the tool is active"
+    );
+
+    backend
+        .run(&key_chord(false, false, Key::Char('f')))
+        .unwrap();
+    assert_eq!(pixel(&backend, 150, 50), [0; 4]);
+}
+
+#[test]
+fn vanishing_ink_expires_without_undo_history() {
+    let mut backend = backend();
+    backend.run(&draw_red_rect()).unwrap();
+    let mut events = key_chord(true, true, Key::Char('V'));
+    events.extend(drag((200, 200), (260, 220)));
+    backend.run(&events).unwrap();
+    assert!(backend.input_state.vanishing_ink_enabled());
+    assert_eq!(shapes(&backend).len(), 2);
+
+    let lifetime = (Config::default().drawing.vanishing_ink_seconds * 1000.0) as u64;
+    backend.run(&[ScriptEvent::Wait { ms: lifetime }]).unwrap();
+    assert!(matches!(
+        shapes(&backend),
+        [DrawnShape {
+            shape: Shape::Rect { .. },
+            ..
+        }]
+    ));
+    let frame = backend.input_state.canvas_set.active_frame();
+    assert_eq!(frame.undo_stack_len(), 1, "expiry is not recorded");
+    assert!(!frame.has_vanishing_shapes());
+
+    // Undo skips the expired stroke and takes back the rectangle.
+    backend
+        .run(&key_chord(true, false, Key::Char('z')))
+        .unwrap();
+    assert!(shapes(&backend).is_empty());
+}
+
+#[test]
+fn vanishing_ink_expires_on_pages_that_are_not_shown() {
+    let mut backend = backend();
+    let mut events = key_chord(true, true, Key::Char('V'));
+    events.extend(drag((200, 200), (260, 220)));
+    backend.run(&events).unwrap();
+    backend.input_state.handle_action(Action::AddPage);
+    backend.input_state.handle_action(Action::ToggleWhiteboard);
+
+    let lifetime = (Config::default().drawing.vanishing_ink_seconds * 1000.0) as u64;
+    backend.run(&[ScriptEvent::Wait { ms: lifetime }]).unwrap();
+    let transparent = backend
+        .input_state
+        .canvas_set
+        .pages(BoardMode::Transparent)
+        .unwrap();
+    assert!(transparent.pages()[0].shapes.is_empty());
+    assert!(!transparent.pages()[0].has_vanishing_shapes());
+}
+
+#[test]
+fn spotlight_dims_outside_the_region_without_drawing() {
+    let mut backend = backend();
+    backend
+        .run(&key_chord(true, false, Key::Char('w')))
+        .unwrap();
+    let board = pixel(&backend, 10, 10);
+
+    let mut events = key_chord(true, true, Key::Char('L'));
+    events.push(ScriptEvent::PointerMotion { x: 100, y: 100 });
+    backend.run(&events).unwrap();
+    assert!(backend.input_state.spotlight_active());
+    assert_eq!(pixel(&backend, 100, 100), board, "lit around the pointer");
+    assert!(pixel(&backend, 310, 230)[0] < board[0], "dimmed elsewhere");
+
+    // Dragging pins a region instead of drawing.
+    backend.run(&drag((200, 150), (300, 220))).unwrap();
+    assert!(shapes(&backend).is_empty());
+    assert_eq!(pixel(&backend, 250, 185), board);
+    assert!(pixel(&backend, 100, 100)[0] < board[0]);
+
+    backend
+        .run(&[ScriptEvent::KeyPress { key: Key::Escape }])
+        .unwrap();
+    assert!(!backend.input_state.spotlight_active());
+    assert!(
+        !backend.input_state.should_exit,
+        "Escape leaves spotlight first"
+    );
+    assert_eq!(pixel(&backend, 100, 100), board);
+}
+
+#[test]
+fn redactions_obscure_what_lies_beneath() {
+    let mut backend = backend();
+    let select_redact = [ScriptEvent::Toolbar {
+        event: ToolbarEvent::SelectTool(Tool::Redact),
+    }];
+
+    // The live transparent board has nothing to sample: ask for a freeze, fill solid meanwhile.
+    backend.run(&select_redact).unwrap();
+    assert_eq!(backend.requests(), [BackendRequest::ToggleFreeze]);
+    backend.run(&drag((20, 20), (60, 60))).unwrap();
+    assert!(matches!(shapes(&backend)[0].shape, Shape::Redact { .. }));
+    assert_eq!(pixel(&backend, 40, 40), [0, 0, 0, 255]);
+
+    let mut events = key_chord(true, false, Key::Char('w'));
+    events.extend(draw_red_rect());
+    backend.run(&events).unwrap();
+    let border = pixel(&backend, 40, 60);
+
+    let mut events = select_redact.to_vec();
+    events.extend(drag((20, 20), (100, 100)));
+    backend.run(&events).unwrap();
+    assert_eq!(shapes(&backend).len(), 2);
+    let obscured = pixel(&backend, 40, 60);
+    assert_ne!(obscured, border, "the rectangle border is no longer sharp");
+    assert_eq!(pixel(&backend, 33, 57), obscured, "one block, one color");
+}
+
+#[test]
+fn pressure_widths_carry_over_samples_without_pressure() {
+    let mut backend = backend();
+    let [down, press, middle, end, release] = drag((40, 100), (140, 100)).try_into().unwrap();
+    backend.input_state.set_pressure_width(Some(6.0));
+    backend.run(&[down, press, middle]).unwrap();
+    backend.input_state.set_pressure_width(None);
+    backend.run(&[end, release]).unwrap();
+
+    let [
+        DrawnShape {
+            shape:
+                Shape::Freehand {
+                    points,
+                    per_point_widths: Some(widths),
+                    ..
+                },
+            ..
+        },
+    ] = shapes(&backend)
+    else {
+        panic!("expected one freehand stroke with widths");
+    };
+    assert_eq!(widths.len(), points.len());
+    assert!(widths.iter().all(|&width| width == 6.0));
+}
+
+#[test]
+fn translucent_pressure_strokes_do_not_darken_at_joints() {
+    let mut backend = backend();
+    backend
+        .run(&[ScriptEvent::Toolbar {
+            event: ToolbarEvent::SetColor(Color { a: 0.5, ..RED }),
+        }])
+        .unwrap();
+    backend.input_state.set_pressure_width(Some(8.0));
+    backend.run(&drag((40, 100), (140, 100))).unwrap();
+
+    // (90, 100) is where the two segments of the stroke meet.
+    assert_eq!(pixel(&backend, 90, 100), pixel(&backend, 65, 100));
+}
+
+#[test]
+fn undo_brings_back_a_deleted_page() {
+    let mut backend = backend();
+    let position =
+        |backend: &HeadlessBackend| backend.input_state.canvas_set.active_page_position();
+    backend.run(&draw_red_rect()).unwrap();
+    backend.input_state.handle_action(Action::AddPage);
+    backend.input_state.handle_action(Action::DeletePage);
+    backend.input_state.handle_action(Action::DeletePage);
+    assert_eq!(position(&backend), (1, 1));
+
+    let undo = key_chord(true, false, Key::Char('z'));
+    backend.run(&undo).unwrap();
+    assert_eq!(position(&backend), (2, 2));
+    assert!(shapes(&backend).is_empty());
+}
+
+#[cfg(tablet)]
+#[test]
+fn stylus_pressure_widens_freehand_strokes() {
+    let mut backend = backend();
+    let max = Config::default().tablet.max_thickness;
+    backend
+        .run(&[
+            ScriptEvent::StylusMotion { x: 20, y: 20 },
+            ScriptEvent::StylusDown,
+            ScriptEvent::StylusPressure { pressure: 0.2 },
+            ScriptEvent::StylusMotion { x: 40, y: 25 },
+            ScriptEvent::StylusPressure { pressure: 1.0 },
+            ScriptEvent::StylusMotion { x: 60, y: 30 },
+            ScriptEvent::StylusUp,
+        ])
+        .unwrap();
+
+    let [
+        DrawnShape {
+            shape: Shape::Freehand {
+                per_point_widths, ..
+            },
+            ..
+        },
+    ] = shapes(&backend)
+    else {
+        panic!("expected one freehand stroke");
+    };
+    assert!(per_point_widths.is_some());
+    assert_eq!(backend.input_state.current_thickness, max);
+}
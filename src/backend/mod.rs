@@ -1,23 +1,108 @@
 use anyhow::Result;
+use std::path::PathBuf;
+use std::time::Duration;
 
+use crate::config::Config;
+use crate::input::{ClickHighlightSettings, InputState, LaserSettings, SpotlightSettings};
+
+pub mod headless;
+mod scene;
 pub mod wayland;
 
 // Removed: Backend trait - no longer needed with single backend
-// Removed: BackendChoice enum - Wayland is the only backend
+// Removed: BackendChoice enum - Wayland is the only interactive backend; `headless` replays
+// scripted and recorded input
 
 /// Run Wayland backend with full event loop
 ///
 /// # Arguments
 /// * `initial_mode` - Optional board mode to start in (overrides config default)
 /// * `freeze_on_start` - Whether to start with the overlay frozen for immediate capture pause
-pub fn run_wayland(initial_mode: Option<String>, freeze_on_start: bool) -> Result<()> {
-    let mut backend = wayland::WaylandBackend::new(initial_mode, freeze_on_start)?;
+/// * `open_document` - `.wayscriber` document to show instead of the saved session
+/// * `record_input` - File to log input events to for `--replay-input`
+pub fn run_wayland(
+    initial_mode: Option<String>,
+    freeze_on_start: bool,
+    open_document: Option<PathBuf>,
+    record_input: Option<PathBuf>,
+) -> Result<()> {
+    let mut backend =
+        wayland::WaylandBackend::new(initial_mode, freeze_on_start, open_document, record_input)?;
     backend.init()?;
     backend.show()?; // show() calls run() internally
     backend.hide()?;
     Ok(())
 }
 
+/// Builds the drawing state both backends start from, using the user's configuration.
+fn input_state_from_config(config: &Config) -> InputState {
+    // Create font descriptor from config
+    let font_descriptor = crate::draw::FontDescriptor::new(
+        config.drawing.font_family.clone(),
+        config.drawing.font_weight.clone(),
+        config.drawing.font_style.clone(),
+    );
+
+    // Build keybinding action map
+    let action_map = config
+        .keybindings
+        .build_action_map()
+        .expect("Failed to build keybinding action map");
+
+    // Initialize input state with config defaults
+    let mut input_state = InputState::with_defaults(
+        config.drawing.default_color.to_color(),
+        config.drawing.default_thickness,
+        config.drawing.default_eraser_size,
+        config.drawing.marker_opacity,
+        config.drawing.default_fill_enabled,
+        config.drawing.default_font_size,
+        font_descriptor,
+        config.drawing.text_background_enabled,
+        config.arrow.length,
+        config.arrow.angle_degrees,
+        config.ui.show_status_bar,
+        config.board.clone(),
+        action_map,
+        config.session.max_shapes_per_frame,
+        ClickHighlightSettings::from(&config.ui.click_highlight),
+        config.history.undo_all_delay_ms,
+        config.history.redo_all_delay_ms,
+        config.history.custom_section_enabled,
+        config.history.custom_undo_delay_ms,
+        config.history.custom_redo_delay_ms,
+        config.history.custom_undo_steps,
+        config.history.custom_redo_steps,
+        config.drawing.rainbow_hue_step_per_pixel,
+        config.drawing.default_rainbow_enabled,
+    );
+
+    input_state.set_hit_test_tolerance(config.drawing.hit_test_tolerance);
+    input_state.set_hit_test_threshold(config.drawing.hit_test_linear_threshold);
+    input_state.set_undo_stack_limit(config.drawing.undo_stack_limit);
+    input_state.set_context_menu_enabled(config.ui.context_menu.enabled);
+    input_state.set_laser_settings(LaserSettings::from(&config.ui.laser));
+    input_state.set_spotlight_settings(SpotlightSettings::from(&config.ui.spotlight));
+    input_state.set_vanishing_ink(
+        config.drawing.default_vanishing_ink_enabled,
+        Duration::from_secs_f64(config.drawing.vanishing_ink_seconds),
+    );
+    input_state.set_redact_mode(config.drawing.default_redact_mode);
+
+    // Initialize toolbar visibility from pinned config
+    input_state.init_toolbar_from_config(
+        config.ui.toolbar.top_pinned,
+        config.ui.toolbar.side_pinned,
+        config.ui.toolbar.use_icons,
+        config.ui.toolbar.show_more_colors,
+        config.ui.toolbar.show_actions_section,
+        config.ui.toolbar.show_delay_sliders,
+        config.ui.toolbar.show_marker_opacity_section,
+    );
+
+    input_state
+}
+
 #[cfg(test)]
 mod tests {
     #[test]
@@ -27,6 +112,6 @@ mod tests {
             eprintln!("WAYLAND_DISPLAY not set; skipping Wayland smoke test");
             return;
         }
-        super::run_wayland(None, false).expect("Wayland backend should start");
+        super::run_wayland(None, false, None, None).expect("Wayland backend should start");
     }
 }
@@ -0,0 +1,152 @@
+// Draws everything above the board background: committed shapes, in-progress previews and the
+// overlay UI. Shared by the Wayland and headless backends so both produce the same pixels.
+use std::collections::HashSet;
+use std::time::Instant;
+
+use log::debug;
+
+use crate::config::Config;
+use crate::draw::EraserReplayContext;
+use crate::input::{DrawingState, InputState};
+
+/// Output geometry and pointer state for one rendered frame.
+pub(crate) struct SceneView {
+    /// Logical output width.
+    pub width: u32,
+    /// Logical output height.
+    pub height: u32,
+    /// Last pointer position, used for provisional shapes.
+    pub pointer: (i32, i32),
+    /// Whether frozen mode is available (listed in the help overlay).
+    pub frozen_enabled: bool,
+    /// Timestamp used to animate click highlights.
+    pub now: Instant,
+}
+
+/// Renders the scene onto `ctx`, which must already be scaled to logical coordinates.
+///
+/// While zoom mode is active the canvas layers are magnified; the UI stays at screen scale.
+pub(crate) fn render_scene(
+    ctx: &cairo::Context,
+    input: &mut InputState,
+    config: &Config,
+    eraser_ctx: &EraserReplayContext,
+    view: SceneView,
+) {
+    let SceneView {
+        width,
+        height,
+        pointer: (mx, my),
+        frozen_enabled,
+        now,
+    } = view;
+
+    // Canvas layers below are drawn in canvas coordinates, magnified while zoomed
+    let _ = ctx.save();
+    if let Some(zoom) = input.zoom_view() {
+        zoom.apply(ctx, 1.0);
+    }
+    let (mx, my) = input.zoom_canvas_point(mx, my);
+
+    // Render all completed shapes from active frame
+    debug!(
+        "Rendering {} completed shapes",
+        input.canvas_set.active_frame().shapes.len()
+    );
+    crate::draw::render_shapes_with_opacity(
+        ctx,
+        &input.canvas_set.active_frame().shapes,
+        Some(eraser_ctx),
+        |drawn| input.vanishing_opacity(drawn.id, now),
+    );
+
+    // Render selection halo overlays
+    if input.has_selection() {
+        let selected: HashSet<_> = input.selected_shape_ids().iter().copied().collect();
+        let frame = input.canvas_set.active_frame();
+        for drawn in &frame.shapes {
+            if selected.contains(&drawn.id) {
+                crate::draw::render_selection_halo(ctx, drawn);
+            }
+        }
+    }
+
+    // Render provisional shape if actively drawing
+    // Use optimized method that avoids cloning for freehand
+    if input.render_provisional_shape(ctx, mx, my) {
+        debug!("Rendered provisional shape");
+    }
+
+    // Render text cursor/buffer if in text mode
+    if let DrawingState::TextInput { x, y, buffer } = &input.state {
+        let preview_text = if buffer.is_empty() {
+            "_".to_string() // Show cursor when buffer is empty
+        } else {
+            format!("{}_", buffer)
+        };
+        crate::draw::render_text(
+            ctx,
+            *x,
+            *y,
+            &preview_text,
+            input.current_color,
+            input.current_font_size,
+            &input.font_descriptor,
+            input.text_background_enabled,
+        );
+    }
+
+    // Spotlight dims the board, frozen image and shapes, but not the pointer aids or UI
+    input.render_spotlight(ctx);
+
+    // Render click highlight overlays before UI so status/help remain legible
+    input.render_click_highlights(ctx, now);
+    input.render_laser(ctx, now);
+    let _ = ctx.restore();
+
+    // Render frozen badge even if status bar is hidden
+    if input.frozen_active() && config.ui.show_frozen_badge {
+        crate::ui::render_frozen_badge(ctx, width, height);
+    }
+
+    // Render status bar if enabled
+    if input.show_status_bar {
+        crate::ui::render_status_bar(
+            ctx,
+            input,
+            config.ui.status_bar_position,
+            &config.ui.status_bar_style,
+            width,
+            height,
+        );
+    }
+
+    // Render help overlay if toggled
+    if input.show_help {
+        crate::ui::render_help_overlay(
+            ctx,
+            &config.ui.help_overlay_style,
+            width,
+            height,
+            frozen_enabled,
+        );
+    }
+
+    crate::ui::render_properties_panel(ctx, input, width, height);
+
+    if input.is_context_menu_open() {
+        input.update_context_menu_layout(ctx, width, height);
+    } else {
+        input.clear_context_menu_layout();
+    }
+
+    // Render context menu if open
+    crate::ui::render_context_menu(ctx, input, width, height);
+
+    // Region selector for capture actions sits above everything else
+    crate::ui::render_capture_region(ctx, input, width, height);
+
+    if input.is_recovery_prompt_open() {
+        crate::ui::render_recovery_prompt(ctx, width, height);
+    }
+}
@@ -4,7 +4,7 @@ use anyhow::{Context, Result};
 use log::{debug, info, warn};
 #[cfg(unix)]
 use signal_hook::{
-    consts::signal::{SIGINT, SIGTERM, SIGUSR1, SIGUSR2},
+    consts::signal::{SIGINT, SIGTERM, SIGUSR1},
     iterator::Signals,
 };
 use smithay_client_toolkit::{
@@ -23,7 +23,8 @@ use smithay_client_toolkit::{
 #[cfg(unix)]
 use std::thread;
 use std::{
-    env, fs,
+    env,
+    path::PathBuf,
     sync::{
         Arc,
         atomic::{AtomicBool, Ordering},
@@ -36,13 +37,14 @@ use wayland_client::{Connection, globals::registry_queue_init};
 use wayland_protocols::wp::tablet::zv2::client::zwp_tablet_manager_v2::ZwpTabletManagerV2;
 use wayland_protocols_wlr::screencopy::v1::client::zwlr_screencopy_manager_v1::ZwlrScreencopyManagerV1;
 
-use super::state::WaylandState;
+use super::{control::OverlayControl, state::WaylandState};
 use crate::{
     RESUME_SESSION_ENV,
+    backend::{headless::InputRecorder, input_state_from_config},
     capture::{CaptureManager, CaptureOutcome},
-    config::{Action, Config, ConfigSource},
-    input::{BoardMode, ClickHighlightSettings, InputState},
-    notification, paths, runtime_session_override, session,
+    config::{Config, ConfigSource},
+    input::BoardMode,
+    notification, paths, runtime_session_override, runtime_session_profile, session,
 };
 
 fn friendly_capture_error(error: &str) -> String {
@@ -59,55 +61,6 @@ fn friendly_capture_error(error: &str) -> String {
     }
 }
 
-fn process_tray_action(state: &mut WaylandState) {
-    let action_path = crate::paths::tray_action_file();
-    // Read-and-delete best effort; if a new action is written between read and delete
-    // it will be picked up on the next signal/start.
-    let action_str = match fs::read_to_string(&action_path) {
-        Ok(content) => content.lines().next().unwrap_or("").trim().to_string(),
-        Err(err) => {
-            if err.kind() != std::io::ErrorKind::NotFound {
-                warn!(
-                    "Tray action signal received but failed to read {}: {}",
-                    action_path.display(),
-                    err
-                );
-            }
-            return;
-        }
-    };
-
-    if action_str.is_empty() {
-        let _ = fs::remove_file(&action_path);
-        return;
-    }
-
-    match action_str.as_str() {
-        "toggle_freeze" => {
-            state.input_state.request_frozen_toggle();
-            state.input_state.needs_redraw = true;
-        }
-        "capture_full" => state.handle_capture_action(Action::CaptureFullScreen),
-        "capture_window" => state.handle_capture_action(Action::CaptureActiveWindow),
-        "capture_region" => {
-            // Honor clipboard preference for region captures
-            if state.config.capture.copy_to_clipboard {
-                state.handle_capture_action(Action::CaptureClipboardRegion);
-            } else {
-                state.handle_capture_action(Action::CaptureFileRegion);
-            }
-        }
-        "toggle_help" => {
-            state.input_state.show_help = !state.input_state.show_help;
-            state.input_state.dirty_tracker.mark_full();
-            state.input_state.needs_redraw = true;
-        }
-        other => warn!("Unknown tray action '{}'", other),
-    }
-
-    let _ = fs::remove_file(&action_path);
-}
-
 fn resume_override_from_env() -> Option<bool> {
     if let Some(runtime) = runtime_session_override() {
         return Some(runtime);
@@ -135,17 +88,26 @@ fn resume_override_from_env() -> Option<bool> {
 pub struct WaylandBackend {
     initial_mode: Option<String>,
     freeze_on_start: bool,
+    open_document: Option<PathBuf>,
+    record_input: Option<PathBuf>,
     /// Tokio runtime for async capture operations
     tokio_runtime: tokio::runtime::Runtime,
 }
 
 impl WaylandBackend {
-    pub fn new(initial_mode: Option<String>, freeze_on_start: bool) -> Result<Self> {
+    pub fn new(
+        initial_mode: Option<String>,
+        freeze_on_start: bool,
+        open_document: Option<PathBuf>,
+        record_input: Option<PathBuf>,
+    ) -> Result<Self> {
         let tokio_runtime = tokio::runtime::Runtime::new()
             .context("Failed to create Tokio runtime for capture operations")?;
         Ok(Self {
             initial_mode,
             freeze_on_start,
+            open_document,
+            record_input,
             tokio_runtime,
         })
     }
@@ -312,6 +274,24 @@ impl WaylandBackend {
             }
             None => {}
         }
+        if let (Some(name), Some(options)) = (runtime_session_profile(), session_options.as_mut()) {
+            match session::apply_profile(options, &name) {
+                Ok(()) => info!(
+                    "Using named session '{}' ({})",
+                    name,
+                    options.base_dir.display()
+                ),
+                Err(err) => {
+                    // Never fall back to the default session's files for a named one.
+                    warn!("Session persistence disabled: {:#}", err);
+                    session_options = None;
+                }
+            }
+        }
+        if self.open_document.is_some() && session_options.take().is_some() {
+            // The saved session stays untouched while a document is open.
+            info!("Opening a document; session persistence is off for this run");
+        }
 
         #[cfg(tablet)]
         let tablet_manager = if config.tablet.enabled {
@@ -355,62 +335,7 @@ impl WaylandBackend {
             xdg_fullscreen = false;
         }
 
-        // Create font descriptor from config
-        let font_descriptor = crate::draw::FontDescriptor::new(
-            config.drawing.font_family.clone(),
-            config.drawing.font_weight.clone(),
-            config.drawing.font_style.clone(),
-        );
-
-        // Build keybinding action map
-        let action_map = config
-            .keybindings
-            .build_action_map()
-            .expect("Failed to build keybinding action map");
-
-        // Initialize input state with config defaults
-        let mut input_state = InputState::with_defaults(
-            config.drawing.default_color.to_color(),
-            config.drawing.default_thickness,
-            config.drawing.default_eraser_size,
-            config.drawing.marker_opacity,
-            config.drawing.default_fill_enabled,
-            config.drawing.default_font_size,
-            font_descriptor,
-            config.drawing.text_background_enabled,
-            config.arrow.length,
-            config.arrow.angle_degrees,
-            config.ui.show_status_bar,
-            config.board.clone(),
-            action_map,
-            config.session.max_shapes_per_frame,
-            ClickHighlightSettings::from(&config.ui.click_highlight),
-            config.history.undo_all_delay_ms,
-            config.history.redo_all_delay_ms,
-            config.history.custom_section_enabled,
-            config.history.custom_undo_delay_ms,
-            config.history.custom_redo_delay_ms,
-            config.history.custom_undo_steps,
-            config.history.custom_redo_steps,
-            config.drawing.rainbow_hue_step_per_pixel,
-            config.drawing.default_rainbow_enabled,
-        );
-
-        input_state.set_hit_test_tolerance(config.drawing.hit_test_tolerance);
-        input_state.set_hit_test_threshold(config.drawing.hit_test_linear_threshold);
-        input_state.set_undo_stack_limit(config.drawing.undo_stack_limit);
-        input_state.set_context_menu_enabled(config.ui.context_menu.enabled);
-
-        // Initialize toolbar visibility from pinned config
-        input_state.init_toolbar_from_config(
-            config.ui.toolbar.top_pinned,
-            config.ui.toolbar.side_pinned,
-            config.ui.toolbar.use_icons,
-            config.ui.toolbar.show_more_colors,
-            config.ui.toolbar.show_actions_section,
-            config.ui.toolbar.show_delay_sliders,
-            config.ui.toolbar.show_marker_opacity_section,
-        );
+        let mut input_state = input_state_from_config(&config);
 
         // Apply initial mode from CLI (if provided) or config default (only if board modes enabled)
         if config.board.enabled {
@@ -480,54 +405,43 @@ impl WaylandBackend {
             tablet_manager,
         );
 
+        state.set_pending_open_document(self.open_document.clone());
+        if let Some(path) = self.record_input.as_deref() {
+            state.set_input_recorder(InputRecorder::create(path)?);
+        }
+
         // Ensure pinned toolbars are created immediately if visible on startup.
         state.sync_toolbar_visibility(&qh);
-        // Process any pending tray action that may have been queued before overlay start.
-        process_tray_action(&mut state);
+        // Serve the overlay control socket so the daemon and scripts can drive this overlay.
+        let control = OverlayControl::start(&conn, &qh);
 
         // Gracefully exit the overlay when external signals request termination
         #[cfg(unix)]
-        let (exit_flag, tray_action_flag): (
-            Option<Arc<AtomicBool>>,
-            Option<Arc<AtomicBool>>,
-        ) = {
+        let exit_flag: Option<Arc<AtomicBool>> = {
             let exit_flag = Arc::new(AtomicBool::new(false));
-            let tray_action_flag = Arc::new(AtomicBool::new(false));
-            match Signals::new([SIGTERM, SIGINT, SIGUSR1, SIGUSR2]) {
+            match Signals::new([SIGTERM, SIGINT, SIGUSR1]) {
                 Ok(mut signals) => {
                     let exit_flag_clone = Arc::clone(&exit_flag);
-                    let tray_action_flag_clone = Arc::clone(&tray_action_flag);
                     thread::spawn(move || {
                         for sig in signals.forever() {
-                            match sig {
-                                SIGUSR2 => {
-                                    debug!("Overlay received SIGUSR2 for tray action");
-                                    tray_action_flag_clone.store(true, Ordering::Release);
-                                }
-                                _ => {
-                                    debug!(
-                                        "Overlay received signal {}; scheduling graceful shutdown",
-                                        sig
-                                    );
-                                    exit_flag_clone.store(true, Ordering::Release);
-                                }
-                            }
+                            debug!(
+                                "Overlay received signal {}; scheduling graceful shutdown",
+                                sig
+                            );
+                            exit_flag_clone.store(true, Ordering::Release);
                         }
                     });
-                    (Some(exit_flag), Some(tray_action_flag))
+                    Some(exit_flag)
                 }
                 Err(err) => {
                     warn!("Failed to register overlay signal handlers: {}", err);
-                    (Some(exit_flag), Some(tray_action_flag))
+                    Some(exit_flag)
                 }
             }
         };
 
         #[cfg(not(unix))]
-        let (exit_flag, tray_action_flag): (
-            Option<Arc<AtomicBool>>,
-            Option<Arc<AtomicBool>>,
-        ) = (None, None);
+        let exit_flag: Option<Arc<AtomicBool>> = None;
 
         // Create surface using layer-shell when available, otherwise fall back to xdg-shell
         let wl_surface = state.compositor_state.create_surface(&qh);
@@ -584,6 +498,21 @@ impl WaylandBackend {
         let mut consecutive_render_failures = 0u32;
         const MAX_RENDER_FAILURES: u32 = 10;
 
+        // Autosave writes happen on a worker thread; it wakes the loop when a timed save is due.
+        let mut autosaver = state.session_options().and_then(|options| {
+            let conn = conn.clone();
+            let qh = qh.clone();
+            session::Autosaver::start(options, move || {
+                conn.display().sync(&qh, ());
+                if let Err(err) = conn.flush() {
+                    debug!(
+                        "Failed to flush Wayland connection for autosave wakeup: {}",
+                        err
+                    );
+                }
+            })
+        });
+
         // Main event loop
         let mut loop_error: Option<anyhow::Error> = None;
         loop {
@@ -606,14 +535,6 @@ impl WaylandBackend {
                 .frozen
                 .poll_portal_capture(&mut state.surface, &mut state.input_state);
 
-            if tray_action_flag
-                .as_ref()
-                .map(|flag| flag.swap(false, Ordering::AcqRel))
-                .unwrap_or(false)
-            {
-                process_tray_action(&mut state);
-            }
-
             // Dispatch all pending events (blocking) but check should_exit after each batch
             match event_queue.blocking_dispatch(&mut state) {
                 Ok(_) => {
@@ -622,6 +543,10 @@ impl WaylandBackend {
                         info!("Exit requested after dispatch, breaking event loop");
                         break;
                     }
+                    // Apply commands forwarded through the control socket.
+                    if let Some(control) = control.as_ref() {
+                        control.process(&mut state);
+                    }
                     // Adjust keyboard interactivity if toolbar visibility changed.
                     state.sync_toolbar_visibility(&qh);
 
@@ -649,6 +574,8 @@ impl WaylandBackend {
                     warn!(
                         "Frozen mode disabled on this compositor (xdg fallback); ignoring toggle"
                     );
+                    // Lets a zoom waiting for the frozen image give up
+                    state.input_state.set_frozen_active(false);
                 } else if state.frozen.is_in_progress() {
                     warn!("Frozen capture already in progress; ignoring toggle");
                 } else if state.input_state.frozen_active() {
@@ -676,6 +603,22 @@ impl WaylandBackend {
                 }
             }
 
+            if state.input_state.take_pending_svg_export() {
+                state.export_svg();
+            }
+
+            if state.input_state.take_pending_pdf_export() {
+                state.export_pdf();
+            }
+
+            if state.input_state.take_pending_document_save() {
+                state.save_document();
+            }
+
+            if state.input_state.take_pending_document_open() {
+                state.open_latest_document();
+            }
+
             // Check for completed capture operations
             if state.capture.is_in_progress() {
                 if let Some(outcome) = state.capture.manager_mut().try_take_result() {
@@ -788,13 +731,30 @@ impl WaylandBackend {
             } else if state.input_state.needs_redraw && state.surface.frame_callback_pending() {
                 debug!("Main loop: Skipping render - frame callback already pending");
             }
+
+            if let Some(restore) = state.input_state.take_recovery_choice() {
+                state.resolve_session_recovery(restore);
+            }
+            if let Some(autosaver) = autosaver.as_mut() {
+                state.autosave_if_due(autosaver);
+            }
+
+            // Let status bars following `ctl watch` see tool, color and mode changes.
+            if let Some(control) = control.as_ref() {
+                control.publish_status(&state);
+            }
         }
 
         info!("Wayland backend exiting");
 
+        // Let queued autosave writes finish before the final save takes the session lock.
+        drop(autosaver);
+
+        let mut saved_cleanly = true;
         if let Some(options) = state.session_options() {
             if let Some(snapshot) = session::snapshot_from_input(&state.input_state, options) {
                 if let Err(err) = session::save_snapshot(&snapshot, options) {
+                    saved_cleanly = false;
                     warn!("Failed to save session state: {}", err);
                     notification::send_notification_async(
                         &state.tokio_handle,
@@ -806,6 +766,8 @@ impl WaylandBackend {
             }
         }
 
+        state.finish_session(saved_cleanly);
+
         // Return error if loop exited due to error, otherwise success
         match loop_error {
             Some(e) => Err(e),
@@ -0,0 +1,177 @@
+// Serves the overlay control socket and applies forwarded commands on the event-loop thread.
+// Requests are queued from the socket thread, which then issues a display sync so the blocking
+// Wayland dispatch returns and the main loop answers immediately instead of on the next input.
+use log::{debug, info, warn};
+use std::sync::mpsc;
+use std::time::Duration;
+use wayland_client::{Connection, QueueHandle};
+
+use super::state::WaylandState;
+use crate::{
+    control::{
+        ControlRequest, ControlResponse, ControlServer, ControlStatus, OverlayStatus, StatusFeed,
+        board_name, capture_action, parse_color, tool_name,
+    },
+    input::{BoardMode, Tool},
+    paths,
+    ui::toolbar::ToolbarEvent,
+    util,
+};
+
+/// How long a socket client waits for the event loop to apply its request.
+const REPLY_TIMEOUT: Duration = Duration::from_secs(2);
+
+struct PendingRequest {
+    request: ControlRequest,
+    reply: mpsc::Sender<ControlResponse>,
+}
+
+/// Overlay end of the control protocol; lives for as long as the event loop runs.
+pub(super) struct OverlayControl {
+    requests: mpsc::Receiver<PendingRequest>,
+    feed: StatusFeed,
+    _server: ControlServer,
+}
+
+impl OverlayControl {
+    /// Starts serving `paths::overlay_socket()`. Returns `None` if the socket cannot be bound.
+    pub(super) fn start(conn: &Connection, qh: &QueueHandle<WaylandState>) -> Option<Self> {
+        let (sender, requests) = mpsc::channel();
+        let conn = conn.clone();
+        let qh = qh.clone();
+        let handler = move |request: ControlRequest| {
+            let (reply, response) = mpsc::channel();
+            if sender.send(PendingRequest { request, reply }).is_err() {
+                return ControlResponse::error("overlay is shutting down", None);
+            }
+            conn.display().sync(&qh, ());
+            if let Err(err) = conn.flush() {
+                debug!(
+                    "Failed to flush Wayland connection for control wakeup: {}",
+                    err
+                );
+            }
+            response
+                .recv_timeout(REPLY_TIMEOUT)
+                .unwrap_or_else(|_| ControlResponse::error("overlay did not respond in time", None))
+        };
+
+        let feed = StatusFeed::default();
+        match ControlServer::bind(paths::overlay_socket(), handler, feed.clone()) {
+            Ok(server) => Some(Self {
+                requests,
+                feed,
+                _server: server,
+            }),
+            Err(err) => {
+                warn!("Overlay control socket unavailable: {}", err);
+                None
+            }
+        }
+    }
+
+    /// Applies every queued request and sends back the resulting status.
+    pub(super) fn process(&self, state: &mut WaylandState) {
+        while let Ok(pending) = self.requests.try_recv() {
+            let response = state.handle_control_request(pending.request);
+            if pending.reply.send(response).is_err() {
+                debug!("Control client went away before the reply was ready");
+            }
+        }
+    }
+
+    /// Pushes the current state to `watch` clients; unchanged states are dropped by the feed.
+    pub(super) fn publish_status(&self, state: &WaylandState) {
+        self.feed.publish(state.control_status());
+    }
+}
+
+impl WaylandState {
+    fn handle_control_request(&mut self, request: ControlRequest) -> ControlResponse {
+        match self.apply_control_request(request) {
+            Ok(()) => ControlResponse::ok(self.control_status()),
+            Err(message) => ControlResponse::error(message, Some(self.control_status())),
+        }
+    }
+
+    fn apply_control_request(&mut self, request: ControlRequest) -> Result<(), String> {
+        match request {
+            ControlRequest::Status | ControlRequest::Show => {}
+            ControlRequest::Watch => {
+                return Err("watch requires a streaming connection".to_string());
+            }
+            ControlRequest::Toggle | ControlRequest::Hide => {
+                info!("Overlay exit requested via control socket");
+                self.input_state.should_exit = true;
+            }
+            ControlRequest::Tool { tool } => {
+                let parsed = tool
+                    .parse::<Tool>()
+                    .map_err(|_| format!("unknown tool '{tool}'"))?;
+                self.handle_toolbar_event(ToolbarEvent::SelectTool(parsed));
+            }
+            ControlRequest::Color { color } => {
+                let parsed = parse_color(&color)?;
+                self.handle_toolbar_event(ToolbarEvent::SetColor(parsed));
+            }
+            ControlRequest::Board { board } => {
+                let mode = board
+                    .parse::<BoardMode>()
+                    .map_err(|_| format!("unknown board '{board}'"))?;
+                self.input_state.switch_board_mode(mode);
+                self.toolbar.mark_dirty();
+            }
+            ControlRequest::Capture {
+                target,
+                destination,
+                annotated,
+            } => {
+                if !self.config.capture.enabled {
+                    return Err("capture is disabled in config".to_string());
+                }
+                if self.capture.is_in_progress() {
+                    return Err("a capture is already in progress".to_string());
+                }
+                let action = capture_action(
+                    target,
+                    destination,
+                    annotated,
+                    self.config.capture.copy_to_clipboard,
+                )?;
+                // Region captures open the in-overlay selector; the rest are queued directly.
+                self.input_state.handle_action(action);
+                if let Some(action) = self.input_state.take_pending_capture_action() {
+                    self.handle_capture_action(action);
+                }
+            }
+            ControlRequest::Freeze => {
+                if !self.frozen_enabled() {
+                    return Err("frozen mode is not available on this compositor".to_string());
+                }
+                self.handle_toolbar_event(ToolbarEvent::ToggleFreeze);
+            }
+            ControlRequest::Help => {
+                self.input_state.show_help = !self.input_state.show_help;
+                self.input_state.dirty_tracker.mark_full();
+                self.input_state.needs_redraw = true;
+            }
+            ControlRequest::Undo => self.handle_toolbar_event(ToolbarEvent::Undo),
+            ControlRequest::Clear => self.handle_toolbar_event(ToolbarEvent::ClearCanvas),
+        }
+        Ok(())
+    }
+
+    fn control_status(&self) -> ControlStatus {
+        ControlStatus {
+            visible: !self.input_state.should_exit,
+            overlay: Some(OverlayStatus {
+                board: board_name(self.input_state.board_mode()).to_string(),
+                tool: tool_name(self.input_state.active_tool()).to_string(),
+                color: util::color_to_hex(&self.input_state.current_color),
+                thickness: self.input_state.current_thickness,
+                frozen: self.input_state.frozen_active(),
+                capture_in_progress: self.capture.is_in_progress(),
+            }),
+        }
+    }
+}
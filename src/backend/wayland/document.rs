@@ -0,0 +1,248 @@
+//! Saving and opening `.wayscriber` documents from the overlay.
+//!
+//! Documents are written next to screenshots, like the SVG and PDF exports. While frozen, the
+//! screenshot is embedded as a PNG and becomes the frozen background again when the document is
+//! opened. Redactions on the transparent board are burned into the embedded screenshot, so
+//! deleting them from the document cannot reveal what they covered.
+
+use anyhow::{Context, Result, anyhow};
+use log::{info, warn};
+use std::path::Path;
+
+use super::frozen::FrozenImage;
+use super::state::WaylandState;
+use crate::capture::file::expand_tilde;
+use crate::capture::sources::frozen::decode_image_to_argb;
+use crate::draw::{DrawnShape, Shape, render_redactions};
+use crate::input::BoardMode;
+use crate::session::{self, DOCUMENT_EXTENSION, DocumentBackground, SessionDocument};
+
+impl WaylandState {
+    /// Saves every board, its history and the frozen background as a new document.
+    pub(super) fn save_document(&mut self) {
+        let options = session::document_options(self.config.session.max_shapes_per_frame);
+        let Some(snapshot) = session::snapshot_from_input(&self.input_state, &options) else {
+            warn!("Nothing to save as a document");
+            return;
+        };
+        let redactions: Vec<DrawnShape> = self
+            .input_state
+            .canvas_set
+            .pages(BoardMode::Transparent)
+            .into_iter()
+            .flat_map(|pages| pages.pages())
+            .flat_map(|page| &page.shapes)
+            .filter(|drawn| matches!(drawn.shape, Shape::Redact { .. }))
+            .cloned()
+            .collect();
+        let logical_width = self.surface.width();
+        let background = self.frozen.image().and_then(|image| {
+            match encode_background(image, &redactions, logical_width) {
+                Ok(background) => Some(background),
+                Err(err) => {
+                    warn!("Saving document without its frozen background: {:#}", err);
+                    None
+                }
+            }
+        });
+
+        let document = SessionDocument {
+            snapshot,
+            background,
+        };
+        let result = self
+            .export_target_path(DOCUMENT_EXTENSION)
+            .and_then(|path| {
+                session::save_document(&path, &document)?;
+                Ok(path)
+            });
+        match result {
+            Ok(path) => {
+                info!("Saved document to {}", path.display());
+                self.notify_document("Document Saved", &path, "document-save");
+            }
+            Err(err) => {
+                warn!("Document save failed: {:#}", err);
+                self.notify_document_error("Document Save Failed", &err);
+            }
+        }
+    }
+
+    /// Opens the most recently saved document from the screenshot directory.
+    pub(super) fn open_latest_document(&mut self) {
+        let directory = expand_tilde(&self.config.capture.save_directory);
+        match session::latest_document(&directory) {
+            Some(path) => self.open_document(&path),
+            None => {
+                let err = anyhow!("no .{DOCUMENT_EXTENSION} files in {}", directory.display());
+                warn!("Document open failed: {}", err);
+                self.notify_document_error("Document Open Failed", &err);
+            }
+        }
+    }
+
+    /// Opens the document passed with `--open`, once the surface is on an output.
+    pub(super) fn open_pending_document(&mut self) {
+        if let Some(path) = self.take_pending_open_document() {
+            self.open_document(&path);
+        }
+    }
+
+    /// Replaces all boards with the contents of the document at `path`.
+    fn open_document(&mut self, path: &Path) {
+        match self.apply_document(path) {
+            Ok(()) => {
+                info!("Opened document {}", path.display());
+                self.notify_document("Document Opened", path, "document-open");
+            }
+            Err(err) => {
+                warn!("Document open failed: {:#}", err);
+                self.notify_document_error("Document Open Failed", &err);
+            }
+        }
+    }
+
+    fn apply_document(&mut self, path: &Path) -> Result<()> {
+        let max_shapes = self.config.session.max_shapes_per_frame;
+        let document = session::load_document(path, max_shapes)?;
+        // Decode before touching the boards so a broken image leaves the canvas as it was.
+        let background = match document.background {
+            Some(background) if self.frozen_enabled() => Some(decode_background(
+                &background,
+                self.surface.physical_dimensions(),
+            )?),
+            Some(_) => {
+                warn!("Frozen mode is unavailable; ignoring the document's background");
+                None
+            }
+            None => None,
+        };
+
+        self.input_state.clear_selection();
+        session::apply_snapshot(
+            &mut self.input_state,
+            document.snapshot,
+            &session::document_options(max_shapes),
+        );
+        match background {
+            Some(image) => self.frozen.set_image(image, &mut self.input_state),
+            None if self.input_state.frozen_active() => self.frozen.unfreeze(&mut self.input_state),
+            None => {}
+        }
+        self.input_state.dirty_tracker.mark_full();
+        self.input_state.needs_redraw = true;
+        self.record_session_state();
+        Ok(())
+    }
+
+    fn notify_document(&self, summary: &str, path: &Path, icon: &str) {
+        let filename = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        crate::notification::send_notification_async(
+            &self.tokio_handle,
+            summary.to_string(),
+            filename,
+            Some(icon.to_string()),
+        );
+    }
+
+    fn notify_document_error(&self, summary: &str, err: &anyhow::Error) {
+        crate::notification::send_notification_async(
+            &self.tokio_handle,
+            summary.to_string(),
+            err.to_string(),
+            Some("dialog-error".to_string()),
+        );
+    }
+}
+
+fn encode_background(
+    image: &FrozenImage,
+    redactions: &[DrawnShape],
+    logical_width: u32,
+) -> Result<DocumentBackground> {
+    let surface = cairo::ImageSurface::create_for_data(
+        image.data.clone(),
+        cairo::Format::ARgb32,
+        image.width as i32,
+        image.height as i32,
+        image.stride,
+    )
+    .context("failed to wrap frozen image")?;
+    if !redactions.is_empty() {
+        let ctx = cairo::Context::new(&surface).context("failed to redact frozen image")?;
+        if logical_width > 0 {
+            let scale = f64::from(image.width) / f64::from(logical_width);
+            ctx.scale(scale, scale);
+        }
+        render_redactions(&ctx, redactions);
+    }
+    surface.flush();
+    let mut png = Vec::new();
+    surface
+        .write_to_png(&mut png)
+        .context("failed to encode frozen image as PNG")?;
+    Ok(DocumentBackground {
+        width: image.width,
+        height: image.height,
+        png,
+    })
+}
+
+/// Decodes an embedded background and scales it to the surface's physical size.
+fn decode_background(
+    background: &DocumentBackground,
+    (target_width, target_height): (u32, u32),
+) -> Result<FrozenImage> {
+    let (data, width, height) = decode_image_to_argb(&background.png)
+        .map_err(|err| anyhow!("failed to decode document background: {err}"))?;
+    let image = FrozenImage {
+        width,
+        height,
+        stride: (width * 4) as i32,
+        data,
+    };
+    if (width, height) == (target_width, target_height) || target_width == 0 || target_height == 0 {
+        return Ok(image);
+    }
+
+    // Documents opened on a screen of another size stretch the background to fit.
+    let source = cairo::ImageSurface::create_for_data(
+        image.data,
+        cairo::Format::ARgb32,
+        width as i32,
+        height as i32,
+        image.stride,
+    )
+    .context("failed to wrap document background")?;
+    let mut scaled = cairo::ImageSurface::create(
+        cairo::Format::ARgb32,
+        target_width as i32,
+        target_height as i32,
+    )
+    .context("failed to allocate scaled background")?;
+    {
+        let ctx = cairo::Context::new(&scaled).context("failed to scale document background")?;
+        ctx.scale(
+            f64::from(target_width) / f64::from(width),
+            f64::from(target_height) / f64::from(height),
+        );
+        ctx.set_source_surface(&source, 0.0, 0.0)
+            .context("failed to scale document background")?;
+        ctx.paint().context("failed to scale document background")?;
+    }
+    scaled.flush();
+    let stride = scaled.stride();
+    let data = scaled
+        .data()
+        .context("failed to read scaled background")?
+        .to_vec();
+    Ok(FrozenImage {
+        width: target_width,
+        height: target_height,
+        stride,
+        data,
+    })
+}
@@ -52,6 +52,36 @@ pub struct FrozenImage {
     pub data: Vec<u8>,
 }
 
+impl FrozenImage {
+    /// Encodes the image as PNG, cropped to `region` (x, y, width, height in image pixels)
+    /// when given.
+    pub fn to_png(&self, region: Option<(i32, i32, u32, u32)>) -> Result<Vec<u8>> {
+        let source = cairo::ImageSurface::create_for_data(
+            self.data.clone(),
+            cairo::Format::ARgb32,
+            self.width as i32,
+            self.height as i32,
+            self.stride,
+        )
+        .context("failed to wrap frozen image")?;
+        let (x, y, width, height) = region.unwrap_or((0, 0, self.width, self.height));
+        let cropped =
+            cairo::ImageSurface::create(cairo::Format::ARgb32, width as i32, height as i32)
+                .context("failed to allocate cropped frozen image")?;
+        {
+            let ctx = cairo::Context::new(&cropped).context("failed to crop frozen image")?;
+            ctx.set_source_surface(&source, -f64::from(x), -f64::from(y))
+                .and_then(|_| ctx.paint())
+                .context("failed to crop frozen image")?;
+        }
+        let mut png = Vec::new();
+        cropped
+            .write_to_png(&mut png)
+            .context("failed to encode frozen image as PNG")?;
+        Ok(png)
+    }
+}
+
 /// Internal capture session tracking a single screencopy frame.
 struct CaptureSession {
     frame: ZwlrScreencopyFrameV1,
@@ -151,6 +181,10 @@ impl FrozenState {
         self.active_geometry = geometry;
     }
 
+    pub fn active_geometry(&self) -> Option<&OutputGeometry> {
+        self.active_geometry.as_ref()
+    }
+
     pub fn active_output_matches(&self, info_id: u32) -> bool {
         self.active_output_id == Some(info_id)
     }
@@ -179,6 +213,14 @@ impl FrozenState {
         }
     }
 
+    /// Freezes on a ready-made image, e.g. the background of an opened document.
+    pub fn set_image(&mut self, image: FrozenImage, input_state: &mut InputState) {
+        self.image = Some(image);
+        input_state.set_frozen_active(true);
+        input_state.dirty_tracker.mark_full();
+        input_state.needs_redraw = true;
+    }
+
     /// Toggle unfreeze: drop the image and mark redraw.
     pub fn unfreeze(&mut self, input_state: &mut InputState) {
         self.image = None;
@@ -1,3 +1,5 @@
+use crate::util::Rect;
+
 /// Geometry and scale details for the active output, used for cropping fallback captures.
 #[derive(Clone, Debug)]
 pub struct OutputGeometry {
@@ -54,6 +56,14 @@ mod tests {
         assert_eq!(geo.logical_height, 600);
     }
 
+    #[test]
+    fn local_rect_to_physical_offsets_by_output_origin() {
+        let geo = OutputGeometry::update_from(Some((1920, 0)), Some((1280, 720)), (800, 600), 2)
+            .expect("geometry");
+        let rect = Rect::new(10, 20, 100, 50).unwrap();
+        assert_eq!(geo.local_rect_to_physical(&rect), (3860, 40, 200, 100));
+    }
+
     #[test]
     fn update_from_rejects_invalid_scale_or_size() {
         assert!(OutputGeometry::update_from(None, Some((0, 600)), (800, 600), 1).is_none());
@@ -78,4 +88,16 @@ impl OutputGeometry {
             self.logical_y.saturating_mul(self.scale),
         )
     }
+
+    /// Maps a surface-local logical rectangle into physical pixels of a compositor-wide
+    /// screenshot (the coordinate space used by full-screen grim/portal captures).
+    pub fn local_rect_to_physical(&self, rect: &Rect) -> (i32, i32, u32, u32) {
+        let (origin_x, origin_y) = self.physical_origin();
+        (
+            origin_x.saturating_add(rect.x.saturating_mul(self.scale)),
+            origin_y.saturating_add(rect.y.saturating_mul(self.scale)),
+            (rect.width.max(0) as u32).saturating_mul(self.scale as u32),
+            (rect.height.max(0) as u32).saturating_mul(self.scale as u32),
+        )
+    }
 }
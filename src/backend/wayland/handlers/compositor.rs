@@ -105,6 +105,7 @@ impl CompositorHandler for WaylandState {
             self.set_pending_freeze_on_start(false);
             self.input_state.request_frozen_toggle();
         }
+        self.open_pending_document();
 
         let identity = self.output_identity_for(output);
 
@@ -153,6 +154,8 @@ impl CompositorHandler for WaylandState {
 
             if load_requested {
                 self.session.mark_loaded();
+                self.record_session_state();
+                self.check_session_recovery();
                 self.input_state.needs_redraw = true;
             }
         }
@@ -8,7 +8,7 @@ use wayland_client::{
     protocol::{wl_keyboard, wl_surface},
 };
 
-use crate::{input::Key, notification};
+use crate::{backend::headless::ScriptEvent, input::Key, notification};
 
 use super::super::state::WaylandState;
 
@@ -71,6 +71,7 @@ impl KeyboardHandler for WaylandState {
         let prev_thickness = self.input_state.current_thickness;
         let key = keysym_to_key(event.keysym);
         debug!("Key pressed: {:?}", key);
+        self.record_input(ScriptEvent::KeyPress { key });
         self.input_state.on_key_press(key);
         self.input_state.needs_redraw = true;
 
@@ -101,6 +102,7 @@ impl KeyboardHandler for WaylandState {
     ) {
         let key = keysym_to_key(event.keysym);
         debug!("Key released: {:?}", key);
+        self.record_input(ScriptEvent::KeyRelease { key });
         self.input_state.on_key_release(key);
     }
 
@@ -118,6 +120,11 @@ impl KeyboardHandler for WaylandState {
             "Modifiers: ctrl={} alt={} shift={}",
             modifiers.ctrl, modifiers.alt, modifiers.shift
         );
+        self.record_input(ScriptEvent::Modifiers {
+            shift: modifiers.shift,
+            ctrl: modifiers.ctrl,
+            alt: modifiers.alt,
+        });
         // Trust compositor-reported modifier state to reconcile any missed key release
         // events and avoid "stuck" modifiers.
         self.input_state
@@ -136,6 +143,7 @@ impl KeyboardHandler for WaylandState {
         let prev_thickness = self.input_state.current_thickness;
         let key = keysym_to_key(event.keysym);
         debug!("Key repeated: {:?}", key);
+        self.record_input(ScriptEvent::KeyPress { key });
         self.input_state.on_key_press(key);
         self.input_state.needs_redraw = true;
 
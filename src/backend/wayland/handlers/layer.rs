@@ -6,7 +6,7 @@ use smithay_client_toolkit::shell::wlr_layer::{
 use wayland_client::{Connection, QueueHandle};
 
 use super::super::state::WaylandState;
-use crate::session;
+use crate::{backend::headless::ScriptEvent, session};
 
 impl LayerShellHandler for WaylandState {
     fn closed(&mut self, _conn: &Connection, _qh: &QueueHandle<Self>, layer: &LayerSurface) {
@@ -44,6 +44,10 @@ impl LayerShellHandler for WaylandState {
                 info!("Surface size changed - recreating SlotPool");
             }
 
+            self.record_input(ScriptEvent::Resize {
+                width: self.surface.width(),
+                height: self.surface.height(),
+            });
             self.input_state
                 .update_screen_dimensions(self.surface.width(), self.surface.height());
             let (phys_w, phys_h) = self.surface.physical_dimensions();
@@ -105,6 +109,8 @@ impl LayerShellHandler for WaylandState {
                 // the identity and triggers a fresh load.
                 if load_succeeded {
                     self.session.mark_loaded();
+                    self.record_session_state();
+                    self.check_session_recovery();
                 }
                 self.input_state.needs_redraw = true;
             }
@@ -4,6 +4,7 @@ use smithay_client_toolkit::{
     delegate_compositor, delegate_keyboard, delegate_layer, delegate_output, delegate_pointer,
     delegate_registry, delegate_seat, delegate_shm, delegate_xdg_shell, delegate_xdg_window,
 };
+use wayland_client::{delegate_noop, protocol::wl_callback::WlCallback};
 
 use super::state::WaylandState;
 
@@ -17,6 +18,8 @@ delegate_pointer!(WaylandState);
 delegate_registry!(WaylandState);
 delegate_xdg_shell!(WaylandState);
 delegate_xdg_window!(WaylandState);
+// Display sync callbacks only exist to wake the event loop for control socket requests.
+delegate_noop!(WaylandState: ignore WlCallback);
 
 mod activation;
 mod buffer;
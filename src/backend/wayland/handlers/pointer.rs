@@ -5,6 +5,7 @@ use smithay_client_toolkit::seat::pointer::{
 };
 use wayland_client::{Connection, QueueHandle, protocol::wl_pointer};
 
+use crate::backend::headless::ScriptEvent;
 use crate::backend::wayland::toolbar_intent::intent_to_event;
 use crate::input::{MouseButton, Tool};
 
@@ -31,6 +32,7 @@ impl PointerHandler for WaylandState {
                     self.set_current_mouse(event.position.0 as i32, event.position.1 as i32);
                     if !on_toolbar {
                         let (mx, my) = self.current_mouse();
+                        let (mx, my) = self.input_state.zoom_canvas_point(mx, my);
                         self.input_state.update_pointer_position(mx, my);
                     }
                     if let Some(pointer) = self.themed_pointer.as_ref() {
@@ -87,8 +89,10 @@ impl PointerHandler for WaylandState {
                     }
                     self.set_current_mouse(event.position.0 as i32, event.position.1 as i32);
                     let (mx, my) = self.current_mouse();
-                    self.input_state.update_pointer_position(mx, my);
-                    self.input_state.on_mouse_motion(mx, my);
+                    self.record_input(ScriptEvent::PointerMotion { x: mx, y: my });
+                    let (cx, cy) = self.input_state.zoom_pointer_motion(mx, my);
+                    self.input_state.update_pointer_position(cx, cy);
+                    self.input_state.on_mouse_motion(cx, cy);
                 }
                 PointerEventKind::Press { button, .. } => {
                     if on_toolbar {
@@ -121,11 +125,10 @@ impl PointerHandler for WaylandState {
                         _ => continue,
                     };
 
-                    self.input_state.on_mouse_press(
-                        mb,
-                        event.position.0 as i32,
-                        event.position.1 as i32,
-                    );
+                    let (x, y) = (event.position.0 as i32, event.position.1 as i32);
+                    self.record_input(ScriptEvent::PointerPress { button: mb, x, y });
+                    let (x, y) = self.input_state.zoom_canvas_point(x, y);
+                    self.input_state.on_mouse_press(mb, x, y);
                     self.input_state.needs_redraw = true;
                 }
                 PointerEventKind::Release { button, .. } => {
@@ -144,11 +147,10 @@ impl PointerHandler for WaylandState {
                         _ => continue,
                     };
 
-                    self.input_state.on_mouse_release(
-                        mb,
-                        event.position.0 as i32,
-                        event.position.1 as i32,
-                    );
+                    let (x, y) = (event.position.0 as i32, event.position.1 as i32);
+                    self.record_input(ScriptEvent::PointerRelease { button: mb, x, y });
+                    let (x, y) = self.input_state.zoom_canvas_point(x, y);
+                    self.input_state.on_mouse_release(mb, x, y);
                     self.input_state.needs_redraw = true;
                 }
                 PointerEventKind::Axis { vertical, .. } => {
@@ -162,6 +164,21 @@ impl PointerHandler for WaylandState {
                     } else {
                         0
                     };
+                    if scroll_direction != 0 {
+                        self.record_input(ScriptEvent::Scroll {
+                            steps: scroll_direction,
+                        });
+                    }
+                    if self.input_state.zoom_view().is_some() {
+                        // Scrolling sets the magnification while zoomed
+                        self.input_state.adjust_zoom(scroll_direction);
+                        continue;
+                    }
+                    if self.input_state.spotlight_active() {
+                        // Scrolling sizes the lit region in spotlight mode
+                        self.input_state.adjust_spotlight(scroll_direction);
+                        continue;
+                    }
 
                     match scroll_direction.cmp(&0) {
                         std::cmp::Ordering::Greater if self.input_state.modifiers.shift => {
@@ -9,6 +9,7 @@ use wayland_protocols::wp::tablet::zv2::client::{
     zwp_tablet_tool_v2::ZwpTabletToolV2, zwp_tablet_v2::ZwpTabletV2,
 };
 
+use crate::backend::headless::ScriptEvent;
 use crate::backend::wayland::toolbar_intent::intent_to_event;
 use crate::input::MouseButton;
 
@@ -330,6 +331,7 @@ impl Dispatch<ZwpTabletToolV2, ()> for WaylandState {
                 }
                 state.stylus_pressure_thickness = None;
                 state.stylus_last_pos = None;
+                state.input_state.set_pressure_width(None);
             }
             Event::Down { .. } => {
                 if state.stylus_on_toolbar {
@@ -354,6 +356,7 @@ impl Dispatch<ZwpTabletToolV2, ()> for WaylandState {
                 if !state.stylus_on_overlay {
                     return;
                 }
+                state.record_input(ScriptEvent::StylusDown);
                 state.stylus_tip_down = true;
                 state.stylus_base_thickness = Some(state.input_state.current_thickness);
                 state.stylus_pressure_thickness = Some(state.input_state.current_thickness);
@@ -363,11 +366,9 @@ impl Dispatch<ZwpTabletToolV2, ()> for WaylandState {
                     state.current_mouse().0,
                     state.current_mouse().1
                 );
-                state.input_state.on_mouse_press(
-                    MouseButton::Left,
-                    state.current_mouse().0,
-                    state.current_mouse().1,
-                );
+                let (mx, my) = state.current_mouse();
+                let (mx, my) = state.input_state.zoom_canvas_point(mx, my);
+                state.input_state.on_mouse_press(MouseButton::Left, mx, my);
                 state.input_state.needs_redraw = true;
             }
             Event::Up => {
@@ -378,6 +379,7 @@ impl Dispatch<ZwpTabletToolV2, ()> for WaylandState {
                 if !state.stylus_on_overlay {
                     return;
                 }
+                state.record_input(ScriptEvent::StylusUp);
                 state.stylus_tip_down = false;
                 let final_thick = state
                     .stylus_peak_thickness
@@ -395,11 +397,13 @@ impl Dispatch<ZwpTabletToolV2, ()> for WaylandState {
                     state.current_mouse().0,
                     state.current_mouse().1
                 );
-                state.input_state.on_mouse_release(
-                    MouseButton::Left,
-                    state.current_mouse().0,
-                    state.current_mouse().1,
-                );
+                let (mx, my) = state.current_mouse();
+                let (mx, my) = state.input_state.zoom_canvas_point(mx, my);
+                state
+                    .input_state
+                    .on_mouse_release(MouseButton::Left, mx, my);
+                // Pressure widths only apply while the tip is down.
+                state.input_state.set_pressure_width(None);
                 state.input_state.needs_redraw = true;
             }
             Event::Motion { x, y } => {
@@ -431,6 +435,8 @@ impl Dispatch<ZwpTabletToolV2, ()> for WaylandState {
                 let yf = y;
                 state.stylus_last_pos = Some((xf, yf));
                 let (mx, my) = state.current_mouse();
+                state.record_input(ScriptEvent::StylusMotion { x: mx, y: my });
+                let (mx, my) = state.input_state.zoom_pointer_motion(mx, my);
                 state.input_state.on_mouse_motion(mx, my);
                 if state.stylus_tip_down {
                     state.stylus_pressure_thickness = Some(state.input_state.current_thickness);
@@ -445,6 +451,7 @@ impl Dispatch<ZwpTabletToolV2, ()> for WaylandState {
                 debug!("Stylus pressure: {} (raw: {}/65535)", p01, pressure);
                 if pressure > 0 {
                     use crate::input::tablet::apply_pressure_to_state;
+                    state.record_input(ScriptEvent::StylusPressure { pressure: p01 });
                     apply_pressure_to_state(p01, &mut state.input_state, state.tablet_settings);
                     // Keep thickness monotonic during a stroke to avoid dips near lift.
                     let current = state.input_state.current_thickness;
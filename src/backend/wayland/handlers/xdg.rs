@@ -5,7 +5,7 @@ use smithay_client_toolkit::shell::xdg::window::{Window, WindowConfigure, Window
 use wayland_client::{Connection, QueueHandle};
 
 use super::super::state::WaylandState;
-use crate::session;
+use crate::{backend::headless::ScriptEvent, session};
 
 impl WindowHandler for WaylandState {
     fn request_close(&mut self, _conn: &Connection, _qh: &QueueHandle<Self>, _window: &Window) {
@@ -78,6 +78,10 @@ impl WindowHandler for WaylandState {
         }
 
         self.surface.set_configured(true);
+        self.record_input(ScriptEvent::Resize {
+            width: self.surface.width(),
+            height: self.surface.height(),
+        });
         self.input_state
             .update_screen_dimensions(self.surface.width(), self.surface.height());
         let (phys_w, phys_h) = self.surface.physical_dimensions();
@@ -129,9 +133,14 @@ impl WindowHandler for WaylandState {
                 // reloads when it sets a new output identity.
                 if load_succeeded {
                     self.session.mark_loaded();
+                    self.record_session_state();
+                    self.check_session_recovery();
                 }
                 self.input_state.needs_redraw = true;
             }
         }
+
+        // Without surface_enter there is no better moment to open a document given with --open.
+        self.open_pending_document();
     }
 }
@@ -1,8 +1,11 @@
 mod backend;
 mod capture;
+mod control;
+mod document;
 mod frozen;
 mod frozen_geometry;
 mod handlers;
+mod recovery;
 mod session;
 mod state;
 mod surface;
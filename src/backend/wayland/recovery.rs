@@ -0,0 +1,108 @@
+//! Crash recovery and background autosave for the overlay session.
+//!
+//! Each session the overlay loads is claimed in its lock file. Claiming a session whose previous
+//! owner died without releasing it brings up the recovery prompt for the autosave it left behind.
+
+use log::{debug, info, warn};
+use std::time::Instant;
+
+use super::state::WaylandState;
+use crate::session::{self, Autosaver};
+
+impl WaylandState {
+    /// Claims the current session and offers its autosave if the last shutdown was unclean.
+    ///
+    /// Called after each session load; a session is only claimed once per run.
+    pub(super) fn check_session_recovery(&mut self) {
+        let Some(options) = self
+            .session_options()
+            .filter(|options| options.autosave_enabled())
+            .cloned()
+        else {
+            return;
+        };
+        if !self.session.mark_claimed(&options) {
+            return;
+        }
+
+        match session::claim_session(&options) {
+            Ok(true) => {}
+            Ok(false) => {
+                // Anything left over belongs to a run that shut down cleanly or is still alive.
+                return;
+            }
+            Err(err) => {
+                warn!("Failed to claim session: {:#}", err);
+                return;
+            }
+        }
+
+        match session::load_autosave_snapshot(&options) {
+            Ok(Some(snapshot)) => {
+                info!(
+                    "Found autosave from an unclean shutdown at {}",
+                    options.autosave_file_path().display()
+                );
+                self.session.set_pending_recovery(snapshot, options);
+                self.input_state.open_recovery_prompt();
+            }
+            Ok(None) => debug!("Unclean shutdown detected but no autosave was written"),
+            Err(err) => warn!("Failed to read autosave: {:#}", err),
+        }
+    }
+
+    /// Applies the user's answer to the recovery prompt.
+    pub(super) fn resolve_session_recovery(&mut self, restore: bool) {
+        let Some((snapshot, options)) = self.session.take_pending_recovery() else {
+            return;
+        };
+        if restore {
+            info!(
+                "Restoring autosave from {}",
+                options.autosave_file_path().display()
+            );
+            session::apply_snapshot(&mut self.input_state, snapshot, &options);
+            self.record_session_state();
+        } else {
+            info!("Discarding autosave from the previous run");
+            if let Err(err) = session::discard_autosave(&options) {
+                warn!("Failed to remove autosave: {:#}", err);
+            }
+        }
+        self.input_state.dirty_tracker.mark_full();
+        self.input_state.needs_redraw = true;
+    }
+
+    /// Queues an autosave when enough edits or time have accumulated.
+    pub(super) fn autosave_if_due(&mut self, autosaver: &mut Autosaver) {
+        // Never overwrite the recovery copy before the user has decided what to do with it.
+        if self.session.has_pending_recovery() || self.input_state.is_recovery_prompt_open() {
+            return;
+        }
+        let Some(options) = self.session_options() else {
+            return;
+        };
+        let revision = self.input_state.canvas_set.revision();
+        if !autosaver.is_due(revision, Instant::now()) {
+            return;
+        }
+        let snapshot = session::snapshot_from_input(&self.input_state, options);
+        autosaver.save(snapshot, options, revision);
+    }
+
+    /// Marks the claimed sessions as shut down cleanly once the final save went through.
+    pub(super) fn finish_session(&self, saved_cleanly: bool) {
+        if !saved_cleanly || self.session.has_pending_recovery() {
+            // Keep the autosave and the owner record so the next start offers recovery again.
+            return;
+        }
+        for options in self.session.claimed() {
+            if let Err(err) = session::discard_autosave(options) {
+                warn!("Failed to remove autosave: {:#}", err);
+            }
+            if let Err(err) = session::release_session(options) {
+                warn!("Failed to release session: {:#}", err);
+            }
+        }
+    }
+}
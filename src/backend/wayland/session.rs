@@ -3,12 +3,16 @@
 //! Tracks the current session options and whether a snapshot has been loaded
 //! so WaylandState can coordinate persistence without storing extra fields.
 
-use crate::session::SessionOptions;
+use crate::session::{SessionOptions, SessionSnapshot};
 
 /// Tracks session persistence state and bookkeeping for per-output snapshots.
 pub struct SessionState {
     options: Option<SessionOptions>,
     loaded: bool,
+    /// Sessions this overlay has recorded itself as the owner of.
+    claimed: Vec<SessionOptions>,
+    /// Autosave found after an unclean shutdown, waiting for the user's answer.
+    pending_recovery: Option<(SessionSnapshot, SessionOptions)>,
 }
 
 impl SessionState {
@@ -17,6 +21,8 @@ impl SessionState {
         Self {
             options,
             loaded: false,
+            claimed: Vec::new(),
+            pending_recovery: None,
         }
     }
 
@@ -39,4 +45,38 @@ impl SessionState {
     pub fn mark_loaded(&mut self) {
         self.loaded = true;
     }
+
+    /// Records `options` as claimed; returns false if it was claimed before.
+    pub fn mark_claimed(&mut self, options: &SessionOptions) -> bool {
+        let lock_path = options.lock_file_path();
+        if self
+            .claimed
+            .iter()
+            .any(|claimed| claimed.lock_file_path() == lock_path)
+        {
+            return false;
+        }
+        self.claimed.push(options.clone());
+        true
+    }
+
+    /// Returns every session claimed during this run.
+    pub fn claimed(&self) -> &[SessionOptions] {
+        &self.claimed
+    }
+
+    /// Stores an autosave that is waiting to be restored or discarded.
+    pub fn set_pending_recovery(&mut self, snapshot: SessionSnapshot, options: SessionOptions) {
+        self.pending_recovery = Some((snapshot, options));
+    }
+
+    /// Returns true while a crash-recovery decision is outstanding.
+    pub fn has_pending_recovery(&self) -> bool {
+        self.pending_recovery.is_some()
+    }
+
+    /// Takes the pending autosave out once the user has answered.
+    pub fn take_pending_recovery(&mut self) -> Option<(SessionSnapshot, SessionOptions)> {
+        self.pending_recovery.take()
+    }
 }
@@ -37,22 +37,26 @@ use wayland_protocols::wp::tablet::zv2::client::{
 #[cfg(tablet)]
 use crate::input::tablet::TabletSettings;
 use crate::{
+    backend::{
+        headless::{InputRecorder, ScriptEvent},
+        scene::{SceneView, render_scene},
+    },
     capture::{
-        CaptureDestination, CaptureManager,
+        AnnotationOverlay, CaptureDestination, CaptureManager,
         file::{FileSaveConfig, expand_tilde},
         types::CaptureType,
     },
     config::{Action, ColorSpec, Config},
-    input::{DrawingState, InputState},
-    session::SessionOptions,
+    input::{BoardMode, InputState},
+    session::{self, SessionOptions},
     ui::toolbar::{ToolbarBindingHints, ToolbarEvent, ToolbarSnapshot},
     util::Rect,
 };
 
 use self::data::StateData;
 use super::{
-    capture::CaptureState, frozen::FrozenState, session::SessionState, surface::SurfaceState,
-    toolbar::ToolbarSurfaceManager,
+    capture::CaptureState, frozen::FrozenState, frozen_geometry::OutputGeometry,
+    session::SessionState, surface::SurfaceState, toolbar::ToolbarSurfaceManager,
 };
 
 mod data;
@@ -324,6 +328,49 @@ impl WaylandState {
         self.data.pending_freeze_on_start = value;
     }
 
+    pub(super) fn set_pending_open_document(&mut self, path: Option<std::path::PathBuf>) {
+        self.data.pending_open_document = path;
+    }
+
+    pub(super) fn take_pending_open_document(&mut self) -> Option<std::path::PathBuf> {
+        self.data.pending_open_document.take()
+    }
+
+    pub(super) fn set_input_recorder(&mut self, recorder: InputRecorder) {
+        self.data.input_recorder = Some(recorder);
+        self.record_session_state();
+    }
+
+    /// Writes the current boards and tool settings to the `--record-input` log, so a replay
+    /// starts from what is on screen. Called when recording starts and after every session load.
+    pub(super) fn record_session_state(&mut self) {
+        if self.data.input_recorder.is_none() {
+            return;
+        }
+        let options = session::document_options(usize::MAX);
+        let Some(snapshot) = session::snapshot_from_input(&self.input_state, &options) else {
+            return;
+        };
+        let profile = self
+            .session_options()
+            .and_then(|options| options.profile.clone());
+        match session::snapshot_to_value(&snapshot) {
+            Ok(state) => self.record_input(ScriptEvent::Session {
+                profile,
+                tool: self.input_state.tool_override(),
+                state,
+            }),
+            Err(err) => warn!("Failed to record session state: {:#}", err),
+        }
+    }
+
+    /// Appends `event` to the `--record-input` log, if recording.
+    pub(super) fn record_input(&mut self, event: ScriptEvent) {
+        if let Some(recorder) = self.data.input_recorder.as_mut() {
+            recorder.record(event);
+        }
+    }
+
     pub(super) fn pending_activation_token(&self) -> Option<String> {
         self.data.pending_activation_token.clone()
     }
@@ -559,6 +606,8 @@ impl WaylandState {
         let phys_height = height.saturating_mul(scale as u32);
         let now = Instant::now();
         let highlight_active = self.input_state.advance_click_highlights(now);
+        let laser_active = self.input_state.advance_laser(now);
+        let vanishing_active = self.input_state.advance_vanishing_ink(now);
         let mut eraser_pattern: Option<cairo::SurfacePattern> = None;
         let mut eraser_bg_color: Option<Color> = None;
 
@@ -632,6 +681,10 @@ impl WaylandState {
                 1.0
             };
             let _ = ctx.save();
+            // Magnify the screenshot along with the shapes while zoomed (buffer pixels here)
+            if let Some(zoom) = self.input_state.zoom_view() {
+                zoom.apply(&ctx, scale as f64);
+            }
             if (scale_x - 1.0).abs() > f64::EPSILON || (scale_y - 1.0).abs() > f64::EPSILON {
                 ctx.scale(scale_x, scale_y);
             }
@@ -669,105 +722,18 @@ impl WaylandState {
         if scale > 1 {
             ctx.scale(scale as f64, scale as f64);
         }
-        // Render all completed shapes from active frame
-        debug!(
-            "Rendering {} completed shapes",
-            self.input_state.canvas_set.active_frame().shapes.len()
-        );
         let eraser_ctx = crate::draw::EraserReplayContext {
             pattern: eraser_pattern.as_ref().map(|p| p as &cairo::Pattern),
             bg_color: eraser_bg_color,
         };
-        crate::draw::render_shapes(
-            &ctx,
-            &self.input_state.canvas_set.active_frame().shapes,
-            Some(&eraser_ctx),
-        );
-
-        // Render selection halo overlays
-        if self.input_state.has_selection() {
-            let selected: HashSet<_> = self
-                .input_state
-                .selected_shape_ids()
-                .iter()
-                .copied()
-                .collect();
-            let frame = self.input_state.canvas_set.active_frame();
-            for drawn in &frame.shapes {
-                if selected.contains(&drawn.id) {
-                    crate::draw::render_selection_halo(&ctx, drawn);
-                }
-            }
-        }
-
-        // Render provisional shape if actively drawing
-        // Use optimized method that avoids cloning for freehand
-        let (mx, my) = self.current_mouse();
-        if self.input_state.render_provisional_shape(&ctx, mx, my) {
-            debug!("Rendered provisional shape");
-        }
-
-        // Render text cursor/buffer if in text mode
-        if let DrawingState::TextInput { x, y, buffer } = &self.input_state.state {
-            let preview_text = if buffer.is_empty() {
-                "_".to_string() // Show cursor when buffer is empty
-            } else {
-                format!("{}_", buffer)
-            };
-            crate::draw::render_text(
-                &ctx,
-                *x,
-                *y,
-                &preview_text,
-                self.input_state.current_color,
-                self.input_state.current_font_size,
-                &self.input_state.font_descriptor,
-                self.input_state.text_background_enabled,
-            );
-        }
-
-        // Render click highlight overlays before UI so status/help remain legible
-        self.input_state.render_click_highlights(&ctx, now);
-
-        // Render frozen badge even if status bar is hidden
-        if self.input_state.frozen_active() && self.config.ui.show_frozen_badge {
-            crate::ui::render_frozen_badge(&ctx, width, height);
-        }
-
-        // Render status bar if enabled
-        if self.input_state.show_status_bar {
-            crate::ui::render_status_bar(
-                &ctx,
-                &self.input_state,
-                self.config.ui.status_bar_position,
-                &self.config.ui.status_bar_style,
-                width,
-                height,
-            );
-        }
-
-        // Render help overlay if toggled
-        if self.input_state.show_help {
-            crate::ui::render_help_overlay(
-                &ctx,
-                &self.config.ui.help_overlay_style,
-                width,
-                height,
-                self.frozen_enabled(),
-            );
-        }
-
-        crate::ui::render_properties_panel(&ctx, &self.input_state, width, height);
-
-        if self.input_state.is_context_menu_open() {
-            self.input_state
-                .update_context_menu_layout(&ctx, width, height);
-        } else {
-            self.input_state.clear_context_menu_layout();
-        }
-
-        // Render context menu if open
-        crate::ui::render_context_menu(&ctx, &self.input_state, width, height);
+        let view = SceneView {
+            width,
+            height,
+            pointer: self.current_mouse(),
+            frozen_enabled: self.frozen_enabled(),
+            now,
+        };
+        render_scene(&ctx, &mut self.input_state, &self.config, &eraser_ctx, view);
 
         let _ = ctx.restore();
 
@@ -828,7 +794,7 @@ impl WaylandState {
             self.render_toolbars(&snapshot);
         }
 
-        Ok(highlight_active)
+        Ok(highlight_active || laser_active || vanishing_active)
     }
 
     /// Returns a snapshot of the current input state for toolbar UI consumption.
@@ -839,6 +805,9 @@ impl WaylandState {
 
     /// Applies an incoming toolbar event and schedules redraws as needed.
     pub(super) fn handle_toolbar_event(&mut self, event: ToolbarEvent) {
+        self.record_input(ScriptEvent::Toolbar {
+            event: event.clone(),
+        });
         #[cfg(tablet)]
         let prev_thickness = self.input_state.current_thickness;
         #[cfg(tablet)]
@@ -984,63 +953,38 @@ impl WaylandState {
             CaptureDestination::FileOnly
         };
 
+        let selection = self.capture_selection_type();
         let (capture_type, destination) = match action {
             Action::CaptureFullScreen => (CaptureType::FullScreen, default_destination),
             Action::CaptureActiveWindow => (CaptureType::ActiveWindow, default_destination),
-            Action::CaptureSelection => (
-                CaptureType::Selection {
-                    x: 0,
-                    y: 0,
-                    width: 0,
-                    height: 0,
-                },
-                default_destination,
-            ),
+            Action::CaptureSelection => (selection, default_destination),
             Action::CaptureClipboardFull => {
                 (CaptureType::FullScreen, CaptureDestination::ClipboardOnly)
             }
             Action::CaptureFileFull => (CaptureType::FullScreen, CaptureDestination::FileOnly),
-            Action::CaptureClipboardSelection => (
-                CaptureType::Selection {
-                    x: 0,
-                    y: 0,
-                    width: 0,
-                    height: 0,
-                },
-                CaptureDestination::ClipboardOnly,
-            ),
-            Action::CaptureFileSelection => (
-                CaptureType::Selection {
-                    x: 0,
-                    y: 0,
-                    width: 0,
-                    height: 0,
-                },
-                CaptureDestination::FileOnly,
-            ),
+            Action::CaptureClipboardSelection => (selection, CaptureDestination::ClipboardOnly),
+            Action::CaptureFileSelection => (selection, CaptureDestination::FileOnly),
             Action::CaptureClipboardRegion => {
                 log::info!("Region clipboard capture requested");
-                (
-                    CaptureType::Selection {
-                        x: 0,
-                        y: 0,
-                        width: 0,
-                        height: 0,
-                    },
-                    CaptureDestination::ClipboardOnly,
-                )
+                (selection, CaptureDestination::ClipboardOnly)
             }
             Action::CaptureFileRegion => {
                 log::info!("Region file capture requested");
-                (
-                    CaptureType::Selection {
-                        x: 0,
-                        y: 0,
-                        width: 0,
-                        height: 0,
-                    },
-                    CaptureDestination::FileOnly,
-                )
+                (selection, CaptureDestination::FileOnly)
+            }
+            Action::CaptureAnnotatedClipboardFull => {
+                (CaptureType::FullScreen, CaptureDestination::ClipboardOnly)
+            }
+            Action::CaptureAnnotatedFileFull => {
+                (CaptureType::FullScreen, CaptureDestination::FileOnly)
+            }
+            Action::CaptureAnnotatedClipboardRegion => {
+                log::info!("Annotated region clipboard capture requested");
+                (selection, CaptureDestination::ClipboardOnly)
+            }
+            Action::CaptureAnnotatedFileRegion => {
+                log::info!("Annotated region file capture requested");
+                (selection, CaptureDestination::FileOnly)
             }
             _ => {
                 log::error!(
@@ -1062,17 +1006,35 @@ impl WaylandState {
             })
         };
 
+        let annotations = if matches!(
+            action,
+            Action::CaptureAnnotatedClipboardFull
+                | Action::CaptureAnnotatedFileFull
+                | Action::CaptureAnnotatedClipboardRegion
+                | Action::CaptureAnnotatedFileRegion
+        ) {
+            Some(self.annotation_overlay(capture_type))
+        } else {
+            None
+        };
+
         // Hide overlay before capture to prevent capturing the overlay itself
         self.capture.hide_overlay(&mut self.surface);
         self.capture.mark_in_progress();
 
         // Request capture
         log::info!("Requesting {:?} capture", capture_type);
-        if let Err(e) =
-            self.capture
-                .manager_mut()
-                .request_capture(capture_type, destination, save_config)
-        {
+        let manager = self.capture.manager_mut();
+        let requested = match annotations {
+            Some(overlay) => manager.request_annotated_capture(
+                capture_type,
+                destination,
+                save_config,
+                Some(overlay),
+            ),
+            None => manager.request_capture(capture_type, destination, save_config),
+        };
+        if let Err(e) = requested {
             log::error!("Failed to request capture: {}", e);
 
             // Restore overlay on error
@@ -1080,6 +1042,264 @@ impl WaylandState {
             self.capture.clear_in_progress();
         }
     }
+
+    /// Snapshots the active frame and board background for compositing into a capture.
+    ///
+    /// The overlay is positioned using the active output's physical origin relative to the
+    /// top-left corner of the captured image (the selection origin for region captures).
+    /// While the screen is frozen, the frozen image (cropped to the selection) is used as
+    /// the screenshot so the capture matches what the user annotated.
+    fn annotation_overlay(&self, capture_type: CaptureType) -> AnnotationOverlay {
+        let geometry = self.capture_geometry();
+        let (origin_x, origin_y) = geometry.physical_origin();
+        let (mut capture_x, mut capture_y) = match capture_type {
+            CaptureType::Selection { x, y, .. } => (x, y),
+            _ => (0, 0),
+        };
+
+        let backdrop = self.frozen_backdrop(capture_type, (origin_x, origin_y));
+        if backdrop.is_some() && !matches!(capture_type, CaptureType::Selection { .. }) {
+            // The frozen image covers only the overlay's output.
+            (capture_x, capture_y) = (origin_x, origin_y);
+        }
+
+        AnnotationOverlay {
+            shapes: self.input_state.canvas_set.active_frame().shapes.clone(),
+            board_mode: self.input_state.board_mode(),
+            board_config: self.input_state.board_config.clone(),
+            offset_x: origin_x - capture_x,
+            offset_y: origin_y - capture_y,
+            scale: geometry.scale as f64,
+            logical_width: self.surface.width(),
+            logical_height: self.surface.height(),
+            backdrop,
+        }
+    }
+
+    /// Encodes the frozen screen for an annotated capture, cropped to the selection.
+    ///
+    /// Returns `None` when the screen is not frozen or the selection has to be picked
+    /// externally, in which case a fresh screenshot is taken instead.
+    fn frozen_backdrop(&self, capture_type: CaptureType, origin: (i32, i32)) -> Option<Vec<u8>> {
+        if !self.input_state.frozen_active() {
+            return None;
+        }
+        let image = self.frozen.image()?;
+        let region = match capture_type {
+            CaptureType::Selection { width: 0, .. } | CaptureType::Selection { height: 0, .. } => {
+                return None;
+            }
+            CaptureType::Selection {
+                x,
+                y,
+                width,
+                height,
+            } => Some((x - origin.0, y - origin.1, width, height)),
+            _ => None,
+        };
+        match image.to_png(region) {
+            Ok(png) => Some(png),
+            Err(err) => {
+                warn!("Failed to encode frozen screen; capturing the live screen: {err:#}");
+                None
+            }
+        }
+    }
+
+    /// Exports the current selection (or the whole active board) as an SVG file.
+    ///
+    /// Files are written next to screenshots, using the capture filename template.
+    pub(super) fn export_svg(&mut self) {
+        let frame = self.input_state.canvas_set.active_frame();
+        let (shapes, view, background) = if self.input_state.has_selection() {
+            let selected: HashSet<_> = self
+                .input_state
+                .selected_shape_ids()
+                .iter()
+                .copied()
+                .collect();
+            let shapes: Vec<_> = frame
+                .shapes
+                .iter()
+                .filter(|drawn| selected.contains(&drawn.id))
+                .cloned()
+                .collect();
+            let view = crate::draw::svg::shapes_bounds(&shapes);
+            (shapes, view, None)
+        } else {
+            let view = Rect::new(
+                0,
+                0,
+                self.surface.width().min(i32::MAX as u32) as i32,
+                self.surface.height().min(i32::MAX as u32) as i32,
+            );
+            let background = self
+                .input_state
+                .board_mode()
+                .background_color(&self.input_state.board_config);
+            (frame.shapes.clone(), view, background)
+        };
+
+        let Some(view) = view else {
+            warn!("Nothing to export as SVG");
+            return;
+        };
+
+        let svg = crate::draw::svg::render_svg(&shapes, view, background);
+        let result = self.export_target_path("svg").and_then(|path| {
+            std::fs::write(&path, svg)
+                .with_context(|| format!("failed to write {}", path.display()))?;
+            Ok(path)
+        });
+        if let Ok(path) = &result {
+            log::info!("Exported {} shapes to {}", shapes.len(), path.display());
+        }
+        self.notify_export("SVG", result);
+    }
+
+    /// Exports every non-empty page of every board as one page of a PDF file.
+    ///
+    /// While frozen, the transparent board's page uses the frozen screenshot as its background.
+    pub(super) fn export_pdf(&mut self) {
+        let canvas_set = &self.input_state.canvas_set;
+        let mut pages = crate::draw::pdf::board_pages(|mode| canvas_set.pages(mode));
+        if pages.is_empty() {
+            warn!("Nothing to export as PDF");
+            return;
+        }
+
+        let screenshot = match self.frozen.image() {
+            Some(image) => match cairo::ImageSurface::create_for_data(
+                image.data.clone(),
+                cairo::Format::ARgb32,
+                image.width as i32,
+                image.height as i32,
+                image.stride,
+            ) {
+                Ok(surface) => Some(surface),
+                Err(err) => {
+                    warn!("Failed to prepare frozen image for PDF export: {}", err);
+                    None
+                }
+            },
+            None => None,
+        };
+        for page in &mut pages {
+            if page.mode == BoardMode::Transparent {
+                page.background = screenshot.as_ref();
+            }
+        }
+
+        let width = f64::from(self.surface.width().max(1));
+        let height = f64::from(self.surface.height().max(1));
+        let page_count = pages.len();
+        let result = self.export_target_path("pdf").and_then(|path| {
+            crate::draw::pdf::write_pdf(
+                &path,
+                width,
+                height,
+                &pages,
+                &self.input_state.board_config,
+            )
+            .with_context(|| format!("failed to write {}", path.display()))?;
+            Ok(path)
+        });
+        if let Ok(path) = &result {
+            log::info!("Exported {} board pages to {}", page_count, path.display());
+        }
+        self.notify_export("PDF", result);
+    }
+
+    /// Picks a fresh path next to screenshots for an exported file.
+    pub(super) fn export_target_path(&self, extension: &str) -> Result<std::path::PathBuf> {
+        let directory = expand_tilde(&self.config.capture.save_directory);
+        let directory = crate::capture::file::ensure_directory_exists(&directory)?;
+        let filename = crate::capture::file::generate_filename(
+            &self.config.capture.filename_template,
+            extension,
+        );
+        Ok(directory.join(filename))
+    }
+
+    fn notify_export(&self, format: &str, result: Result<std::path::PathBuf>) {
+        match result {
+            Ok(path) => {
+                let filename = path
+                    .file_name()
+                    .map(|name| name.to_string_lossy().into_owned())
+                    .unwrap_or_default();
+                crate::notification::send_notification_async(
+                    &self.tokio_handle,
+                    format!("{} Exported", format),
+                    format!("Saved as {}", filename),
+                    Some("document-save".to_string()),
+                );
+            }
+            Err(err) => {
+                warn!("{} export failed: {:#}", format, err);
+                crate::notification::send_notification_async(
+                    &self.tokio_handle,
+                    format!("{} Export Failed", format),
+                    err.to_string(),
+                    Some("dialog-error".to_string()),
+                );
+            }
+        }
+    }
+
+    /// Geometry of the output the overlay is on, for placing it within full-screen captures.
+    ///
+    /// Without cached geometry, falls back to the surface size and the logical origin of
+    /// the current output, since full-screen captures span every output.
+    fn capture_geometry(&self) -> OutputGeometry {
+        if let Some(geometry) = self.frozen.active_geometry() {
+            return geometry.clone();
+        }
+        let (logical_x, logical_y) = self
+            .surface
+            .current_output()
+            .and_then(|output| self.output_state.info(&output))
+            .and_then(|info| info.logical_position)
+            .unwrap_or((0, 0));
+        OutputGeometry {
+            logical_x,
+            logical_y,
+            logical_width: self.surface.width(),
+            logical_height: self.surface.height(),
+            scale: self.surface.scale().max(1),
+        }
+    }
+
+    /// Builds a selection capture from the region confirmed in the overlay.
+    ///
+    /// The rectangle is converted from surface-local logical coordinates into physical
+    /// pixels of a full-screen capture so the pipeline can crop it. Falls back to an
+    /// empty selection (external picker) when no region was chosen.
+    fn capture_selection_type(&mut self) -> CaptureType {
+        let Some(rect) = self.input_state.take_pending_capture_region() else {
+            return CaptureType::Selection {
+                x: 0,
+                y: 0,
+                width: 0,
+                height: 0,
+            };
+        };
+
+        let (x, y, width, height) = self.capture_geometry().local_rect_to_physical(&rect);
+        log::info!(
+            "Using overlay selection {}x{} at ({}, {}) for capture",
+            width,
+            height,
+            x,
+            y
+        );
+        CaptureType::Selection {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
 }
 
 #[allow(dead_code)]
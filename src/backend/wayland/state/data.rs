@@ -1,3 +1,6 @@
+use std::path::PathBuf;
+
+use crate::backend::headless::InputRecorder;
 use wayland_client::protocol::wl_seat;
 
 /// Focus/pointer/toolbar interaction data owned by WaylandState and shared with handlers.
@@ -16,9 +19,13 @@ pub struct StateData {
     pub(super) toolbar_needs_recreate: bool,
     pub(super) pending_activation_token: Option<String>,
     pub(super) pending_freeze_on_start: bool,
+    /// Document passed with `--open`, opened once the surface is on an output.
+    pub(super) pending_open_document: Option<PathBuf>,
     pub(super) frozen_enabled: bool,
     pub(super) preferred_output_identity: Option<String>,
     pub(super) xdg_fullscreen: bool,
+    /// Log of input events requested with `--record-input`.
+    pub(super) input_recorder: Option<InputRecorder>,
 }
 
 impl StateData {
@@ -36,9 +43,11 @@ impl StateData {
             toolbar_needs_recreate: true,
             pending_activation_token: None,
             pending_freeze_on_start: false,
+            pending_open_document: None,
             frozen_enabled: false,
             preferred_output_identity: None,
             xdg_fullscreen: false,
+            input_recorder: None,
         }
     }
 }
@@ -0,0 +1,234 @@
+//! Compositing of overlay annotations onto captured screenshots.
+
+use crate::capture::types::CaptureError;
+use crate::config::BoardConfig;
+use crate::draw::{DrawnShape, EraserReplayContext, render_board_background, render_shapes};
+use crate::input::BoardMode;
+
+/// Snapshot of the drawing state that should be burned into a capture.
+///
+/// Shapes are stored in the overlay's logical coordinates; `offset_x`/`offset_y` give the
+/// position of the overlay's top-left corner within the captured image in physical pixels,
+/// and `scale` converts logical units to physical pixels.
+#[derive(Debug, Clone)]
+pub struct AnnotationOverlay {
+    pub shapes: Vec<DrawnShape>,
+    pub board_mode: BoardMode,
+    pub board_config: BoardConfig,
+    pub offset_x: i32,
+    pub offset_y: i32,
+    pub scale: f64,
+    pub logical_width: u32,
+    pub logical_height: u32,
+    /// PNG to composite onto instead of a fresh screenshot, e.g. the frozen screen.
+    pub backdrop: Option<Vec<u8>>,
+}
+
+/// Renders the annotation overlay on top of a PNG screenshot and re-encodes the result.
+///
+/// The board background (whiteboard/blackboard) and shapes are drawn into a separate layer
+/// clipped to the overlay's output, so eraser strokes reveal the screenshot underneath rather
+/// than punching holes into it. On the transparent board the layer starts as a copy of the
+/// screenshot, which gives redactions real pixels to blur or pixelate.
+pub fn composite_annotations(
+    data: &[u8],
+    overlay: &AnnotationOverlay,
+) -> Result<Vec<u8>, CaptureError> {
+    let image = cairo::ImageSurface::create_from_png(&mut std::io::Cursor::new(data))
+        .map_err(|e| CaptureError::ImageError(format!("Failed to decode screenshot: {}", e)))?;
+
+    let (width, height) = (image.width(), image.height());
+    let layer = cairo::ImageSurface::create(cairo::Format::ARgb32, width, height).map_err(|e| {
+        CaptureError::ImageError(format!("Failed to allocate annotation layer: {}", e))
+    })?;
+    {
+        let ctx = cairo::Context::new(&layer)
+            .map_err(|e| CaptureError::ImageError(format!("Failed to create context: {}", e)))?;
+        let scale = if overlay.scale > 0.0 {
+            overlay.scale
+        } else {
+            1.0
+        };
+        ctx.translate(f64::from(overlay.offset_x), f64::from(overlay.offset_y));
+        ctx.scale(scale, scale);
+        ctx.rectangle(
+            0.0,
+            0.0,
+            f64::from(overlay.logical_width),
+            f64::from(overlay.logical_height),
+        );
+        ctx.clip();
+
+        let bg_color = overlay.board_mode.background_color(&overlay.board_config);
+        let screenshot = cairo::SurfacePattern::create(&image);
+        screenshot.set_matrix(ctx.matrix());
+        if bg_color.is_none() {
+            ctx.set_source(&screenshot)
+                .and_then(|_| ctx.paint())
+                .map_err(|e| {
+                    CaptureError::ImageError(format!("Failed to copy screenshot: {}", e))
+                })?;
+        }
+
+        render_board_background(&ctx, overlay.board_mode, &overlay.board_config);
+        let eraser_ctx = EraserReplayContext {
+            pattern: bg_color.is_none().then_some(&*screenshot),
+            bg_color,
+        };
+        render_shapes(&ctx, &overlay.shapes, Some(&eraser_ctx));
+    }
+    layer.flush();
+
+    {
+        let ctx = cairo::Context::new(&image)
+            .map_err(|e| CaptureError::ImageError(format!("Failed to create context: {}", e)))?;
+        ctx.set_source_surface(&layer, 0.0, 0.0)
+            .map_err(|e| CaptureError::ImageError(format!("Failed to set source: {}", e)))?;
+        ctx.set_operator(cairo::Operator::Over);
+        ctx.paint().map_err(|e| {
+            CaptureError::ImageError(format!("Failed to composite annotations: {}", e))
+        })?;
+    }
+
+    let mut out = Vec::new();
+    image
+        .write_to_png(&mut out)
+        .map_err(|e| CaptureError::ImageError(format!("Failed to encode screenshot: {}", e)))?;
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::draw::{Color, Frame, RedactMode, Shape};
+    use crate::util::Rect;
+
+    fn solid_png(width: i32, height: i32) -> Vec<u8> {
+        let surface = cairo::ImageSurface::create(cairo::Format::ARgb32, width, height).unwrap();
+        {
+            let ctx = cairo::Context::new(&surface).unwrap();
+            ctx.set_source_rgb(0.0, 0.0, 1.0);
+            ctx.paint().unwrap();
+        }
+        let mut buffer = Vec::new();
+        surface.write_to_png(&mut buffer).unwrap();
+        buffer
+    }
+
+    fn pixel(data: &[u8], x: i32, y: i32) -> (u8, u8, u8) {
+        let mut surface =
+            cairo::ImageSurface::create_from_png(&mut std::io::Cursor::new(data)).unwrap();
+        let stride = surface.stride() as usize;
+        let bytes = surface.data().unwrap();
+        let offset = y as usize * stride + x as usize * 4;
+        // ARGB32 is stored as BGRA in little-endian memory.
+        (bytes[offset + 2], bytes[offset + 1], bytes[offset])
+    }
+
+    fn overlay_with_rect(mode: BoardMode) -> AnnotationOverlay {
+        let mut frame = Frame::new();
+        frame.add_shape(Shape::Rect {
+            x: 2,
+            y: 2,
+            w: 6,
+            h: 6,
+            fill: true,
+            color: Color {
+                r: 1.0,
+                g: 0.0,
+                b: 0.0,
+                a: 1.0,
+            },
+            thick: 1.0,
+            start_color: None,
+            end_color: None,
+        });
+        AnnotationOverlay {
+            shapes: frame.shapes.clone(),
+            board_mode: mode,
+            board_config: BoardConfig::default(),
+            offset_x: 0,
+            offset_y: 0,
+            scale: 2.0,
+            logical_width: 20,
+            logical_height: 20,
+            backdrop: None,
+        }
+    }
+
+    #[test]
+    fn composite_draws_shapes_over_screenshot() {
+        let overlay = overlay_with_rect(BoardMode::Transparent);
+        let output = composite_annotations(&solid_png(40, 40), &overlay).expect("composite");
+
+        // Shape at logical (2..8) maps to physical (4..16) at scale 2.
+        assert_eq!(pixel(&output, 10, 10), (255, 0, 0));
+        assert_eq!(pixel(&output, 30, 30), (0, 0, 255));
+    }
+
+    #[test]
+    fn composite_applies_offset_into_capture() {
+        let mut overlay = overlay_with_rect(BoardMode::Transparent);
+        overlay.offset_x = -8;
+        overlay.offset_y = -8;
+        let output = composite_annotations(&solid_png(40, 40), &overlay).expect("composite");
+
+        assert_eq!(pixel(&output, 2, 2), (255, 0, 0));
+        assert_eq!(pixel(&output, 10, 10), (0, 0, 255));
+    }
+
+    #[test]
+    fn composite_paints_board_background_within_output() {
+        let mut overlay = overlay_with_rect(BoardMode::Whiteboard);
+        overlay.board_config.whiteboard_color = [1.0, 1.0, 1.0];
+        overlay.logical_width = 10;
+        let output = composite_annotations(&solid_png(40, 40), &overlay).expect("composite");
+
+        assert_eq!(pixel(&output, 18, 30), (255, 255, 255));
+        // Outside the overlay's output the screenshot is left untouched.
+        assert_eq!(pixel(&output, 30, 30), (0, 0, 255));
+    }
+
+    #[test]
+    fn composite_redaction_obscures_the_screenshot() {
+        // A one-pixel white "secret" across a blue screenshot.
+        let surface =
+            cairo::ImageSurface::create_from_png(&mut std::io::Cursor::new(solid_png(40, 40)))
+                .unwrap();
+        {
+            let ctx = cairo::Context::new(&surface).unwrap();
+            ctx.set_source_rgb(1.0, 1.0, 1.0);
+            ctx.rectangle(0.0, 20.0, 40.0, 1.0);
+            ctx.fill().unwrap();
+        }
+        let mut screenshot = Vec::new();
+        surface.write_to_png(&mut screenshot).unwrap();
+
+        let mut frame = Frame::new();
+        frame.add_shape(Shape::Redact {
+            rect: Rect::new(0, 0, 20, 20).unwrap(),
+            mode: RedactMode::Pixelate,
+        });
+        let mut overlay = overlay_with_rect(BoardMode::Transparent);
+        overlay.shapes = frame.shapes.clone();
+        let output = composite_annotations(&screenshot, &overlay).expect("composite");
+
+        let (r, g, b) = pixel(&output, 10, 20);
+        assert!(
+            r < 128 && g < 128 && b > 128,
+            "secret still visible: {:?}",
+            (r, g, b)
+        );
+        // Every pixel of a block shows the same averaged color.
+        assert_eq!(pixel(&output, 1, 21), pixel(&output, 18, 38));
+
+        frame = Frame::new();
+        frame.add_shape(Shape::Redact {
+            rect: Rect::new(0, 0, 20, 20).unwrap(),
+            mode: RedactMode::Solid,
+        });
+        overlay.shapes = frame.shapes.clone();
+        let output = composite_annotations(&screenshot, &overlay).expect("composite");
+        assert_eq!(pixel(&output, 10, 20), (0, 0, 0));
+    }
+}
@@ -3,6 +3,7 @@ use std::sync::Arc;
 use tokio::sync::{Mutex, mpsc};
 
 use crate::capture::{
+    annotate::AnnotationOverlay,
     dependencies::CaptureDependencies,
     file::FileSaveConfig,
     pipeline::{CaptureRequest, perform_capture},
@@ -99,11 +100,26 @@ impl CaptureManager {
         capture_type: CaptureType,
         destination: CaptureDestination,
         save_config: Option<FileSaveConfig>,
+    ) -> Result<(), CaptureError> {
+        self.request_annotated_capture(capture_type, destination, save_config, None)
+    }
+
+    /// Request a screenshot capture with the given annotations composited on top.
+    ///
+    /// Behaves like [`CaptureManager::request_capture`]; when `annotations` is `Some`, the
+    /// drawing is rendered over the captured image before it is saved or copied.
+    pub fn request_annotated_capture(
+        &self,
+        capture_type: CaptureType,
+        destination: CaptureDestination,
+        save_config: Option<FileSaveConfig>,
+        annotations: Option<AnnotationOverlay>,
     ) -> Result<(), CaptureError> {
         let request = CaptureRequest {
             capture_type,
             destination,
             save_config,
+            annotations,
         };
 
         self.request_tx
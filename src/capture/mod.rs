@@ -4,6 +4,7 @@
 //! - Full screen capture
 //! - Active window capture
 //! - Selection-based capture
+//! - Compositing of on-screen annotations into the captured image
 //! - Clipboard integration
 //! - File saving with configurable formats
 
@@ -14,12 +15,14 @@ pub mod portal;
 pub mod sources;
 pub mod types;
 
+mod annotate;
 mod dependencies;
 mod manager;
 mod pipeline;
 #[cfg(test)]
 mod tests;
 
+pub use annotate::AnnotationOverlay;
 pub use manager::CaptureManager;
 #[allow(unused_imports)]
 pub use types::{
@@ -1,6 +1,7 @@
 use std::{fmt, path::PathBuf, sync::Arc};
 
 use crate::capture::{
+    annotate::{AnnotationOverlay, composite_annotations},
     dependencies::{CaptureClipboard, CaptureDependencies, CaptureFileSaver},
     file::FileSaveConfig,
     types::{CaptureDestination, CaptureError, CaptureResult, CaptureType},
@@ -12,6 +13,8 @@ pub(crate) struct CaptureRequest {
     pub(crate) capture_type: CaptureType,
     pub(crate) destination: CaptureDestination,
     pub(crate) save_config: Option<FileSaveConfig>,
+    /// Drawing state to composite over the screenshot before it is saved or copied.
+    pub(crate) annotations: Option<AnnotationOverlay>,
 }
 
 impl fmt::Debug for CaptureRequest {
@@ -26,24 +29,39 @@ impl fmt::Debug for CaptureRequest {
                     .as_ref()
                     .map(|cfg| cfg.filename_template.clone()),
             )
+            .field(
+                "annotations",
+                &self
+                    .annotations
+                    .as_ref()
+                    .map(|overlay| overlay.shapes.len()),
+            )
             .finish()
     }
 }
 
 pub(crate) async fn perform_capture(
-    request: CaptureRequest,
+    mut request: CaptureRequest,
     dependencies: Arc<CaptureDependencies>,
 ) -> Result<CaptureResult, CaptureError> {
     log::info!("Starting capture: {:?}", request.capture_type);
 
-    // Step 1: Capture image bytes (prefer compositor-specific path where possible)
-    let image_data = match dependencies.source.capture(request.capture_type).await {
-        Ok(data) => data,
-        Err(CaptureError::Cancelled(reason)) => {
-            log::info!("Capture cancelled: {}", reason);
-            return Err(CaptureError::Cancelled(reason));
-        }
-        Err(err) => return Err(err),
+    // Step 1: Capture image bytes (prefer compositor-specific path where possible).
+    // Annotations drawn over a frozen screen bring that image along instead.
+    let backdrop = request
+        .annotations
+        .as_mut()
+        .and_then(|overlay| overlay.backdrop.take());
+    let image_data = match backdrop {
+        Some(data) => data,
+        None => match dependencies.source.capture(request.capture_type).await {
+            Ok(data) => data,
+            Err(CaptureError::Cancelled(reason)) => {
+                log::info!("Capture cancelled: {}", reason);
+                return Err(CaptureError::Cancelled(reason));
+            }
+            Err(err) => return Err(err),
+        },
     };
 
     log::info!("Obtained screenshot data ({} bytes)", image_data.len());
@@ -54,6 +72,12 @@ pub(crate) async fn perform_capture(
         request.capture_type
     );
 
+    // Step 2: Burn annotations into the image (if requested)
+    let image_data = match request.annotations {
+        Some(overlay) => composite(image_data, overlay).await?,
+        None => image_data,
+    };
+
     // Step 3: Save to file (if requested)
     let saved_path = match request.destination {
         CaptureDestination::FileOnly | CaptureDestination::ClipboardAndFile => {
@@ -96,6 +120,19 @@ pub(crate) async fn perform_capture(
     })
 }
 
+async fn composite(
+    image_data: Vec<u8>,
+    overlay: AnnotationOverlay,
+) -> Result<Vec<u8>, CaptureError> {
+    log::info!(
+        "Compositing {} annotation shapes onto screenshot",
+        overlay.shapes.len()
+    );
+    task::spawn_blocking(move || composite_annotations(&image_data, &overlay))
+        .await
+        .map_err(|e| CaptureError::ImageError(format!("Composite task failed: {}", e)))?
+}
+
 async fn save_image(
     saver: Arc<dyn CaptureFileSaver>,
     image_data: Vec<u8>,
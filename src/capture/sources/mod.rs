@@ -1,23 +1,16 @@
 use crate::capture::types::{CaptureError, CaptureType};
+use tokio::task;
 
 pub(crate) mod frozen;
 mod hyprland;
 #[cfg(feature = "portal")]
 pub mod portal;
 pub(crate) mod reader;
+mod region;
 
 pub async fn capture_image(capture_type: CaptureType) -> Result<Vec<u8>, CaptureError> {
     match capture_type {
-        CaptureType::FullScreen => match hyprland::capture_full_screen_hyprland().await {
-            Ok(data) => Ok(data),
-            Err(e) => {
-                log::warn!(
-                    "Full screen capture via Hyprland failed: {}. Falling back to portal.",
-                    e
-                );
-                portal_fallback(CaptureType::FullScreen).await
-            }
-        },
+        CaptureType::FullScreen => capture_full_screen().await,
         CaptureType::ActiveWindow => match hyprland::capture_active_window_hyprland().await {
             Ok(data) => Ok(data),
             Err(e) => {
@@ -28,6 +21,20 @@ pub async fn capture_image(capture_type: CaptureType) -> Result<Vec<u8>, Capture
                 portal_fallback(CaptureType::ActiveWindow).await
             }
         },
+        CaptureType::Selection {
+            x,
+            y,
+            width,
+            height,
+        } if width > 0 && height > 0 => {
+            // Region chosen inside the overlay: grab the whole screen and crop locally.
+            let full = capture_full_screen().await?;
+            task::spawn_blocking(move || region::crop_png(&full, x, y, width, height))
+                .await
+                .map_err(|e| {
+                    CaptureError::ImageError(format!("Region crop task failed to join: {}", e))
+                })?
+        }
         CaptureType::Selection { .. } => match hyprland::capture_selection_hyprland().await {
             Ok(data) => Ok(data),
             Err(e) => {
@@ -47,6 +54,19 @@ pub async fn capture_image(capture_type: CaptureType) -> Result<Vec<u8>, Capture
     }
 }
 
+async fn capture_full_screen() -> Result<Vec<u8>, CaptureError> {
+    match hyprland::capture_full_screen_hyprland().await {
+        Ok(data) => Ok(data),
+        Err(e) => {
+            log::warn!(
+                "Full screen capture via Hyprland failed: {}. Falling back to portal.",
+                e
+            );
+            portal_fallback(CaptureType::FullScreen).await
+        }
+    }
+}
+
 async fn portal_fallback(capture_type: CaptureType) -> Result<Vec<u8>, CaptureError> {
     #[cfg(feature = "portal")]
     {
@@ -0,0 +1,92 @@
+//! Cropping for regions selected inside the overlay.
+
+use crate::capture::types::CaptureError;
+
+/// Crop a PNG screenshot to the given pixel rectangle and re-encode it as PNG.
+///
+/// The rectangle is clamped to the image bounds; a selection that lies entirely outside the
+/// image is reported as an error rather than producing an empty file.
+pub fn crop_png(
+    data: &[u8],
+    x: i32,
+    y: i32,
+    width: u32,
+    height: u32,
+) -> Result<Vec<u8>, CaptureError> {
+    let source = cairo::ImageSurface::create_from_png(&mut std::io::Cursor::new(data))
+        .map_err(|e| CaptureError::ImageError(format!("Failed to decode screenshot: {}", e)))?;
+
+    let image_w = i64::from(source.width());
+    let image_h = i64::from(source.height());
+    let left = i64::from(x).clamp(0, image_w);
+    let top = i64::from(y).clamp(0, image_h);
+    let right = (i64::from(x) + i64::from(width)).clamp(0, image_w);
+    let bottom = (i64::from(y) + i64::from(height)).clamp(0, image_h);
+    let crop_w = (right - left) as i32;
+    let crop_h = (bottom - top) as i32;
+
+    if crop_w <= 0 || crop_h <= 0 {
+        return Err(CaptureError::ImageError(format!(
+            "Selection {}x{} at ({}, {}) lies outside the {}x{} screenshot",
+            width, height, x, y, image_w, image_h
+        )));
+    }
+
+    let cropped = cairo::ImageSurface::create(cairo::Format::ARgb32, crop_w, crop_h)
+        .map_err(|e| CaptureError::ImageError(format!("Failed to allocate crop: {}", e)))?;
+    {
+        let ctx = cairo::Context::new(&cropped)
+            .map_err(|e| CaptureError::ImageError(format!("Failed to create context: {}", e)))?;
+        ctx.set_source_surface(&source, -(left as f64), -(top as f64))
+            .map_err(|e| CaptureError::ImageError(format!("Failed to set source: {}", e)))?;
+        ctx.set_operator(cairo::Operator::Source);
+        ctx.paint()
+            .map_err(|e| CaptureError::ImageError(format!("Failed to crop screenshot: {}", e)))?;
+    }
+
+    let mut out = Vec::new();
+    cropped
+        .write_to_png(&mut out)
+        .map_err(|e| CaptureError::ImageError(format!("Failed to encode crop: {}", e)))?;
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn solid_png(width: i32, height: i32) -> Vec<u8> {
+        let surface = cairo::ImageSurface::create(cairo::Format::ARgb32, width, height).unwrap();
+        {
+            let ctx = cairo::Context::new(&surface).unwrap();
+            ctx.set_source_rgb(0.2, 0.4, 0.6);
+            ctx.paint().unwrap();
+        }
+        let mut buffer = Vec::new();
+        surface.write_to_png(&mut buffer).unwrap();
+        buffer
+    }
+
+    fn png_size(data: &[u8]) -> (i32, i32) {
+        let surface =
+            cairo::ImageSurface::create_from_png(&mut std::io::Cursor::new(data)).unwrap();
+        (surface.width(), surface.height())
+    }
+
+    #[test]
+    fn crop_png_returns_requested_size() {
+        let cropped = crop_png(&solid_png(40, 30), 5, 6, 10, 12).expect("crop");
+        assert_eq!(png_size(&cropped), (10, 12));
+    }
+
+    #[test]
+    fn crop_png_clamps_to_image_bounds() {
+        let cropped = crop_png(&solid_png(40, 30), 30, 20, 50, 50).expect("crop");
+        assert_eq!(png_size(&cropped), (10, 10));
+    }
+
+    #[test]
+    fn crop_png_rejects_selection_outside_image() {
+        assert!(crop_png(&solid_png(40, 30), 100, 100, 10, 10).is_err());
+    }
+}
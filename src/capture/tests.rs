@@ -134,6 +134,7 @@ async fn test_perform_capture_clipboard_only_success() {
         capture_type: CaptureType::FullScreen,
         destination: CaptureDestination::ClipboardOnly,
         save_config: None,
+        annotations: None,
     };
 
     let result = perform_capture(request, Arc::new(deps.clone()))
@@ -172,6 +173,7 @@ async fn test_perform_capture_file_only_success() {
         capture_type: CaptureType::FullScreen,
         destination: CaptureDestination::FileOnly,
         save_config: Some(FileSaveConfig::default()),
+        annotations: None,
     };
 
     let result = perform_capture(request, Arc::new(deps.clone()))
@@ -209,6 +211,7 @@ async fn test_perform_capture_clipboard_failure() {
         capture_type: CaptureType::FullScreen,
         destination: CaptureDestination::ClipboardOnly,
         save_config: None,
+        annotations: None,
     };
 
     let result = perform_capture(request, Arc::new(deps.clone()))
@@ -244,6 +247,7 @@ async fn test_perform_capture_save_failure() {
         capture_type: CaptureType::FullScreen,
         destination: CaptureDestination::FileOnly,
         save_config: Some(FileSaveConfig::default()),
+        annotations: None,
     };
 
     let err = perform_capture(request, Arc::new(deps.clone()))
@@ -335,6 +339,7 @@ async fn test_perform_capture_clipboard_and_file_success() {
         capture_type: CaptureType::FullScreen,
         destination: CaptureDestination::ClipboardAndFile,
         save_config: Some(FileSaveConfig::default()),
+        annotations: None,
     };
 
     let result = perform_capture(request, Arc::new(deps)).await.unwrap();
@@ -445,6 +450,7 @@ async fn perform_capture_propagates_source_error() {
         capture_type: CaptureType::FullScreen,
         destination: CaptureDestination::ClipboardOnly,
         save_config: None,
+        annotations: None,
     };
 
     let err = perform_capture(request, Arc::new(deps)).await.unwrap_err();
@@ -456,3 +462,135 @@ async fn perform_capture_propagates_source_error() {
         other => panic!("expected ImageError, got {other:?}"),
     }
 }
+
+#[tokio::test]
+async fn perform_capture_composites_annotations_before_delivery() {
+    use crate::capture::AnnotationOverlay;
+    use crate::config::BoardConfig;
+    use crate::draw::{Color, Frame, Shape};
+    use crate::input::BoardMode;
+
+    let placeholder = create_placeholder_image();
+    let source = MockSource {
+        data: placeholder.clone(),
+        error: Arc::new(Mutex::new(None)),
+        captured_types: Arc::new(Mutex::new(Vec::new())),
+    };
+    let clipboard = MockClipboard {
+        should_fail: false,
+        calls: Arc::new(Mutex::new(0)),
+    };
+    let deps = CaptureDependencies {
+        source: Arc::new(source),
+        saver: Arc::new(MockSaver {
+            should_fail: false,
+            path: PathBuf::from("unused.png"),
+            calls: Arc::new(Mutex::new(0)),
+        }),
+        clipboard: Arc::new(clipboard.clone()),
+    };
+
+    let mut frame = Frame::new();
+    frame.add_shape(Shape::Rect {
+        x: 0,
+        y: 80,
+        w: 20,
+        h: 20,
+        fill: true,
+        color: Color {
+            r: 0.0,
+            g: 1.0,
+            b: 0.0,
+            a: 1.0,
+        },
+        thick: 1.0,
+        start_color: None,
+        end_color: None,
+    });
+    let request = CaptureRequest {
+        capture_type: CaptureType::FullScreen,
+        destination: CaptureDestination::ClipboardOnly,
+        save_config: None,
+        annotations: Some(AnnotationOverlay {
+            shapes: frame.shapes.clone(),
+            board_mode: BoardMode::Transparent,
+            board_config: BoardConfig::default(),
+            offset_x: 0,
+            offset_y: 0,
+            scale: 1.0,
+            logical_width: 100,
+            logical_height: 100,
+            backdrop: None,
+        }),
+    };
+
+    let result = perform_capture(request, Arc::new(deps)).await.unwrap();
+    assert!(result.copied_to_clipboard);
+    assert_ne!(result.image_data, placeholder);
+
+    let mut surface =
+        cairo::ImageSurface::create_from_png(&mut std::io::Cursor::new(&result.image_data))
+            .unwrap();
+    let stride = surface.stride() as usize;
+    let data = surface.data().unwrap();
+    let offset = 90 * stride + 10 * 4;
+    // Pixel under the annotation is green (BGRA byte order).
+    assert_eq!(&data[offset..offset + 3], &[0, 255, 0]);
+}
+
+#[tokio::test]
+async fn perform_capture_uses_the_annotation_backdrop_instead_of_the_source() {
+    use crate::capture::AnnotationOverlay;
+    use crate::config::BoardConfig;
+    use crate::input::BoardMode;
+
+    let captured_types = Arc::new(Mutex::new(Vec::new()));
+    let source = MockSource {
+        data: vec![],
+        error: Arc::new(Mutex::new(Some(CaptureError::ImageError(
+            "live screen".to_string(),
+        )))),
+        captured_types: captured_types.clone(),
+    };
+    let deps = CaptureDependencies {
+        source: Arc::new(source),
+        saver: Arc::new(MockSaver {
+            should_fail: false,
+            path: PathBuf::from("unused.png"),
+            calls: Arc::new(Mutex::new(0)),
+        }),
+        clipboard: Arc::new(MockClipboard {
+            should_fail: false,
+            calls: Arc::new(Mutex::new(0)),
+        }),
+    };
+    let frozen = create_placeholder_image();
+    let request = CaptureRequest {
+        capture_type: CaptureType::FullScreen,
+        destination: CaptureDestination::ClipboardOnly,
+        save_config: None,
+        annotations: Some(AnnotationOverlay {
+            shapes: Vec::new(),
+            board_mode: BoardMode::Transparent,
+            board_config: BoardConfig::default(),
+            offset_x: 0,
+            offset_y: 0,
+            scale: 1.0,
+            logical_width: 100,
+            logical_height: 100,
+            backdrop: Some(frozen.clone()),
+        }),
+    };
+
+    let result = perform_capture(request, Arc::new(deps)).await.unwrap();
+    assert!(captured_types.lock().unwrap().is_empty());
+    let surface =
+        cairo::ImageSurface::create_from_png(&mut std::io::Cursor::new(&result.image_data))
+            .unwrap();
+    let expected =
+        cairo::ImageSurface::create_from_png(&mut std::io::Cursor::new(&frozen)).unwrap();
+    assert_eq!(
+        (surface.width(), surface.height()),
+        (expected.width(), expected.height())
+    );
+}
@@ -11,7 +11,9 @@ pub enum CaptureType {
     /// Capture the currently focused window.
     ActiveWindow,
     /// Capture a user-selected rectangular region.
-    #[allow(dead_code)] // Will be used in Phase 2 for region selection
+    ///
+    /// Coordinates are physical pixels within a full-screen capture. A zero-sized
+    /// selection asks the capture source to prompt for a region itself (e.g. via slurp).
     Selection {
         x: i32,
         y: i32,
@@ -21,6 +21,16 @@ pub enum StatusPosition {
     BottomRight,
 }
 
+/// Shape of the lit region in spotlight mode.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum SpotlightShape {
+    /// Ellipse inscribed in the spotlight bounds
+    Ellipse,
+    /// The spotlight bounds themselves
+    Rectangle,
+}
+
 /// Color specification - either a named color or RGB values.
 ///
 /// # Examples
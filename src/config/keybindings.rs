@@ -45,6 +45,9 @@ pub enum Action {
     SelectEllipseTool,
     SelectArrowTool,
     SelectHighlightTool,
+    SelectLaserTool,
+    SelectRedactTool,
+    CycleRedactMode,
     IncreaseFontSize,
     DecreaseFontSize,
 
@@ -53,6 +56,13 @@ pub enum Action {
     ToggleBlackboard,
     ReturnToTransparent,
 
+    // Board pages
+    AddPage,
+    DuplicatePage,
+    DeletePage,
+    NextPage,
+    PreviousPage,
+
     // UI toggles
     ToggleHelp,
     ToggleStatusBar,
@@ -61,6 +71,7 @@ pub enum Action {
     ToggleHighlightTool,
     ToggleFill,
     ToggleRainbowMode,
+    ToggleVanishingInk,
     OpenContextMenu,
 
     // Configurator
@@ -86,7 +97,21 @@ pub enum Action {
     CaptureFileSelection,
     CaptureClipboardRegion,
     CaptureFileRegion,
+    CaptureAnnotatedClipboardFull,
+    CaptureAnnotatedFileFull,
+    CaptureAnnotatedClipboardRegion,
+    CaptureAnnotatedFileRegion,
     ToggleFrozenMode,
+    ToggleZoom,
+    ToggleSpotlight,
+
+    // Export actions
+    ExportSvg,
+    ExportPdf,
+
+    // Document actions
+    SaveDocument,
+    OpenDocument,
 }
 
 /// A single keybinding: a key character with optional modifiers.
@@ -271,6 +296,15 @@ pub struct KeybindingsConfig {
     #[serde(default = "default_select_highlight_tool")]
     pub select_highlight_tool: Vec<String>,
 
+    #[serde(default = "default_select_laser_tool")]
+    pub select_laser_tool: Vec<String>,
+
+    #[serde(default = "default_select_redact_tool")]
+    pub select_redact_tool: Vec<String>,
+
+    #[serde(default = "default_cycle_redact_mode")]
+    pub cycle_redact_mode: Vec<String>,
+
     #[serde(default = "default_increase_font_size")]
     pub increase_font_size: Vec<String>,
 
@@ -286,6 +320,21 @@ pub struct KeybindingsConfig {
     #[serde(default = "default_return_to_transparent")]
     pub return_to_transparent: Vec<String>,
 
+    #[serde(default = "default_add_page")]
+    pub add_page: Vec<String>,
+
+    #[serde(default = "default_duplicate_page")]
+    pub duplicate_page: Vec<String>,
+
+    #[serde(default = "default_delete_page")]
+    pub delete_page: Vec<String>,
+
+    #[serde(default = "default_next_page")]
+    pub next_page: Vec<String>,
+
+    #[serde(default = "default_previous_page")]
+    pub previous_page: Vec<String>,
+
     #[serde(default = "default_toggle_help")]
     pub toggle_help: Vec<String>,
     #[serde(default = "default_toggle_status_bar")]
@@ -302,6 +351,9 @@ pub struct KeybindingsConfig {
     #[serde(default = "default_toggle_rainbow_mode")]
     pub toggle_rainbow_mode: Vec<String>,
 
+    #[serde(default = "default_toggle_vanishing_ink")]
+    pub toggle_vanishing_ink: Vec<String>,
+
     #[serde(default = "default_toggle_highlight_tool")]
     pub toggle_highlight_tool: Vec<String>,
     #[serde(default = "default_open_context_menu")]
@@ -361,8 +413,38 @@ pub struct KeybindingsConfig {
     #[serde(default = "default_capture_file_region")]
     pub capture_file_region: Vec<String>,
 
+    #[serde(default = "default_capture_annotated_clipboard_full")]
+    pub capture_annotated_clipboard_full: Vec<String>,
+
+    #[serde(default = "default_capture_annotated_file_full")]
+    pub capture_annotated_file_full: Vec<String>,
+
+    #[serde(default = "default_capture_annotated_clipboard_region")]
+    pub capture_annotated_clipboard_region: Vec<String>,
+
+    #[serde(default = "default_capture_annotated_file_region")]
+    pub capture_annotated_file_region: Vec<String>,
+
     #[serde(default = "default_toggle_frozen_mode")]
     pub toggle_frozen_mode: Vec<String>,
+
+    #[serde(default = "default_toggle_zoom")]
+    pub toggle_zoom: Vec<String>,
+
+    #[serde(default = "default_toggle_spotlight")]
+    pub toggle_spotlight: Vec<String>,
+
+    #[serde(default = "default_export_svg")]
+    pub export_svg: Vec<String>,
+
+    #[serde(default = "default_export_pdf")]
+    pub export_pdf: Vec<String>,
+
+    #[serde(default = "default_save_document")]
+    pub save_document: Vec<String>,
+
+    #[serde(default = "default_open_document")]
+    pub open_document: Vec<String>,
 }
 
 impl Default for KeybindingsConfig {
@@ -397,17 +479,26 @@ impl Default for KeybindingsConfig {
             select_ellipse_tool: default_select_ellipse_tool(),
             select_arrow_tool: default_select_arrow_tool(),
             select_highlight_tool: default_select_highlight_tool(),
+            select_laser_tool: default_select_laser_tool(),
+            select_redact_tool: default_select_redact_tool(),
+            cycle_redact_mode: default_cycle_redact_mode(),
             increase_font_size: default_increase_font_size(),
             decrease_font_size: default_decrease_font_size(),
             toggle_whiteboard: default_toggle_whiteboard(),
             toggle_blackboard: default_toggle_blackboard(),
             return_to_transparent: default_return_to_transparent(),
+            add_page: default_add_page(),
+            duplicate_page: default_duplicate_page(),
+            delete_page: default_delete_page(),
+            next_page: default_next_page(),
+            previous_page: default_previous_page(),
             toggle_help: default_toggle_help(),
             toggle_status_bar: default_toggle_status_bar(),
             toggle_click_highlight: default_toggle_click_highlight(),
             toggle_toolbar: default_toggle_toolbar(),
             toggle_fill: default_toggle_fill(),
             toggle_rainbow_mode: default_toggle_rainbow_mode(),
+            toggle_vanishing_ink: default_toggle_vanishing_ink(),
             toggle_highlight_tool: default_toggle_highlight_tool(),
             open_context_menu: default_open_context_menu(),
             open_configurator: default_open_configurator(),
@@ -428,7 +519,17 @@ impl Default for KeybindingsConfig {
             capture_file_selection: default_capture_file_selection(),
             capture_clipboard_region: default_capture_clipboard_region(),
             capture_file_region: default_capture_file_region(),
+            capture_annotated_clipboard_full: default_capture_annotated_clipboard_full(),
+            capture_annotated_file_full: default_capture_annotated_file_full(),
+            capture_annotated_clipboard_region: default_capture_annotated_clipboard_region(),
+            capture_annotated_file_region: default_capture_annotated_file_region(),
             toggle_frozen_mode: default_toggle_frozen_mode(),
+            toggle_zoom: default_toggle_zoom(),
+            toggle_spotlight: default_toggle_spotlight(),
+            export_svg: default_export_svg(),
+            export_pdf: default_export_pdf(),
+            save_document: default_save_document(),
+            open_document: default_open_document(),
         }
     }
 }
@@ -567,6 +668,18 @@ impl KeybindingsConfig {
             insert_binding(binding_str, Action::SelectHighlightTool)?;
         }
 
+        for binding_str in &self.select_laser_tool {
+            insert_binding(binding_str, Action::SelectLaserTool)?;
+        }
+
+        for binding_str in &self.select_redact_tool {
+            insert_binding(binding_str, Action::SelectRedactTool)?;
+        }
+
+        for binding_str in &self.cycle_redact_mode {
+            insert_binding(binding_str, Action::CycleRedactMode)?;
+        }
+
         for binding_str in &self.increase_font_size {
             insert_binding(binding_str, Action::IncreaseFontSize)?;
         }
@@ -587,6 +700,26 @@ impl KeybindingsConfig {
             insert_binding(binding_str, Action::ReturnToTransparent)?;
         }
 
+        for binding_str in &self.add_page {
+            insert_binding(binding_str, Action::AddPage)?;
+        }
+
+        for binding_str in &self.duplicate_page {
+            insert_binding(binding_str, Action::DuplicatePage)?;
+        }
+
+        for binding_str in &self.delete_page {
+            insert_binding(binding_str, Action::DeletePage)?;
+        }
+
+        for binding_str in &self.next_page {
+            insert_binding(binding_str, Action::NextPage)?;
+        }
+
+        for binding_str in &self.previous_page {
+            insert_binding(binding_str, Action::PreviousPage)?;
+        }
+
         // Ensure help is reachable via F1 even if older configs only include F10.
         let mut help_bindings = if self.toggle_help.is_empty() {
             default_toggle_help()
@@ -633,6 +766,10 @@ impl KeybindingsConfig {
             insert_binding(binding_str, Action::ToggleRainbowMode)?;
         }
 
+        for binding_str in &self.toggle_vanishing_ink {
+            insert_binding(binding_str, Action::ToggleVanishingInk)?;
+        }
+
         for binding_str in &self.toggle_highlight_tool {
             insert_binding(binding_str, Action::ToggleHighlightTool)?;
         }
@@ -713,10 +850,50 @@ impl KeybindingsConfig {
             insert_binding(binding_str, Action::CaptureFileRegion)?;
         }
 
+        for binding_str in &self.capture_annotated_clipboard_full {
+            insert_binding(binding_str, Action::CaptureAnnotatedClipboardFull)?;
+        }
+
+        for binding_str in &self.capture_annotated_file_full {
+            insert_binding(binding_str, Action::CaptureAnnotatedFileFull)?;
+        }
+
+        for binding_str in &self.capture_annotated_clipboard_region {
+            insert_binding(binding_str, Action::CaptureAnnotatedClipboardRegion)?;
+        }
+
+        for binding_str in &self.capture_annotated_file_region {
+            insert_binding(binding_str, Action::CaptureAnnotatedFileRegion)?;
+        }
+
         for binding_str in &self.toggle_frozen_mode {
             insert_binding(binding_str, Action::ToggleFrozenMode)?;
         }
 
+        for binding_str in &self.toggle_zoom {
+            insert_binding(binding_str, Action::ToggleZoom)?;
+        }
+
+        for binding_str in &self.toggle_spotlight {
+            insert_binding(binding_str, Action::ToggleSpotlight)?;
+        }
+
+        for binding_str in &self.export_svg {
+            insert_binding(binding_str, Action::ExportSvg)?;
+        }
+
+        for binding_str in &self.export_pdf {
+            insert_binding(binding_str, Action::ExportPdf)?;
+        }
+
+        for binding_str in &self.save_document {
+            insert_binding(binding_str, Action::SaveDocument)?;
+        }
+
+        for binding_str in &self.open_document {
+            insert_binding(binding_str, Action::OpenDocument)?;
+        }
+
         Ok(map)
     }
 }
@@ -825,6 +1002,18 @@ fn default_select_highlight_tool() -> Vec<String> {
     Vec::new()
 }
 
+fn default_select_laser_tool() -> Vec<String> {
+    vec!["L".to_string()]
+}
+
+fn default_select_redact_tool() -> Vec<String> {
+    vec!["X".to_string()]
+}
+
+fn default_cycle_redact_mode() -> Vec<String> {
+    vec!["Shift+X".to_string()]
+}
+
 fn default_increase_font_size() -> Vec<String> {
     vec!["Ctrl+Shift++".to_string(), "Ctrl+Shift+=".to_string()]
 }
@@ -845,6 +1034,26 @@ fn default_return_to_transparent() -> Vec<String> {
     vec!["Ctrl+Shift+T".to_string()]
 }
 
+fn default_add_page() -> Vec<String> {
+    vec!["Ctrl+Alt+N".to_string()]
+}
+
+fn default_duplicate_page() -> Vec<String> {
+    vec!["Ctrl+Alt+D".to_string()]
+}
+
+fn default_delete_page() -> Vec<String> {
+    vec!["Ctrl+Alt+X".to_string()]
+}
+
+fn default_next_page() -> Vec<String> {
+    vec!["Ctrl+Alt+ArrowRight".to_string()]
+}
+
+fn default_previous_page() -> Vec<String> {
+    vec!["Ctrl+Alt+ArrowLeft".to_string()]
+}
+
 fn default_toggle_help() -> Vec<String> {
     vec!["F10".to_string(), "F1".to_string()]
 }
@@ -869,6 +1078,10 @@ fn default_toggle_rainbow_mode() -> Vec<String> {
     vec!["Ctrl+Shift+R".to_string()]
 }
 
+fn default_toggle_vanishing_ink() -> Vec<String> {
+    vec!["Ctrl+Shift+V".to_string()]
+}
+
 fn default_toggle_highlight_tool() -> Vec<String> {
     vec!["Ctrl+Alt+H".to_string()]
 }
@@ -949,10 +1162,50 @@ fn default_capture_file_region() -> Vec<String> {
     vec!["Ctrl+Shift+6".to_string()]
 }
 
+fn default_capture_annotated_clipboard_full() -> Vec<String> {
+    vec!["Ctrl+Alt+C".to_string()]
+}
+
+fn default_capture_annotated_file_full() -> Vec<String> {
+    vec!["Ctrl+Alt+S".to_string()]
+}
+
+fn default_capture_annotated_clipboard_region() -> Vec<String> {
+    vec!["Ctrl+Alt+Shift+C".to_string()]
+}
+
+fn default_capture_annotated_file_region() -> Vec<String> {
+    vec!["Ctrl+Alt+Shift+S".to_string()]
+}
+
 fn default_toggle_frozen_mode() -> Vec<String> {
     vec!["Ctrl+Shift+F".to_string()]
 }
 
+fn default_toggle_zoom() -> Vec<String> {
+    vec!["Ctrl+1".to_string()]
+}
+
+fn default_toggle_spotlight() -> Vec<String> {
+    vec!["Ctrl+Shift+L".to_string()]
+}
+
+fn default_export_svg() -> Vec<String> {
+    vec!["Ctrl+Shift+E".to_string()]
+}
+
+fn default_export_pdf() -> Vec<String> {
+    vec!["Ctrl+Alt+E".to_string()]
+}
+
+fn default_save_document() -> Vec<String> {
+    vec!["Ctrl+Shift+D".to_string()]
+}
+
+fn default_open_document() -> Vec<String> {
+    vec!["Ctrl+O".to_string()]
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1078,6 +1331,46 @@ mod tests {
             map.get(&toggle_highlight_tool),
             Some(&Action::ToggleHighlightTool)
         );
+
+        let annotated_full = KeyBinding::parse("Ctrl+Alt+C").unwrap();
+        assert_eq!(
+            map.get(&annotated_full),
+            Some(&Action::CaptureAnnotatedClipboardFull)
+        );
+
+        let annotated_region = KeyBinding::parse("Ctrl+Alt+Shift+S").unwrap();
+        assert_eq!(
+            map.get(&annotated_region),
+            Some(&Action::CaptureAnnotatedFileRegion)
+        );
+
+        let export_pdf = KeyBinding::parse("Ctrl+Alt+E").unwrap();
+        assert_eq!(map.get(&export_pdf), Some(&Action::ExportPdf));
+
+        let laser = KeyBinding::parse("L").unwrap();
+        assert_eq!(map.get(&laser), Some(&Action::SelectLaserTool));
+
+        let redact = KeyBinding::parse("X").unwrap();
+        assert_eq!(map.get(&redact), Some(&Action::SelectRedactTool));
+        let redact_mode = KeyBinding::parse("Shift+X").unwrap();
+        assert_eq!(map.get(&redact_mode), Some(&Action::CycleRedactMode));
+
+        let vanishing_ink = KeyBinding::parse("Ctrl+Shift+V").unwrap();
+        assert_eq!(map.get(&vanishing_ink), Some(&Action::ToggleVanishingInk));
+
+        let toggle_zoom = KeyBinding::parse("Ctrl+1").unwrap();
+        assert_eq!(map.get(&toggle_zoom), Some(&Action::ToggleZoom));
+
+        let toggle_spotlight = KeyBinding::parse("Ctrl+Shift+L").unwrap();
+        assert_eq!(map.get(&toggle_spotlight), Some(&Action::ToggleSpotlight));
+
+        let save_document = KeyBinding::parse("Ctrl+Shift+D").unwrap();
+        assert_eq!(map.get(&save_document), Some(&Action::SaveDocument));
+        let open_document = KeyBinding::parse("Ctrl+O").unwrap();
+        assert_eq!(map.get(&open_document), Some(&Action::OpenDocument));
+
+        let next_page = KeyBinding::parse("Ctrl+Alt+ArrowRight").unwrap();
+        assert_eq!(map.get(&next_page), Some(&Action::NextPage));
     }
 
     #[test]
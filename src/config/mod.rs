@@ -11,15 +11,15 @@ pub mod keybindings;
 pub mod types;
 
 // Re-export commonly used types at module level
-pub use enums::StatusPosition;
+pub use enums::{SpotlightShape, StatusPosition};
 pub use keybindings::{Action, KeyBinding, KeybindingsConfig};
 #[cfg(tablet)]
 pub use types::TabletInputConfig;
 #[allow(unused_imports)]
 pub use types::{
     ArrowConfig, BoardConfig, CaptureConfig, ClickHighlightConfig, DrawingConfig, HelpOverlayStyle,
-    HistoryConfig, PerformanceConfig, SessionCompression, SessionConfig, SessionStorageMode,
-    StatusBarStyle, ToolbarConfig, UiConfig,
+    HistoryConfig, LaserConfig, PerformanceConfig, SessionCompression, SessionConfig,
+    SessionFormat, SessionStorageMode, SpotlightConfig, StatusBarStyle, ToolbarConfig, UiConfig,
 };
 
 // Re-export for public API (unused internally but part of public interface)
@@ -114,6 +114,7 @@ mod tests {
         config.drawing.default_font_size = 3.0;
         config.drawing.font_weight = "not-a-real-weight".to_string();
         config.drawing.font_style = "diagonal".to_string();
+        config.drawing.vanishing_ink_seconds = 0.0;
         config.arrow.length = 100.0;
         config.arrow.angle_degrees = 5.0;
         config.performance.buffer_count = 8;
@@ -122,6 +123,10 @@ mod tests {
         config.board.blackboard_color = [-0.2, 2.0, 0.5];
         config.board.whiteboard_pen_color = [2.0, 2.0, 2.0];
         config.board.blackboard_pen_color = [-1.0, -1.0, -1.0];
+        config.ui.laser.radius = 0.5;
+        config.ui.laser.trail_duration_ms = 60_000;
+        config.ui.spotlight.dim_alpha = 1.0;
+        config.ui.spotlight.width = 5.0;
 
         config.validate_and_clamp();
 
@@ -129,10 +134,15 @@ mod tests {
         assert_eq!(config.drawing.default_font_size, 8.0);
         assert_eq!(config.drawing.font_weight, "bold");
         assert_eq!(config.drawing.font_style, "normal");
+        assert_eq!(config.drawing.vanishing_ink_seconds, 1.0);
         assert_eq!(config.arrow.length, 50.0);
         assert_eq!(config.arrow.angle_degrees, 15.0);
         assert_eq!(config.performance.buffer_count, 4);
         assert_eq!(config.board.default_mode, "transparent");
+        assert_eq!(config.ui.laser.radius, 2.0);
+        assert_eq!(config.ui.laser.trail_duration_ms, 5000);
+        assert_eq!(config.ui.spotlight.dim_alpha, 0.95);
+        assert_eq!(config.ui.spotlight.width, 40.0);
         assert!(
             config
                 .board
@@ -577,6 +587,16 @@ impl Config {
                 self.drawing.rainbow_hue_step_per_pixel.clamp(0.01, 1.0);
         }
 
+        // Validate vanishing ink lifetime: 1.0 - 60.0 seconds
+        if !(1.0..=60.0).contains(&self.drawing.vanishing_ink_seconds) {
+            log::warn!(
+                "Invalid vanishing_ink_seconds {:.1}, clamping to 1.0-60.0 range",
+                self.drawing.vanishing_ink_seconds
+            );
+            self.drawing.vanishing_ink_seconds =
+                self.drawing.vanishing_ink_seconds.clamp(1.0, 60.0);
+        }
+
         // Validate board mode default
         if !matches!(
             self.board.default_mode.to_lowercase().as_str(),
@@ -675,6 +695,57 @@ impl Config {
             }
         }
 
+        // Validate laser pointer settings
+        if !(2.0..=40.0).contains(&self.ui.laser.radius) {
+            log::warn!(
+                "Invalid laser radius {:.1}, clamping to 2.0-40.0 range",
+                self.ui.laser.radius
+            );
+            self.ui.laser.radius = self.ui.laser.radius.clamp(2.0, 40.0);
+        }
+
+        if !(100..=5000).contains(&self.ui.laser.trail_duration_ms) {
+            log::warn!(
+                "Invalid laser trail duration {}ms, clamping to 100-5000ms range",
+                self.ui.laser.trail_duration_ms
+            );
+            self.ui.laser.trail_duration_ms = self.ui.laser.trail_duration_ms.clamp(100, 5000);
+        }
+
+        for i in 0..4 {
+            if !(0.0..=1.0).contains(&self.ui.laser.color[i]) {
+                log::warn!(
+                    "Invalid laser color[{}] = {:.3}, clamping to 0.0-1.0",
+                    i,
+                    self.ui.laser.color[i]
+                );
+                self.ui.laser.color[i] = self.ui.laser.color[i].clamp(0.0, 1.0);
+            }
+        }
+
+        // Validate spotlight settings
+        if !(0.1..=0.95).contains(&self.ui.spotlight.dim_alpha) {
+            log::warn!(
+                "Invalid spotlight dim_alpha {:.2}, clamping to 0.1-0.95 range",
+                self.ui.spotlight.dim_alpha
+            );
+            self.ui.spotlight.dim_alpha = self.ui.spotlight.dim_alpha.clamp(0.1, 0.95);
+        }
+
+        for (name, value) in [
+            ("width", &mut self.ui.spotlight.width),
+            ("height", &mut self.ui.spotlight.height),
+        ] {
+            if !(40.0..=4000.0).contains(value) {
+                log::warn!(
+                    "Invalid spotlight {} {:.1}, clamping to 40.0-4000.0 range",
+                    name,
+                    value
+                );
+                *value = value.clamp(40.0, 4000.0);
+            }
+        }
+
         // Validate keybindings (try to build action map to catch parse errors)
         if let Err(e) = self.keybindings.build_action_map() {
             log::warn!("Invalid keybinding configuration: {}. Using defaults.", e);
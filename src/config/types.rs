@@ -1,6 +1,7 @@
 //! Configuration type definitions.
 
-use super::enums::{ColorSpec, StatusPosition};
+use super::enums::{ColorSpec, SpotlightShape, StatusPosition};
+use crate::draw::RedactMode;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
@@ -76,6 +77,18 @@ pub struct DrawingConfig {
     /// Enable rainbow mode by default on startup
     #[serde(default = "default_rainbow_enabled")]
     pub default_rainbow_enabled: bool,
+
+    /// Start with vanishing ink enabled (new strokes fade away on their own)
+    #[serde(default = "default_vanishing_ink_enabled")]
+    pub default_vanishing_ink_enabled: bool,
+
+    /// Seconds a vanishing-ink stroke stays on screen, including its fade (valid range: 1.0 - 60.0)
+    #[serde(default = "default_vanishing_ink_seconds")]
+    pub vanishing_ink_seconds: f64,
+
+    /// How the redaction tool obscures screen regions: "blur", "pixelate" or "solid"
+    #[serde(default)]
+    pub default_redact_mode: RedactMode,
 }
 
 impl Default for DrawingConfig {
@@ -96,6 +109,9 @@ impl Default for DrawingConfig {
             text_background_enabled: default_text_background(),
             rainbow_hue_step_per_pixel: default_rainbow_hue_step(),
             default_rainbow_enabled: default_rainbow_enabled(),
+            default_vanishing_ink_enabled: default_vanishing_ink_enabled(),
+            vanishing_ink_seconds: default_vanishing_ink_seconds(),
+            default_redact_mode: RedactMode::default(),
         }
     }
 }
@@ -237,6 +253,14 @@ pub struct UiConfig {
     #[serde(default)]
     pub click_highlight: ClickHighlightConfig,
 
+    /// Laser pointer tool appearance
+    #[serde(default)]
+    pub laser: LaserConfig,
+
+    /// Spotlight mode appearance
+    #[serde(default)]
+    pub spotlight: SpotlightConfig,
+
     /// Context menu preferences
     #[serde(default)]
     pub context_menu: ContextMenuUiConfig,
@@ -257,6 +281,8 @@ impl Default for UiConfig {
             preferred_output: None,
             xdg_fullscreen: default_xdg_fullscreen(),
             click_highlight: ClickHighlightConfig::default(),
+            laser: LaserConfig::default(),
+            spotlight: SpotlightConfig::default(),
             context_menu: ContextMenuUiConfig::default(),
             toolbar: ToolbarConfig::default(),
         }
@@ -331,6 +357,63 @@ pub struct ClickHighlightConfig {
     pub use_pen_color: bool,
 }
 
+/// Laser pointer configuration: a glowing dot with a fading trail that is never saved.
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct LaserConfig {
+    /// Radius of the laser dot in pixels
+    #[serde(default = "default_laser_radius")]
+    pub radius: f64,
+
+    /// How long the trail takes to fade out, in milliseconds
+    #[serde(default = "default_laser_trail_duration_ms")]
+    pub trail_duration_ms: u64,
+
+    /// Dot and trail color RGBA (0.0-1.0)
+    #[serde(default = "default_laser_color")]
+    pub color: [f64; 4],
+}
+
+impl Default for LaserConfig {
+    fn default() -> Self {
+        Self {
+            radius: default_laser_radius(),
+            trail_duration_ms: default_laser_trail_duration_ms(),
+            color: default_laser_color(),
+        }
+    }
+}
+
+/// Spotlight configuration: dims the output except for a region around the pointer.
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct SpotlightConfig {
+    /// Opacity of the dimming outside the spotlight (0.1 - 0.95)
+    #[serde(default = "default_spotlight_dim_alpha")]
+    pub dim_alpha: f64,
+
+    /// Shape of the lit region ("ellipse" or "rectangle")
+    #[serde(default = "default_spotlight_shape")]
+    pub shape: SpotlightShape,
+
+    /// Width of the lit region in pixels when it follows the pointer
+    #[serde(default = "default_spotlight_width")]
+    pub width: f64,
+
+    /// Height of the lit region in pixels when it follows the pointer
+    #[serde(default = "default_spotlight_height")]
+    pub height: f64,
+}
+
+impl Default for SpotlightConfig {
+    fn default() -> Self {
+        Self {
+            dim_alpha: default_spotlight_dim_alpha(),
+            shape: default_spotlight_shape(),
+            width: default_spotlight_width(),
+            height: default_spotlight_height(),
+        }
+    }
+}
+
 /// Context menu visibility configuration.
 #[derive(Debug, Serialize, Deserialize, JsonSchema)]
 pub struct ContextMenuUiConfig {
@@ -511,6 +594,14 @@ fn default_rainbow_enabled() -> bool {
     false
 }
 
+fn default_vanishing_ink_enabled() -> bool {
+    false
+}
+
+fn default_vanishing_ink_seconds() -> f64 {
+    4.0
+}
+
 fn default_hit_test_tolerance() -> f64 {
     6.0
 }
@@ -662,6 +753,36 @@ fn default_click_highlight_use_pen_color() -> bool {
     true
 }
 
+// Laser pointer defaults
+fn default_laser_radius() -> f64 {
+    6.0
+}
+
+fn default_laser_trail_duration_ms() -> u64 {
+    600
+}
+
+fn default_laser_color() -> [f64; 4] {
+    [1.0, 0.1, 0.1, 1.0]
+}
+
+// Spotlight defaults
+fn default_spotlight_dim_alpha() -> f64 {
+    0.65
+}
+
+fn default_spotlight_shape() -> SpotlightShape {
+    SpotlightShape::Ellipse
+}
+
+fn default_spotlight_width() -> f64 {
+    360.0
+}
+
+fn default_spotlight_height() -> f64 {
+    240.0
+}
+
 fn default_context_menu_enabled() -> bool {
     true
 }
@@ -847,11 +968,15 @@ pub struct SessionConfig {
     #[serde(default = "default_session_compression")]
     pub compress: SessionCompression,
 
+    /// On-disk encoding for session files (loading detects either).
+    #[serde(default = "default_session_format")]
+    pub format: SessionFormat,
+
     /// Threshold (in kilobytes) beyond which automatic compression engages.
     #[serde(default = "default_auto_compress_threshold_kb")]
     pub auto_compress_threshold_kb: u64,
 
-    /// Number of rotated backups to retain (0 disables backups).
+    /// Number of timestamped backups to retain (0 disables backups).
     #[serde(default = "default_backup_retention")]
     pub backup_retention: usize,
 
@@ -862,6 +987,14 @@ pub struct SessionConfig {
     /// Maximum undo history depth persisted on disk (None = follow runtime limit).
     #[serde(default)]
     pub max_persisted_undo_depth: Option<usize>,
+
+    /// Seconds after the first unsaved edit before an autosave runs (0 disables the timer).
+    #[serde(default = "default_autosave_interval_secs")]
+    pub autosave_interval_secs: u64,
+
+    /// Autosave after this many undoable actions (0 disables the counter).
+    #[serde(default = "default_autosave_after_actions")]
+    pub autosave_after_actions: usize,
 }
 
 impl Default for SessionConfig {
@@ -877,10 +1010,13 @@ impl Default for SessionConfig {
             max_shapes_per_frame: default_max_shapes_per_frame(),
             max_file_size_mb: default_max_file_size_mb(),
             compress: default_session_compression(),
+            format: default_session_format(),
             auto_compress_threshold_kb: default_auto_compress_threshold_kb(),
             backup_retention: default_backup_retention(),
             per_output: default_session_per_output(),
             max_persisted_undo_depth: None,
+            autosave_interval_secs: default_autosave_interval_secs(),
+            autosave_after_actions: default_autosave_after_actions(),
         }
     }
 }
@@ -903,6 +1039,16 @@ pub enum SessionCompression {
     Off,
 }
 
+/// Session file encodings.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum SessionFormat {
+    /// Human-readable JSON.
+    Json,
+    /// Binary encoding with interned colours/fonts and delta-encoded points.
+    Compact,
+}
+
 fn default_restore_tool_state() -> bool {
     true
 }
@@ -923,6 +1069,10 @@ fn default_session_compression() -> SessionCompression {
     SessionCompression::Auto
 }
 
+fn default_session_format() -> SessionFormat {
+    SessionFormat::Json
+}
+
 fn default_auto_compress_threshold_kb() -> u64 {
     100
 }
@@ -931,6 +1081,14 @@ fn default_backup_retention() -> usize {
     1
 }
 
+fn default_autosave_interval_secs() -> u64 {
+    30
+}
+
+fn default_autosave_after_actions() -> usize {
+    20
+}
+
 fn default_session_per_output() -> bool {
     true
 }
@@ -0,0 +1,468 @@
+//! Session-bus front end for the control protocol.
+//!
+//! Method calls are turned into `ControlRequest`s and answered by the same handler as the control
+//! socket; properties and signals follow the daemon's `StatusFeed`, so bus clients see exactly
+//! what `ctl watch` sees.
+
+use anyhow::{Result, anyhow};
+use clap::ValueEnum;
+use log::{debug, info, warn};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc;
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+use zbus::object_server::{InterfaceRef, SignalEmitter};
+use zbus::{fdo, interface};
+
+use super::feed::StatusFeed;
+use super::protocol::{
+    CaptureOutput, CaptureTarget, ControlRequest, ControlResponse, ControlStatus,
+};
+
+/// Well-known name claimed on the session bus.
+pub const BUS_NAME: &str = "com.devmobasa.wayscriber";
+/// Object path serving the `com.devmobasa.wayscriber.Control` interface.
+pub const OBJECT_PATH: &str = "/com/devmobasa/wayscriber";
+
+const START_TIMEOUT: Duration = Duration::from_secs(5);
+
+type Handler = dyn Fn(ControlRequest) -> ControlResponse + Send + Sync;
+
+struct ControlInterface {
+    handler: Arc<Handler>,
+    status: ControlStatus,
+}
+
+impl ControlInterface {
+    async fn call(&self, request: ControlRequest) -> fdo::Result<()> {
+        let handler = self.handler.clone();
+        // The handler blocks while the daemon or overlay applies the request.
+        let response = tokio::task::spawn_blocking(move || handler(request))
+            .await
+            .map_err(|err| fdo::Error::Failed(format!("control handler failed: {err}")))?;
+        if response.ok {
+            Ok(())
+        } else {
+            Err(fdo::Error::Failed(
+                response
+                    .error
+                    .unwrap_or_else(|| "command failed".to_string()),
+            ))
+        }
+    }
+
+    fn overlay(&self) -> Option<&super::OverlayStatus> {
+        self.status.overlay.as_ref()
+    }
+}
+
+#[interface(name = "com.devmobasa.wayscriber.Control")]
+impl ControlInterface {
+    /// Show the overlay when hidden, hide it when visible.
+    async fn toggle(&self) -> fdo::Result<()> {
+        self.call(ControlRequest::Toggle).await
+    }
+
+    async fn show(&self) -> fdo::Result<()> {
+        self.call(ControlRequest::Show).await
+    }
+
+    async fn hide(&self) -> fdo::Result<()> {
+        self.call(ControlRequest::Hide).await
+    }
+
+    /// Select a tool by name (`pen`, `marker`, `arrow`, ...).
+    async fn set_tool(&self, tool: String) -> fdo::Result<()> {
+        self.call(ControlRequest::Tool { tool }).await
+    }
+
+    /// Set the pen color from a name or `#rrggbb`.
+    async fn set_color(&self, color: String) -> fdo::Result<()> {
+        self.call(ControlRequest::Color { color }).await
+    }
+
+    /// Switch to `transparent`, `whiteboard` or `blackboard`.
+    async fn switch_board(&self, board: String) -> fdo::Result<()> {
+        self.call(ControlRequest::Board { board }).await
+    }
+
+    /// Capture `full`, `window` or `region`; an empty `destination` follows the config.
+    async fn capture(&self, target: &str, destination: &str, annotated: bool) -> fdo::Result<()> {
+        let request =
+            capture_request(target, destination, annotated).map_err(fdo::Error::InvalidArgs)?;
+        self.call(request).await
+    }
+
+    /// Toggle frozen mode.
+    async fn freeze(&self) -> fdo::Result<()> {
+        self.call(ControlRequest::Freeze).await
+    }
+
+    async fn undo(&self) -> fdo::Result<()> {
+        self.call(ControlRequest::Undo).await
+    }
+
+    async fn clear(&self) -> fdo::Result<()> {
+        self.call(ControlRequest::Clear).await
+    }
+
+    /// `visible` or `hidden`.
+    #[zbus(property)]
+    fn overlay_state(&self) -> String {
+        overlay_state_name(&self.status).to_string()
+    }
+
+    /// Active board mode; empty while the overlay is hidden.
+    #[zbus(property)]
+    fn board(&self) -> String {
+        self.overlay()
+            .map(|overlay| overlay.board.clone())
+            .unwrap_or_default()
+    }
+
+    #[zbus(property)]
+    fn tool(&self) -> String {
+        self.overlay()
+            .map(|overlay| overlay.tool.clone())
+            .unwrap_or_default()
+    }
+
+    /// Pen color as `#rrggbb`.
+    #[zbus(property)]
+    fn color(&self) -> String {
+        self.overlay()
+            .map(|overlay| overlay.color.clone())
+            .unwrap_or_default()
+    }
+
+    #[zbus(property)]
+    fn thickness(&self) -> f64 {
+        self.overlay().map_or(0.0, |overlay| overlay.thickness)
+    }
+
+    #[zbus(property)]
+    fn frozen(&self) -> bool {
+        self.overlay().is_some_and(|overlay| overlay.frozen)
+    }
+
+    /// Emitted when the overlay is shown or hidden, or switches board.
+    #[zbus(signal)]
+    async fn state_changed(
+        emitter: &SignalEmitter<'_>,
+        overlay_state: &str,
+        board: &str,
+    ) -> zbus::Result<()>;
+}
+
+fn overlay_state_name(status: &ControlStatus) -> &'static str {
+    if status.visible { "visible" } else { "hidden" }
+}
+
+fn capture_request(
+    target: &str,
+    destination: &str,
+    annotated: bool,
+) -> Result<ControlRequest, String> {
+    let target = CaptureTarget::from_str(target, true)
+        .map_err(|_| format!("unknown capture target '{target}' (use full, window or region)"))?;
+    let destination = match destination {
+        "" => None,
+        "clipboard" => Some(CaptureOutput::Clipboard),
+        "file" => Some(CaptureOutput::File),
+        other => {
+            return Err(format!(
+                "unknown capture destination '{other}' (use clipboard, file or an empty string)"
+            ));
+        }
+    };
+    Ok(ControlRequest::Capture {
+        target,
+        destination,
+        annotated,
+    })
+}
+
+/// Published values that differ between two statuses.
+#[derive(Debug, Default, PartialEq, Eq)]
+struct StatusChanges {
+    overlay_state: bool,
+    board: bool,
+    tool: bool,
+    color: bool,
+    thickness: bool,
+    frozen: bool,
+}
+
+impl StatusChanges {
+    fn between(previous: &ControlStatus, current: &ControlStatus) -> Self {
+        let before = previous.overlay.as_ref();
+        let after = current.overlay.as_ref();
+        Self {
+            overlay_state: previous.visible != current.visible,
+            board: before.map(|o| &o.board) != after.map(|o| &o.board),
+            tool: before.map(|o| &o.tool) != after.map(|o| &o.tool),
+            color: before.map(|o| &o.color) != after.map(|o| &o.color),
+            thickness: before.map(|o| o.thickness) != after.map(|o| o.thickness),
+            frozen: before.map(|o| o.frozen) != after.map(|o| o.frozen),
+        }
+    }
+
+    /// `StateChanged` covers visibility and board switches only.
+    fn state_changed(&self) -> bool {
+        self.overlay_state || self.board
+    }
+}
+
+/// Stores `status` on the interface and emits change notifications for what differs.
+async fn update_status(
+    iface: &InterfaceRef<ControlInterface>,
+    status: ControlStatus,
+) -> zbus::Result<()> {
+    let emitter = iface.signal_emitter();
+    let mut guard = iface.get_mut().await;
+    let previous = std::mem::replace(&mut guard.status, status);
+    let changes = StatusChanges::between(&previous, &guard.status);
+
+    if changes.overlay_state {
+        guard.overlay_state_changed(emitter).await?;
+    }
+    if changes.board {
+        guard.board_changed(emitter).await?;
+    }
+    if changes.tool {
+        guard.tool_changed(emitter).await?;
+    }
+    if changes.color {
+        guard.color_changed(emitter).await?;
+    }
+    if changes.thickness {
+        guard.thickness_changed(emitter).await?;
+    }
+    if changes.frozen {
+        guard.frozen_changed(emitter).await?;
+    }
+    if changes.state_changed() {
+        let board = guard.board();
+        ControlInterface::state_changed(emitter, overlay_state_name(&guard.status), &board).await?;
+    }
+    Ok(())
+}
+
+/// Bridges the feed's blocking receiver onto the runtime.
+fn forward_feed(feed: &StatusFeed) -> tokio::sync::mpsc::UnboundedReceiver<ControlStatus> {
+    let (status_tx, status_rx) = tokio::sync::mpsc::unbounded_channel();
+    let updates = feed.subscribe();
+    thread::spawn(move || {
+        for status in updates {
+            if status_tx.send(status).is_err() {
+                break;
+            }
+        }
+    });
+    status_rx
+}
+
+/// Claims [`BUS_NAME`] and serves the control interface from a background thread until `quit`
+/// is set.
+///
+/// Requests are answered by `handler`; properties and signals track `feed`. Fails if the session
+/// bus is unreachable or another instance already owns the name.
+pub fn spawn_dbus_service<F>(
+    handler: F,
+    feed: StatusFeed,
+    quit: Arc<AtomicBool>,
+) -> Result<JoinHandle<()>>
+where
+    F: Fn(ControlRequest) -> ControlResponse + Send + Sync + 'static,
+{
+    let (ready_tx, ready_rx) = mpsc::channel::<Result<()>>();
+    let thread = thread::Builder::new()
+        .name("wayscriber-dbus".into())
+        .spawn(move || {
+            let rt = match tokio::runtime::Runtime::new() {
+                Ok(runtime) => runtime,
+                Err(err) => {
+                    let _ = ready_tx.send(Err(anyhow!(
+                        "Failed to create Tokio runtime for D-Bus service: {err}"
+                    )));
+                    return;
+                }
+            };
+            rt.block_on(serve(handler, feed, quit, ready_tx));
+        })?;
+
+    match ready_rx.recv_timeout(START_TIMEOUT) {
+        Ok(Ok(())) => Ok(thread),
+        Ok(Err(err)) => Err(err),
+        Err(_) => Err(anyhow!(
+            "Timed out registering {BUS_NAME} on the session bus"
+        )),
+    }
+}
+
+async fn serve<F>(
+    handler: F,
+    feed: StatusFeed,
+    quit: Arc<AtomicBool>,
+    ready: mpsc::Sender<Result<()>>,
+) where
+    F: Fn(ControlRequest) -> ControlResponse + Send + Sync + 'static,
+{
+    let interface = ControlInterface {
+        handler: Arc::new(handler),
+        status: ControlStatus::hidden(),
+    };
+    let connection = match zbus::connection::Builder::session()
+        .and_then(|builder| builder.name(BUS_NAME))
+        .and_then(|builder| builder.serve_at(OBJECT_PATH, interface))
+    {
+        Ok(builder) => builder.build().await,
+        Err(err) => Err(err),
+    };
+    let connection = match connection {
+        Ok(connection) => connection,
+        Err(err) => {
+            let _ = ready.send(Err(anyhow!("Failed to register {BUS_NAME}: {err}")));
+            return;
+        }
+    };
+    let iface = match connection
+        .object_server()
+        .interface::<_, ControlInterface>(OBJECT_PATH)
+        .await
+    {
+        Ok(iface) => iface,
+        Err(err) => {
+            let _ = ready.send(Err(anyhow!("Failed to look up D-Bus interface: {err}")));
+            return;
+        }
+    };
+    info!("D-Bus service registered as {BUS_NAME}");
+    let _ = ready.send(Ok(()));
+
+    let mut status_rx = forward_feed(&feed);
+
+    loop {
+        tokio::select! {
+            Some(status) = status_rx.recv() => {
+                if let Err(err) = update_status(&iface, status).await {
+                    warn!("Failed to publish D-Bus status change: {}", err);
+                }
+            }
+            _ = tokio::time::sleep(Duration::from_millis(100)) => {
+                if quit.load(Ordering::Acquire) {
+                    debug!("Quit signal received - releasing {BUS_NAME}");
+                    break;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::control::OverlayStatus;
+
+    fn undo_only(request: ControlRequest) -> ControlResponse {
+        match request {
+            ControlRequest::Undo => ControlResponse::ok(ControlStatus::hidden()),
+            other => ControlResponse::error(format!("unsupported: {other:?}"), None),
+        }
+    }
+
+    fn interface() -> ControlInterface {
+        ControlInterface {
+            handler: Arc::new(undo_only),
+            status: ControlStatus::hidden(),
+        }
+    }
+
+    fn visible_on(board: &str) -> ControlStatus {
+        ControlStatus {
+            visible: true,
+            overlay: Some(OverlayStatus {
+                board: board.to_string(),
+                tool: "pen".to_string(),
+                color: "#ff0000".to_string(),
+                thickness: 3.0,
+                frozen: false,
+                capture_in_progress: false,
+            }),
+        }
+    }
+
+    #[test]
+    fn method_calls_dispatch_to_the_handler() {
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        let iface = interface();
+
+        assert!(rt.block_on(iface.undo()).is_ok());
+        match rt.block_on(iface.clear()) {
+            Err(fdo::Error::Failed(message)) => assert_eq!(message, "unsupported: Clear"),
+            other => panic!("expected a failed reply, got {other:?}"),
+        }
+        assert!(matches!(
+            rt.block_on(iface.capture("desktop", "", false)),
+            Err(fdo::Error::InvalidArgs(_))
+        ));
+    }
+
+    #[test]
+    fn state_changed_follows_visibility_and_board() {
+        let hidden = ControlStatus::hidden();
+        let whiteboard = visible_on("whiteboard");
+
+        let shown = StatusChanges::between(&hidden, &whiteboard);
+        assert!(shown.overlay_state && shown.state_changed());
+
+        let switched = StatusChanges::between(&whiteboard, &visible_on("blackboard"));
+        assert!(!switched.overlay_state && switched.board && switched.state_changed());
+
+        let mut recolored = whiteboard.clone();
+        recolored.overlay.as_mut().unwrap().color = "#00ff00".to_string();
+        let changes = StatusChanges::between(&whiteboard, &recolored);
+        assert_eq!(
+            changes,
+            StatusChanges {
+                color: true,
+                ..StatusChanges::default()
+            }
+        );
+        assert!(!changes.state_changed());
+    }
+
+    #[test]
+    fn feed_updates_reach_the_runtime() {
+        let feed = StatusFeed::default();
+        feed.publish(ControlStatus::hidden());
+        let mut updates = forward_feed(&feed);
+
+        assert_eq!(updates.blocking_recv(), Some(ControlStatus::hidden()));
+        feed.publish(visible_on("whiteboard"));
+        assert_eq!(updates.blocking_recv(), Some(visible_on("whiteboard")));
+    }
+
+    #[test]
+    fn capture_arguments_map_to_request() {
+        assert_eq!(
+            capture_request("Region", "clipboard", true),
+            Ok(ControlRequest::Capture {
+                target: CaptureTarget::Region,
+                destination: Some(CaptureOutput::Clipboard),
+                annotated: true,
+            })
+        );
+        assert_eq!(
+            capture_request("full", "", false),
+            Ok(ControlRequest::Capture {
+                target: CaptureTarget::Full,
+                destination: None,
+                annotated: false,
+            })
+        );
+        assert!(capture_request("desktop", "", false).is_err());
+        assert!(capture_request("full", "printer", false).is_err());
+    }
+}
@@ -0,0 +1,79 @@
+use std::sync::mpsc;
+use std::sync::{Arc, Mutex};
+
+use super::protocol::ControlStatus;
+
+/// Fans status changes out to every `watch` client.
+///
+/// Publishing an unchanged status is a no-op, so callers can publish after every event-loop
+/// iteration without flooding subscribers.
+#[derive(Clone, Default)]
+pub struct StatusFeed {
+    inner: Arc<Mutex<FeedState>>,
+}
+
+#[derive(Default)]
+struct FeedState {
+    last: Option<ControlStatus>,
+    subscribers: Vec<mpsc::Sender<ControlStatus>>,
+}
+
+impl StatusFeed {
+    /// Records `status` and forwards it to subscribers if it differs from the previous one.
+    pub fn publish(&self, status: ControlStatus) {
+        let mut state = self
+            .inner
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        if state.last.as_ref() == Some(&status) {
+            return;
+        }
+        state
+            .subscribers
+            .retain(|subscriber| subscriber.send(status.clone()).is_ok());
+        state.last = Some(status);
+    }
+
+    /// Returns a receiver that yields the current status first and then every change.
+    pub fn subscribe(&self) -> mpsc::Receiver<ControlStatus> {
+        let (sender, receiver) = mpsc::channel();
+        let mut state = self
+            .inner
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        if let Some(last) = state.last.clone() {
+            let _ = sender.send(last);
+        }
+        state.subscribers.push(sender);
+        receiver
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn subscribers_get_current_status_then_changes_only() {
+        let feed = StatusFeed::default();
+        feed.publish(ControlStatus::hidden());
+        let updates = feed.subscribe();
+
+        feed.publish(ControlStatus::hidden());
+        feed.publish(ControlStatus {
+            visible: true,
+            overlay: None,
+        });
+
+        let received: Vec<bool> = updates.try_iter().map(|status| status.visible).collect();
+        assert_eq!(received, vec![false, true]);
+    }
+
+    #[test]
+    fn dropped_subscribers_are_pruned() {
+        let feed = StatusFeed::default();
+        drop(feed.subscribe());
+        feed.publish(ControlStatus::hidden());
+        assert!(feed.inner.lock().unwrap().subscribers.is_empty());
+    }
+}
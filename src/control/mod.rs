@@ -0,0 +1,30 @@
+//! Request/response control socket for scripting the daemon and overlay.
+//!
+//! Clients write one JSON request per line and read one JSON reply per line. The daemon listens
+//! on [`paths::control_socket`](crate::paths::control_socket), handles visibility itself and
+//! forwards drawing commands to the overlay, which serves
+//! [`paths::overlay_socket`](crate::paths::overlay_socket) while it is running.
+//!
+//! A `watch` request turns the connection into a status stream: the server writes one
+//! [`ControlStatus`] line whenever the state changes, which status bars such as Waybar consume.
+//!
+//! With the `dbus` feature the daemon also exposes the same commands, plus properties and signals
+//! for the overlay state, on the session bus as `com.devmobasa.wayscriber`.
+
+#[cfg(feature = "dbus")]
+mod dbus;
+mod feed;
+mod protocol;
+mod server;
+mod waybar;
+
+#[cfg(feature = "dbus")]
+pub use dbus::spawn_dbus_service;
+pub use feed::StatusFeed;
+
+pub use protocol::{
+    CaptureOutput, CaptureTarget, ControlRequest, ControlResponse, ControlStatus, OverlayStatus,
+    board_name, capture_action, parse_color, tool_name,
+};
+pub use server::{ControlServer, send_request, watch_status};
+pub use waybar::waybar_status;
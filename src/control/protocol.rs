@@ -0,0 +1,273 @@
+use serde::{Deserialize, Serialize};
+
+use crate::config::Action;
+use crate::draw::Color;
+use crate::input::{BoardMode, Tool};
+use crate::util;
+
+/// A single control command, encoded as `{"command": "...", ...}`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "command", rename_all = "snake_case")]
+pub enum ControlRequest {
+    /// Report the current state without changing anything.
+    Status,
+    /// Keep the connection open and stream a [`ControlStatus`] line whenever the state changes.
+    Watch,
+    /// Show the overlay when hidden, hide it when visible.
+    Toggle,
+    Show,
+    Hide,
+    /// Select a drawing tool by name (`pen`, `marker`, `arrow`, ...).
+    Tool {
+        tool: String,
+    },
+    /// Set the pen color from a name (`red`) or hex string (`#ff8800`).
+    Color {
+        color: String,
+    },
+    /// Switch to `transparent`, `whiteboard` or `blackboard`.
+    Board {
+        board: String,
+    },
+    Capture {
+        target: CaptureTarget,
+        /// Where the image goes; follows `capture.copy_to_clipboard` when omitted.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        destination: Option<CaptureOutput>,
+        /// Burn the current annotations into the screenshot.
+        #[serde(default)]
+        annotated: bool,
+    },
+    /// Toggle frozen mode.
+    Freeze,
+    /// Toggle the help overlay.
+    Help,
+    Undo,
+    Clear,
+}
+
+impl ControlRequest {
+    /// Returns `true` for commands the overlay has to execute.
+    pub fn targets_overlay(&self) -> bool {
+        !matches!(
+            self,
+            ControlRequest::Status
+                | ControlRequest::Watch
+                | ControlRequest::Toggle
+                | ControlRequest::Show
+                | ControlRequest::Hide
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, clap::ValueEnum)]
+#[serde(rename_all = "snake_case")]
+pub enum CaptureTarget {
+    Full,
+    Window,
+    Region,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CaptureOutput {
+    Clipboard,
+    File,
+}
+
+/// Reply to a [`ControlRequest`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ControlResponse {
+    pub ok: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// State after the command ran; absent when the request could not be parsed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<ControlStatus>,
+}
+
+impl ControlResponse {
+    pub fn ok(status: ControlStatus) -> Self {
+        Self {
+            ok: true,
+            error: None,
+            status: Some(status),
+        }
+    }
+
+    pub fn error(message: impl Into<String>, status: Option<ControlStatus>) -> Self {
+        Self {
+            ok: false,
+            error: Some(message.into()),
+            status,
+        }
+    }
+}
+
+/// Overall state reported by the daemon or overlay.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ControlStatus {
+    pub visible: bool,
+    /// Drawing state; only present while the overlay is running and reachable.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub overlay: Option<OverlayStatus>,
+}
+
+impl ControlStatus {
+    pub fn hidden() -> Self {
+        Self {
+            visible: false,
+            overlay: None,
+        }
+    }
+}
+
+/// Drawing state of a running overlay.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OverlayStatus {
+    pub board: String,
+    pub tool: String,
+    /// Pen color as `#rrggbb`.
+    pub color: String,
+    pub thickness: f64,
+    pub frozen: bool,
+    pub capture_in_progress: bool,
+}
+
+/// Parses a color name (`red`) or hex string (`#ff8800`).
+pub fn parse_color(value: &str) -> Result<Color, String> {
+    util::name_to_color(value)
+        .or_else(|| util::hex_to_color(value))
+        .ok_or_else(|| format!("unknown color '{value}' (use a name or #rrggbb)"))
+}
+
+/// Lowercase name used for a tool in control replies.
+pub fn tool_name(tool: Tool) -> &'static str {
+    match tool {
+        Tool::Select => "select",
+        Tool::Pen => "pen",
+        Tool::Line => "line",
+        Tool::Rect => "rect",
+        Tool::Ellipse => "ellipse",
+        Tool::Arrow => "arrow",
+        Tool::Marker => "marker",
+        Tool::Highlight => "highlight",
+        Tool::Eraser => "eraser",
+        Tool::Laser => "laser",
+        Tool::Redact => "redact",
+    }
+}
+
+/// Lowercase name used for a board mode in control replies.
+pub fn board_name(mode: BoardMode) -> &'static str {
+    match mode {
+        BoardMode::Transparent => "transparent",
+        BoardMode::Whiteboard => "whiteboard",
+        BoardMode::Blackboard => "blackboard",
+    }
+}
+
+/// Maps a capture request onto the matching capture [`Action`].
+///
+/// `prefer_clipboard` mirrors `capture.copy_to_clipboard` and picks the destination for requests
+/// that need one but did not specify it.
+pub fn capture_action(
+    target: CaptureTarget,
+    destination: Option<CaptureOutput>,
+    annotated: bool,
+    prefer_clipboard: bool,
+) -> Result<Action, String> {
+    let resolved = destination.unwrap_or(if prefer_clipboard {
+        CaptureOutput::Clipboard
+    } else {
+        CaptureOutput::File
+    });
+    let action = match (target, annotated, resolved) {
+        (CaptureTarget::Window, true, _) => {
+            return Err("annotated window captures are not supported".to_string());
+        }
+        (CaptureTarget::Window, false, _) if destination.is_some() => {
+            return Err(
+                "window captures always follow capture.copy_to_clipboard; omit the destination"
+                    .to_string(),
+            );
+        }
+        (CaptureTarget::Window, false, _) => Action::CaptureActiveWindow,
+        (CaptureTarget::Full, false, _) if destination.is_none() => Action::CaptureFullScreen,
+        (CaptureTarget::Full, false, CaptureOutput::Clipboard) => Action::CaptureClipboardFull,
+        (CaptureTarget::Full, false, CaptureOutput::File) => Action::CaptureFileFull,
+        (CaptureTarget::Full, true, CaptureOutput::Clipboard) => {
+            Action::CaptureAnnotatedClipboardFull
+        }
+        (CaptureTarget::Full, true, CaptureOutput::File) => Action::CaptureAnnotatedFileFull,
+        (CaptureTarget::Region, false, CaptureOutput::Clipboard) => Action::CaptureClipboardRegion,
+        (CaptureTarget::Region, false, CaptureOutput::File) => Action::CaptureFileRegion,
+        (CaptureTarget::Region, true, CaptureOutput::Clipboard) => {
+            Action::CaptureAnnotatedClipboardRegion
+        }
+        (CaptureTarget::Region, true, CaptureOutput::File) => Action::CaptureAnnotatedFileRegion,
+    };
+    Ok(action)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn requests_use_command_tag() {
+        let request: ControlRequest =
+            serde_json::from_str(r##"{"command":"color","color":"#ff8800"}"##).unwrap();
+        assert_eq!(
+            request,
+            ControlRequest::Color {
+                color: "#ff8800".into()
+            }
+        );
+
+        let capture = serde_json::to_string(&ControlRequest::Capture {
+            target: CaptureTarget::Region,
+            destination: Some(CaptureOutput::Clipboard),
+            annotated: false,
+        })
+        .unwrap();
+        assert_eq!(
+            capture,
+            r#"{"command":"capture","target":"region","destination":"clipboard","annotated":false}"#
+        );
+        assert!(serde_json::from_str::<ControlRequest>(r#"{"command":"explode"}"#).is_err());
+    }
+
+    #[test]
+    fn error_response_omits_missing_status() {
+        let json = serde_json::to_string(&ControlResponse::error("nope", None)).unwrap();
+        assert_eq!(json, r#"{"ok":false,"error":"nope"}"#);
+    }
+
+    #[test]
+    fn capture_action_resolves_destination() {
+        assert_eq!(
+            capture_action(CaptureTarget::Region, None, false, true),
+            Ok(Action::CaptureClipboardRegion)
+        );
+        assert_eq!(
+            capture_action(CaptureTarget::Full, None, false, true),
+            Ok(Action::CaptureFullScreen)
+        );
+        assert_eq!(
+            capture_action(CaptureTarget::Full, None, true, false),
+            Ok(Action::CaptureAnnotatedFileFull)
+        );
+        assert!(capture_action(CaptureTarget::Window, None, true, false).is_err());
+    }
+
+    #[test]
+    fn parse_color_accepts_names_and_hex() {
+        assert_eq!(
+            parse_color("red").unwrap(),
+            util::name_to_color("red").unwrap()
+        );
+        assert!(parse_color("#ff8800").is_ok());
+        assert!(parse_color("mauve-ish").is_err());
+    }
+}
@@ -0,0 +1,395 @@
+use log::{debug, warn};
+use serde::Serialize;
+use std::fs;
+use std::io::{self, BufRead, BufReader, Write};
+use std::os::unix::fs::{DirBuilderExt, PermissionsExt};
+use std::os::unix::net::{UnixListener, UnixStream};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+use super::feed::StatusFeed;
+use super::protocol::{ControlRequest, ControlResponse, ControlStatus};
+
+type Handler = dyn Fn(ControlRequest) -> ControlResponse + Send + Sync;
+
+/// Idle connections are dropped after this long so stuck clients do not pin threads forever.
+const CLIENT_IDLE_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Connections served at once (status watchers included); further clients are turned away.
+const MAX_CLIENTS: usize = 32;
+
+/// Serves newline-delimited JSON requests on a Unix socket from a background thread.
+///
+/// The socket file is removed again when the server is dropped.
+pub struct ControlServer {
+    path: PathBuf,
+    shutdown: Arc<AtomicBool>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl ControlServer {
+    /// Binds `path` and answers every request with `handler`.
+    ///
+    /// `watch` requests are not passed to the handler; the connection is subscribed to `feed`
+    /// instead and receives one status line per change.
+    ///
+    /// A stale socket left behind by a crashed process is replaced; a socket that still accepts
+    /// connections is reported as [`io::ErrorKind::AddrInUse`].
+    ///
+    /// The parent directory is created (or tightened) to mode 0700, so the socket is never
+    /// reachable by other users, not even between `bind` and the `chmod` that follows it.
+    pub fn bind<F>(path: PathBuf, handler: F, feed: StatusFeed) -> io::Result<Self>
+    where
+        F: Fn(ControlRequest) -> ControlResponse + Send + Sync + 'static,
+    {
+        if let Some(parent) = path.parent() {
+            fs::DirBuilder::new()
+                .recursive(true)
+                .mode(0o700)
+                .create(parent)?;
+            if fs::metadata(parent)?.permissions().mode() & 0o077 != 0 {
+                fs::set_permissions(parent, fs::Permissions::from_mode(0o700))?;
+            }
+        }
+        if path.exists() {
+            if UnixStream::connect(&path).is_ok() {
+                return Err(io::Error::new(
+                    io::ErrorKind::AddrInUse,
+                    format!("{} is already being served", path.display()),
+                ));
+            }
+            fs::remove_file(&path)?;
+        }
+
+        let listener = UnixListener::bind(&path)?;
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o600))?;
+
+        let shutdown = Arc::new(AtomicBool::new(false));
+        let thread_shutdown = shutdown.clone();
+        let handler: Arc<Handler> = Arc::new(handler);
+        let clients = Arc::new(AtomicUsize::new(0));
+        let thread = thread::Builder::new()
+            .name("wayscriber-control".into())
+            .spawn(move || {
+                for stream in listener.incoming() {
+                    if thread_shutdown.load(Ordering::Acquire) {
+                        break;
+                    }
+                    match stream {
+                        Ok(mut stream) => {
+                            let Some(slot) = ClientSlot::claim(&clients) else {
+                                warn!("Refusing control client: {} already connected", MAX_CLIENTS);
+                                let busy = ControlResponse::error("too many control clients", None);
+                                let _ = write_line(&mut stream, &busy);
+                                continue;
+                            };
+                            let handler = handler.clone();
+                            let feed = feed.clone();
+                            thread::spawn(move || {
+                                serve_connection(stream, handler.as_ref(), &feed);
+                                drop(slot);
+                            });
+                        }
+                        Err(err) => warn!("Control socket accept failed: {}", err),
+                    }
+                }
+            })?;
+
+        debug!("Control socket listening on {}", path.display());
+        Ok(Self {
+            path,
+            shutdown,
+            thread: Some(thread),
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Drop for ControlServer {
+    fn drop(&mut self) {
+        self.shutdown.store(true, Ordering::Release);
+        // Wake the blocking accept so the thread sees the shutdown flag.
+        let _ = UnixStream::connect(&self.path);
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+/// Counts a connected client against [`MAX_CLIENTS`] until dropped.
+struct ClientSlot(Arc<AtomicUsize>);
+
+impl ClientSlot {
+    fn claim(clients: &Arc<AtomicUsize>) -> Option<Self> {
+        clients
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |count| {
+                (count < MAX_CLIENTS).then_some(count + 1)
+            })
+            .ok()
+            .map(|_| Self(clients.clone()))
+    }
+}
+
+impl Drop for ClientSlot {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::AcqRel);
+    }
+}
+
+fn serve_connection(stream: UnixStream, handler: &Handler, feed: &StatusFeed) {
+    if let Err(err) = stream.set_read_timeout(Some(CLIENT_IDLE_TIMEOUT)) {
+        debug!("Failed to set control client timeout: {}", err);
+    }
+    let mut writer = match stream.try_clone() {
+        Ok(writer) => writer,
+        Err(err) => {
+            warn!("Failed to clone control connection: {}", err);
+            return;
+        }
+    };
+
+    for line in BufReader::new(stream).lines() {
+        let line = match line {
+            Ok(line) => line,
+            Err(err) => {
+                debug!("Control connection closed: {}", err);
+                break;
+            }
+        };
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let response = match serde_json::from_str::<ControlRequest>(&line) {
+            Ok(ControlRequest::Watch) => {
+                stream_status(&mut writer, feed);
+                break;
+            }
+            Ok(request) => {
+                debug!("Control request: {:?}", request);
+                handler(request)
+            }
+            Err(err) => ControlResponse::error(format!("invalid request: {err}"), None),
+        };
+        if let Err(err) = write_line(&mut writer, &response) {
+            debug!("Failed to write control reply: {}", err);
+            break;
+        }
+    }
+}
+
+/// Writes every status published on `feed` until the client disconnects.
+fn stream_status(writer: &mut UnixStream, feed: &StatusFeed) {
+    for status in feed.subscribe() {
+        if let Err(err) = write_line(writer, &status) {
+            debug!("Status watcher disconnected: {}", err);
+            return;
+        }
+    }
+}
+
+/// Sends one request to the socket at `path` and waits up to `timeout` for the reply.
+pub fn send_request(
+    path: &Path,
+    request: &ControlRequest,
+    timeout: Duration,
+) -> io::Result<ControlResponse> {
+    let mut stream = UnixStream::connect(path)?;
+    stream.set_read_timeout(Some(timeout))?;
+    stream.set_write_timeout(Some(timeout))?;
+    write_line(&mut stream, request)?;
+
+    let mut line = String::new();
+    BufReader::new(stream).read_line(&mut line)?;
+    if line.is_empty() {
+        return Err(io::Error::new(
+            io::ErrorKind::UnexpectedEof,
+            "control socket closed without replying",
+        ));
+    }
+    serde_json::from_str(&line).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+}
+
+/// Subscribes to the status stream at `path` and calls `on_status` for every update.
+///
+/// Returns once the server closes the stream or `on_status` returns `false`.
+pub fn watch_status(
+    path: &Path,
+    mut on_status: impl FnMut(ControlStatus) -> bool,
+) -> io::Result<()> {
+    let mut stream = UnixStream::connect(path)?;
+    write_line(&mut stream, &ControlRequest::Watch)?;
+    for line in BufReader::new(stream).lines() {
+        let status = serde_json::from_str(&line?)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+        if !on_status(status) {
+            break;
+        }
+    }
+    Ok(())
+}
+
+fn write_line<T: Serialize>(writer: &mut impl Write, value: &T) -> io::Result<()> {
+    let mut buffer = serde_json::to_vec(value).map_err(io::Error::other)?;
+    buffer.push(b'\n');
+    writer.write_all(&buffer)?;
+    writer.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TIMEOUT: Duration = Duration::from_secs(5);
+
+    fn echo_visible(request: ControlRequest) -> ControlResponse {
+        match request {
+            ControlRequest::Status => ControlResponse::ok(ControlStatus {
+                visible: true,
+                overlay: None,
+            }),
+            other => ControlResponse::error(format!("unsupported: {other:?}"), None),
+        }
+    }
+
+    #[test]
+    fn request_round_trips_through_socket() {
+        let dir = tempfile::tempdir().unwrap();
+        let server = ControlServer::bind(
+            dir.path().join("control.sock"),
+            echo_visible,
+            StatusFeed::default(),
+        )
+        .unwrap();
+
+        let reply = send_request(server.path(), &ControlRequest::Status, TIMEOUT).unwrap();
+        assert!(reply.ok);
+        assert!(reply.status.unwrap().visible);
+
+        let reply = send_request(server.path(), &ControlRequest::Undo, TIMEOUT).unwrap();
+        assert!(!reply.ok);
+    }
+
+    #[test]
+    fn malformed_request_gets_error_reply() {
+        let dir = tempfile::tempdir().unwrap();
+        let server = ControlServer::bind(
+            dir.path().join("control.sock"),
+            echo_visible,
+            StatusFeed::default(),
+        )
+        .unwrap();
+
+        let mut stream = UnixStream::connect(server.path()).unwrap();
+        stream.write_all(b"{\"command\":42}\n").unwrap();
+        let mut line = String::new();
+        BufReader::new(stream).read_line(&mut line).unwrap();
+        let reply: ControlResponse = serde_json::from_str(&line).unwrap();
+        assert!(!reply.ok);
+        assert!(reply.error.unwrap().starts_with("invalid request"));
+    }
+
+    #[test]
+    fn watch_streams_status_changes() {
+        let dir = tempfile::tempdir().unwrap();
+        let feed = StatusFeed::default();
+        feed.publish(ControlStatus::hidden());
+        let server =
+            ControlServer::bind(dir.path().join("control.sock"), echo_visible, feed.clone())
+                .unwrap();
+
+        let path = server.path().to_path_buf();
+        let watcher = thread::spawn(move || {
+            let mut seen = Vec::new();
+            watch_status(&path, |status| {
+                seen.push(status.visible);
+                seen.len() < 2
+            })
+            .unwrap();
+            seen
+        });
+        // Keep toggling until the watcher has subscribed and received a change; unchanged
+        // publishes are dropped, so a constant status could be missed if it lands first.
+        let mut visible = false;
+        while !watcher.is_finished() {
+            visible = !visible;
+            feed.publish(ControlStatus {
+                visible,
+                overlay: None,
+            });
+            thread::sleep(Duration::from_millis(10));
+        }
+        let seen = watcher.join().unwrap();
+        assert_eq!(seen.len(), 2);
+        assert_ne!(seen[0], seen[1], "second status should be a change");
+    }
+
+    #[test]
+    fn socket_directory_is_private_to_the_user() {
+        let dir = tempfile::tempdir().unwrap();
+        let parent = dir.path().join("runtime");
+        fs::create_dir(&parent).unwrap();
+        fs::set_permissions(&parent, fs::Permissions::from_mode(0o755)).unwrap();
+
+        let server = ControlServer::bind(
+            parent.join("control.sock"),
+            echo_visible,
+            StatusFeed::default(),
+        )
+        .unwrap();
+        let mode = |path: &Path| fs::metadata(path).unwrap().permissions().mode() & 0o777;
+        assert_eq!(mode(&parent), 0o700);
+        assert_eq!(mode(server.path()), 0o600);
+    }
+
+    #[test]
+    fn clients_beyond_the_limit_are_turned_away() {
+        let dir = tempfile::tempdir().unwrap();
+        let server = ControlServer::bind(
+            dir.path().join("control.sock"),
+            echo_visible,
+            StatusFeed::default(),
+        )
+        .unwrap();
+
+        let idle: Vec<_> = (0..MAX_CLIENTS)
+            .map(|_| UnixStream::connect(server.path()).unwrap())
+            .collect();
+        let reply = send_request(server.path(), &ControlRequest::Status, TIMEOUT).unwrap();
+        assert_eq!(reply.error.as_deref(), Some("too many control clients"));
+
+        drop(idle);
+        let reply = (0..100)
+            .map(|_| {
+                thread::sleep(Duration::from_millis(10));
+                send_request(server.path(), &ControlRequest::Status, TIMEOUT).unwrap()
+            })
+            .find(|reply| reply.ok);
+        assert!(reply.is_some(), "released slots are reused");
+    }
+
+    #[test]
+    fn bind_replaces_stale_socket_but_not_live_one() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("control.sock");
+        drop(UnixListener::bind(&path).unwrap());
+        assert!(path.exists());
+
+        let server = ControlServer::bind(path.clone(), echo_visible, StatusFeed::default())
+            .expect("replace stale socket");
+        let err = ControlServer::bind(path.clone(), echo_visible, StatusFeed::default())
+            .err()
+            .expect("live socket");
+        assert_eq!(err.kind(), io::ErrorKind::AddrInUse);
+
+        drop(server);
+        assert!(!path.exists());
+    }
+}
@@ -0,0 +1,77 @@
+use serde_json::{Value, json};
+
+use super::protocol::ControlStatus;
+
+/// Formats a status update as a Waybar custom-module line (`return-type: json`).
+///
+/// `text` is the active tool while the overlay is visible and empty otherwise, so the module can
+/// hide itself; `alt` and `class` carry `hidden`, `visible`, `frozen` or `capturing` for styling.
+pub fn waybar_status(status: &ControlStatus) -> Value {
+    if !status.visible {
+        return json!({
+            "text": "",
+            "alt": "hidden",
+            "class": ["hidden"],
+            "tooltip": "wayscriber: hidden",
+        });
+    }
+
+    let Some(overlay) = &status.overlay else {
+        return json!({
+            "text": "",
+            "alt": "visible",
+            "class": ["visible"],
+            "tooltip": "wayscriber: starting",
+        });
+    };
+
+    let mut classes = vec!["visible"];
+    if overlay.frozen {
+        classes.push("frozen");
+    }
+    if overlay.capture_in_progress {
+        classes.push("capturing");
+    }
+    let alt = classes.last().copied().unwrap_or("visible");
+    json!({
+        "text": overlay.tool,
+        "alt": alt,
+        "class": classes,
+        "tooltip": format!(
+            "wayscriber: {} board\ntool: {}\ncolor: {}\nthickness: {:.0}px",
+            overlay.board, overlay.tool, overlay.color, overlay.thickness
+        ),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::control::OverlayStatus;
+
+    #[test]
+    fn hidden_status_has_empty_text() {
+        let line = waybar_status(&ControlStatus::hidden());
+        assert_eq!(line["text"], "");
+        assert_eq!(line["alt"], "hidden");
+    }
+
+    #[test]
+    fn visible_status_reports_tool_and_classes() {
+        let line = waybar_status(&ControlStatus {
+            visible: true,
+            overlay: Some(OverlayStatus {
+                board: "whiteboard".into(),
+                tool: "pen".into(),
+                color: "#ff0000".into(),
+                thickness: 3.0,
+                frozen: true,
+                capture_in_progress: false,
+            }),
+        });
+        assert_eq!(line["text"], "pen");
+        assert_eq!(line["alt"], "frozen");
+        assert_eq!(line["class"], json!(["visible", "frozen"]));
+        assert!(line["tooltip"].as_str().unwrap().contains("whiteboard"));
+    }
+}
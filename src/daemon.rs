@@ -15,13 +15,11 @@ use std::env;
 #[cfg(feature = "tray")]
 use std::fs;
 use std::io::ErrorKind;
-#[cfg(feature = "tray")]
 use std::path::PathBuf;
 use std::process::{Child, Command, Stdio};
-use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, AtomicU8, AtomicU32, Ordering};
-#[cfg(feature = "tray")]
 use std::sync::mpsc;
+use std::sync::{Arc, Mutex};
 use std::thread;
 use std::thread::JoinHandle;
 use std::time::{Duration, Instant};
@@ -30,14 +28,21 @@ use std::time::{Duration, Instant};
 use crate::SESSION_OVERRIDE_FOLLOW_CONFIG;
 #[cfg(feature = "tray")]
 use crate::config::Config;
+#[cfg(feature = "tray")]
+use crate::control::CaptureTarget;
 use crate::{
-    RESUME_SESSION_ENV, decode_session_override, encode_session_override, runtime_session_override,
-    set_runtime_session_override,
+    RESUME_SESSION_ENV,
+    control::{
+        ControlRequest, ControlResponse, ControlServer, ControlStatus, StatusFeed, send_request,
+        watch_status,
+    },
+    decode_session_override, encode_session_override, paths, runtime_session_override,
+    runtime_session_profile, set_runtime_session_override, set_runtime_session_profile,
 };
 #[cfg(feature = "tray")]
 use crate::{
-    paths::{log_dir, tray_action_file},
-    session::{clear_session, options_from_config},
+    paths::log_dir,
+    session::{SessionOptions, apply_profile, clear_session, list_profiles, options_from_config},
 };
 
 /// Overlay state for daemon mode
@@ -53,16 +58,168 @@ type BackendRunner = dyn Fn(Option<String>) -> Result<()> + Send + Sync;
 #[cfg(feature = "tray")]
 const TRAY_START_TIMEOUT: Duration = Duration::from_secs(5);
 
-#[cfg(feature = "tray")]
-/// Tray-to-overlay IPC:
-/// - Daemon writes an action string to `tray_action_file()` and signals SIGUSR2 to the overlay PID.
-/// - If the overlay is not running, the daemon will auto-toggle it so queued actions run at startup.
-/// - The overlay consumes the action file on SIGUSR2 and also once at startup to catch queued work.
-/// - Action strings are simple identifiers (e.g., "toggle_freeze", "capture_full", "toggle_help").
-///   This is intentionally simple/best-effort; if a write happens between read/delete, it will be
-///   processed on the next signal/start.
-#[allow(dead_code)]
-const _: () = ();
+/// How long forwarded commands wait for a freshly started overlay to open its socket.
+const OVERLAY_START_TIMEOUT: Duration = Duration::from_secs(5);
+/// How long to wait for a single reply from the daemon loop or the overlay.
+const CONTROL_REPLY_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// Daemon end of the control protocol, shared by the control socket and the tray:
+/// - Toggle/show/hide are handed to the daemon loop, which owns the overlay process.
+/// - Everything else is forwarded to `paths::overlay_socket()`; when the overlay is hidden it is
+///   started first and the command is retried until its socket accepts connections.
+/// - Every reply carries the resulting status, so callers always learn whether the command ran.
+/// - `watch` clients are served from `feed`, which relays the overlay's own status stream while it
+///   runs and reports `hidden` otherwise.
+#[derive(Clone)]
+struct ControlRouter {
+    overlay_pid: Arc<AtomicU32>,
+    visibility: mpsc::Sender<VisibilityRequest>,
+    overlay_socket: PathBuf,
+    feed: StatusFeed,
+}
+
+struct VisibilityRequest {
+    request: ControlRequest,
+    reply: mpsc::Sender<Result<(), String>>,
+}
+
+impl ControlRouter {
+    fn new(overlay_pid: Arc<AtomicU32>, visibility: mpsc::Sender<VisibilityRequest>) -> Self {
+        Self {
+            overlay_pid,
+            visibility,
+            overlay_socket: paths::overlay_socket(),
+            feed: StatusFeed::default(),
+        }
+    }
+
+    /// Called by the daemon loop whenever the overlay process starts (`pid != 0`) or exits.
+    fn overlay_changed(&self, pid: u32) {
+        if pid == 0 {
+            self.feed.publish(ControlStatus::hidden());
+            return;
+        }
+        self.feed.publish(ControlStatus {
+            visible: true,
+            overlay: None,
+        });
+        let router = self.clone();
+        thread::spawn(move || router.relay_overlay_status(pid));
+    }
+
+    /// Copies the overlay's status stream into `feed` until overlay `pid` exits.
+    fn relay_overlay_status(&self, pid: u32) {
+        let current = || self.overlay_pid.load(Ordering::Acquire) == pid;
+        let deadline = Instant::now() + OVERLAY_START_TIMEOUT;
+        loop {
+            let result = watch_status(&self.overlay_socket, |status| {
+                if current() {
+                    self.feed.publish(status);
+                }
+                current()
+            });
+            match result {
+                Ok(()) => return,
+                Err(err)
+                    if matches!(
+                        err.kind(),
+                        ErrorKind::NotFound | ErrorKind::ConnectionRefused
+                    ) && current()
+                        && Instant::now() < deadline =>
+                {
+                    thread::sleep(Duration::from_millis(50));
+                }
+                Err(err) => {
+                    debug!("Overlay status stream ended: {}", err);
+                    return;
+                }
+            }
+        }
+    }
+
+    fn handle(&self, request: ControlRequest) -> ControlResponse {
+        if request.targets_overlay() {
+            return self.forward(request);
+        }
+        let changed = match request {
+            ControlRequest::Status => Ok(()),
+            ControlRequest::Watch => Err("watch requires a streaming connection".to_string()),
+            other => self.change_visibility(other),
+        };
+        if let Err(err) = changed {
+            return ControlResponse::error(err, Some(self.status()));
+        }
+        ControlResponse::ok(self.status())
+    }
+
+    fn overlay_running(&self) -> bool {
+        self.overlay_pid.load(Ordering::Acquire) != 0
+    }
+
+    fn change_visibility(&self, request: ControlRequest) -> Result<(), String> {
+        let (reply, result) = mpsc::channel();
+        self.visibility
+            .send(VisibilityRequest { request, reply })
+            .map_err(|_| "daemon is shutting down".to_string())?;
+        result
+            .recv_timeout(CONTROL_REPLY_TIMEOUT)
+            .map_err(|_| "daemon did not respond in time".to_string())?
+    }
+
+    fn status(&self) -> ControlStatus {
+        if !self.overlay_running() {
+            return ControlStatus::hidden();
+        }
+        match send_request(
+            &self.overlay_socket,
+            &ControlRequest::Status,
+            CONTROL_REPLY_TIMEOUT,
+        ) {
+            Ok(ControlResponse {
+                status: Some(status),
+                ..
+            }) => status,
+            // The overlay is starting up or shutting down and cannot report yet.
+            _ => ControlStatus {
+                visible: true,
+                overlay: None,
+            },
+        }
+    }
+
+    fn forward(&self, request: ControlRequest) -> ControlResponse {
+        let started = if self.overlay_running() {
+            Ok(())
+        } else {
+            self.change_visibility(ControlRequest::Show)
+        };
+        if let Err(err) = started {
+            return ControlResponse::error(err, Some(ControlStatus::hidden()));
+        }
+
+        let deadline = Instant::now() + OVERLAY_START_TIMEOUT;
+        loop {
+            match send_request(&self.overlay_socket, &request, CONTROL_REPLY_TIMEOUT) {
+                Ok(response) => return response,
+                Err(err)
+                    if matches!(
+                        err.kind(),
+                        ErrorKind::NotFound | ErrorKind::ConnectionRefused
+                    ) && self.overlay_running()
+                        && Instant::now() < deadline =>
+                {
+                    thread::sleep(Duration::from_millis(50));
+                }
+                Err(err) => {
+                    return ControlResponse::error(
+                        format!("overlay unreachable: {err}"),
+                        Some(self.status()),
+                    );
+                }
+            }
+        }
+    }
+}
 
 #[cfg(feature = "tray")]
 fn load_session_resume_enabled_from_config() -> bool {
@@ -126,6 +283,8 @@ pub struct Daemon {
     overlay_child: Option<Child>,
     overlay_pid: Arc<AtomicU32>,
     session_resume_override: Arc<AtomicU8>,
+    /// Named session the overlay opens; the tray can switch it while the daemon runs.
+    session_profile: Arc<Mutex<Option<String>>>,
 }
 
 #[cfg(feature = "tray")]
@@ -134,8 +293,8 @@ pub(crate) struct WayscriberTray {
     quit_flag: Arc<AtomicBool>,
     configurator_binary: String,
     session_resume_enabled: bool,
-    overlay_pid: Arc<AtomicU32>,
-    tray_action_path: PathBuf,
+    session_profile: Arc<Mutex<Option<String>>>,
+    control: ControlRouter,
 }
 
 #[cfg(feature = "tray")]
@@ -145,16 +304,16 @@ impl WayscriberTray {
         quit_flag: Arc<AtomicBool>,
         configurator_binary: String,
         session_resume_enabled: bool,
-        overlay_pid: Arc<AtomicU32>,
-        tray_action_path: PathBuf,
+        session_profile: Arc<Mutex<Option<String>>>,
+        control: ControlRouter,
     ) -> Self {
         Self {
             toggle_flag,
             quit_flag,
             configurator_binary,
             session_resume_enabled,
-            overlay_pid,
-            tray_action_path,
+            session_profile,
+            control,
         }
     }
 
@@ -169,8 +328,8 @@ impl WayscriberTray {
             quit_flag,
             "true".into(),
             session_resume_enabled,
-            Arc::new(AtomicU32::new(0)),
-            tray_action_file(),
+            Arc::new(Mutex::new(None)),
+            ControlRouter::new(Arc::new(AtomicU32::new(0)), mpsc::channel().0),
         )
     }
 }
@@ -237,80 +396,125 @@ impl WayscriberTray {
         }
     }
 
-    fn dispatch_overlay_action(&self, action: &str) {
-        if let Some(parent) = self.tray_action_path.parent() {
-            if let Err(err) = fs::create_dir_all(parent) {
+    fn dispatch_overlay_action(&self, request: ControlRequest) {
+        let control = self.control.clone();
+        // Forwarding may wait for the overlay to start; keep the tray menu responsive meanwhile.
+        thread::spawn(move || {
+            let response = control.handle(request.clone());
+            if !response.ok {
                 warn!(
-                    "Failed to prepare tray action directory {}: {}",
-                    parent.display(),
-                    err
+                    "Tray action {:?} failed: {}",
+                    request,
+                    response.error.unwrap_or_default()
                 );
-                return;
-            }
-        }
-
-        if let Err(err) = fs::write(&self.tray_action_path, action) {
-            warn!(
-                "Failed to write tray action {} to {}: {}",
-                action,
-                self.tray_action_path.display(),
-                err
-            );
-            return;
-        }
-
-        let pid = self.overlay_pid.load(Ordering::Acquire);
-
-        #[cfg(unix)]
-        {
-            if pid != 0 {
-                if unsafe { libc::kill(pid as i32, libc::SIGUSR2) } != 0 {
-                    warn!(
-                        "Failed to signal overlay process {} for tray action {}: {}",
-                        pid,
-                        action,
-                        std::io::Error::last_os_error()
-                    );
-                }
-            } else {
-                // Overlay not running; request it to show so the action can run on startup.
-                self.toggle_flag.store(true, Ordering::Release);
-            }
-        }
-        #[cfg(not(unix))]
-        {
-            if pid == 0 {
-                self.toggle_flag.store(true, Ordering::Release);
-            } else {
-                warn!("Tray overlay actions are only supported on Unix platforms");
             }
-        }
+        });
     }
 
-    fn clear_session_files(&self) {
+    /// Session options for the default session, as configured on disk.
+    fn load_session_options(&self) -> Option<SessionOptions> {
         match Config::load() {
             Ok(loaded) => {
                 let config_dir = match Config::config_directory_from_source(&loaded.source) {
                     Ok(dir) => dir,
                     Err(err) => {
                         warn!("Failed to resolve config directory: {}", err);
-                        return;
+                        return None;
                     }
                 };
                 match options_from_config(&loaded.config.session, &config_dir, None) {
-                    Ok(opts) => match clear_session(&opts) {
-                        Ok(outcome) => {
-                            info!("Cleared session files: {:?}", outcome);
-                        }
-                        Err(err) => warn!("Failed to clear session files: {}", err),
-                    },
-                    Err(err) => warn!("Failed to build session options: {}", err),
+                    Ok(opts) => Some(opts),
+                    Err(err) => {
+                        warn!("Failed to build session options: {}", err);
+                        None
+                    }
                 }
             }
-            Err(err) => warn!("Failed to load config for clearing session: {}", err),
+            Err(err) => {
+                warn!("Failed to load config for session options: {}", err);
+                None
+            }
         }
     }
 
+    fn current_session_profile(&self) -> Option<String> {
+        self.session_profile
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .clone()
+    }
+
+    fn clear_session_files(&self) {
+        let Some(mut opts) = self.load_session_options() else {
+            return;
+        };
+        if let Some(name) = self.current_session_profile()
+            && let Err(err) = apply_profile(&mut opts, &name)
+        {
+            warn!("Failed to select session '{}': {}", name, err);
+            return;
+        }
+        match clear_session(&opts) {
+            Ok(outcome) => {
+                info!("Cleared session files: {:?}", outcome);
+            }
+            Err(err) => warn!("Failed to clear session files: {}", err),
+        }
+    }
+
+    /// Switches the daemon to `profile` (`None` = default session).
+    ///
+    /// A running overlay is closed and reopened so it saves to the old session and loads the
+    /// new one.
+    fn select_session_profile(&self, profile: Option<String>) {
+        {
+            let mut current = self
+                .session_profile
+                .lock()
+                .unwrap_or_else(|err| err.into_inner());
+            if *current == profile {
+                return;
+            }
+            info!(
+                "Switching to session {}",
+                profile.as_deref().unwrap_or("(default)")
+            );
+            *current = profile;
+        }
+        if self.control.overlay_running() {
+            let control = self.control.clone();
+            thread::spawn(move || {
+                for request in [ControlRequest::Hide, ControlRequest::Show] {
+                    if let Err(err) = control.change_visibility(request) {
+                        warn!("Failed to reopen overlay for the new session: {}", err);
+                        break;
+                    }
+                }
+            });
+        }
+    }
+
+    /// Radio entries for the session menu: the default session followed by every named one.
+    fn session_menu_entries(&self) -> (Vec<Option<String>>, usize) {
+        let current = self.current_session_profile();
+        let mut entries: Vec<Option<String>> = vec![None];
+        if let Some(options) = self.load_session_options() {
+            match list_profiles(&options) {
+                Ok(profiles) => entries.extend(profiles.into_iter().map(|p| Some(p.name))),
+                Err(err) => warn!("Failed to list named sessions: {}", err),
+            }
+        }
+        // A session picked with --session only appears on disk after its first save.
+        if current.is_some() && !entries.contains(&current) {
+            entries.push(current.clone());
+        }
+        let selected = entries
+            .iter()
+            .position(|entry| *entry == current)
+            .unwrap_or(0);
+        (entries, selected)
+    }
+
     fn open_log_folder(&self) {
         let dir = log_dir();
         if let Err(err) = fs::create_dir_all(&dir) {
@@ -421,7 +625,7 @@ impl ksni::Tray for WayscriberTray {
                 label: "Toggle Freeze (overlay)".to_string(),
                 icon_name: "media-playback-pause".into(),
                 activate: Box::new(|this: &mut Self| {
-                    this.dispatch_overlay_action("toggle_freeze");
+                    this.dispatch_overlay_action(ControlRequest::Freeze);
                 }),
                 ..Default::default()
             }
@@ -430,7 +634,11 @@ impl ksni::Tray for WayscriberTray {
                 label: "Capture: Full Screen".to_string(),
                 icon_name: "camera-photo".into(),
                 activate: Box::new(|this: &mut Self| {
-                    this.dispatch_overlay_action("capture_full");
+                    this.dispatch_overlay_action(ControlRequest::Capture {
+                        target: CaptureTarget::Full,
+                        destination: None,
+                        annotated: false,
+                    });
                 }),
                 ..Default::default()
             }
@@ -439,7 +647,11 @@ impl ksni::Tray for WayscriberTray {
                 label: "Capture: Active Window".to_string(),
                 icon_name: "window-duplicate".into(),
                 activate: Box::new(|this: &mut Self| {
-                    this.dispatch_overlay_action("capture_window");
+                    this.dispatch_overlay_action(ControlRequest::Capture {
+                        target: CaptureTarget::Window,
+                        destination: None,
+                        annotated: false,
+                    });
                 }),
                 ..Default::default()
             }
@@ -448,7 +660,11 @@ impl ksni::Tray for WayscriberTray {
                 label: "Capture: Region".to_string(),
                 icon_name: "selection-rectangular".into(),
                 activate: Box::new(|this: &mut Self| {
-                    this.dispatch_overlay_action("capture_region");
+                    this.dispatch_overlay_action(ControlRequest::Capture {
+                        target: CaptureTarget::Region,
+                        destination: None,
+                        annotated: false,
+                    });
                 }),
                 ..Default::default()
             }
@@ -457,7 +673,7 @@ impl ksni::Tray for WayscriberTray {
                 label: "Toggle Help Overlay".to_string(),
                 icon_name: "help-browser".into(),
                 activate: Box::new(|this: &mut Self| {
-                    this.dispatch_overlay_action("toggle_help");
+                    this.dispatch_overlay_action(ControlRequest::Help);
                 }),
                 ..Default::default()
             }
@@ -497,6 +713,7 @@ impl ksni::Tray for WayscriberTray {
                 ..Default::default()
             }
             .into(),
+            self.session_submenu(),
             StandardItem {
                 label: "Clear saved session data".to_string(),
                 icon_name: "edit-clear".into(),
@@ -529,11 +746,51 @@ impl ksni::Tray for WayscriberTray {
     }
 }
 
+#[cfg(feature = "tray")]
+impl WayscriberTray {
+    fn session_submenu(&self) -> ksni::MenuItem<Self> {
+        use ksni::menu::*;
+
+        let (entries, selected) = self.session_menu_entries();
+        // ksni treats single underscores as access-key markers.
+        let label = |entry: &Option<String>| match entry {
+            Some(name) => name.replace('_', "__"),
+            None => "Default session".to_string(),
+        };
+        let options = entries
+            .iter()
+            .map(|entry| RadioItem {
+                label: label(entry),
+                ..Default::default()
+            })
+            .collect();
+        SubMenu {
+            label: format!("Session: {}", label(&entries[selected])),
+            icon_name: "document-open-recent".into(),
+            submenu: vec![
+                RadioGroup {
+                    selected,
+                    select: Box::new(move |this: &mut Self, index| {
+                        if let Some(entry) = entries.get(index) {
+                            this.select_session_profile(entry.clone());
+                        }
+                    }),
+                    options,
+                }
+                .into(),
+            ],
+            ..Default::default()
+        }
+        .into()
+    }
+}
+
 impl Daemon {
     pub fn new(
         initial_mode: Option<String>,
         tray_enabled: bool,
         session_resume_override: Option<bool>,
+        session_profile: Option<String>,
     ) -> Self {
         let override_state = Arc::new(AtomicU8::new(encode_session_override(
             session_resume_override,
@@ -549,6 +806,7 @@ impl Daemon {
             overlay_child: None,
             overlay_pid: Arc::new(AtomicU32::new(0)),
             session_resume_override: override_state,
+            session_profile: Arc::new(Mutex::new(session_profile)),
         }
     }
 
@@ -569,6 +827,7 @@ impl Daemon {
             overlay_child: None,
             overlay_pid: Arc::new(AtomicU32::new(0)),
             session_resume_override: override_state,
+            session_profile: Arc::new(Mutex::new(None)),
         }
     }
 
@@ -584,6 +843,13 @@ impl Daemon {
         decode_session_override(self.session_resume_override.load(Ordering::Acquire))
     }
 
+    fn session_profile(&self) -> Option<String> {
+        self.session_profile
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .clone()
+    }
+
     fn apply_session_override_env(&self, command: &mut Command) {
         match self.session_resume_override() {
             Some(true) => {
@@ -602,7 +868,7 @@ impl Daemon {
     pub fn run(&mut self) -> Result<()> {
         info!("Starting wayscriber daemon");
         info!("Send SIGUSR1 to toggle overlay (e.g., pkill -SIGUSR1 wayscriber)");
-        info!("Configure Hyprland: bind = SUPER, D, exec, pkill -SIGUSR1 wayscriber");
+        info!("Configure Hyprland: bind = SUPER, D, exec, wayscriber ctl toggle");
 
         // Set up signal handling
         let mut signals = Signals::new([SIGUSR1, SIGTERM, SIGINT])
@@ -645,12 +911,53 @@ impl Daemon {
             }
         });
 
+        // Serve the control socket; visibility requests come back to this loop.
+        let (visibility_tx, visibility_rx) = mpsc::channel();
+        let router = ControlRouter::new(self.overlay_pid.clone(), visibility_tx);
+        let socket_router = router.clone();
+        router.overlay_changed(0);
+        let _control_server = match ControlServer::bind(
+            paths::control_socket(),
+            move |request| socket_router.handle(request),
+            router.feed.clone(),
+        ) {
+            Ok(server) => {
+                info!("Control socket listening on {}", server.path().display());
+                Some(server)
+            }
+            Err(err) => {
+                warn!("Control socket unavailable: {}", err);
+                None
+            }
+        };
+
+        // Expose the same commands on the session bus for desktop integrations
+        #[cfg(feature = "dbus")]
+        let dbus_thread = {
+            let dbus_router = router.clone();
+            match crate::control::spawn_dbus_service(
+                move |request| dbus_router.handle(request),
+                router.feed.clone(),
+                self.should_quit.clone(),
+            ) {
+                Ok(handle) => Some(handle),
+                Err(err) => {
+                    warn!("D-Bus service unavailable: {:#}", err);
+                    None
+                }
+            }
+        };
+
         // Start system tray (optional)
         if self.tray_enabled {
             let tray_toggle = self.toggle_requested.clone();
             let tray_quit = self.should_quit.clone();
-            let tray_overlay_pid = self.overlay_pid.clone();
-            match start_system_tray(tray_toggle, tray_quit, tray_overlay_pid) {
+            match start_system_tray(
+                tray_toggle,
+                tray_quit,
+                self.session_profile.clone(),
+                router.clone(),
+            ) {
                 Ok(tray_handle) => {
                     self.tray_thread = Some(tray_handle);
                 }
@@ -668,9 +975,17 @@ impl Daemon {
         info!("Daemon ready - waiting for toggle signal");
 
         // Main daemon loop
+        let mut last_overlay_pid = 0;
         loop {
             self.update_overlay_process_state()?;
 
+            // Keep `watch` clients in sync with the overlay process
+            let overlay_pid = self.overlay_pid.load(Ordering::Acquire);
+            if overlay_pid != last_overlay_pid {
+                router.overlay_changed(overlay_pid);
+                last_overlay_pid = overlay_pid;
+            }
+
             // Check for quit signal
             // Use Acquire ordering to ensure we see all memory operations
             // that happened before the flag was set
@@ -686,6 +1001,14 @@ impl Daemon {
                 self.toggle_overlay()?;
             }
 
+            // Apply visibility changes requested over the control socket
+            while let Ok(pending) = visibility_rx.try_recv() {
+                let result = self
+                    .apply_visibility_request(&pending.request)
+                    .map_err(|err| format!("{err:#}"));
+                let _ = pending.reply.send(result);
+            }
+
             // Small sleep to avoid busy-waiting
             thread::sleep(Duration::from_millis(100));
         }
@@ -702,6 +1025,10 @@ impl Daemon {
                 Err(err) => warn!("System tray thread panicked: {:?}", err),
             }
         }
+        #[cfg(feature = "dbus")]
+        if let Some(Err(err)) = dbus_thread.map(JoinHandle::join) {
+            warn!("D-Bus service thread panicked: {:?}", err);
+        }
         Ok(())
     }
 
@@ -720,6 +1047,15 @@ impl Daemon {
         Ok(())
     }
 
+    fn apply_visibility_request(&mut self, request: &ControlRequest) -> Result<()> {
+        match request {
+            ControlRequest::Toggle => self.toggle_overlay(),
+            ControlRequest::Show => self.show_overlay(),
+            ControlRequest::Hide => self.hide_overlay(),
+            other => Err(anyhow!("{other:?} is not a visibility request")),
+        }
+    }
+
     /// Show overlay (create layer surface and enter drawing mode)
     fn show_overlay(&mut self) -> Result<()> {
         if self.overlay_state == OverlayState::Visible {
@@ -731,9 +1067,12 @@ impl Daemon {
             self.overlay_state = OverlayState::Visible;
             info!("Overlay state set to Visible");
             let previous_override = runtime_session_override();
+            let previous_profile = runtime_session_profile();
             set_runtime_session_override(self.session_resume_override());
+            set_runtime_session_profile(self.session_profile());
             let result = runner(self.initial_mode.clone());
             set_runtime_session_override(previous_override);
+            set_runtime_session_profile(previous_profile);
             self.overlay_state = OverlayState::Hidden;
             info!("Overlay closed, back to daemon mode");
             return result;
@@ -868,7 +1207,8 @@ fn fallback_tray_icon() -> Vec<ksni::Icon> {
 fn start_system_tray(
     toggle_flag: Arc<AtomicBool>,
     quit_flag: Arc<AtomicBool>,
-    overlay_pid: Arc<AtomicU32>,
+    session_profile: Arc<Mutex<Option<String>>>,
+    control: ControlRouter,
 ) -> Result<JoinHandle<()>> {
     let configurator_binary = std::env::var("WAYSCRIBER_CONFIGURATOR")
         .unwrap_or_else(|_| "wayscriber-configurator".to_string());
@@ -880,8 +1220,8 @@ fn start_system_tray(
         tray_quit_flag.clone(),
         configurator_binary,
         session_resume_enabled,
-        overlay_pid,
-        tray_action_file(),
+        session_profile,
+        control,
     );
     let (ready_tx, ready_rx) = mpsc::channel::<Result<()>>();
 
@@ -956,7 +1296,8 @@ fn start_system_tray(
 fn start_system_tray(
     _toggle_flag: Arc<AtomicBool>,
     _quit_flag: Arc<AtomicBool>,
-    _overlay_pid: Arc<AtomicU32>,
+    _session_profile: Arc<Mutex<Option<String>>>,
+    _control: ControlRouter,
 ) -> Result<JoinHandle<()>> {
     info!("Tray feature disabled; skipping system tray startup");
     Ok(thread::spawn(|| ()))
@@ -971,6 +1312,9 @@ impl Daemon {
         if let Some(mode) = &self.initial_mode {
             command.arg("--mode").arg(mode);
         }
+        if let Some(name) = self.session_profile() {
+            command.arg("--session").arg(name);
+        }
         command.stdin(Stdio::null());
         command.stdout(Stdio::null());
         command.stderr(Stdio::null());
@@ -1132,6 +1476,43 @@ mod tests {
         assert!(toggle.load(Ordering::SeqCst));
     }
 
+    #[test]
+    fn control_router_reports_hidden_status_without_overlay() {
+        let (tx, _rx) = mpsc::channel();
+        let router = ControlRouter::new(Arc::new(AtomicU32::new(0)), tx);
+
+        let reply = router.handle(ControlRequest::Status);
+        assert!(reply.ok);
+        assert_eq!(reply.status, Some(ControlStatus::hidden()));
+    }
+
+    #[test]
+    fn control_router_hands_visibility_to_daemon_loop() {
+        let (tx, rx) = mpsc::channel::<VisibilityRequest>();
+        let router = ControlRouter::new(Arc::new(AtomicU32::new(0)), tx);
+        let daemon_loop = thread::spawn(move || {
+            let pending = rx.recv().unwrap();
+            pending.reply.send(Err("spawn failed".into())).unwrap();
+            pending.request
+        });
+
+        let reply = router.handle(ControlRequest::Show);
+        assert_eq!(daemon_loop.join().unwrap(), ControlRequest::Show);
+        assert!(!reply.ok);
+        assert_eq!(reply.error.as_deref(), Some("spawn failed"));
+    }
+
+    #[test]
+    fn control_router_fails_overlay_commands_when_daemon_is_gone() {
+        let (tx, rx) = mpsc::channel::<VisibilityRequest>();
+        drop(rx);
+        let router = ControlRouter::new(Arc::new(AtomicU32::new(0)), tx);
+
+        let reply = router.handle(ControlRequest::Undo);
+        assert!(!reply.ok);
+        assert_eq!(reply.status, Some(ControlStatus::hidden()));
+    }
+
     #[test]
     fn tray_quit_action_sets_quit_flag() {
         let toggle = Arc::new(AtomicBool::new(false));
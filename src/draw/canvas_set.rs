@@ -3,62 +3,289 @@
 use super::Frame;
 use crate::input::BoardMode;
 use std::sync::LazyLock;
+use std::time::Instant;
+
+/// Deleted pages kept per board so the deletions can be undone.
+const MAX_DELETED_PAGES: usize = 16;
+
+/// A deleted page, restorable while the page it left active is untouched.
+#[derive(Debug, Clone)]
+struct DeletedPage {
+    index: usize,
+    page: Frame,
+    /// [`Frame::history_position`] of the page that became active when this one was deleted.
+    left_active: u64,
+}
 
-/// Manages multiple frames, one per board mode (with lazy initialization).
+/// Ordered list of pages for a single board, each page being its own [`Frame`].
 ///
-/// This structure maintains separate drawing frames for each board mode:
-/// - Transparent mode always has a frame (used for screen annotation)
-/// - Whiteboard and Blackboard frames are lazily created on first use
+/// Every page keeps its own shapes and undo/redo history. A board always has at least one
+/// page, and exactly one page is active at a time.
+#[derive(Debug, Clone)]
+pub struct BoardPages {
+    pages: Vec<Frame>,
+    active: usize,
+    /// Deleted pages, most recent last, for [`BoardPages::undo_delete_page`].
+    deleted: Vec<DeletedPage>,
+    /// Restored pages (index and [`Frame::revision`] at restore time) for
+    /// [`BoardPages::redo_delete_page`].
+    restored: Vec<(usize, u64)>,
+    /// Revisions of pages that left the board plus one per page added, deleted or restored, so
+    /// [`BoardPages::revision`] never goes down.
+    retired: u64,
+}
+
+impl BoardPages {
+    /// Creates a board with a single empty page.
+    pub fn new() -> Self {
+        Self {
+            pages: vec![Frame::new()],
+            active: 0,
+            deleted: Vec::new(),
+            restored: Vec::new(),
+            retired: 0,
+        }
+    }
+
+    /// Builds a board from existing pages, clamping the active index into range.
+    ///
+    /// An empty list yields a single empty page.
+    pub fn from_pages(mut pages: Vec<Frame>, active: usize) -> Self {
+        if pages.is_empty() {
+            pages.push(Frame::new());
+        }
+        let active = active.min(pages.len() - 1);
+        Self {
+            pages,
+            active,
+            deleted: Vec::new(),
+            restored: Vec::new(),
+            retired: 0,
+        }
+    }
+
+    /// Returns all pages in order.
+    pub fn pages(&self) -> &[Frame] {
+        &self.pages
+    }
+
+    /// Returns all pages in order (mutable).
+    pub fn pages_mut(&mut self) -> &mut [Frame] {
+        &mut self.pages
+    }
+
+    /// Zero-based index of the active page.
+    pub fn active_index(&self) -> usize {
+        self.active
+    }
+
+    /// Number of pages on this board.
+    pub fn page_count(&self) -> usize {
+        self.pages.len()
+    }
+
+    /// Returns the active page.
+    pub fn active_page(&self) -> &Frame {
+        &self.pages[self.active]
+    }
+
+    /// Returns the active page (mutable).
+    pub fn active_page_mut(&mut self) -> &mut Frame {
+        &mut self.pages[self.active]
+    }
+
+    /// Edit count of the board: page edits plus page additions, deletions and restores.
+    pub fn revision(&self) -> u64 {
+        self.pages.iter().fold(self.retired, |total, page| {
+            total.wrapping_add(page.revision())
+        })
+    }
+
+    /// Returns `true` if any page has shapes or history worth persisting.
+    pub fn has_persistable_data(&self) -> bool {
+        self.pages.iter().any(Frame::has_persistable_data)
+    }
+
+    /// Inserts an empty page after the active one and makes it active.
+    pub fn add_page(&mut self) {
+        self.forget_deleted_pages();
+        self.active += 1;
+        self.pages.insert(self.active, Frame::new());
+        self.retired = self.retired.wrapping_add(1);
+    }
+
+    /// Copies the active page's shapes into a new page right after it and makes it active.
+    ///
+    /// The copy starts with an empty undo history.
+    pub fn duplicate_page(&mut self) {
+        self.forget_deleted_pages();
+        let mut copy = self.active_page().clone();
+        copy.clamp_history_depth(0);
+        self.active += 1;
+        self.pages.insert(self.active, copy);
+        self.retired = self.retired.wrapping_add(1);
+    }
+
+    /// Removes the active page, activating the page before it (or the new first page).
+    ///
+    /// The page is kept so [`BoardPages::undo_delete_page`] can bring it back. Returns `false`
+    /// without changes when this is the only page.
+    pub fn delete_page(&mut self) -> bool {
+        self.restored.clear();
+        self.remove_active_page()
+    }
+
+    /// Restores the most recently deleted page once the page that became active is back at the
+    /// history position it had then, so undo walks back through page deletions in order.
+    pub fn undo_delete_page(&mut self) -> bool {
+        let position = self.active_page().history_position();
+        let undoable = self.deleted.last().is_some_and(|deleted| {
+            self.active == deleted.index.saturating_sub(1) && position == deleted.left_active
+        });
+        if !undoable {
+            return false;
+        }
+        let Some(deleted) = self.deleted.pop() else {
+            return false;
+        };
+        self.pages.insert(deleted.index, deleted.page);
+        self.active = deleted.index;
+        self.retired = self.retired.wrapping_add(1);
+        self.restored
+            .push((deleted.index, self.active_page().revision()));
+        true
+    }
+
+    /// Deletes a page restored by [`BoardPages::undo_delete_page`] again if it is still active
+    /// and untouched.
+    pub fn redo_delete_page(&mut self) -> bool {
+        let active = (self.active, self.active_page().revision());
+        if self.restored.last() != Some(&active) {
+            return false;
+        }
+        self.restored.pop();
+        self.remove_active_page()
+    }
+
+    fn remove_active_page(&mut self) -> bool {
+        if self.pages.len() <= 1 {
+            return false;
+        }
+        let index = self.active;
+        let page = self.pages.remove(index);
+        self.active = index.saturating_sub(1);
+        self.retired = self.retired.wrapping_add(page.revision()).wrapping_add(1);
+        if self.deleted.len() == MAX_DELETED_PAGES {
+            self.deleted.remove(0);
+        }
+        self.deleted.push(DeletedPage {
+            index,
+            page,
+            left_active: self.active_page().history_position(),
+        });
+        true
+    }
+
+    /// Drops undo information for deleted pages once page indices shift.
+    fn forget_deleted_pages(&mut self) {
+        self.deleted.clear();
+        self.restored.clear();
+    }
+
+    /// Activates the next page. Returns `false` when already on the last page.
+    pub fn next_page(&mut self) -> bool {
+        if self.active + 1 >= self.pages.len() {
+            return false;
+        }
+        self.active += 1;
+        true
+    }
+
+    /// Activates the previous page. Returns `false` when already on the first page.
+    pub fn previous_page(&mut self) -> bool {
+        if self.active == 0 {
+            return false;
+        }
+        self.active -= 1;
+        true
+    }
+}
+
+impl Default for BoardPages {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Manages the pages of each board mode (with lazy initialization).
+///
+/// This structure maintains separate pages for each board mode:
+/// - Transparent mode always has pages (used for screen annotation)
+/// - Whiteboard and Blackboard pages are lazily created on first use
 ///
 /// This design allows seamless mode switching while preserving work,
-/// and saves memory when board modes are never activated.
+/// and saves memory when board modes are never activated. Drawing always
+/// targets the active page of the active board.
 pub struct CanvasSet {
-    /// Frame for transparent overlay mode (always exists)
-    transparent: Frame,
-    /// Frame for whiteboard mode (lazy: created on first use)
-    whiteboard: Option<Frame>,
-    /// Frame for blackboard mode (lazy: created on first use)
-    blackboard: Option<Frame>,
+    /// Pages for transparent overlay mode (always exists)
+    transparent: BoardPages,
+    /// Pages for whiteboard mode (lazy: created on first use)
+    whiteboard: Option<BoardPages>,
+    /// Pages for blackboard mode (lazy: created on first use)
+    blackboard: Option<BoardPages>,
     /// Currently active mode
     active_mode: BoardMode,
+    /// Revisions of boards replaced through [`CanvasSet::set_pages`].
+    retired: u64,
 }
 
 impl CanvasSet {
-    /// Creates a new canvas set with only the transparent frame initialized.
+    /// Creates a new canvas set with only the transparent board initialized.
     pub fn new() -> Self {
         Self {
-            transparent: Frame::new(),
+            transparent: BoardPages::new(),
             whiteboard: None,
             blackboard: None,
             active_mode: BoardMode::Transparent,
+            retired: 0,
         }
     }
 
-    /// Gets the currently active frame (mutable).
+    /// Gets the active page of the current board (mutable).
     ///
-    /// Lazily creates whiteboard/blackboard frames if they don't exist yet.
+    /// Lazily creates whiteboard/blackboard pages if they don't exist yet.
     pub fn active_frame_mut(&mut self) -> &mut Frame {
-        match self.active_mode {
-            BoardMode::Transparent => &mut self.transparent,
-            BoardMode::Whiteboard => self.whiteboard.get_or_insert_with(Frame::new),
-            BoardMode::Blackboard => self.blackboard.get_or_insert_with(Frame::new),
-        }
+        self.active_pages_mut().active_page_mut()
     }
 
-    /// Gets the currently active frame (immutable).
+    /// Gets the active page of the current board (immutable).
     ///
     /// For board modes that don't exist yet, returns a reference to a static empty frame
     /// instead of creating one (since we can't mutate in an immutable method).
     pub fn active_frame(&self) -> &Frame {
         static EMPTY_FRAME: LazyLock<Frame> = LazyLock::new(Frame::new);
 
+        self.pages(self.active_mode)
+            .map(BoardPages::active_page)
+            .unwrap_or(&EMPTY_FRAME)
+    }
+
+    /// Gets the pages of the current board, creating them if needed.
+    pub fn active_pages_mut(&mut self) -> &mut BoardPages {
         match self.active_mode {
-            BoardMode::Transparent => &self.transparent,
-            BoardMode::Whiteboard => self.whiteboard.as_ref().unwrap_or(&EMPTY_FRAME),
-            BoardMode::Blackboard => self.blackboard.as_ref().unwrap_or(&EMPTY_FRAME),
+            BoardMode::Transparent => &mut self.transparent,
+            BoardMode::Whiteboard => self.whiteboard.get_or_insert_with(BoardPages::new),
+            BoardMode::Blackboard => self.blackboard.get_or_insert_with(BoardPages::new),
         }
     }
 
+    /// Returns the 1-based active page number and page count of the current board.
+    pub fn active_page_position(&self) -> (usize, usize) {
+        self.pages(self.active_mode).map_or((1, 1), |pages| {
+            (pages.active_index() + 1, pages.page_count())
+        })
+    }
+
     /// Returns the current active board mode.
     pub fn active_mode(&self) -> BoardMode {
         self.active_mode
@@ -66,20 +293,20 @@ impl CanvasSet {
 
     /// Switches to a different board mode.
     ///
-    /// This does not create frames lazily - they are created when first accessed
+    /// This does not create pages lazily - they are created when first accessed
     /// via `active_frame_mut()`.
     pub fn switch_mode(&mut self, new_mode: BoardMode) {
         self.active_mode = new_mode;
     }
 
-    /// Clears only the active frame.
+    /// Clears only the active page.
     #[allow(dead_code)]
     pub fn clear_active(&mut self) {
         self.active_frame_mut().clear();
     }
 
-    /// Returns an immutable reference to the frame for the requested mode, if it exists.
-    pub fn frame(&self, mode: BoardMode) -> Option<&Frame> {
+    /// Returns every page of the requested mode, if the board exists.
+    pub fn pages(&self, mode: BoardMode) -> Option<&BoardPages> {
         match mode {
             BoardMode::Transparent => Some(&self.transparent),
             BoardMode::Whiteboard => self.whiteboard.as_ref(),
@@ -87,8 +314,8 @@ impl CanvasSet {
         }
     }
 
-    /// Returns a mutable reference to the frame for the requested mode, if it exists.
-    pub fn frame_mut(&mut self, mode: BoardMode) -> Option<&mut Frame> {
+    /// Returns every page of the requested mode (mutable), if the board exists.
+    pub fn pages_mut(&mut self, mode: BoardMode) -> Option<&mut BoardPages> {
         match mode {
             BoardMode::Transparent => Some(&mut self.transparent),
             BoardMode::Whiteboard => self.whiteboard.as_mut(),
@@ -96,17 +323,65 @@ impl CanvasSet {
         }
     }
 
-    /// Replaces the frame for the requested mode with the provided data.
-    pub fn set_frame(&mut self, mode: BoardMode, frame: Option<Frame>) {
+    /// Combined [`BoardPages::revision`] of every board.
+    ///
+    /// Grows whenever an undoable action is recorded, undone or redone anywhere, or a page is
+    /// added, deleted or restored, and never goes down, which is what autosave uses to count
+    /// unsaved edits.
+    pub fn revision(&self) -> u64 {
+        [
+            Some(&self.transparent),
+            self.whiteboard.as_ref(),
+            self.blackboard.as_ref(),
+        ]
+        .into_iter()
+        .flatten()
+        .fold(self.retired, |total, board| {
+            total.wrapping_add(board.revision())
+        })
+    }
+
+    /// Removes expired vanishing shapes from every page except the active one.
+    ///
+    /// Off-screen pages need no repaint, so the expired shapes are simply dropped. Returns true
+    /// while any of those pages still holds vanishing shapes.
+    pub fn expire_background_vanishing(&mut self, now: Instant) -> bool {
+        let active_mode = self.active_mode;
+        let boards = [
+            (BoardMode::Transparent, Some(&mut self.transparent)),
+            (BoardMode::Whiteboard, self.whiteboard.as_mut()),
+            (BoardMode::Blackboard, self.blackboard.as_mut()),
+        ];
+        let mut pending = false;
+        for (mode, board) in boards {
+            let Some(board) = board else {
+                continue;
+            };
+            let active = (mode == active_mode).then_some(board.active);
+            for (index, page) in board.pages.iter_mut().enumerate() {
+                if Some(index) == active || !page.has_vanishing_shapes() {
+                    continue;
+                }
+                page.expire_vanishing(now);
+                pending |= page.has_vanishing_shapes();
+            }
+        }
+        pending
+    }
+
+    /// Replaces the pages for the requested mode with the provided data.
+    pub fn set_pages(&mut self, mode: BoardMode, pages: Option<BoardPages>) {
+        let replaced = self.pages(mode).map_or(0, BoardPages::revision);
+        self.retired = self.retired.wrapping_add(replaced);
         match mode {
             BoardMode::Transparent => {
-                self.transparent = frame.unwrap_or_default();
+                self.transparent = pages.unwrap_or_default();
             }
             BoardMode::Whiteboard => {
-                self.whiteboard = frame;
+                self.whiteboard = pages;
             }
             BoardMode::Blackboard => {
-                self.blackboard = frame;
+                self.blackboard = pages;
             }
         }
     }
@@ -123,6 +398,21 @@ mod tests {
     use super::*;
     use crate::draw::{BLACK, RED, Shape, frame::UndoAction};
 
+    fn frame_with_line() -> Frame {
+        let mut frame = Frame::new();
+        frame.add_shape(Shape::Line {
+            x1: 0,
+            y1: 0,
+            x2: 100,
+            y2: 100,
+            color: RED,
+            thick: 3.0,
+            start_color: None,
+            end_color: None,
+        });
+        frame
+    }
+
     #[test]
     fn test_initial_mode_is_transparent() {
         let canvas_set = CanvasSet::new();
@@ -287,6 +577,34 @@ mod tests {
         assert_eq!(canvas_set.active_frame().shapes.len(), 1);
     }
 
+    #[test]
+    fn test_background_vanishing_expires_on_every_board() {
+        let mut canvas_set = CanvasSet::new();
+        let now = Instant::now();
+        let line = Shape::Line {
+            x1: 0,
+            y1: 0,
+            x2: 10,
+            y2: 10,
+            color: RED,
+            thick: 3.0,
+            start_color: None,
+            end_color: None,
+        };
+        for mode in [BoardMode::Transparent, BoardMode::Whiteboard] {
+            canvas_set.switch_mode(mode);
+            let frame = canvas_set.active_frame_mut();
+            let id = frame.add_shape(line.clone());
+            frame.set_vanishing(id, now);
+        }
+
+        assert!(!canvas_set.expire_background_vanishing(now));
+        let transparent = canvas_set.pages(BoardMode::Transparent).unwrap();
+        assert!(transparent.active_page().shapes.is_empty());
+        // The page on screen is expired by the input state, which also repaints it.
+        assert_eq!(canvas_set.active_frame().shapes.len(), 1);
+    }
+
     #[test]
     fn test_immutable_access_to_nonexistent_frame() {
         let canvas_set = CanvasSet::new();
@@ -296,4 +614,164 @@ mod tests {
         // This test demonstrates the static EMPTY_FRAME pattern
         assert_eq!(canvas_set.active_frame().shapes.len(), 0);
     }
+
+    #[test]
+    fn test_pages_keep_separate_shapes() {
+        let mut canvas_set = CanvasSet::new();
+        canvas_set.switch_mode(BoardMode::Whiteboard);
+        *canvas_set.active_frame_mut() = frame_with_line();
+
+        canvas_set.active_pages_mut().add_page();
+        assert_eq!(canvas_set.active_page_position(), (2, 2));
+        assert_eq!(canvas_set.active_frame().shapes.len(), 0);
+
+        assert!(canvas_set.active_pages_mut().previous_page());
+        assert_eq!(canvas_set.active_page_position(), (1, 2));
+        assert_eq!(canvas_set.active_frame().shapes.len(), 1);
+        assert!(!canvas_set.active_pages_mut().previous_page());
+    }
+
+    #[test]
+    fn test_duplicate_page_copies_shapes_without_history() {
+        let mut pages = BoardPages::new();
+        let frame = pages.active_page_mut();
+        let id = frame.add_shape(Shape::Rect {
+            x: 10,
+            y: 10,
+            w: 50,
+            h: 50,
+            fill: false,
+            color: BLACK,
+            thick: 2.0,
+            start_color: None,
+            end_color: None,
+        });
+        let index = frame.find_index(id).unwrap();
+        frame.push_undo_action(
+            UndoAction::Create {
+                shapes: vec![(index, frame.shape(id).unwrap().clone())],
+            },
+            10,
+        );
+
+        pages.duplicate_page();
+        assert_eq!(pages.page_count(), 2);
+        assert_eq!(pages.active_index(), 1);
+        assert_eq!(pages.active_page().shapes.len(), 1);
+        assert_eq!(pages.active_page().undo_stack_len(), 0);
+        assert_eq!(pages.pages()[0].undo_stack_len(), 1);
+    }
+
+    #[test]
+    fn test_delete_page_keeps_at_least_one_page() {
+        let mut pages = BoardPages::from_pages(vec![frame_with_line(), Frame::new()], 1);
+
+        assert!(pages.delete_page());
+        assert_eq!(pages.page_count(), 1);
+        assert_eq!(pages.active_index(), 0);
+        assert_eq!(pages.active_page().shapes.len(), 1);
+
+        assert!(!pages.delete_page());
+        assert_eq!(pages.page_count(), 1);
+    }
+
+    #[test]
+    fn test_undo_restores_deleted_pages_in_order() {
+        let mut pages =
+            BoardPages::from_pages(vec![frame_with_line(), Frame::new(), frame_with_line()], 2);
+        assert!(pages.delete_page());
+        assert!(pages.delete_page());
+        assert_eq!(pages.page_count(), 1);
+
+        assert!(pages.undo_delete_page());
+        assert_eq!((pages.active_index(), pages.page_count()), (1, 2));
+        assert!(pages.undo_delete_page());
+        assert_eq!((pages.active_index(), pages.page_count()), (2, 3));
+        assert_eq!(pages.active_page().shapes.len(), 1);
+        assert!(!pages.undo_delete_page());
+
+        assert!(pages.redo_delete_page());
+        assert_eq!((pages.active_index(), pages.page_count()), (1, 2));
+        assert!(pages.undo_delete_page());
+        assert_eq!((pages.active_index(), pages.page_count()), (2, 3));
+    }
+
+    #[test]
+    fn test_page_deletion_is_undone_after_newer_history() {
+        let mut pages = BoardPages::from_pages(vec![Frame::new(), frame_with_line()], 1);
+        assert!(pages.delete_page());
+
+        let frame = pages.active_page_mut();
+        let id = frame.add_shape(Shape::Line {
+            x1: 5,
+            y1: 5,
+            x2: 50,
+            y2: 50,
+            color: BLACK,
+            thick: 2.0,
+            start_color: None,
+            end_color: None,
+        });
+        let index = frame.find_index(id).unwrap();
+        frame.push_undo_action(
+            UndoAction::Create {
+                shapes: vec![(index, frame.shape(id).unwrap().clone())],
+            },
+            10,
+        );
+        assert!(!pages.undo_delete_page(), "the new line is undone first");
+
+        pages.active_page_mut().undo_last();
+        assert!(pages.undo_delete_page());
+        assert_eq!(pages.page_count(), 2);
+
+        pages.add_page();
+        assert!(!pages.redo_delete_page(), "adding a page forgets deletions");
+    }
+
+    #[test]
+    fn test_page_deletion_waits_for_strokes_at_the_undo_limit() {
+        let record_line = |frame: &mut Frame| {
+            let id = frame.add_shape(Shape::Line {
+                x1: 5,
+                y1: 5,
+                x2: 50,
+                y2: 50,
+                color: BLACK,
+                thick: 2.0,
+                start_color: None,
+                end_color: None,
+            });
+            let index = frame.find_index(id).unwrap();
+            let shape = frame.shape(id).unwrap().clone();
+            frame.push_undo_action(
+                UndoAction::Create {
+                    shapes: vec![(index, shape)],
+                },
+                1,
+            );
+        };
+        let mut pages = BoardPages::from_pages(vec![Frame::new(), Frame::new()], 0);
+        record_line(pages.active_page_mut());
+        assert!(pages.next_page());
+        assert!(pages.delete_page());
+
+        // The page is already at its undo limit, so the new line pushes the old one out.
+        record_line(pages.active_page_mut());
+        assert_eq!(pages.active_page().undo_stack_len(), 1);
+        assert!(!pages.undo_delete_page(), "the new line is undone first");
+
+        pages.active_page_mut().undo_last();
+        assert!(pages.undo_delete_page());
+        assert_eq!(pages.page_count(), 2);
+    }
+
+    #[test]
+    fn test_from_pages_clamps_active_index() {
+        let pages = BoardPages::from_pages(vec![Frame::new(), Frame::new()], 7);
+        assert_eq!(pages.active_index(), 1);
+
+        let empty = BoardPages::from_pages(Vec::new(), 0);
+        assert_eq!(empty.page_count(), 1);
+    }
 }
@@ -2,8 +2,8 @@
 
 use super::shape::Shape;
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::collections::{HashMap, HashSet};
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
 
 /// Unique identifier for a drawn shape within a frame.
 pub type ShapeId = u64;
@@ -146,6 +146,16 @@ pub struct Frame {
     redo_stack: Vec<UndoAction>,
     #[serde(skip)]
     next_shape_id: ShapeId,
+    /// Bumped on every recorded, undone or redone action; lets autosave spot unsaved edits.
+    #[serde(skip)]
+    revision: u64,
+    /// Actions recorded or redone minus actions undone; unlike the undo stack length it keeps
+    /// counting once the stack is trimmed to its limit.
+    #[serde(skip)]
+    history_position: u64,
+    /// Vanishing-ink shapes and the instant each one expires; runtime only.
+    #[serde(skip)]
+    vanishing: HashMap<ShapeId, Instant>,
 }
 
 impl Default for Frame {
@@ -162,6 +172,9 @@ impl Frame {
             undo_stack: Vec::new(),
             redo_stack: Vec::new(),
             next_shape_id: 1,
+            revision: 0,
+            history_position: 0,
+            vanishing: HashMap::new(),
         }
     }
 
@@ -172,6 +185,7 @@ impl Frame {
         self.undo_stack.clear();
         self.redo_stack.clear();
         self.next_shape_id = 1;
+        self.vanishing.clear();
     }
 
     #[allow(dead_code)]
@@ -231,6 +245,8 @@ impl Frame {
             self.undo_stack.drain(0..overflow);
         }
         self.redo_stack.clear();
+        self.revision = self.revision.wrapping_add(1);
+        self.history_position = self.history_position.wrapping_add(1);
     }
 
     /// Undoes the most recent action, returning it for external bookkeeping.
@@ -238,6 +254,8 @@ impl Frame {
         let action = self.undo_stack.pop()?;
         self.apply_inverse(&action);
         self.redo_stack.push(action.clone());
+        self.revision = self.revision.wrapping_add(1);
+        self.history_position = self.history_position.wrapping_sub(1);
         Some(action)
     }
 
@@ -246,6 +264,8 @@ impl Frame {
         let action = self.redo_stack.pop()?;
         self.apply_action(&action);
         self.undo_stack.push(action.clone());
+        self.revision = self.revision.wrapping_add(1);
+        self.history_position = self.history_position.wrapping_add(1);
         Some(action)
     }
 
@@ -263,6 +283,18 @@ impl Frame {
         Self::primary_shape_for_redo(&action)
     }
 
+    /// Counts the undoable actions recorded, undone or redone on this page since it was created
+    /// or loaded.
+    pub fn revision(&self) -> u64 {
+        self.revision
+    }
+
+    /// Position in the page's undo history: the same value means the same action is next to
+    /// undo, even after older entries were dropped at the undo stack limit.
+    pub fn history_position(&self) -> u64 {
+        self.history_position
+    }
+
     #[allow(dead_code)]
     /// Returns a reference to the undo stack (for testing).
     pub fn undo_stack_len(&self) -> usize {
@@ -368,6 +400,59 @@ impl Frame {
         Some((index, self.shapes.remove(index)))
     }
 
+    /// Schedules a shape to vanish at `expires_at` (see [`Frame::expire_vanishing`]).
+    pub fn set_vanishing(&mut self, id: ShapeId, expires_at: Instant) {
+        self.vanishing.insert(id, expires_at);
+    }
+
+    /// Returns when a vanishing shape expires, or `None` for regular shapes.
+    pub fn vanishing_deadline(&self, id: ShapeId) -> Option<Instant> {
+        self.vanishing.get(&id).copied()
+    }
+
+    /// Returns true if any shape is scheduled to vanish.
+    pub fn has_vanishing_shapes(&self) -> bool {
+        !self.vanishing.is_empty()
+    }
+
+    /// Removes vanishing shapes whose deadline is at or before `now`.
+    ///
+    /// Expiry is not an undoable action: nothing is pushed to the history, and history entries
+    /// that reference the expired shapes are pruned so undo/redo cannot bring them back.
+    pub fn expire_vanishing(&mut self, now: Instant) -> Vec<DrawnShape> {
+        self.remove_vanishing_where(|expires_at| expires_at <= now)
+    }
+
+    /// Removes every vanishing shape at once, through the same path as [`Frame::expire_vanishing`].
+    pub fn remove_all_vanishing(&mut self) -> Vec<DrawnShape> {
+        self.remove_vanishing_where(|_| true)
+    }
+
+    fn remove_vanishing_where(&mut self, expired: impl Fn(Instant) -> bool) -> Vec<DrawnShape> {
+        let ids: HashSet<ShapeId> = self
+            .vanishing
+            .iter()
+            .filter(|(_, expires_at)| expired(**expires_at))
+            .map(|(id, _)| *id)
+            .collect();
+        if ids.is_empty() {
+            return Vec::new();
+        }
+
+        self.vanishing.retain(|id, _| !ids.contains(id));
+        let mut removed = Vec::new();
+        self.shapes.retain(|drawn| {
+            if ids.contains(&drawn.id) {
+                removed.push(drawn.clone());
+                false
+            } else {
+                true
+            }
+        });
+        self.prune_history_for_removed_ids(&ids);
+        removed
+    }
+
     /// Moves a shape from one index to another.
     pub fn move_shape(&mut self, from: usize, to: usize) -> Option<()> {
         if from >= self.shapes.len() || to >= self.shapes.len() {
@@ -599,6 +684,9 @@ impl<'de> Deserialize<'de> for Frame {
             undo_stack: helper.undo_stack,
             redo_stack: helper.redo_stack,
             next_shape_id: 1,
+            revision: 0,
+            history_position: 0,
+            vanishing: HashMap::new(),
         };
         frame.rebuild_next_id();
         Ok(frame)
@@ -1163,4 +1251,48 @@ mod tests {
             other => panic!("expected modify action, got {:?}", other),
         }
     }
+
+    #[test]
+    fn expired_vanishing_shapes_are_removed_without_history() {
+        let mut frame = Frame::new();
+        let line = Shape::Line {
+            x1: 0,
+            y1: 0,
+            x2: 10,
+            y2: 10,
+            color: BLACK,
+            thick: 2.0,
+            start_color: None,
+            end_color: None,
+        };
+        let kept = frame.add_shape(line.clone());
+        let vanishing = frame.add_shape(line);
+        for id in [kept, vanishing] {
+            let index = frame.find_index(id).unwrap();
+            frame.push_undo_action(
+                UndoAction::Create {
+                    shapes: vec![(index, frame.shape(id).unwrap().clone())],
+                },
+                100,
+            );
+        }
+
+        let now = Instant::now();
+        frame.set_vanishing(vanishing, now + std::time::Duration::from_secs(1));
+        assert!(frame.expire_vanishing(now).is_empty());
+        assert_eq!(frame.vanishing_deadline(kept), None);
+
+        let expired = frame.expire_vanishing(now + std::time::Duration::from_secs(1));
+        assert_eq!(expired.len(), 1);
+        assert_eq!(expired[0].id, vanishing);
+        assert!(!frame.has_vanishing_shapes());
+        assert_eq!(frame.shapes.len(), 1);
+        assert_eq!(frame.undo_stack_len(), 1);
+
+        // Undo only reaches the regular shape.
+        frame.undo_last();
+        assert!(frame.shapes.is_empty());
+        frame.redo_last();
+        assert_eq!(frame.shapes[0].id, kept);
+    }
 }
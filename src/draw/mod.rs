@@ -5,28 +5,35 @@
 //! - [`Shape`]: Different annotation types (lines, rectangles, text, etc.)
 //! - [`Frame`]: Container for all shapes in the current drawing
 //! - Rendering functions for Cairo-based output
+//! - SVG export of drawn shapes
+//! - Multi-page PDF export of the boards
+//! - Headless rasterisation of board pages to PNG
 
 pub mod canvas_set;
 pub mod color;
 pub mod dirty;
 pub mod font;
 pub mod frame;
+pub mod pdf;
+pub mod raster;
 pub mod render;
 pub mod shape;
+pub mod svg;
 
 // Re-export commonly used types at module level
 #[allow(unused_imports)]
-pub use canvas_set::CanvasSet;
+pub use canvas_set::{BoardPages, CanvasSet};
 pub use color::Color;
 pub use dirty::DirtyTracker;
 pub use font::FontDescriptor;
 pub use frame::{DrawnShape, Frame, ShapeId};
 pub use render::{
     EraserReplayContext, render_board_background, render_click_highlight, render_freehand_borrowed,
-    render_marker_stroke_borrowed, render_selection_halo, render_shape, render_shapes, render_text,
+    render_marker_stroke_borrowed, render_redactions, render_selection_halo, render_shape,
+    render_shapes, render_shapes_with_opacity, render_text,
 };
 #[allow(unused_imports)]
-pub use shape::{EraserBrush, EraserKind, Shape};
+pub use shape::{EraserBrush, EraserKind, RedactMode, Shape};
 
 // Re-export color constants for public API (unused internally but part of public interface)
 #[allow(unused_imports)]
@@ -0,0 +1,190 @@
+//! Multi-page PDF export of board frames.
+//!
+//! Every page of each board (transparent, whiteboard, blackboard) becomes one PDF page rendered
+//! through the same Cairo path as the live overlay, so the PDF matches what was on screen.
+
+use std::path::Path;
+
+use super::canvas_set::BoardPages;
+use super::frame::DrawnShape;
+use super::render::{EraserReplayContext, render_board_background, render_shapes};
+use crate::config::BoardConfig;
+use crate::input::BoardMode;
+
+/// Board order used for exported pages.
+const PAGE_ORDER: [BoardMode; 3] = [
+    BoardMode::Transparent,
+    BoardMode::Whiteboard,
+    BoardMode::Blackboard,
+];
+
+/// A single page of a PDF export.
+pub struct PdfPage<'a> {
+    pub mode: BoardMode,
+    pub shapes: &'a [DrawnShape],
+    /// Screenshot painted beneath the shapes instead of the board background.
+    pub background: Option<&'a cairo::ImageSurface>,
+}
+
+/// Collects one PDF page per board page that has something drawn on it, in board order.
+///
+/// `pages_for` looks up the pages of a board; missing boards and empty pages are skipped so
+/// the PDF does not end up with blank pages.
+pub fn board_pages<'a>(
+    pages_for: impl Fn(BoardMode) -> Option<&'a BoardPages>,
+) -> Vec<PdfPage<'a>> {
+    PAGE_ORDER
+        .into_iter()
+        .filter_map(|mode| pages_for(mode).map(|board| (mode, board)))
+        .flat_map(|(mode, board)| {
+            board
+                .pages()
+                .iter()
+                .filter(|frame| !frame.shapes.is_empty())
+                .map(move |frame| PdfPage {
+                    mode,
+                    shapes: &frame.shapes,
+                    background: None,
+                })
+        })
+        .collect()
+}
+
+/// Returns the smallest page size (origin at 0,0) that contains every shape on `pages`.
+pub fn pages_extent(pages: &[PdfPage]) -> Option<(f64, f64)> {
+    pages
+        .iter()
+        .flat_map(|page| page.shapes.iter())
+        .filter_map(|drawn| drawn.shape.bounding_box())
+        .map(|rect| (rect.x + rect.width, rect.y + rect.height))
+        .reduce(|(w, h), (x, y)| (w.max(x), h.max(y)))
+        .filter(|&(w, h)| w > 0 && h > 0)
+        .map(|(w, h)| (f64::from(w), f64::from(h)))
+}
+
+/// Writes `pages` to a PDF file at `path`, each page `width` x `height` points.
+///
+/// Board pages get their whiteboard/blackboard fill; a page with a `background` image has it
+/// stretched to the page and eraser strokes reveal it, just like frozen mode on screen.
+pub fn write_pdf(
+    path: &Path,
+    width: f64,
+    height: f64,
+    pages: &[PdfPage],
+    board_config: &BoardConfig,
+) -> Result<(), cairo::Error> {
+    let surface = cairo::PdfSurface::new(width, height, path)?;
+    let ctx = cairo::Context::new(&surface)?;
+
+    for page in pages {
+        ctx.save()?;
+        let background = match page.background {
+            Some(image) => Some(image_pattern(&ctx, image, width, height)?),
+            None => {
+                render_board_background(&ctx, page.mode, board_config);
+                None
+            }
+        };
+        let eraser_ctx = EraserReplayContext {
+            pattern: background.as_deref(),
+            bg_color: page.mode.background_color(board_config),
+        };
+        render_shapes(&ctx, page.shapes, Some(&eraser_ctx));
+        ctx.restore()?;
+        ctx.show_page()?;
+    }
+
+    drop(ctx);
+    surface.finish();
+    surface.status()
+}
+
+/// Paints `image` stretched over the page and returns a matching pattern for eraser replay.
+fn image_pattern(
+    ctx: &cairo::Context,
+    image: &cairo::ImageSurface,
+    width: f64,
+    height: f64,
+) -> Result<cairo::SurfacePattern, cairo::Error> {
+    let pattern = cairo::SurfacePattern::create(image);
+    pattern.set_extend(cairo::Extend::Pad);
+    let mut matrix = cairo::Matrix::identity();
+    if width > 0.0 && height > 0.0 {
+        matrix.scale(
+            f64::from(image.width()) / width,
+            f64::from(image.height()) / height,
+        );
+    }
+    pattern.set_matrix(matrix);
+
+    ctx.set_source(&pattern)?;
+    ctx.paint()?;
+    Ok(pattern)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::draw::{Color, Frame, Shape};
+
+    fn frame_with_line() -> Frame {
+        let mut frame = Frame::new();
+        frame.add_shape(Shape::Line {
+            x1: 10,
+            y1: 20,
+            x2: 110,
+            y2: 60,
+            color: Color {
+                r: 1.0,
+                g: 0.0,
+                b: 0.0,
+                a: 1.0,
+            },
+            thick: 3.0,
+            start_color: None,
+            end_color: None,
+        });
+        frame
+    }
+
+    #[test]
+    fn board_pages_skips_missing_boards_and_empty_pages() {
+        let transparent = BoardPages::new();
+        let whiteboard =
+            BoardPages::from_pages(vec![frame_with_line(), Frame::new(), frame_with_line()], 0);
+        let pages = board_pages(|mode| match mode {
+            BoardMode::Transparent => Some(&transparent),
+            BoardMode::Whiteboard => Some(&whiteboard),
+            BoardMode::Blackboard => None,
+        });
+
+        assert_eq!(pages.len(), 2);
+        assert!(pages.iter().all(|page| page.mode == BoardMode::Whiteboard));
+    }
+
+    #[test]
+    fn pages_extent_covers_all_shapes() {
+        let board = BoardPages::from_pages(vec![frame_with_line()], 0);
+        let pages = board_pages(|_| Some(&board));
+
+        let (width, height) = pages_extent(&pages).expect("extent");
+        assert!(width >= 110.0);
+        assert!(height >= 60.0);
+        assert!(pages_extent(&[]).is_none());
+    }
+
+    #[test]
+    fn write_pdf_produces_pdf_document() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("boards.pdf");
+        let board = BoardPages::from_pages(vec![frame_with_line()], 0);
+        let screenshot = cairo::ImageSurface::create(cairo::Format::ARgb32, 40, 30).unwrap();
+        let mut pages = board_pages(|_| Some(&board));
+        pages[0].background = Some(&screenshot);
+
+        write_pdf(&path, 200.0, 100.0, &pages, &BoardConfig::default()).expect("write pdf");
+
+        let bytes = std::fs::read(&path).unwrap();
+        assert!(bytes.starts_with(b"%PDF"));
+    }
+}
@@ -0,0 +1,62 @@
+//! Headless rasterisation of board pages.
+//!
+//! Draws a page onto an in-memory Cairo `ImageSurface` through the same render path as the live
+//! overlay, so boards can be turned into PNG files without a Wayland connection.
+
+use std::fs::File;
+use std::path::Path;
+
+use super::frame::DrawnShape;
+use super::render::{EraserReplayContext, render_board_background, render_shapes};
+use crate::config::BoardConfig;
+use crate::input::BoardMode;
+
+/// Largest width or height Cairo accepts for an image surface.
+pub const MAX_IMAGE_DIMENSION: u32 = 32_767;
+
+/// Returns the smallest canvas size (origin at 0,0) that contains every shape.
+pub fn shapes_extent(shapes: &[DrawnShape]) -> Option<(u32, u32)> {
+    shapes
+        .iter()
+        .filter_map(|drawn| drawn.shape.bounding_box())
+        .map(|rect| (rect.x + rect.width, rect.y + rect.height))
+        .reduce(|(w, h), (x, y)| (w.max(x), h.max(y)))
+        .filter(|&(w, h)| w > 0 && h > 0)
+        .map(|(w, h)| (w as u32, h as u32))
+}
+
+/// Renders `shapes` as a `mode` board page of `width` x `height` logical pixels.
+///
+/// The image is `scale` times larger than the logical size. Whiteboard and blackboard pages get
+/// their configured fill; the transparent board keeps a transparent background.
+pub fn render_image(
+    shapes: &[DrawnShape],
+    mode: BoardMode,
+    (width, height): (u32, u32),
+    scale: f64,
+    board_config: &BoardConfig,
+) -> Result<cairo::ImageSurface, cairo::Error> {
+    let pixel_width = (f64::from(width) * scale).ceil() as i32;
+    let pixel_height = (f64::from(height) * scale).ceil() as i32;
+    let surface = cairo::ImageSurface::create(cairo::Format::ARgb32, pixel_width, pixel_height)?;
+    {
+        let ctx = cairo::Context::new(&surface)?;
+        ctx.scale(scale, scale);
+        render_board_background(&ctx, mode, board_config);
+        let eraser_ctx = EraserReplayContext {
+            pattern: None,
+            bg_color: mode.background_color(board_config),
+        };
+        render_shapes(&ctx, shapes, Some(&eraser_ctx));
+    }
+    surface.flush();
+    Ok(surface)
+}
+
+/// Writes `image` to `path` as a PNG file.
+pub fn write_png(image: &cairo::ImageSurface, path: &Path) -> std::io::Result<()> {
+    let mut file = File::create(path)?;
+    image
+        .write_to_png(&mut file)
+        .map_err(|err| std::io::Error::other(err.to_string()))
+}
@@ -2,10 +2,15 @@
 
 use super::color::Color;
 use super::frame::DrawnShape;
-use super::shape::{EraserBrush, EraserKind, Shape};
+use super::shape::{EraserBrush, EraserKind, RedactMode, Shape};
 use crate::config::BoardConfig;
 use crate::input::BoardMode;
-use crate::util;
+use crate::util::{self, Rect};
+
+/// Size of a pixelated redaction block, in logical pixels.
+const REDACT_PIXEL_BLOCK: f64 = 12.0;
+/// Spacing of the samples a blurred redaction is rebuilt from; larger is blurrier.
+const REDACT_BLUR_BLOCK: f64 = 10.0;
 
 /// Background replay context for eraser strokes.
 pub struct EraserReplayContext<'a> {
@@ -48,16 +53,57 @@ pub fn render_shapes(
     ctx: &cairo::Context,
     shapes: &[DrawnShape],
     eraser_ctx: Option<&EraserReplayContext>,
+) {
+    render_shapes_with_opacity(ctx, shapes, eraser_ctx, |_| 1.0);
+}
+
+/// Renders shapes like [`render_shapes`], painting each at the opacity returned by `opacity`.
+///
+/// Shapes below full opacity are drawn into a group first so overlapping segments of one stroke
+/// fade together instead of darkening where they cross. Used to fade out vanishing ink.
+pub fn render_shapes_with_opacity(
+    ctx: &cairo::Context,
+    shapes: &[DrawnShape],
+    eraser_ctx: Option<&EraserReplayContext>,
+    opacity: impl Fn(&DrawnShape) -> f64,
 ) {
     for drawn in shapes {
+        let alpha = opacity(drawn).clamp(0.0, 1.0);
+        if alpha <= 0.0 {
+            continue;
+        }
+        let grouped = alpha < 1.0;
+        if grouped {
+            ctx.push_group();
+        }
         match &drawn.shape {
             Shape::EraserStroke { points, brush } => {
                 if let Some(ctx_eraser) = eraser_ctx {
                     render_eraser_stroke(ctx, points, brush, ctx_eraser);
                 }
             }
+            Shape::Redact { rect, mode } => {
+                // Without a board or frozen image beneath, the target holds nothing to obscure
+                let backdrop =
+                    eraser_ctx.is_some_and(|ctx| ctx.pattern.is_some() || ctx.bg_color.is_some());
+                render_redaction(ctx, *rect, *mode, backdrop);
+            }
             other => render_shape(ctx, other),
         }
+        if grouped && ctx.pop_group_to_source().is_ok() {
+            let _ = ctx.paint_with_alpha(alpha);
+        }
+    }
+}
+
+/// Burns the redactions among `shapes` into the pixels already on the target of `ctx`.
+///
+/// Other shapes are skipped. Used to obscure a screenshot before it is stored anywhere.
+pub fn render_redactions(ctx: &cairo::Context, shapes: &[DrawnShape]) {
+    for drawn in shapes {
+        if let Shape::Redact { rect, mode } = &drawn.shape {
+            render_redaction(ctx, *rect, *mode, true);
+        }
     }
 }
 
@@ -74,8 +120,23 @@ pub fn render_selection_halo(ctx: &cairo::Context, drawn: &DrawnShape) {
     // Ensure halo does not modify primary drawing state.
     let _ = ctx.save();
     match &drawn.shape {
-        Shape::Freehand { points, thick, .. } => {
-            render_freehand_borrowed(ctx, points, glow, thick + outline_width, None);
+        Shape::Freehand {
+            points,
+            thick,
+            per_point_widths,
+            ..
+        } => {
+            let halo_widths = per_point_widths
+                .as_ref()
+                .map(|widths| widths.iter().map(|w| w + outline_width).collect::<Vec<_>>());
+            render_freehand_borrowed(
+                ctx,
+                points,
+                glow,
+                thick + outline_width,
+                None,
+                halo_widths.as_deref(),
+            );
         }
         Shape::Line {
             x1,
@@ -134,13 +195,13 @@ pub fn render_selection_halo(ctx: &cairo::Context, drawn: &DrawnShape) {
             );
         }
         Shape::MarkerStroke { points, thick, .. } => {
-            render_freehand_borrowed(ctx, points, glow, thick + outline_width, None);
+            render_freehand_borrowed(ctx, points, glow, thick + outline_width, None, None);
         }
         Shape::EraserStroke { points, brush } => {
             let outline = brush.size + outline_width;
-            render_freehand_borrowed(ctx, points, glow, outline, None);
+            render_freehand_borrowed(ctx, points, glow, outline, None, None);
         }
-        Shape::Text { .. } => {
+        Shape::Text { .. } | Shape::Redact { .. } => {
             if let Some(bounds) = drawn.shape.bounding_box() {
                 let padding = 4.0;
                 let x = bounds.x as f64 - padding;
@@ -175,8 +236,16 @@ pub fn render_shape(ctx: &cairo::Context, shape: &Shape) {
             color,
             thick,
             per_point_colors,
+            per_point_widths,
         } => {
-            render_freehand_borrowed(ctx, points, *color, *thick, per_point_colors.as_deref());
+            render_freehand_borrowed(
+                ctx,
+                points,
+                *color,
+                *thick,
+                per_point_colors.as_deref(),
+                per_point_widths.as_deref(),
+            );
         }
         Shape::Line {
             x1,
@@ -273,6 +342,7 @@ pub fn render_shape(ctx: &cairo::Context, shape: &Shape) {
         Shape::EraserStroke { .. } => {
             // Eraser strokes require an eraser replay context; ignore in generic rendering.
         }
+        Shape::Redact { rect, mode } => render_redaction(ctx, *rect, *mode, true),
     }
 }
 
@@ -327,12 +397,14 @@ pub fn render_click_highlight(
 /// This function accepts a borrowed slice, avoiding clones for better performance.
 /// Use this for rendering provisional shapes during drawing to prevent quadratic behavior.
 /// If per_point_colors is Some, each segment will be rendered with its own color (rainbow effect).
+/// If per_point_widths is Some, each segment is stroked with the average width of its endpoints.
 pub fn render_freehand_borrowed(
     ctx: &cairo::Context,
     points: &[(i32, i32)],
     color: Color,
     thick: f64,
     per_point_colors: Option<&[Color]>,
+    per_point_widths: Option<&[f64]>,
 ) {
     if points.is_empty() {
         return;
@@ -342,33 +414,56 @@ pub fn render_freehand_borrowed(
     ctx.set_line_cap(cairo::LineCap::Round);
     ctx.set_line_join(cairo::LineJoin::Round);
 
-    // If we have per-point colors, render each segment with a gradient
-    if let Some(colors) = per_point_colors {
-        if colors.len() == points.len() && points.len() > 1 {
-            log::debug!("Rendering freehand with {} rainbow gradient segments", points.len() - 1);
-            for i in 0..points.len() - 1 {
-                let (x0, y0) = points[i];
-                let (x1, y1) = points[i + 1];
+    let colors = per_point_colors.filter(|colors| colors.len() == points.len());
+    let widths = per_point_widths.filter(|widths| widths.len() == points.len());
+
+    // If we have per-point colors or widths, render each segment on its own
+    if (colors.is_some() || widths.is_some()) && points.len() > 1 {
+        log::debug!(
+            "Rendering freehand with {} per-point segments (colors: {}, widths: {})",
+            points.len() - 1,
+            colors.is_some(),
+            widths.is_some()
+        );
+        // Segments overlap at their round caps. Draw them into a group with SOURCE so the
+        // joints are covered once, then composite the whole stroke in a single paint;
+        // otherwise translucent strokes come out darker at every joint.
+        ctx.push_group();
+        ctx.set_operator(cairo::Operator::Source);
+        for i in 0..points.len() - 1 {
+            let (x0, y0) = points[i];
+            let (x1, y1) = points[i + 1];
+
+            if let Some(widths) = widths {
+                ctx.set_line_width(((widths[i] + widths[i + 1]) / 2.0).max(0.5));
+            }
+
+            if let Some(colors) = colors {
                 let c0 = colors[i];
                 let c1 = colors[i + 1];
 
                 // Create a linear gradient from start point to end point
-                let gradient = cairo::LinearGradient::new(
-                    x0 as f64,
-                    y0 as f64,
-                    x1 as f64,
-                    y1 as f64,
-                );
+                let gradient =
+                    cairo::LinearGradient::new(x0 as f64, y0 as f64, x1 as f64, y1 as f64);
                 gradient.add_color_stop_rgba(0.0, c0.r, c0.g, c0.b, c0.a);
                 gradient.add_color_stop_rgba(1.0, c1.r, c1.g, c1.b, c1.a);
-
                 let _ = ctx.set_source(&gradient);
-                ctx.move_to(x0 as f64, y0 as f64);
-                ctx.line_to(x1 as f64, y1 as f64);
-                let _ = ctx.stroke();
+            } else {
+                ctx.set_source_rgba(color.r, color.g, color.b, color.a);
             }
-            return;
+
+            ctx.move_to(x0 as f64, y0 as f64);
+            ctx.line_to(x1 as f64, y1 as f64);
+            let _ = ctx.stroke();
+        }
+        if ctx.pop_group_to_source().is_ok() {
+            let _ = ctx.paint();
         }
+        return;
+    }
+
+    if let Some(widths) = widths {
+        ctx.set_line_width(widths[0].max(0.5));
     }
 
     // Default rendering with single color
@@ -446,6 +541,93 @@ fn render_eraser_stroke(
     let _ = ctx.restore();
 }
 
+/// Obscures the pixels already drawn inside `rect`.
+///
+/// Blur and pixelate shrink the covered device pixels to a few averaged samples and stretch them
+/// back (smoothly or as blocks), so no detail of the original survives. When there is no
+/// `backdrop` to sample, or the target cannot be read back (e.g. PDF), the region is filled
+/// solid instead.
+fn render_redaction(ctx: &cairo::Context, rect: Rect, mode: RedactMode, backdrop: bool) {
+    let (x, y, w, h) = (
+        rect.x as f64,
+        rect.y as f64,
+        rect.width as f64,
+        rect.height as f64,
+    );
+    if w <= 0.0 || h <= 0.0 {
+        return;
+    }
+
+    let _ = ctx.save();
+    ctx.rectangle(x, y, w, h);
+    ctx.clip();
+    let block = match mode {
+        RedactMode::Blur => Some(REDACT_BLUR_BLOCK),
+        RedactMode::Pixelate => Some(REDACT_PIXEL_BLOCK),
+        RedactMode::Solid => None,
+    };
+    let obscured = backdrop
+        && block.is_some_and(|block| {
+            paint_obscured(ctx, (x, y, w, h), block, mode == RedactMode::Blur).is_ok()
+        });
+    if !obscured {
+        ctx.set_operator(cairo::Operator::Over);
+        ctx.set_source_rgb(0.0, 0.0, 0.0);
+        let _ = ctx.paint();
+    }
+    let _ = ctx.restore();
+}
+
+/// Resamples the target under the (already clipped) region at `block` logical pixels per sample.
+fn paint_obscured(
+    ctx: &cairo::Context,
+    (x, y, w, h): (f64, f64, f64, f64),
+    block: f64,
+    smooth: bool,
+) -> Result<(), cairo::Error> {
+    let source = cairo::ImageSurface::try_from(ctx.target())
+        .map_err(|_| cairo::Error::SurfaceTypeMismatch)?;
+    let (x1, y1) = ctx.user_to_device(x, y);
+    let (x2, y2) = ctx.user_to_device(x + w, y + h);
+    let (left, top) = (x1.min(x2), y1.min(y2));
+    let (width, height) = ((x2 - x1).abs(), (y2 - y1).abs());
+    if width < 1.0 || height < 1.0 {
+        return Err(cairo::Error::InvalidSize);
+    }
+
+    // Blocks keep their logical size on scaled and zoomed outputs
+    let device_block = block * width / w;
+    let cols = (width / device_block).ceil().max(1.0);
+    let rows = (height / device_block).ceil().max(1.0);
+    let samples = cairo::ImageSurface::create(cairo::Format::ARgb32, cols as i32, rows as i32)?;
+    {
+        let sample_ctx = cairo::Context::new(&samples)?;
+        sample_ctx.scale(cols / width, rows / height);
+        sample_ctx.set_source_surface(&source, -left, -top)?;
+        let pattern = sample_ctx.source();
+        pattern.set_extend(cairo::Extend::Pad);
+        // Box-filters while shrinking, so every sample averages its whole block
+        pattern.set_filter(cairo::Filter::Good);
+        sample_ctx.set_operator(cairo::Operator::Source);
+        sample_ctx.paint()?;
+    }
+    samples.flush();
+
+    ctx.identity_matrix();
+    ctx.translate(left, top);
+    ctx.scale(width / cols, height / rows);
+    ctx.set_source_surface(&samples, 0.0, 0.0)?;
+    let pattern = ctx.source();
+    pattern.set_extend(cairo::Extend::Pad);
+    pattern.set_filter(if smooth {
+        cairo::Filter::Bilinear
+    } else {
+        cairo::Filter::Nearest
+    });
+    ctx.set_operator(cairo::Operator::Source);
+    ctx.paint()
+}
+
 /// Render a marker stroke with soft edges and screen blending to mimic a physical highlighter.
 /// If per_point_colors is Some, each segment will be rendered with its own color (rainbow effect).
 pub fn render_marker_stroke_borrowed(
@@ -3,6 +3,7 @@
 use super::color::Color;
 use super::font::FontDescriptor;
 use crate::util::{self, Rect};
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 /// Brush options for eraser strokes.
@@ -21,6 +22,19 @@ pub enum EraserKind {
     Rect,
 }
 
+/// How a redaction obscures the pixels beneath it.
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum RedactMode {
+    /// Heavy blur of the covered pixels
+    Blur,
+    /// Coarse blocks averaging the covered pixels
+    #[default]
+    Pixelate,
+    /// Opaque fill that ignores the pixels beneath
+    Solid,
+}
+
 /// Represents a drawable shape or annotation on screen.
 ///
 /// Each variant represents a different drawing tool/primitive with its specific parameters.
@@ -38,6 +52,9 @@ pub enum Shape {
         /// Optional per-point colors for rainbow/gradient effects (if Some, overrides color field)
         #[serde(default, skip_serializing_if = "Option::is_none")]
         per_point_colors: Option<Vec<Color>>,
+        /// Optional per-point widths sampled from stylus pressure (if Some, overrides thick field)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        per_point_widths: Option<Vec<f64>>,
     },
     /// Straight line between two points (drawn with Shift modifier)
     Line {
@@ -167,6 +184,16 @@ pub enum Shape {
         /// Brush options (shape + diameter)
         brush: EraserBrush,
     },
+    /// Region whose underlying pixels are blurred, pixelated or blanked out
+    ///
+    /// Only the geometry and mode are stored; the obscured pixels are sampled again on every
+    /// render, so the original content never ends up in a session file.
+    Redact {
+        /// Covered region (normalized, non-negative size)
+        rect: Rect,
+        /// How the region is obscured
+        mode: RedactMode,
+    },
 }
 
 impl Shape {
@@ -176,7 +203,15 @@ impl Shape {
     /// Returns `None` only when the shape has no drawable area (e.g., degenerate data).
     pub fn bounding_box(&self) -> Option<Rect> {
         match self {
-            Shape::Freehand { points, thick, .. } => bounding_box_for_points(points, *thick),
+            Shape::Freehand {
+                points,
+                thick,
+                per_point_widths,
+                ..
+            } => bounding_box_for_points(
+                points,
+                max_stroke_width(*thick, per_point_widths.as_deref()),
+            ),
             Shape::Line {
                 x1,
                 y1,
@@ -220,6 +255,7 @@ impl Shape {
                 bounding_box_for_points(points, inflated)
             }
             Shape::EraserStroke { points, brush } => bounding_box_for_eraser(points, brush.size),
+            Shape::Redact { rect, .. } => Some(*rect),
         }
     }
 
@@ -234,6 +270,7 @@ impl Shape {
             Shape::Text { .. } => "Text",
             Shape::MarkerStroke { .. } => "Marker",
             Shape::EraserStroke { .. } => "Eraser",
+            Shape::Redact { .. } => "Redaction",
         }
     }
 }
@@ -447,6 +484,13 @@ pub(crate) fn bounding_box_for_eraser(points: &[(i32, i32)], diameter: f64) -> O
     ensure_positive_rect(min_x, min_y, max_x, max_y)
 }
 
+/// Returns the widest stroke width for a freehand stroke, honouring per-point widths.
+pub(crate) fn max_stroke_width(thick: f64, per_point_widths: Option<&[f64]>) -> f64 {
+    per_point_widths
+        .map(|widths| widths.iter().copied().fold(thick, f64::max))
+        .unwrap_or(thick)
+}
+
 fn ensure_positive_rect(min_x: i32, min_y: i32, max_x: i32, max_y: i32) -> Option<Rect> {
     let (min_x, max_x) = if min_x == max_x {
         (min_x, max_x + 1)
@@ -482,6 +526,7 @@ mod tests {
             color: WHITE,
             thick: 6.0,
             per_point_colors: None,
+            per_point_widths: None,
         };
 
         let rect = shape.bounding_box().expect("freehand should have bounds");
@@ -491,6 +536,23 @@ mod tests {
         assert_eq!(rect.height, 26);
     }
 
+    #[test]
+    fn freehand_bounding_box_uses_widest_pressure_sample() {
+        let shape = Shape::Freehand {
+            points: vec![(10, 20), (30, 40)],
+            color: WHITE,
+            thick: 2.0,
+            per_point_colors: None,
+            per_point_widths: Some(vec![2.0, 10.0]),
+        };
+
+        let rect = shape.bounding_box().expect("freehand should have bounds");
+        assert_eq!(rect.x, 5);
+        assert_eq!(rect.y, 15);
+        assert_eq!(rect.width, 30);
+        assert_eq!(rect.height, 30);
+    }
+
     #[test]
     fn line_bounding_box_covers_stroke() {
         let shape = Shape::Line {
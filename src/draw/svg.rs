@@ -0,0 +1,955 @@
+//! SVG export for drawn shapes.
+//!
+//! Produces a standalone SVG document that mirrors the Cairo renderer in `render.rs`:
+//! gradients become `linearGradient` definitions, marker strokes keep their translucent
+//! two-pass look, text carries its font family/weight/style, and eraser strokes are
+//! expressed as masks over everything drawn before them. Redactions cannot sample what lies
+//! beneath them in a vector document, so every mode is written as an opaque box.
+
+use std::fmt::Write as _;
+
+use super::color::Color;
+use super::font::FontDescriptor;
+use super::frame::DrawnShape;
+use super::shape::{EraserBrush, EraserKind, Shape};
+use crate::util::{self, Rect};
+
+/// Serialises `shapes` into an SVG document covering `view`.
+///
+/// `background` paints a solid backdrop (whiteboard/blackboard) beneath all shapes; eraser
+/// strokes reveal it the same way they do on screen.
+pub fn render_svg(shapes: &[DrawnShape], view: Rect, background: Option<Color>) -> String {
+    let mut writer = SvgWriter::new(view);
+    for drawn in shapes {
+        writer.push_shape(&drawn.shape);
+    }
+    writer.finish(background)
+}
+
+/// Returns the union of the shapes' bounding boxes, or `None` when nothing is drawable.
+pub fn shapes_bounds(shapes: &[DrawnShape]) -> Option<Rect> {
+    let mut bounds: Option<(i32, i32, i32, i32)> = None;
+    for rect in shapes.iter().filter_map(|drawn| drawn.shape.bounding_box()) {
+        let (min_x, min_y, max_x, max_y) =
+            bounds.unwrap_or((rect.x, rect.y, rect.x + rect.width, rect.y + rect.height));
+        bounds = Some((
+            min_x.min(rect.x),
+            min_y.min(rect.y),
+            max_x.max(rect.x + rect.width),
+            max_y.max(rect.y + rect.height),
+        ));
+    }
+    bounds.and_then(|(min_x, min_y, max_x, max_y)| Rect::from_min_max(min_x, min_y, max_x, max_y))
+}
+
+struct SvgWriter {
+    view: Rect,
+    defs: String,
+    body: String,
+    next_id: usize,
+}
+
+impl SvgWriter {
+    fn new(view: Rect) -> Self {
+        Self {
+            view,
+            defs: String::new(),
+            body: String::new(),
+            next_id: 0,
+        }
+    }
+
+    fn finish(self, background: Option<Color>) -> String {
+        let view = self.view;
+        let mut out = String::new();
+        let _ = writeln!(out, r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+        let _ = writeln!(
+            out,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}" height="{h}" viewBox="{x} {y} {w} {h}">"#,
+            x = view.x,
+            y = view.y,
+            w = view.width,
+            h = view.height
+        );
+        if !self.defs.is_empty() {
+            let _ = writeln!(out, "<defs>\n{}</defs>", self.defs);
+        }
+        if let Some(color) = background {
+            let _ = writeln!(
+                out,
+                r#"<rect x="{}" y="{}" width="{}" height="{}" fill="{}"{}/>"#,
+                view.x,
+                view.y,
+                view.width,
+                view.height,
+                hex(color),
+                opacity_attr("fill-opacity", color.a)
+            );
+        }
+        out.push_str(&self.body);
+        out.push_str("</svg>\n");
+        out
+    }
+
+    fn next_id(&mut self, prefix: &str) -> String {
+        let id = format!("{}-{}", prefix, self.next_id);
+        self.next_id += 1;
+        id
+    }
+
+    /// Adds a two-stop linear gradient in user space and returns a `url(#..)` reference.
+    fn gradient(&mut self, from: (f64, f64), to: (f64, f64), start: Color, end: Color) -> String {
+        let id = self.next_id("gradient");
+        let _ = writeln!(
+            self.defs,
+            r#"<linearGradient id="{}" gradientUnits="userSpaceOnUse" x1="{}" y1="{}" x2="{}" y2="{}"><stop offset="0" stop-color="{}" stop-opacity="{}"/><stop offset="1" stop-color="{}" stop-opacity="{}"/></linearGradient>"#,
+            id,
+            num(from.0),
+            num(from.1),
+            num(to.0),
+            num(to.1),
+            hex(start),
+            num(start.a),
+            hex(end),
+            num(end.a)
+        );
+        format!("url(#{})", id)
+    }
+
+    /// Paint for a shape outline: a gradient when both stops are set, otherwise the base color.
+    fn stroke_paint(
+        &mut self,
+        color: Color,
+        (from, to): ((f64, f64), (f64, f64)),
+        start_color: &Option<Color>,
+        end_color: &Option<Color>,
+    ) -> String {
+        match (start_color, end_color) {
+            (Some(start), Some(end)) => {
+                let paint = self.gradient(from, to, *start, *end);
+                format!(r#"stroke="{}""#, paint)
+            }
+            _ => format!(
+                r#"stroke="{}"{}"#,
+                hex(color),
+                opacity_attr("stroke-opacity", color.a)
+            ),
+        }
+    }
+
+    fn push_shape(&mut self, shape: &Shape) {
+        match shape {
+            Shape::Freehand {
+                points,
+                color,
+                thick,
+                per_point_colors,
+                per_point_widths,
+            } => self.push_freehand(
+                points,
+                *color,
+                *thick,
+                per_point_colors.as_deref(),
+                per_point_widths.as_deref(),
+            ),
+            Shape::Line {
+                x1,
+                y1,
+                x2,
+                y2,
+                color,
+                thick,
+                start_color,
+                end_color,
+            } => self.push_line(*x1, *y1, *x2, *y2, *color, *thick, start_color, end_color),
+            Shape::Rect {
+                x,
+                y,
+                w,
+                h,
+                fill,
+                color,
+                thick,
+                start_color,
+                end_color,
+            } => {
+                let (nx, nw) = if *w >= 0 { (*x, *w) } else { (x + w, -w) };
+                let (ny, nh) = if *h >= 0 { (*y, *h) } else { (y + h, -h) };
+                let stroke = self.stroke_paint(
+                    *color,
+                    ((*x as f64, *y as f64), ((x + w) as f64, (y + h) as f64)),
+                    start_color,
+                    end_color,
+                );
+                let _ = writeln!(
+                    self.body,
+                    r#"<rect x="{}" y="{}" width="{}" height="{}" {} {} stroke-width="{}" stroke-linejoin="miter"/>"#,
+                    nx,
+                    ny,
+                    nw,
+                    nh,
+                    fill_attr(*fill, *color),
+                    stroke,
+                    num(*thick)
+                );
+            }
+            Shape::Ellipse {
+                cx,
+                cy,
+                rx,
+                ry,
+                fill,
+                color,
+                thick,
+                start_color,
+                end_color,
+            } => {
+                if *rx == 0 || *ry == 0 {
+                    return;
+                }
+                let stroke = self.stroke_paint(
+                    *color,
+                    (
+                        ((cx - rx) as f64, *cy as f64),
+                        ((cx + rx) as f64, *cy as f64),
+                    ),
+                    start_color,
+                    end_color,
+                );
+                let _ = writeln!(
+                    self.body,
+                    r#"<ellipse cx="{}" cy="{}" rx="{}" ry="{}" {} {} stroke-width="{}"/>"#,
+                    cx,
+                    cy,
+                    rx.abs(),
+                    ry.abs(),
+                    fill_attr(*fill, *color),
+                    stroke,
+                    num(*thick)
+                );
+            }
+            Shape::Arrow {
+                x1,
+                y1,
+                x2,
+                y2,
+                color,
+                thick,
+                arrow_length,
+                arrow_angle,
+                start_color,
+                end_color,
+            } => {
+                self.push_line(*x1, *y1, *x2, *y2, *color, *thick, start_color, end_color);
+                let head = util::calculate_arrowhead_custom(
+                    *x1,
+                    *y1,
+                    *x2,
+                    *y2,
+                    *arrow_length,
+                    *arrow_angle,
+                );
+                let head_color = end_color.unwrap_or(*color);
+                let _ = writeln!(
+                    self.body,
+                    r#"<polyline points="{},{} {},{} {},{}" fill="none" stroke="{}"{} stroke-width="{}" stroke-linecap="round" stroke-linejoin="round"/>"#,
+                    num(head[0].0),
+                    num(head[0].1),
+                    x1,
+                    y1,
+                    num(head[1].0),
+                    num(head[1].1),
+                    hex(head_color),
+                    opacity_attr("stroke-opacity", head_color.a),
+                    num(*thick)
+                );
+            }
+            Shape::Text {
+                x,
+                y,
+                text,
+                color,
+                size,
+                font_descriptor,
+                background_enabled,
+            } => self.push_text(
+                *x,
+                *y,
+                text,
+                *color,
+                *size,
+                font_descriptor,
+                *background_enabled,
+            ),
+            Shape::MarkerStroke {
+                points,
+                color,
+                thick,
+                per_point_colors,
+            } => self.push_marker(points, *color, *thick, per_point_colors.as_deref()),
+            Shape::EraserStroke { points, brush } => self.push_eraser(points, brush),
+            Shape::Redact { rect, .. } => {
+                let _ = writeln!(
+                    self.body,
+                    r#"<rect x="{}" y="{}" width="{}" height="{}" fill="black"/>"#,
+                    rect.x, rect.y, rect.width, rect.height
+                );
+            }
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn push_line(
+        &mut self,
+        x1: i32,
+        y1: i32,
+        x2: i32,
+        y2: i32,
+        color: Color,
+        thick: f64,
+        start_color: &Option<Color>,
+        end_color: &Option<Color>,
+    ) {
+        let stroke = self.stroke_paint(
+            color,
+            ((x1 as f64, y1 as f64), (x2 as f64, y2 as f64)),
+            start_color,
+            end_color,
+        );
+        let _ = writeln!(
+            self.body,
+            r#"<line x1="{}" y1="{}" x2="{}" y2="{}" {} stroke-width="{}" stroke-linecap="round"/>"#,
+            x1,
+            y1,
+            x2,
+            y2,
+            stroke,
+            num(thick)
+        );
+    }
+
+    fn push_freehand(
+        &mut self,
+        points: &[(i32, i32)],
+        color: Color,
+        thick: f64,
+        per_point_colors: Option<&[Color]>,
+        per_point_widths: Option<&[f64]>,
+    ) {
+        if points.is_empty() {
+            return;
+        }
+        let colors = per_point_colors.filter(|colors| colors.len() == points.len());
+        let widths = per_point_widths.filter(|widths| widths.len() == points.len());
+
+        if (colors.is_some() || widths.is_some()) && points.len() > 1 {
+            // Segments overlap at their round caps, so a shared alpha goes on the group and the
+            // lines are drawn opaque; otherwise translucent strokes darken at every joint.
+            let uniform = colors.is_none_or(|colors| colors.iter().all(|c| c.a == colors[0].a));
+            let alpha = match colors {
+                Some(colors) if uniform => colors[0].a,
+                Some(_) => 1.0,
+                None => color.a,
+            };
+            let opaque = |c: Color| if uniform { Color { a: 1.0, ..c } } else { c };
+            let _ = writeln!(
+                self.body,
+                r#"<g fill="none" stroke-linecap="round" stroke-linejoin="round"{}>"#,
+                opacity_attr("opacity", alpha)
+            );
+            for i in 0..points.len() - 1 {
+                let (x0, y0) = points[i];
+                let (x1, y1) = points[i + 1];
+                let width = match widths {
+                    Some(widths) => ((widths[i] + widths[i + 1]) / 2.0).max(0.5),
+                    None => thick,
+                };
+                let stroke = match colors {
+                    Some(colors) if (x0, y0) != (x1, y1) => {
+                        let paint = self.gradient(
+                            (x0 as f64, y0 as f64),
+                            (x1 as f64, y1 as f64),
+                            opaque(colors[i]),
+                            opaque(colors[i + 1]),
+                        );
+                        format!(r#"stroke="{}""#, paint)
+                    }
+                    Some(colors) => solid_stroke(opaque(colors[i])),
+                    None => solid_stroke(opaque(color)),
+                };
+                let _ = writeln!(
+                    self.body,
+                    r#"<line x1="{}" y1="{}" x2="{}" y2="{}" {} stroke-width="{}"/>"#,
+                    x0,
+                    y0,
+                    x1,
+                    y1,
+                    stroke,
+                    num(width)
+                );
+            }
+            let _ = writeln!(self.body, "</g>");
+            return;
+        }
+
+        let width = widths.map(|widths| widths[0].max(0.5)).unwrap_or(thick);
+        let _ = writeln!(
+            self.body,
+            r#"<polyline points="{}" fill="none" {} stroke-width="{}" stroke-linecap="round" stroke-linejoin="round"/>"#,
+            point_list(points),
+            solid_stroke(color),
+            num(width)
+        );
+    }
+
+    fn push_marker(
+        &mut self,
+        points: &[(i32, i32)],
+        color: Color,
+        thick: f64,
+        per_point_colors: Option<&[Color]>,
+    ) {
+        if points.is_empty() {
+            return;
+        }
+        // Matches render_marker_stroke_borrowed: a wide feathered pass under a core pass,
+        // both screen-blended with the configured marker opacity.
+        let soft_width = (thick * 1.25).max(thick + 1.0);
+        let marker_alpha = |c: Color| (c.a * 0.32).clamp(0.05, 0.85);
+
+        let _ = writeln!(
+            self.body,
+            r#"<g fill="none" stroke-linecap="round" stroke-linejoin="round" style="mix-blend-mode:screen">"#
+        );
+        match per_point_colors {
+            Some(colors) if colors.len() == points.len() && points.len() > 1 => {
+                for i in 0..points.len() - 1 {
+                    let (x0, y0) = points[i];
+                    let (x1, y1) = points[i + 1];
+                    for (width, factor) in [(soft_width, 0.7), (thick, 1.0)] {
+                        let start = Color {
+                            a: marker_alpha(colors[i]) * factor,
+                            ..colors[i]
+                        };
+                        let end = Color {
+                            a: marker_alpha(colors[i + 1]) * factor,
+                            ..colors[i + 1]
+                        };
+                        let paint = self.gradient(
+                            (x0 as f64, y0 as f64),
+                            (x1 as f64, y1 as f64),
+                            start,
+                            end,
+                        );
+                        let _ = writeln!(
+                            self.body,
+                            r#"<line x1="{}" y1="{}" x2="{}" y2="{}" stroke="{}" stroke-width="{}"/>"#,
+                            x0,
+                            y0,
+                            x1,
+                            y1,
+                            paint,
+                            num(width)
+                        );
+                    }
+                }
+            }
+            _ => {
+                let base_alpha = marker_alpha(color);
+                let list = point_list(points);
+                for (width, alpha) in [(soft_width, base_alpha * 0.7), (thick, base_alpha)] {
+                    let _ = writeln!(
+                        self.body,
+                        r#"<polyline points="{}" stroke="{}" stroke-opacity="{}" stroke-width="{}"/>"#,
+                        list,
+                        hex(color),
+                        num(alpha),
+                        num(width)
+                    );
+                }
+            }
+        }
+        let _ = writeln!(self.body, "</g>");
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn push_text(
+        &mut self,
+        x: i32,
+        y: i32,
+        text: &str,
+        color: Color,
+        size: f64,
+        font: &FontDescriptor,
+        background_enabled: bool,
+    ) {
+        if text.is_empty() {
+            return;
+        }
+        let metrics = TextMetrics::measure(text, size, font);
+
+        let brightness = color.r * 0.299 + color.g * 0.587 + color.b * 0.114;
+        let contrast = if brightness > 0.5 {
+            "#000000"
+        } else {
+            "#ffffff"
+        };
+
+        if let (true, Some(metrics)) = (background_enabled, metrics.as_ref()) {
+            let (ink_x, ink_y, ink_w, ink_h) = metrics.ink;
+            if ink_w > 0.0 && ink_h > 0.0 {
+                let padding = size * 0.15;
+                let top = y as f64 - metrics.baseline + ink_y;
+                let _ = writeln!(
+                    self.body,
+                    r#"<rect x="{}" y="{}" width="{}" height="{}" fill="{}" fill-opacity="0.3"/>"#,
+                    num(x as f64 + ink_x - padding),
+                    num(top - padding),
+                    num(ink_w + padding * 2.0),
+                    num(ink_h + padding * 2.0),
+                    contrast
+                );
+            }
+        }
+
+        let lines: Vec<&str> = text.split('\n').collect();
+        let offsets = metrics
+            .map(|m| m.line_offsets)
+            .filter(|offsets| offsets.len() == lines.len())
+            .unwrap_or_else(|| {
+                (0..lines.len())
+                    .map(|i| i as f64 * size * 96.0 / 72.0 * 1.2)
+                    .collect()
+            });
+
+        let font_attrs = format!(
+            r#"font-family="{}" font-weight="{}" font-style="{}" font-size="{}pt" xml:space="preserve""#,
+            escape(&font.family),
+            css_font_weight(&font.weight),
+            escape(&font.style.to_lowercase()),
+            size.round()
+        );
+        let shadow_offset = size * 0.04;
+        let spans = |origin_x: f64, origin_y: f64| {
+            lines
+                .iter()
+                .zip(&offsets)
+                .map(|(line, offset)| {
+                    format!(
+                        r#"<tspan x="{}" y="{}">{}</tspan>"#,
+                        num(origin_x),
+                        num(origin_y + offset),
+                        escape(line)
+                    )
+                })
+                .collect::<String>()
+        };
+
+        let _ = writeln!(
+            self.body,
+            r##"<text {} fill="#000000" fill-opacity="0.4">{}</text>"##,
+            font_attrs,
+            spans(x as f64 + shadow_offset, y as f64 + shadow_offset)
+        );
+        let _ = writeln!(
+            self.body,
+            r#"<text {} fill="{}"{} stroke="{}" stroke-width="{}" stroke-linejoin="round" paint-order="stroke">{}</text>"#,
+            font_attrs,
+            hex(color),
+            opacity_attr("fill-opacity", color.a),
+            contrast,
+            num(size * 0.06),
+            spans(x as f64, y as f64)
+        );
+    }
+
+    /// Wraps everything drawn so far in a mask that hides the eraser's footprint.
+    fn push_eraser(&mut self, points: &[(i32, i32)], brush: &EraserBrush) {
+        if points.is_empty() || self.body.is_empty() {
+            return;
+        }
+        let id = self.next_id("eraser");
+        let width = brush.size.max(1.0);
+        let view = self.view;
+
+        let footprint = if points.len() == 1 {
+            let (x, y) = points[0];
+            let half = width / 2.0;
+            match brush.kind {
+                EraserKind::Circle => format!(
+                    r#"<circle cx="{}" cy="{}" r="{}" fill="black"/>"#,
+                    x,
+                    y,
+                    num(half)
+                ),
+                EraserKind::Rect => format!(
+                    r#"<rect x="{}" y="{}" width="{}" height="{}" fill="black"/>"#,
+                    num(x as f64 - half),
+                    num(y as f64 - half),
+                    num(width),
+                    num(width)
+                ),
+            }
+        } else {
+            let (cap, join) = match brush.kind {
+                EraserKind::Circle => ("round", "round"),
+                EraserKind::Rect => ("square", "miter"),
+            };
+            format!(
+                r#"<polyline points="{}" fill="none" stroke="black" stroke-width="{}" stroke-linecap="{}" stroke-linejoin="{}"/>"#,
+                point_list(points),
+                num(width),
+                cap,
+                join
+            )
+        };
+
+        let _ = writeln!(
+            self.defs,
+            r#"<mask id="{id}" maskUnits="userSpaceOnUse" x="{x}" y="{y}" width="{w}" height="{h}"><rect x="{x}" y="{y}" width="{w}" height="{h}" fill="white"/>{footprint}</mask>"#,
+            id = id,
+            x = view.x,
+            y = view.y,
+            w = view.width,
+            h = view.height,
+            footprint = footprint
+        );
+        self.body = format!("<g mask=\"url(#{})\">\n{}</g>\n", id, self.body);
+    }
+}
+
+/// Pango measurements used to place text the same way `render_text` does.
+struct TextMetrics {
+    /// Ink rectangle relative to the layout origin: (x, y, width, height).
+    ink: (f64, f64, f64, f64),
+    /// Distance from the layout top to the first baseline.
+    baseline: f64,
+    /// Baseline offset of each line relative to the first line.
+    line_offsets: Vec<f64>,
+}
+
+impl TextMetrics {
+    fn measure(text: &str, size: f64, font: &FontDescriptor) -> Option<Self> {
+        let surface = cairo::ImageSurface::create(cairo::Format::ARgb32, 1, 1).ok()?;
+        let ctx = cairo::Context::new(&surface).ok()?;
+        let layout = pangocairo::functions::create_layout(&ctx);
+        let font_desc = pango::FontDescription::from_string(&font.to_pango_string(size));
+        layout.set_font_description(Some(&font_desc));
+        layout.set_text(text);
+
+        let scale = pango::SCALE as f64;
+        let (ink_rect, _logical_rect) = layout.extents();
+        let baseline = layout.baseline() as f64 / scale;
+
+        let mut line_offsets = Vec::new();
+        let mut iter = layout.iter();
+        loop {
+            line_offsets.push(iter.baseline() as f64 / scale - baseline);
+            if !iter.next_line() {
+                break;
+            }
+        }
+
+        Some(Self {
+            ink: (
+                ink_rect.x() as f64 / scale,
+                ink_rect.y() as f64 / scale,
+                ink_rect.width() as f64 / scale,
+                ink_rect.height() as f64 / scale,
+            ),
+            baseline,
+            line_offsets,
+        })
+    }
+}
+
+fn solid_stroke(color: Color) -> String {
+    format!(
+        r#"stroke="{}"{}"#,
+        hex(color),
+        opacity_attr("stroke-opacity", color.a)
+    )
+}
+
+fn fill_attr(fill: bool, color: Color) -> String {
+    if fill {
+        format!(
+            r#"fill="{}"{}"#,
+            hex(color),
+            opacity_attr("fill-opacity", color.a)
+        )
+    } else {
+        r#"fill="none""#.to_string()
+    }
+}
+
+fn opacity_attr(name: &str, alpha: f64) -> String {
+    if alpha >= 1.0 {
+        String::new()
+    } else {
+        format!(r#" {}="{}""#, name, num(alpha.max(0.0)))
+    }
+}
+
+fn hex(color: Color) -> String {
+    let channel = |v: f64| (v.clamp(0.0, 1.0) * 255.0).round() as u8;
+    format!(
+        "#{:02x}{:02x}{:02x}",
+        channel(color.r),
+        channel(color.g),
+        channel(color.b)
+    )
+}
+
+fn point_list(points: &[(i32, i32)]) -> String {
+    points
+        .iter()
+        .map(|(x, y)| format!("{},{}", x, y))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Formats a coordinate with at most three decimals and no trailing zeros.
+fn num(value: f64) -> String {
+    let formatted = format!("{:.3}", value);
+    let trimmed = formatted.trim_end_matches('0').trim_end_matches('.');
+    if trimmed == "-0" {
+        "0".to_string()
+    } else {
+        trimmed.to_string()
+    }
+}
+
+/// Maps Pango-style weight names onto CSS `font-weight` values.
+fn css_font_weight(weight: &str) -> String {
+    let lower = weight.trim().to_lowercase();
+    let mapped = match lower.as_str() {
+        "thin" => "100",
+        "ultralight" | "extralight" => "200",
+        "light" => "300",
+        "normal" | "regular" | "book" => "400",
+        "medium" => "500",
+        "semibold" | "demibold" => "600",
+        "bold" => "700",
+        "ultrabold" | "extrabold" => "800",
+        "heavy" | "black" => "900",
+        other if other.parse::<u16>().is_ok() => other,
+        _ => "400",
+    };
+    mapped.to_string()
+}
+
+fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for ch in text.chars() {
+        match ch {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&apos;"),
+            _ => out.push(ch),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::draw::{Frame, RED, RedactMode};
+
+    fn view() -> Rect {
+        Rect::new(0, 0, 200, 100).unwrap()
+    }
+
+    fn color(r: f64, g: f64, b: f64, a: f64) -> Color {
+        Color { r, g, b, a }
+    }
+
+    #[test]
+    fn line_gradient_emits_linear_gradient_definition() {
+        let mut frame = Frame::new();
+        frame.add_shape(Shape::Line {
+            x1: 10,
+            y1: 10,
+            x2: 90,
+            y2: 10,
+            color: RED,
+            thick: 3.0,
+            start_color: Some(color(1.0, 0.0, 0.0, 1.0)),
+            end_color: Some(color(0.0, 0.0, 1.0, 0.5)),
+        });
+
+        let svg = render_svg(&frame.shapes, view(), None);
+        assert!(svg.contains(r#"<linearGradient id="gradient-0" gradientUnits="userSpaceOnUse" x1="10" y1="10" x2="90" y2="10">"#));
+        assert!(svg.contains(r##"stop-color="#0000ff" stop-opacity="0.5""##));
+        assert!(svg.contains(r#"stroke="url(#gradient-0)""#));
+    }
+
+    #[test]
+    fn rainbow_freehand_emits_gradient_per_segment() {
+        let mut frame = Frame::new();
+        frame.add_shape(Shape::Freehand {
+            points: vec![(0, 0), (10, 0), (20, 0)],
+            color: RED,
+            thick: 4.0,
+            per_point_colors: Some(vec![
+                color(1.0, 0.0, 0.0, 1.0),
+                color(0.0, 1.0, 0.0, 1.0),
+                color(0.0, 0.0, 1.0, 1.0),
+            ]),
+            per_point_widths: Some(vec![2.0, 4.0, 6.0]),
+        });
+
+        let svg = render_svg(&frame.shapes, view(), None);
+        assert_eq!(svg.matches("<linearGradient").count(), 2);
+        assert!(svg.contains(r#"stroke="url(#gradient-1)" stroke-width="5""#));
+    }
+
+    #[test]
+    fn translucent_pressure_stroke_puts_opacity_on_the_group() {
+        let mut frame = Frame::new();
+        frame.add_shape(Shape::Freehand {
+            points: vec![(0, 0), (10, 0), (20, 0)],
+            color: color(1.0, 0.0, 0.0, 0.5),
+            thick: 4.0,
+            per_point_colors: None,
+            per_point_widths: Some(vec![2.0, 4.0, 6.0]),
+        });
+
+        let svg = render_svg(&frame.shapes, view(), None);
+        assert!(svg.contains(r#"stroke-linejoin="round" opacity="0.5">"#));
+        assert!(svg.contains(r##"x2="10" y2="0" stroke="#ff0000" stroke-width="3"/>"##));
+        assert!(!svg.contains("stroke-opacity"));
+    }
+
+    #[test]
+    fn text_carries_font_descriptor() {
+        let mut frame = Frame::new();
+        frame.add_shape(Shape::Text {
+            x: 5,
+            y: 40,
+            text: "a < b & c".to_string(),
+            color: RED,
+            size: 24.0,
+            font_descriptor: FontDescriptor::new(
+                "JetBrains Mono".to_string(),
+                "light".to_string(),
+                "Italic".to_string(),
+            ),
+            background_enabled: false,
+        });
+
+        let svg = render_svg(&frame.shapes, view(), None);
+        assert!(svg.contains(
+            r#"font-family="JetBrains Mono" font-weight="300" font-style="italic" font-size="24pt""#
+        ));
+        assert!(svg.contains("a &lt; b &amp; c"));
+    }
+
+    #[test]
+    fn marker_uses_scaled_opacity_and_screen_blend() {
+        let mut frame = Frame::new();
+        frame.add_shape(Shape::MarkerStroke {
+            points: vec![(0, 0), (50, 50)],
+            color: color(1.0, 1.0, 0.0, 1.0),
+            thick: 10.0,
+            per_point_colors: None,
+        });
+
+        let svg = render_svg(&frame.shapes, view(), None);
+        assert!(svg.contains("mix-blend-mode:screen"));
+        assert!(svg.contains(r#"stroke-opacity="0.32" stroke-width="10""#));
+        assert!(svg.contains(r#"stroke-opacity="0.224" stroke-width="12.5""#));
+    }
+
+    #[test]
+    fn eraser_masks_only_earlier_shapes() {
+        let mut frame = Frame::new();
+        frame.add_shape(Shape::Rect {
+            x: 0,
+            y: 0,
+            w: 50,
+            h: 50,
+            fill: true,
+            color: RED,
+            thick: 1.0,
+            start_color: None,
+            end_color: None,
+        });
+        frame.add_shape(Shape::EraserStroke {
+            points: vec![(10, 10), (40, 40)],
+            brush: EraserBrush {
+                size: 8.0,
+                kind: EraserKind::Circle,
+            },
+        });
+        frame.add_shape(Shape::Ellipse {
+            cx: 100,
+            cy: 50,
+            rx: 20,
+            ry: 10,
+            fill: false,
+            color: RED,
+            thick: 2.0,
+            start_color: None,
+            end_color: None,
+        });
+
+        let svg = render_svg(&frame.shapes, view(), Some(color(1.0, 1.0, 1.0, 1.0)));
+        assert!(svg.contains(r#"<mask id="eraser-0""#));
+        let masked_start = svg.find(r#"<g mask="url(#eraser-0)">"#).unwrap();
+        let masked_end = masked_start + svg[masked_start..].find("</g>").unwrap();
+        let rect = svg.find("<rect x=\"0\" y=\"0\" width=\"50\"").unwrap();
+        let ellipse = svg.find("<ellipse").unwrap();
+        assert!(rect > masked_start && rect < masked_end);
+        assert!(ellipse > masked_end);
+        // Background stays outside the mask so erased areas reveal it.
+        assert!(svg.find(r##"fill="#ffffff""##).unwrap() < masked_start);
+    }
+
+    #[test]
+    fn redactions_export_as_opaque_boxes() {
+        let mut frame = Frame::new();
+        frame.add_shape(Shape::Redact {
+            rect: Rect::new(10, 20, 30, 40).unwrap(),
+            mode: RedactMode::Blur,
+        });
+
+        let svg = render_svg(&frame.shapes, view(), None);
+        assert!(svg.contains(r#"<rect x="10" y="20" width="30" height="40" fill="black"/>"#));
+    }
+
+    #[test]
+    fn shapes_bounds_unions_all_shapes() {
+        let mut frame = Frame::new();
+        frame.add_shape(Shape::Rect {
+            x: 10,
+            y: 10,
+            w: 10,
+            h: 10,
+            fill: false,
+            color: RED,
+            thick: 2.0,
+            start_color: None,
+            end_color: None,
+        });
+        frame.add_shape(Shape::Rect {
+            x: 50,
+            y: 60,
+            w: 10,
+            h: 10,
+            fill: false,
+            color: RED,
+            thick: 2.0,
+            start_color: None,
+            end_color: None,
+        });
+
+        let bounds = shapes_bounds(&frame.shapes).expect("bounds");
+        assert!(bounds.x <= 10 && bounds.y <= 10);
+        assert!(bounds.x + bounds.width >= 60 && bounds.y + bounds.height >= 70);
+        assert!(shapes_bounds(&[]).is_none());
+    }
+}
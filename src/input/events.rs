@@ -1,10 +1,12 @@
 //! Generic input event types for cross-backend compatibility.
 
+use serde::{Deserialize, Serialize};
+
 /// Generic key representation for cross-backend compatibility.
 ///
 /// Backend implementations map their native key codes to these generic
 /// key values for unified input handling.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[allow(dead_code)] // Some variants used only in specific contexts
 pub enum Key {
     /// Regular character key (a-z, 0-9, symbols)
@@ -60,7 +62,7 @@ pub enum Key {
 }
 
 /// Mouse button identification.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum MouseButton {
     /// Left mouse button (primary drawing button)
     Left,
@@ -21,6 +21,13 @@ pub fn compute_hit_bounds(shape: &DrawnShape, tolerance: f64) -> Option<Rect> {
 /// Returns `true` if the point intersects the provided shape within tolerance.
 pub fn hit_test(shape: &DrawnShape, point: (i32, i32), tolerance: f64) -> bool {
     match &shape.shape {
+        Shape::Freehand {
+            points,
+            per_point_widths: Some(widths),
+            ..
+        } if widths.len() == points.len() => {
+            variable_width_freehand_hit(points, widths, point, tolerance)
+        }
         Shape::Freehand { points, thick, .. } => freehand_hit(points, point, *thick, tolerance),
         Shape::Line {
             x1,
@@ -63,7 +70,7 @@ pub fn hit_test(shape: &DrawnShape, point: (i32, i32), tolerance: f64) -> bool {
                     tolerance,
                 )
         }
-        Shape::Text { .. } => {
+        Shape::Text { .. } | Shape::Redact { .. } => {
             if let Some(bounds) = shape.shape.bounding_box() {
                 let inflate = tolerance.ceil() as i32;
                 bounds
@@ -181,6 +188,25 @@ mod tests {
         assert!(!hit_test(&ellipse, (60, 90), 1.0));
     }
 
+    #[test]
+    fn freehand_hit_follows_pressure_widths() {
+        let stroke = DrawnShape {
+            id: 3,
+            shape: Shape::Freehand {
+                points: vec![(0, 0), (100, 0), (200, 0)],
+                color: BLACK,
+                thick: 2.0,
+                per_point_colors: None,
+                per_point_widths: Some(vec![2.0, 2.0, 40.0]),
+            },
+            created_at: 0,
+            locked: false,
+        };
+
+        assert!(hit_test(&stroke, (180, 10), 1.0), "wide end should be hit");
+        assert!(!hit_test(&stroke, (20, 10), 1.0), "thin end should miss");
+    }
+
     #[test]
     fn arrowhead_hit_detects_point_near_tip_and_rejects_distant_point() {
         // Arrow pointing upwards from tail at (0, -20) to tip at (0, 0).
@@ -231,6 +257,29 @@ fn freehand_hit(points: &[(i32, i32)], point: (i32, i32), thickness: f64, tolera
     false
 }
 
+fn variable_width_freehand_hit(
+    points: &[(i32, i32)],
+    widths: &[f64],
+    point: (i32, i32),
+    tolerance: f64,
+) -> bool {
+    if points.is_empty() {
+        return false;
+    }
+    if points.len() == 1 {
+        let padded = tolerance.max(widths[0] / 2.0);
+        return distance_point_to_point(points[0], point) <= padded;
+    }
+    for i in 0..points.len() - 1 {
+        let width = (widths[i] + widths[i + 1]) / 2.0;
+        let padded = tolerance.max(width / 2.0);
+        if distance_point_to_segment(point, points[i], points[i + 1]) <= padded {
+            return true;
+        }
+    }
+    false
+}
+
 fn segment_hit(
     x1: i32,
     y1: i32,
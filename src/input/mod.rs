@@ -16,7 +16,9 @@ pub mod tool;
 // Re-export commonly used types at module level
 pub use board_mode::BoardMode;
 pub use events::{Key, MouseButton};
-pub use state::{ClickHighlightSettings, DrawingState, InputState};
+pub use state::{
+    ClickHighlightSettings, DrawingState, InputState, LaserSettings, SpotlightSettings,
+};
 #[cfg(tablet)]
 #[allow(unused_imports)]
 pub use tablet::TabletSettings;
@@ -42,6 +42,26 @@ impl InputState {
             _ => {}
         }
 
+        if self.is_recovery_prompt_open() {
+            match key {
+                Key::Return | Key::Char('y') | Key::Char('Y') => self.answer_recovery_prompt(true),
+                Key::Escape | Key::Char('n') | Key::Char('N') => self.answer_recovery_prompt(false),
+                _ => {}
+            }
+            return;
+        }
+
+        if self.is_selecting_capture_region() {
+            match key {
+                Key::Escape => self.cancel_capture_region(),
+                Key::Return | Key::Space => {
+                    let _ = self.confirm_capture_region();
+                }
+                _ => {}
+            }
+            return;
+        }
+
         if matches!(key, Key::Escape) && self.properties_panel().is_some() {
             self.close_properties_panel();
             return;
@@ -275,8 +295,8 @@ impl InputState {
         }
     }
 
-    /// Handle an action triggered by a keybinding.
-    pub(super) fn handle_action(&mut self, action: Action) {
+    /// Handle an action triggered by a keybinding or the control socket.
+    pub fn handle_action(&mut self, action: Action) {
         if !matches!(action, Action::OpenContextMenu) {
             self.close_properties_panel();
         }
@@ -301,6 +321,13 @@ impl InputState {
                         self.restore_selection_from_snapshots(snapshots.clone());
                         self.state = DrawingState::Idle;
                     }
+                    DrawingState::Idle if self.spotlight_active() => {
+                        self.exit_spotlight();
+                    }
+                    DrawingState::Idle if self.zoom_active() => {
+                        // Leave zoom before leaving the overlay
+                        self.exit_zoom();
+                    }
                     DrawingState::Idle => {
                         // Exit application
                         self.should_exit = true;
@@ -325,12 +352,17 @@ impl InputState {
                 }
             }
             Action::Undo => {
-                if let Some(action) = self.canvas_set.active_frame_mut().undo_last() {
+                // A page deletion is newer than anything left in the remaining page's history.
+                if !self.undo_delete_page()
+                    && let Some(action) = self.canvas_set.active_frame_mut().undo_last()
+                {
                     self.apply_action_side_effects(&action);
                 }
             }
             Action::Redo => {
-                if let Some(action) = self.canvas_set.active_frame_mut().redo_last() {
+                if !self.redo_delete_page()
+                    && let Some(action) = self.canvas_set.active_frame_mut().redo_last()
+                {
                     self.apply_action_side_effects(&action);
                 }
             }
@@ -465,6 +497,15 @@ impl InputState {
                 self.set_highlight_tool(true);
                 self.set_tool_override(Some(Tool::Highlight));
             }
+            Action::SelectLaserTool => {
+                self.set_tool_override(Some(Tool::Laser));
+            }
+            Action::SelectRedactTool => {
+                self.set_tool_override(Some(Tool::Redact));
+            }
+            Action::CycleRedactMode => {
+                self.cycle_redact_mode();
+            }
             Action::IncreaseFontSize => {
                 self.adjust_font_size(2.0);
             }
@@ -489,6 +530,21 @@ impl InputState {
                     self.switch_board_mode(BoardMode::Transparent);
                 }
             }
+            Action::AddPage => {
+                self.add_page();
+            }
+            Action::DuplicatePage => {
+                self.duplicate_page();
+            }
+            Action::DeletePage => {
+                self.delete_page();
+            }
+            Action::NextPage => {
+                self.next_page();
+            }
+            Action::PreviousPage => {
+                self.previous_page();
+            }
             Action::ToggleHelp => {
                 self.show_help = !self.show_help;
                 self.dirty_tracker.mark_full();
@@ -533,6 +589,9 @@ impl InputState {
                     );
                 }
             }
+            Action::ToggleVanishingInk => {
+                self.toggle_vanishing_ink();
+            }
             Action::ToggleHighlightTool => {
                 let enabled = self.toggle_all_highlights();
                 let message = if enabled {
@@ -543,6 +602,8 @@ impl InputState {
                 info!("{}", message);
             }
             Action::OpenContextMenu => {
+                // Menus are laid out in screen space, which zoom no longer matches
+                self.exit_zoom();
                 self.toggle_context_menu_via_keyboard();
             }
             Action::OpenConfigurator => {
@@ -572,15 +633,29 @@ impl InputState {
             Action::SetColorBlack => {
                 let _ = self.set_color(util::key_to_color('k').unwrap());
             }
+            Action::CaptureSelection
+            | Action::CaptureClipboardSelection
+            | Action::CaptureFileSelection
+            | Action::CaptureClipboardRegion
+            | Action::CaptureFileRegion
+            | Action::CaptureAnnotatedClipboardRegion
+            | Action::CaptureAnnotatedFileRegion => {
+                // Region captures first let the user drag out a rectangle on the overlay;
+                // the capture action is queued once the selection is confirmed.
+                self.reset_modifiers();
+                if matches!(self.state, DrawingState::Drawing { .. }) {
+                    self.clear_provisional_dirty();
+                    self.state = DrawingState::Idle;
+                }
+                self.close_context_menu();
+                self.begin_capture_region(action);
+            }
             Action::CaptureFullScreen
             | Action::CaptureActiveWindow
-            | Action::CaptureSelection
             | Action::CaptureClipboardFull
             | Action::CaptureFileFull
-            | Action::CaptureClipboardSelection
-            | Action::CaptureFileSelection
-            | Action::CaptureClipboardRegion
-            | Action::CaptureFileRegion => {
+            | Action::CaptureAnnotatedClipboardFull
+            | Action::CaptureAnnotatedFileFull => {
                 // Capture actions are handled externally by WaylandState
                 // since they require access to CaptureManager
                 // Store the action for later retrieval
@@ -596,6 +671,33 @@ impl InputState {
                 self.request_frozen_toggle();
                 self.reset_modifiers();
             }
+            Action::ToggleSpotlight => {
+                self.toggle_spotlight();
+            }
+            Action::ToggleZoom => {
+                self.toggle_zoom();
+                self.reset_modifiers();
+            }
+            Action::ExportSvg => {
+                log::info!("SVG export requested");
+                self.request_svg_export();
+                self.reset_modifiers();
+            }
+            Action::ExportPdf => {
+                log::info!("PDF export requested");
+                self.request_pdf_export();
+                self.reset_modifiers();
+            }
+            Action::SaveDocument => {
+                log::info!("Document save requested");
+                self.request_document_save();
+                self.reset_modifiers();
+            }
+            Action::OpenDocument => {
+                log::info!("Document open requested");
+                self.request_document_open();
+                self.reset_modifiers();
+            }
         }
     }
 
@@ -4,15 +4,22 @@ pub const MIN_STROKE_THICKNESS: f64 = 1.0;
 pub const MAX_STROKE_THICKNESS: f64 = 50.0;
 
 use super::{
+    capture_region::CaptureRegionState,
     index::SpatialGrid,
     menus::{ContextMenuLayout, ContextMenuState},
     properties::ShapePropertiesPanel,
     selection::SelectionState,
+    spotlight::SpotlightState,
+    vanishing::VanishingInkState,
+    zoom::ZoomState,
 };
 use crate::config::{Action, BoardConfig, KeyBinding};
 use crate::draw::frame::ShapeSnapshot;
-use crate::draw::{CanvasSet, Color, DirtyTracker, EraserKind, FontDescriptor, ShapeId};
+use crate::draw::{
+    CanvasSet, Color, DirtyTracker, EraserKind, FontDescriptor, RedactMode, ShapeId,
+};
 use crate::input::state::highlight::{ClickHighlightSettings, ClickHighlightState};
+use crate::input::state::laser::{LaserSettings, LaserState};
 use crate::input::{modifiers::Modifiers, tool::Tool};
 use crate::util::Rect;
 use std::collections::HashMap;
@@ -69,12 +76,20 @@ pub struct InputState {
     pub(super) rainbow_hue: f64,
     /// How much to increment hue per pixel of distance in rainbow mode
     pub rainbow_hue_step_per_pixel: f64,
+    /// Vanishing ink mode (strokes that fade out on their own)
+    pub(super) vanishing_ink: VanishingInkState,
     /// Current pen/line thickness in pixels (changed with +/- keys)
     pub current_thickness: f64,
+    /// Stroke width derived from the latest stylus pressure sample (None without pressure input)
+    pub(crate) pressure_width: Option<f64>,
+    /// Per-point widths sampled for the in-progress freehand stroke
+    pub(crate) stroke_widths: Vec<f64>,
     /// Current eraser size in pixels
     pub eraser_size: f64,
     /// Current eraser brush shape
     pub eraser_kind: EraserKind,
+    /// How new redactions obscure the region they cover
+    pub redact_mode: RedactMode,
     /// Opacity multiplier for marker tool strokes
     pub marker_opacity: f64,
     /// Current font size for text mode (from config)
@@ -131,10 +146,16 @@ pub struct InputState {
     pub(super) action_map: HashMap<KeyBinding, Action>,
     /// Pending capture action (to be handled by WaylandState)
     pub(super) pending_capture_action: Option<Action>,
+    /// Active in-overlay region selection for capture actions
+    pub(super) capture_region: Option<CaptureRegionState>,
+    /// Confirmed capture region (logical coordinates) awaiting the backend
+    pub(super) pending_capture_region: Option<Rect>,
     /// Maximum number of shapes allowed per frame (0 = unlimited)
     pub max_shapes_per_frame: usize,
     /// Click highlight animation state
     pub(crate) click_highlight: ClickHighlightState,
+    /// Laser pointer dot and fading trail (never committed to the frame)
+    pub(super) laser: LaserState,
     /// Optional tool override independent of modifier keys
     pub(super) tool_override: Option<Tool>,
     /// Current selection information
@@ -185,6 +206,22 @@ pub struct InputState {
     pub(super) frozen_active: bool,
     /// Pending toggle request for the backend (handled in the Wayland loop)
     pub(super) pending_frozen_toggle: bool,
+    /// Live zoom viewport and the freeze it depends on
+    pub(super) zoom: ZoomState,
+    /// Spotlight mode (dims everything outside a region)
+    pub(super) spotlight: SpotlightState,
+    /// Pending SVG export request for the backend (handled in the Wayland loop)
+    pub(super) pending_svg_export: bool,
+    /// Pending PDF export request for the backend (handled in the Wayland loop)
+    pub(super) pending_pdf_export: bool,
+    /// Pending document save request for the backend (handled in the Wayland loop)
+    pub(super) pending_document_save: bool,
+    /// Pending document open request for the backend (handled in the Wayland loop)
+    pub(super) pending_document_open: bool,
+    /// Whether the crash-recovery prompt is showing (drawing input is ignored meanwhile)
+    pub(super) recovery_prompt_open: bool,
+    /// Answer to the crash-recovery prompt awaiting the backend (true = restore)
+    pub(super) pending_recovery_choice: Option<bool>,
     /// Whether to show extended color palette
     pub show_more_colors: bool,
     /// Whether to show the Actions section (undo all, redo all, etc.)
@@ -256,11 +293,15 @@ impl InputState {
             canvas_set: CanvasSet::new(),
             current_color: color,
             rainbow_mode_enabled: rainbow_enabled,
+            vanishing_ink: VanishingInkState::default(),
             rainbow_hue: 0.0,
             rainbow_hue_step_per_pixel,
             current_thickness: thickness,
+            pressure_width: None,
+            stroke_widths: Vec::new(),
             eraser_size: clamped_eraser,
             eraser_kind: EraserKind::Circle,
+            redact_mode: RedactMode::default(),
             marker_opacity,
             current_font_size: font_size,
             font_descriptor,
@@ -289,8 +330,11 @@ impl InputState {
             last_text_preview_bounds: None,
             action_map,
             pending_capture_action: None,
+            capture_region: None,
+            pending_capture_region: None,
             max_shapes_per_frame,
             click_highlight: ClickHighlightState::new(click_highlight_settings),
+            laser: LaserState::new(LaserSettings::default()),
             tool_override: None,
             selection_state: SelectionState::None,
             context_menu_state: ContextMenuState::Hidden,
@@ -316,6 +360,14 @@ impl InputState {
             shape_properties_panel: None,
             frozen_active: false,
             pending_frozen_toggle: false,
+            zoom: ZoomState::default(),
+            spotlight: SpotlightState::default(),
+            pending_svg_export: false,
+            pending_pdf_export: false,
+            pending_document_save: false,
+            pending_document_open: false,
+            recovery_prompt_open: false,
+            pending_recovery_choice: None,
             show_more_colors: false,
             show_actions_section: true, // Show by default
         };
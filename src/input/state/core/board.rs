@@ -71,10 +71,90 @@ impl InputState {
         // Reset drawing state to prevent partial shapes crossing modes
         self.state = super::base::DrawingState::Idle;
 
+        // Zooming the transparent board needs a frozen image to magnify
+        self.sync_zoom_with_frozen();
+
         // Trigger redraw
         self.dirty_tracker.mark_full();
         self.needs_redraw = true;
 
         log::info!("Switched from {:?} to {:?} mode", current_mode, target_mode);
     }
+
+    /// Inserts an empty page after the current one on the active board and switches to it.
+    pub fn add_page(&mut self) {
+        self.canvas_set.active_pages_mut().add_page();
+        self.after_page_change("Added");
+    }
+
+    /// Copies the current page into a new page right after it and switches to the copy.
+    pub fn duplicate_page(&mut self) {
+        self.canvas_set.active_pages_mut().duplicate_page();
+        self.after_page_change("Duplicated into");
+    }
+
+    /// Deletes the current page of the active board, keeping at least one page.
+    pub fn delete_page(&mut self) -> bool {
+        if !self.canvas_set.active_pages_mut().delete_page() {
+            log::info!("Cannot delete the only page of the board");
+            return false;
+        }
+        self.after_page_change("Deleted page; now on");
+        true
+    }
+
+    /// Brings back the most recently deleted page when its deletion is next in undo order.
+    pub(crate) fn undo_delete_page(&mut self) -> bool {
+        if !self.canvas_set.active_pages_mut().undo_delete_page() {
+            return false;
+        }
+        self.after_page_change("Restored page");
+        true
+    }
+
+    /// Deletes a page restored by undo again, if it is still untouched.
+    pub(crate) fn redo_delete_page(&mut self) -> bool {
+        if !self.canvas_set.active_pages_mut().redo_delete_page() {
+            return false;
+        }
+        self.after_page_change("Deleted page; now on");
+        true
+    }
+
+    /// Moves to the next page of the active board, if any.
+    pub fn next_page(&mut self) -> bool {
+        if !self.canvas_set.active_pages_mut().next_page() {
+            return false;
+        }
+        self.after_page_change("Switched to");
+        true
+    }
+
+    /// Moves to the previous page of the active board, if any.
+    pub fn previous_page(&mut self) -> bool {
+        if !self.canvas_set.active_pages_mut().previous_page() {
+            return false;
+        }
+        self.after_page_change("Switched to");
+        true
+    }
+
+    /// Resets per-page interaction state after the active page changes.
+    fn after_page_change(&mut self, verb: &str) {
+        // Shape ids are only unique within a page, so cached hits and selections are stale.
+        self.state = super::base::DrawingState::Idle;
+        self.clear_selection();
+        self.invalidate_hit_cache();
+        self.dirty_tracker.mark_full();
+        self.needs_redraw = true;
+
+        let (page, count) = self.canvas_set.active_page_position();
+        log::info!(
+            "{} page {}/{} of {:?} board",
+            verb,
+            page,
+            count,
+            self.board_mode()
+        );
+    }
 }
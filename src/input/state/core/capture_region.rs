@@ -0,0 +1,291 @@
+use super::base::InputState;
+use crate::config::Action;
+use crate::util::Rect;
+
+/// Half-size (in logical pixels) of the square grab handles drawn on the selection.
+pub const CAPTURE_REGION_HANDLE_RADIUS: i32 = 6;
+
+/// Grab handles around the selection rectangle used to adjust its edges.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RegionHandle {
+    TopLeft,
+    Top,
+    TopRight,
+    Right,
+    BottomRight,
+    Bottom,
+    BottomLeft,
+    Left,
+}
+
+impl RegionHandle {
+    pub const ALL: [RegionHandle; 8] = [
+        RegionHandle::TopLeft,
+        RegionHandle::Top,
+        RegionHandle::TopRight,
+        RegionHandle::Right,
+        RegionHandle::BottomRight,
+        RegionHandle::Bottom,
+        RegionHandle::BottomLeft,
+        RegionHandle::Left,
+    ];
+
+    /// Returns the handle centre for the given rectangle.
+    pub fn position(self, rect: &Rect) -> (i32, i32) {
+        let left = rect.x;
+        let top = rect.y;
+        let right = rect.x + rect.width;
+        let bottom = rect.y + rect.height;
+        let mid_x = rect.x + rect.width / 2;
+        let mid_y = rect.y + rect.height / 2;
+        match self {
+            RegionHandle::TopLeft => (left, top),
+            RegionHandle::Top => (mid_x, top),
+            RegionHandle::TopRight => (right, top),
+            RegionHandle::Right => (right, mid_y),
+            RegionHandle::BottomRight => (right, bottom),
+            RegionHandle::Bottom => (mid_x, bottom),
+            RegionHandle::BottomLeft => (left, bottom),
+            RegionHandle::Left => (left, mid_y),
+        }
+    }
+
+    fn moves_left(self) -> bool {
+        matches!(
+            self,
+            RegionHandle::TopLeft | RegionHandle::Left | RegionHandle::BottomLeft
+        )
+    }
+
+    fn moves_right(self) -> bool {
+        matches!(
+            self,
+            RegionHandle::TopRight | RegionHandle::Right | RegionHandle::BottomRight
+        )
+    }
+
+    fn moves_top(self) -> bool {
+        matches!(
+            self,
+            RegionHandle::TopLeft | RegionHandle::Top | RegionHandle::TopRight
+        )
+    }
+
+    fn moves_bottom(self) -> bool {
+        matches!(
+            self,
+            RegionHandle::BottomLeft | RegionHandle::Bottom | RegionHandle::BottomRight
+        )
+    }
+}
+
+/// Pointer interaction currently applied to the selection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RegionDrag {
+    Idle,
+    Creating { anchor_x: i32, anchor_y: i32 },
+    Moving { last_x: i32, last_y: i32 },
+    Resizing { handle: RegionHandle },
+}
+
+/// In-overlay rubber-band selection used by region capture actions.
+#[derive(Debug, Clone)]
+pub struct CaptureRegionState {
+    action: Action,
+    rect: Option<Rect>,
+    drag: RegionDrag,
+}
+
+impl CaptureRegionState {
+    fn new(action: Action) -> Self {
+        Self {
+            action,
+            rect: None,
+            drag: RegionDrag::Idle,
+        }
+    }
+
+    /// Current selection rectangle in logical surface coordinates.
+    pub fn rect(&self) -> Option<Rect> {
+        self.rect
+    }
+
+    fn handle_at(&self, x: i32, y: i32) -> Option<RegionHandle> {
+        let rect = self.rect?;
+        let reach = CAPTURE_REGION_HANDLE_RADIUS + 2;
+        RegionHandle::ALL.into_iter().find(|handle| {
+            let (hx, hy) = handle.position(&rect);
+            (x - hx).abs() <= reach && (y - hy).abs() <= reach
+        })
+    }
+}
+
+impl InputState {
+    /// Starts an interactive region selection for the given capture action.
+    pub(crate) fn begin_capture_region(&mut self, action: Action) {
+        log::info!("Starting in-overlay region selection for {:?}", action);
+        self.capture_region = Some(CaptureRegionState::new(action));
+        self.pending_capture_region = None;
+        self.dirty_tracker.mark_full();
+        self.needs_redraw = true;
+    }
+
+    /// Returns the active region selection, if any.
+    pub fn capture_region(&self) -> Option<&CaptureRegionState> {
+        self.capture_region.as_ref()
+    }
+
+    /// Returns `true` while the region selector owns pointer and keyboard input.
+    pub fn is_selecting_capture_region(&self) -> bool {
+        self.capture_region.is_some()
+    }
+
+    /// Abandons the active region selection without capturing.
+    pub fn cancel_capture_region(&mut self) {
+        if self.capture_region.take().is_some() {
+            log::info!("Region selection cancelled");
+            self.dirty_tracker.mark_full();
+            self.needs_redraw = true;
+        }
+    }
+
+    /// Confirms the selection and queues its capture action for the backend.
+    ///
+    /// Returns `false` (and keeps the selector open) when no rectangle has been drawn yet.
+    pub fn confirm_capture_region(&mut self) -> bool {
+        let Some(region) = self.capture_region.as_ref() else {
+            return false;
+        };
+        let Some(rect) = region.rect else {
+            log::debug!("Region selection confirmed without a rectangle; ignoring");
+            return false;
+        };
+        let action = region.action;
+        self.capture_region = None;
+        self.pending_capture_region = Some(rect);
+        self.set_pending_capture_action(action);
+        self.reset_modifiers();
+        self.dirty_tracker.mark_full();
+        self.needs_redraw = true;
+        true
+    }
+
+    /// Takes the confirmed selection rectangle (logical surface coordinates), if any.
+    pub fn take_pending_capture_region(&mut self) -> Option<Rect> {
+        self.pending_capture_region.take()
+    }
+
+    pub(crate) fn capture_region_press(&mut self, x: i32, y: i32) {
+        let Some(region) = self.capture_region.as_mut() else {
+            return;
+        };
+        region.drag = if let Some(handle) = region.handle_at(x, y) {
+            RegionDrag::Resizing { handle }
+        } else if region.rect.is_some_and(|rect| rect.contains(x, y)) {
+            RegionDrag::Moving {
+                last_x: x,
+                last_y: y,
+            }
+        } else {
+            region.rect = None;
+            RegionDrag::Creating {
+                anchor_x: x,
+                anchor_y: y,
+            }
+        };
+        self.needs_redraw = true;
+    }
+
+    pub(crate) fn capture_region_motion(&mut self, x: i32, y: i32) {
+        let bounds = self.capture_region_bounds();
+        let Some(region) = self.capture_region.as_mut() else {
+            return;
+        };
+        match region.drag {
+            RegionDrag::Idle => return,
+            RegionDrag::Creating { anchor_x, anchor_y } => {
+                region.rect = Rect::from_min_max(
+                    anchor_x.min(x),
+                    anchor_y.min(y),
+                    anchor_x.max(x),
+                    anchor_y.max(y),
+                );
+            }
+            RegionDrag::Moving { last_x, last_y } => {
+                if let Some(rect) = region.rect.as_mut() {
+                    let mut nx = rect.x + (x - last_x);
+                    let mut ny = rect.y + (y - last_y);
+                    if let Some((max_w, max_h)) = bounds {
+                        nx = nx.clamp(0, (max_w - rect.width).max(0));
+                        ny = ny.clamp(0, (max_h - rect.height).max(0));
+                    }
+                    rect.x = nx;
+                    rect.y = ny;
+                }
+                region.drag = RegionDrag::Moving {
+                    last_x: x,
+                    last_y: y,
+                };
+            }
+            RegionDrag::Resizing { handle } => {
+                if let Some(rect) = region.rect {
+                    let mut left = rect.x;
+                    let mut top = rect.y;
+                    let mut right = rect.x + rect.width;
+                    let mut bottom = rect.y + rect.height;
+                    if handle.moves_left() {
+                        left = x;
+                    }
+                    if handle.moves_right() {
+                        right = x;
+                    }
+                    if handle.moves_top() {
+                        top = y;
+                    }
+                    if handle.moves_bottom() {
+                        bottom = y;
+                    }
+                    // Allow dragging a handle past the opposite edge by normalising.
+                    if let Some(resized) = Rect::from_min_max(
+                        left.min(right),
+                        top.min(bottom),
+                        left.max(right),
+                        top.max(bottom),
+                    ) {
+                        region.rect = Some(resized);
+                    }
+                }
+            }
+        }
+        if let (Some(rect), Some((max_w, max_h))) = (region.rect, bounds) {
+            region.rect = clamp_rect(rect, max_w, max_h);
+        }
+        self.needs_redraw = true;
+    }
+
+    pub(crate) fn capture_region_release(&mut self, x: i32, y: i32) {
+        self.capture_region_motion(x, y);
+        if let Some(region) = self.capture_region.as_mut() {
+            region.drag = RegionDrag::Idle;
+        }
+        self.needs_redraw = true;
+    }
+
+    fn capture_region_bounds(&self) -> Option<(i32, i32)> {
+        if self.screen_width == 0 || self.screen_height == 0 {
+            return None;
+        }
+        Some((
+            self.screen_width.min(i32::MAX as u32) as i32,
+            self.screen_height.min(i32::MAX as u32) as i32,
+        ))
+    }
+}
+
+fn clamp_rect(rect: Rect, max_w: i32, max_h: i32) -> Option<Rect> {
+    let left = rect.x.clamp(0, max_w);
+    let top = rect.y.clamp(0, max_h);
+    let right = (rect.x + rect.width).clamp(0, max_w);
+    let bottom = (rect.y + rect.height).clamp(0, max_h);
+    Rect::from_min_max(left, top, right, bottom)
+}
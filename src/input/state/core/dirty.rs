@@ -2,6 +2,7 @@ use super::base::{DrawingState, InputState};
 use crate::draw::shape::{
     bounding_box_for_arrow, bounding_box_for_ellipse, bounding_box_for_eraser,
     bounding_box_for_line, bounding_box_for_points, bounding_box_for_rect, bounding_box_for_text,
+    max_stroke_width,
 };
 use crate::input::tool::Tool;
 use crate::util::{self, Rect};
@@ -42,7 +43,13 @@ impl InputState {
         } = &self.state
         {
             match tool {
-                Tool::Pen => bounding_box_for_points(points, self.current_thickness),
+                Tool::Pen => bounding_box_for_points(
+                    points,
+                    max_stroke_width(
+                        self.current_thickness,
+                        self.sampled_stroke_widths(points.len()),
+                    ),
+                ),
                 Tool::Marker => {
                     let inflated =
                         (self.current_thickness * 1.35).max(self.current_thickness + 1.0);
@@ -83,7 +90,8 @@ impl InputState {
                     self.arrow_length,
                     self.arrow_angle,
                 ),
-                Tool::Highlight => None,
+                Tool::Redact => Rect::from_corners((*start_x, *start_y), (current_x, current_y)),
+                Tool::Highlight | Tool::Laser => None,
                 Tool::Select => None,
             }
         } else {
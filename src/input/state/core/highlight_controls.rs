@@ -1,5 +1,6 @@
 use super::base::{DelayedHistory, HistoryMode};
 use super::base::{DrawingState, InputState};
+use crate::input::state::laser::LaserSettings;
 use crate::input::tool::Tool;
 use cairo::Context as CairoContext;
 use std::time::{Duration, Instant};
@@ -49,6 +50,30 @@ impl InputState {
         self.click_highlight.render(ctx, now);
     }
 
+    /// Replaces the laser pointer appearance (from `[ui.laser]`).
+    pub fn set_laser_settings(&mut self, settings: LaserSettings) {
+        self.laser.set_settings(settings, &mut self.dirty_tracker);
+        self.needs_redraw = true;
+    }
+
+    /// Moves the laser dot to the pointer and extends its trail.
+    pub(crate) fn move_laser(&mut self, x: i32, y: i32) {
+        self.laser
+            .move_to(x, y, Instant::now(), &mut self.dirty_tracker);
+        self.needs_redraw = true;
+    }
+
+    /// Advance the laser trail fade. Returns true while the trail is still visible.
+    pub fn advance_laser(&mut self, now: Instant) -> bool {
+        self.laser.advance(now, &mut self.dirty_tracker)
+    }
+
+    /// Render the laser trail, plus the dot while the laser tool is active.
+    pub fn render_laser(&self, ctx: &CairoContext, now: Instant) {
+        self.laser
+            .render(ctx, now, self.active_tool() == Tool::Laser);
+    }
+
     /// Returns the active tool considering overrides and drawing state.
     pub fn active_tool(&self) -> Tool {
         if let DrawingState::Drawing { tool, .. } = &self.state {
@@ -58,7 +83,7 @@ impl InputState {
         let modifier_tool = self.modifiers.current_tool();
 
         if let Some(override_tool) = self.tool_override {
-            if matches!(override_tool, Tool::Highlight | Tool::Eraser) {
+            if matches!(override_tool, Tool::Highlight | Tool::Eraser | Tool::Laser) {
                 return override_tool;
             }
 
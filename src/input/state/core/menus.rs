@@ -44,6 +44,12 @@ pub enum MenuCommand {
     ReturnToTransparent,
     ToggleHelp,
     OpenConfigFile,
+    ExportSvg,
+    ExportPdf,
+    SaveDocument,
+    OpenDocument,
+    Zoom,
+    Spotlight,
 }
 
 /// Lightweight descriptor for rendering context menu entries.
@@ -304,6 +310,48 @@ impl InputState {
             }
         }
 
+        entries.push(ContextMenuEntry::new(
+            "Zoom In",
+            Some("Ctrl+1"),
+            false,
+            false,
+            Some(MenuCommand::Zoom),
+        ));
+        entries.push(ContextMenuEntry::new(
+            "Spotlight",
+            Some("Ctrl+Shift+L"),
+            false,
+            false,
+            Some(MenuCommand::Spotlight),
+        ));
+        entries.push(ContextMenuEntry::new(
+            "Export as SVG",
+            Some("Ctrl+Shift+E"),
+            false,
+            false,
+            Some(MenuCommand::ExportSvg),
+        ));
+        entries.push(ContextMenuEntry::new(
+            "Export Boards as PDF",
+            Some("Ctrl+Alt+E"),
+            false,
+            false,
+            Some(MenuCommand::ExportPdf),
+        ));
+        entries.push(ContextMenuEntry::new(
+            "Save as Document",
+            Some("Ctrl+Shift+D"),
+            false,
+            false,
+            Some(MenuCommand::SaveDocument),
+        ));
+        entries.push(ContextMenuEntry::new(
+            "Open Latest Document",
+            Some("Ctrl+O"),
+            false,
+            false,
+            Some(MenuCommand::OpenDocument),
+        ));
         entries.push(ContextMenuEntry::new(
             "Help",
             Some("F10"),
@@ -391,6 +439,13 @@ impl InputState {
             false,
             Some(MenuCommand::Properties),
         ));
+        entries.push(ContextMenuEntry::new(
+            "Export Selection as SVG",
+            Some("Ctrl+Shift+E"),
+            false,
+            false,
+            Some(MenuCommand::ExportSvg),
+        ));
 
         if ids.len() == 1 {
             let shape_id = ids[0];
@@ -788,6 +843,30 @@ impl InputState {
                 self.open_config_file_default();
                 self.close_context_menu();
             }
+            MenuCommand::ExportSvg => {
+                self.request_svg_export();
+                self.close_context_menu();
+            }
+            MenuCommand::ExportPdf => {
+                self.request_pdf_export();
+                self.close_context_menu();
+            }
+            MenuCommand::SaveDocument => {
+                self.request_document_save();
+                self.close_context_menu();
+            }
+            MenuCommand::OpenDocument => {
+                self.request_document_open();
+                self.close_context_menu();
+            }
+            MenuCommand::Zoom => {
+                self.close_context_menu();
+                self.toggle_zoom();
+            }
+            MenuCommand::Spotlight => {
+                self.close_context_menu();
+                self.toggle_spotlight();
+            }
         }
     }
 
@@ -1,17 +1,26 @@
 mod base;
 mod board;
+mod capture_region;
 mod dirty;
 mod highlight_controls;
 mod history;
 mod index;
 mod menus;
 mod properties;
+mod redact;
 mod selection;
 mod selection_actions;
+mod spotlight;
 mod tool_controls;
 mod utility;
+mod vanishing;
+mod zoom;
 
 pub use base::{DrawingState, InputState, MAX_STROKE_THICKNESS, MIN_STROKE_THICKNESS};
 #[allow(unused_imports)]
+pub use capture_region::{CAPTURE_REGION_HANDLE_RADIUS, CaptureRegionState, RegionHandle};
+#[allow(unused_imports)]
 pub use menus::{ContextMenuEntry, ContextMenuKind, ContextMenuState, MenuCommand};
 pub use selection::SelectionState;
+pub use spotlight::SpotlightSettings;
+pub use zoom::ZoomView;
@@ -0,0 +1,40 @@
+use super::base::InputState;
+use crate::draw::RedactMode;
+use crate::input::board_mode::BoardMode;
+use crate::input::tool::Tool;
+
+impl InputState {
+    /// Sets how new redactions obscure the region they cover.
+    pub fn set_redact_mode(&mut self, mode: RedactMode) {
+        self.redact_mode = mode;
+        self.ensure_redaction_backdrop();
+        self.needs_redraw = true;
+    }
+
+    /// Switches new redactions to the next mode: blur, pixelate, solid.
+    pub(crate) fn cycle_redact_mode(&mut self) {
+        let next = match self.redact_mode {
+            RedactMode::Blur => RedactMode::Pixelate,
+            RedactMode::Pixelate => RedactMode::Solid,
+            RedactMode::Solid => RedactMode::Blur,
+        };
+        log::info!("Redaction mode: {:?}", next);
+        self.set_redact_mode(next);
+    }
+
+    /// Freezes the screen when the redaction tool needs pixels to blur or pixelate.
+    ///
+    /// The live transparent board has nothing beneath it to sample, so redactions drawn there
+    /// unfrozen fall back to a solid fill. The freeze is left in place once the tool changes, as
+    /// the redactions already drawn depend on it.
+    pub(crate) fn ensure_redaction_backdrop(&mut self) {
+        if self.tool_override == Some(Tool::Redact)
+            && self.redact_mode != RedactMode::Solid
+            && self.board_mode() == BoardMode::Transparent
+            && !self.frozen_active()
+        {
+            log::info!("Freezing the screen for redaction");
+            self.request_frozen_toggle();
+        }
+    }
+}
@@ -427,6 +427,10 @@ impl InputState {
                     point.1 += dy;
                 }
             }
+            Shape::Redact { rect, .. } => {
+                rect.x += dx;
+                rect.y += dy;
+            }
         }
     }
 
@@ -0,0 +1,288 @@
+use super::base::{DrawingState, InputState};
+use crate::config::{SpotlightConfig, SpotlightShape};
+use crate::util::Rect;
+
+/// Smallest lit region, so a stray scroll cannot make the spotlight vanish.
+const SPOTLIGHT_MIN_SIZE: f64 = 24.0;
+/// Factor applied to the lit region per scroll step.
+const SPOTLIGHT_STEP: f64 = 1.15;
+/// Drags shorter than this (in pixels) count as a click, which releases a pinned spotlight.
+const SPOTLIGHT_CLICK_SLOP: i32 = 4;
+
+/// Runtime settings for spotlight mode.
+#[derive(Debug, Clone, Copy)]
+pub struct SpotlightSettings {
+    pub dim_alpha: f64,
+    pub shape: SpotlightShape,
+    pub size: (f64, f64),
+}
+
+impl Default for SpotlightSettings {
+    fn default() -> Self {
+        Self::from(&SpotlightConfig::default())
+    }
+}
+
+impl From<&SpotlightConfig> for SpotlightSettings {
+    fn from(cfg: &SpotlightConfig) -> Self {
+        Self {
+            dim_alpha: cfg.dim_alpha,
+            shape: cfg.shape,
+            size: (cfg.width, cfg.height),
+        }
+    }
+}
+
+/// The part of the canvas left undimmed, as a bounding box plus the shape inscribed in it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpotlightRegion {
+    pub shape: SpotlightShape,
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+impl SpotlightRegion {
+    fn centered(shape: SpotlightShape, center: (f64, f64), size: (f64, f64)) -> Self {
+        Self {
+            shape,
+            x: center.0 - size.0 / 2.0,
+            y: center.1 - size.1 / 2.0,
+            width: size.0,
+            height: size.1,
+        }
+    }
+
+    fn from_corners(shape: SpotlightShape, a: (i32, i32), b: (i32, i32)) -> Self {
+        Self {
+            shape,
+            x: a.0.min(b.0) as f64,
+            y: a.1.min(b.1) as f64,
+            width: (a.0 - b.0).abs() as f64,
+            height: (a.1 - b.1).abs() as f64,
+        }
+    }
+
+    fn bounds(&self) -> Option<Rect> {
+        // One pixel of padding covers the anti-aliased edge of the hole
+        Rect::from_min_max(
+            self.x.floor() as i32 - 1,
+            self.y.floor() as i32 - 1,
+            (self.x + self.width).ceil() as i32 + 1,
+            (self.y + self.height).ceil() as i32 + 1,
+        )
+    }
+
+    fn add_path(&self, ctx: &cairo::Context) {
+        match self.shape {
+            SpotlightShape::Rectangle => ctx.rectangle(self.x, self.y, self.width, self.height),
+            SpotlightShape::Ellipse => {
+                if self.width <= 0.0 || self.height <= 0.0 {
+                    return;
+                }
+                let _ = ctx.save();
+                ctx.translate(self.x + self.width / 2.0, self.y + self.height / 2.0);
+                ctx.scale(self.width / 2.0, self.height / 2.0);
+                ctx.new_sub_path();
+                ctx.arc(0.0, 0.0, 1.0, 0.0, std::f64::consts::TAU);
+                let _ = ctx.restore();
+            }
+        }
+    }
+}
+
+/// Spotlight mode: follows the pointer, or stays where the user dragged it out.
+#[derive(Debug, Clone, Default)]
+pub struct SpotlightState {
+    settings: SpotlightSettings,
+    active: bool,
+    /// Size of the region that follows the pointer (changed by scrolling)
+    size: (f64, f64),
+    /// Region fixed in place by a drag
+    pinned: Option<SpotlightRegion>,
+    /// Press position of a drag in progress
+    drag_start: Option<(i32, i32)>,
+    /// Region as last marked dirty, so a move repaints where the hole used to be
+    shown: Option<SpotlightRegion>,
+}
+
+impl InputState {
+    /// Returns whether spotlight mode is active.
+    pub fn spotlight_active(&self) -> bool {
+        self.spotlight.active
+    }
+
+    /// Applies the spotlight appearance from `[ui.spotlight]`.
+    pub fn set_spotlight_settings(&mut self, settings: SpotlightSettings) {
+        self.spotlight.settings = settings;
+        self.spotlight.size = settings.size;
+        if self.spotlight.active {
+            self.dirty_tracker.mark_full();
+            self.needs_redraw = true;
+        }
+    }
+
+    /// Turns spotlight mode on (following the pointer) or off.
+    pub(crate) fn toggle_spotlight(&mut self) {
+        if self.spotlight.active {
+            self.exit_spotlight();
+            return;
+        }
+        log::info!("Spotlight enabled");
+        self.spotlight.active = true;
+        self.spotlight.size = self.spotlight.settings.size;
+        self.spotlight.pinned = None;
+        self.spotlight.drag_start = None;
+        self.spotlight.shown = self.spotlight_region();
+        self.dirty_tracker.mark_full();
+        self.needs_redraw = true;
+    }
+
+    /// Leaves spotlight mode.
+    pub fn exit_spotlight(&mut self) {
+        if !self.spotlight.active {
+            return;
+        }
+        log::info!("Spotlight disabled");
+        self.spotlight.active = false;
+        self.spotlight.pinned = None;
+        self.spotlight.drag_start = None;
+        self.spotlight.shown = None;
+        self.dirty_tracker.mark_full();
+        self.needs_redraw = true;
+    }
+
+    /// Returns the undimmed region while spotlight mode is active.
+    pub fn spotlight_region(&self) -> Option<SpotlightRegion> {
+        if !self.spotlight.active {
+            return None;
+        }
+        let shape = self.spotlight.settings.shape;
+        if let Some(start) = self.spotlight.drag_start {
+            return Some(SpotlightRegion::from_corners(
+                shape,
+                start,
+                self.last_pointer_position,
+            ));
+        }
+        if let Some(pinned) = self.spotlight.pinned {
+            return Some(pinned);
+        }
+        let (x, y) = self.last_pointer_position;
+        Some(SpotlightRegion::centered(
+            shape,
+            (x as f64, y as f64),
+            self.spotlight.size,
+        ))
+    }
+
+    /// Starts dragging out a new spotlight region (left button while Idle).
+    pub(crate) fn spotlight_press(&mut self, x: i32, y: i32) {
+        self.spotlight.drag_start = Some((x, y));
+        self.refresh_spotlight();
+    }
+
+    /// Repaints the spotlight if it moved, e.g. after the pointer moved.
+    ///
+    /// Both the previous and the new region are marked dirty.
+    pub(crate) fn refresh_spotlight(&mut self) {
+        let region = self.spotlight_region();
+        if region == self.spotlight.shown {
+            return;
+        }
+        let previous = std::mem::replace(&mut self.spotlight.shown, region);
+        for bounds in [previous, region]
+            .into_iter()
+            .flatten()
+            .filter_map(|region| region.bounds())
+        {
+            self.dirty_tracker.mark_rect(bounds);
+        }
+        self.needs_redraw = true;
+    }
+
+    /// Finishes a drag. A real drag pins the region; a click goes back to following the pointer.
+    pub(crate) fn spotlight_release(&mut self, x: i32, y: i32) -> bool {
+        let Some(start) = self.spotlight.drag_start else {
+            return false;
+        };
+        self.spotlight.drag_start = None;
+        let region = SpotlightRegion::from_corners(self.spotlight.settings.shape, start, (x, y));
+        let is_click = (x - start.0).abs() < SPOTLIGHT_CLICK_SLOP
+            && (y - start.1).abs() < SPOTLIGHT_CLICK_SLOP;
+        self.spotlight.pinned = if is_click { None } else { Some(region) };
+        self.refresh_spotlight();
+        true
+    }
+
+    /// Grows (negative steps) or shrinks the lit region around its center.
+    pub fn adjust_spotlight(&mut self, steps: i32) {
+        if !self.spotlight.active || steps == 0 {
+            return;
+        }
+        let factor = SPOTLIGHT_STEP.powi(-steps);
+        let max = (
+            (self.screen_width as f64).max(SPOTLIGHT_MIN_SIZE),
+            (self.screen_height as f64).max(SPOTLIGHT_MIN_SIZE),
+        );
+        let scale = |size: (f64, f64)| {
+            (
+                (size.0 * factor).clamp(SPOTLIGHT_MIN_SIZE, max.0),
+                (size.1 * factor).clamp(SPOTLIGHT_MIN_SIZE, max.1),
+            )
+        };
+        if let Some(pinned) = self.spotlight.pinned.as_mut() {
+            let center = (
+                pinned.x + pinned.width / 2.0,
+                pinned.y + pinned.height / 2.0,
+            );
+            *pinned = SpotlightRegion::centered(
+                pinned.shape,
+                center,
+                scale((pinned.width, pinned.height)),
+            );
+        } else {
+            self.spotlight.size = scale(self.spotlight.size);
+        }
+        self.refresh_spotlight();
+    }
+
+    /// Dims everything outside the spotlight region.
+    ///
+    /// Drawn as a transient layer over the board, frozen image and shapes; never part of a frame.
+    pub fn render_spotlight(&self, ctx: &cairo::Context) {
+        let Some(region) = self.spotlight_region() else {
+            return;
+        };
+        let Ok((x1, y1, x2, y2)) = ctx.clip_extents() else {
+            return;
+        };
+        let _ = ctx.save();
+        ctx.new_path();
+        ctx.rectangle(x1, y1, x2 - x1, y2 - y1);
+        region.add_path(ctx);
+        ctx.set_fill_rule(cairo::FillRule::EvenOdd);
+        ctx.set_source_rgba(0.0, 0.0, 0.0, self.spotlight.settings.dim_alpha);
+        let _ = ctx.fill();
+        let _ = ctx.restore();
+    }
+
+    /// Whether a left press should drive the spotlight instead of the drawing tool.
+    pub(crate) fn spotlight_takes_pointer(&self) -> bool {
+        self.spotlight.active && matches!(self.state, DrawingState::Idle)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn drag_corners_are_normalized() {
+        let region = SpotlightRegion::from_corners(SpotlightShape::Rectangle, (50, 80), (10, 20));
+        assert_eq!((region.x, region.y), (10.0, 20.0));
+        assert_eq!((region.width, region.height), (40.0, 60.0));
+        assert_eq!(region.bounds(), Rect::new(9, 19, 42, 62));
+    }
+}
@@ -12,6 +12,15 @@ impl InputState {
 
         self.tool_override = tool;
 
+        // The laser dot starts under the pointer and leaves with the tool
+        if tool == Some(Tool::Laser) {
+            let (x, y) = self.last_pointer_position;
+            self.move_laser(x, y);
+        } else {
+            self.laser.clear(&mut self.dirty_tracker);
+        }
+        self.ensure_redaction_backdrop();
+
         // Ensure we are not mid-drawing with a stale tool
         if !matches!(
             self.state,
@@ -281,4 +290,24 @@ impl InputState {
     pub fn is_rainbow_mode_enabled(&self) -> bool {
         self.rainbow_mode_enabled
     }
+
+    /// Sets (or clears) the pressure-derived width used for subsequent freehand samples.
+    pub fn set_pressure_width(&mut self, width: Option<f64>) {
+        self.pressure_width = width.map(|w| w.clamp(MIN_STROKE_THICKNESS, MAX_STROKE_THICKNESS));
+    }
+
+    /// Returns the per-point widths sampled for the in-progress stroke, if any were
+    /// sampled. Points without a sample reuse the previous width. Clears the sample buffer.
+    pub(crate) fn take_stroke_widths(&mut self, point_count: usize) -> Option<Vec<f64>> {
+        let mut widths = std::mem::take(&mut self.stroke_widths);
+        let last = *widths.last()?;
+        widths.resize(point_count, last);
+        (point_count > 0).then_some(widths)
+    }
+
+    /// Returns the per-point widths sampled so far for the in-progress stroke.
+    pub(crate) fn sampled_stroke_widths(&self, point_count: usize) -> Option<&[f64]> {
+        (self.stroke_widths.len() == point_count && point_count > 0)
+            .then_some(self.stroke_widths.as_slice())
+    }
 }
@@ -79,13 +79,89 @@ impl InputState {
         pending
     }
 
+    /// Marks an SVG export request for the backend.
+    pub(crate) fn request_svg_export(&mut self) {
+        self.pending_svg_export = true;
+    }
+
+    /// Returns and clears any pending SVG export request.
+    pub fn take_pending_svg_export(&mut self) -> bool {
+        let pending = self.pending_svg_export;
+        self.pending_svg_export = false;
+        pending
+    }
+
+    /// Marks a PDF export request for the backend.
+    pub(crate) fn request_pdf_export(&mut self) {
+        self.pending_pdf_export = true;
+    }
+
+    /// Returns and clears any pending PDF export request.
+    pub fn take_pending_pdf_export(&mut self) -> bool {
+        let pending = self.pending_pdf_export;
+        self.pending_pdf_export = false;
+        pending
+    }
+
+    /// Marks a request to save the boards as a `.wayscriber` document.
+    pub(crate) fn request_document_save(&mut self) {
+        self.pending_document_save = true;
+    }
+
+    /// Returns and clears any pending document save request.
+    pub fn take_pending_document_save(&mut self) -> bool {
+        let pending = self.pending_document_save;
+        self.pending_document_save = false;
+        pending
+    }
+
+    /// Marks a request to open the most recent `.wayscriber` document.
+    pub(crate) fn request_document_open(&mut self) {
+        self.pending_document_open = true;
+    }
+
+    /// Returns and clears any pending document open request.
+    pub fn take_pending_document_open(&mut self) -> bool {
+        let pending = self.pending_document_open;
+        self.pending_document_open = false;
+        pending
+    }
+
+    /// Shows the crash-recovery prompt; drawing input is ignored until it is answered.
+    pub fn open_recovery_prompt(&mut self) {
+        self.recovery_prompt_open = true;
+        self.dirty_tracker.mark_full();
+        self.needs_redraw = true;
+    }
+
+    /// Returns true while the crash-recovery prompt is showing.
+    pub fn is_recovery_prompt_open(&self) -> bool {
+        self.recovery_prompt_open
+    }
+
+    /// Closes the crash-recovery prompt and records the answer for the backend.
+    pub(crate) fn answer_recovery_prompt(&mut self, restore: bool) {
+        self.recovery_prompt_open = false;
+        self.pending_recovery_choice = Some(restore);
+        self.dirty_tracker.mark_full();
+        self.needs_redraw = true;
+    }
+
+    /// Returns and clears the crash-recovery answer (true = restore the autosave).
+    pub fn take_recovery_choice(&mut self) -> Option<bool> {
+        self.pending_recovery_choice.take()
+    }
+
     /// Updates the cached frozen-mode status and triggers a redraw when it changes.
+    ///
+    /// Also called when a freeze request fails, so zoom waiting on the capture can give up.
     pub fn set_frozen_active(&mut self, active: bool) {
         if self.frozen_active != active {
             self.frozen_active = active;
             self.dirty_tracker.mark_full();
             self.needs_redraw = true;
         }
+        self.sync_zoom_with_frozen();
     }
 
     /// Returns whether frozen mode is active.
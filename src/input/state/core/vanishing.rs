@@ -0,0 +1,144 @@
+use std::time::{Duration, Instant};
+
+use super::base::{DrawingState, InputState};
+use crate::draw::ShapeId;
+
+/// Longest fade-out at the end of a vanishing stroke's lifetime.
+const VANISH_FADE: Duration = Duration::from_millis(1000);
+
+/// Vanishing ink mode: new strokes remove themselves after `lifetime`.
+#[derive(Debug, Clone)]
+pub struct VanishingInkState {
+    enabled: bool,
+    lifetime: Duration,
+}
+
+impl Default for VanishingInkState {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            lifetime: Duration::from_secs(4),
+        }
+    }
+}
+
+impl VanishingInkState {
+    /// Fade window, capped at half the lifetime so short-lived ink is visible at full strength first.
+    fn fade(&self) -> Duration {
+        VANISH_FADE.min(self.lifetime / 2)
+    }
+}
+
+impl InputState {
+    /// Returns whether new strokes are drawn with vanishing ink.
+    pub fn vanishing_ink_enabled(&self) -> bool {
+        self.vanishing_ink.enabled
+    }
+
+    /// Applies the vanishing ink settings from `[drawing]`.
+    pub fn set_vanishing_ink(&mut self, enabled: bool, lifetime: Duration) {
+        self.vanishing_ink.enabled = enabled;
+        self.vanishing_ink.lifetime = lifetime;
+        self.needs_redraw = true;
+    }
+
+    /// Turns vanishing ink on or off. Strokes already fading keep their deadline.
+    pub(crate) fn toggle_vanishing_ink(&mut self) {
+        self.vanishing_ink.enabled = !self.vanishing_ink.enabled;
+        log::info!(
+            "Vanishing ink {} ({:.1}s)",
+            if self.vanishing_ink.enabled {
+                "enabled"
+            } else {
+                "disabled"
+            },
+            self.vanishing_ink.lifetime.as_secs_f64()
+        );
+        self.needs_redraw = true;
+    }
+
+    /// Schedules a freshly committed shape to vanish if vanishing ink is on.
+    pub(crate) fn schedule_vanishing(&mut self, id: ShapeId) {
+        if !self.vanishing_ink.enabled {
+            return;
+        }
+        let expires_at = Instant::now() + self.vanishing_ink.lifetime;
+        self.canvas_set
+            .active_frame_mut()
+            .set_vanishing(id, expires_at);
+    }
+
+    /// Advances the vanishing ink fade and removes expired strokes from every page and board.
+    ///
+    /// Fading strokes on the active page are marked dirty every tick. Returns true while any
+    /// page still holds vanishing strokes, so the caller keeps ticking until they are gone.
+    pub fn advance_vanishing_ink(&mut self, now: Instant) -> bool {
+        let background = self.canvas_set.expire_background_vanishing(now);
+        let fade = self.vanishing_ink.fade();
+        let frame = self.canvas_set.active_frame_mut();
+        if !frame.has_vanishing_shapes() {
+            return background;
+        }
+
+        let mut fading = false;
+        for drawn in &frame.shapes {
+            if frame
+                .vanishing_deadline(drawn.id)
+                .is_some_and(|deadline| deadline.saturating_duration_since(now) <= fade)
+            {
+                self.dirty_tracker
+                    .mark_optional_rect(drawn.shape.bounding_box());
+                fading = true;
+            }
+        }
+
+        let expired = frame.expire_vanishing(now);
+        let still_vanishing = frame.has_vanishing_shapes();
+        if !expired.is_empty() {
+            log::debug!("{} vanishing shape(s) expired", expired.len());
+            let selection_hit = expired
+                .iter()
+                .any(|drawn| self.selected_shape_ids().contains(&drawn.id));
+            for drawn in &expired {
+                self.invalidate_hit_cache_for(drawn.id);
+                self.dirty_tracker
+                    .mark_optional_rect(drawn.shape.bounding_box());
+            }
+            if selection_hit {
+                if matches!(self.state, DrawingState::MovingSelection { .. }) {
+                    self.state = DrawingState::Idle;
+                }
+                self.clear_selection();
+            }
+        }
+        if fading || !expired.is_empty() {
+            self.needs_redraw = true;
+        }
+        still_vanishing || background
+    }
+
+    /// Opacity for a committed shape: 1.0 unless it is a vanishing stroke in its fade window.
+    pub fn vanishing_opacity(&self, id: ShapeId, now: Instant) -> f64 {
+        let Some(deadline) = self.canvas_set.active_frame().vanishing_deadline(id) else {
+            return 1.0;
+        };
+        let fade = self.vanishing_ink.fade().as_secs_f64();
+        if fade <= 0.0 {
+            return 1.0;
+        }
+        (deadline.saturating_duration_since(now).as_secs_f64() / fade).clamp(0.0, 1.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fade_is_capped_at_half_the_lifetime() {
+        let mut state = VanishingInkState::default();
+        assert_eq!(state.fade(), VANISH_FADE);
+        state.lifetime = Duration::from_millis(1200);
+        assert_eq!(state.fade(), Duration::from_millis(600));
+    }
+}
@@ -0,0 +1,312 @@
+//! Live zoom: magnifies the canvas around the pointer and maps pointer coordinates back.
+
+use super::base::{DrawingState, InputState};
+use crate::input::board_mode::BoardMode;
+
+/// Magnification applied when zoom mode starts.
+pub const ZOOM_DEFAULT_LEVEL: f64 = 2.0;
+/// Largest magnification reachable with the scroll wheel.
+pub const ZOOM_MAX_LEVEL: f64 = 8.0;
+/// Factor applied per scroll step.
+const ZOOM_STEP: f64 = 1.25;
+
+/// Magnified viewport onto the canvas.
+///
+/// `origin` is the canvas point shown at the top-left corner of the output; a screen point `s`
+/// shows canvas point `origin + s / level`. Shapes are always stored in canvas (unzoomed)
+/// coordinates.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ZoomView {
+    level: f64,
+    origin: (f64, f64),
+    pointer: (f64, f64),
+}
+
+impl ZoomView {
+    /// Starts at the default level with the canvas point under `pointer` kept in place.
+    fn new(pointer: (f64, f64), size: (f64, f64)) -> Self {
+        let mut view = Self {
+            level: ZOOM_DEFAULT_LEVEL,
+            origin: (0.0, 0.0),
+            pointer,
+        };
+        let scale = 1.0 - 1.0 / view.level;
+        view.origin = (pointer.0 * scale, pointer.1 * scale);
+        view.clamp(size);
+        view
+    }
+
+    /// Current magnification factor.
+    pub fn level(&self) -> f64 {
+        self.level
+    }
+
+    /// Maps a point on the output to canvas coordinates.
+    pub fn to_canvas(self, x: f64, y: f64) -> (f64, f64) {
+        (
+            self.origin.0 + x / self.level,
+            self.origin.1 + y / self.level,
+        )
+    }
+
+    /// Transforms `ctx` so canvas coordinates land where the zoomed view shows them.
+    ///
+    /// `unit` is the number of device pixels per canvas unit already applied (or to be applied)
+    /// by the caller, e.g. the buffer scale when painting the frozen image in buffer pixels.
+    pub fn apply(&self, ctx: &cairo::Context, unit: f64) {
+        ctx.scale(self.level, self.level);
+        ctx.translate(-self.origin.0 * unit, -self.origin.1 * unit);
+    }
+
+    /// Tracks the pointer, panning so the output edges converge on the canvas edges.
+    ///
+    /// Moving toward an edge pans by the share of the hidden canvas left on that side, so the
+    /// view reaches the canvas edge exactly when the pointer reaches the output edge.
+    fn follow_pointer(&mut self, pointer: (f64, f64), size: (f64, f64), pan: bool) -> bool {
+        let previous = (self.pointer, self.origin);
+        if pan {
+            self.origin.0 =
+                Self::pan_axis(self.origin.0, self.pointer.0, pointer.0, size.0, self.level);
+            self.origin.1 =
+                Self::pan_axis(self.origin.1, self.pointer.1, pointer.1, size.1, self.level);
+            self.clamp(size);
+        }
+        self.pointer = pointer;
+        previous.1 != self.origin
+    }
+
+    fn pan_axis(origin: f64, from: f64, to: f64, extent: f64, level: f64) -> f64 {
+        let max_origin = (extent - extent / level).max(0.0);
+        let delta = to - from;
+        if delta < 0.0 && from > 0.0 {
+            origin + delta * origin / from
+        } else if delta > 0.0 && from < extent {
+            origin + delta * (max_origin - origin) / (extent - from)
+        } else {
+            origin
+        }
+    }
+
+    /// Changes the level by `steps` scroll steps (negative zooms in) around the pointer.
+    fn zoom_by(&mut self, steps: i32, size: (f64, f64)) -> bool {
+        let level = (self.level * ZOOM_STEP.powi(-steps)).clamp(1.0, ZOOM_MAX_LEVEL);
+        if (level - self.level).abs() < f64::EPSILON {
+            return false;
+        }
+        let anchor = self.to_canvas(self.pointer.0, self.pointer.1);
+        self.level = level;
+        self.origin = (
+            anchor.0 - self.pointer.0 / level,
+            anchor.1 - self.pointer.1 / level,
+        );
+        self.clamp(size);
+        true
+    }
+
+    fn clamp(&mut self, size: (f64, f64)) {
+        let max_x = (size.0 - size.0 / self.level).max(0.0);
+        let max_y = (size.1 - size.1 / self.level).max(0.0);
+        self.origin = (
+            self.origin.0.clamp(0.0, max_x),
+            self.origin.1.clamp(0.0, max_y),
+        );
+    }
+}
+
+/// Zoom mode bookkeeping, including the freeze it may have started on the transparent board.
+#[derive(Debug, Clone, Default)]
+pub struct ZoomState {
+    view: Option<ZoomView>,
+    /// Zoom was requested on the transparent board and waits for the frozen image.
+    awaiting_freeze: bool,
+    /// The screen was frozen for zoom and should be unfrozen when zoom ends.
+    froze_screen: bool,
+}
+
+impl InputState {
+    /// Returns the zoomed viewport while zoom mode is active.
+    pub fn zoom_view(&self) -> Option<&ZoomView> {
+        self.zoom.view.as_ref()
+    }
+
+    /// Returns whether zoom mode is active (or about to start once the screen is frozen).
+    pub fn zoom_active(&self) -> bool {
+        self.zoom.view.is_some() || self.zoom.awaiting_freeze
+    }
+
+    /// Enters or leaves zoom mode.
+    ///
+    /// The transparent board has nothing to magnify on its own, so zooming there freezes the
+    /// screen first and unfreezes it again when zoom ends.
+    pub(crate) fn toggle_zoom(&mut self) {
+        if self.zoom_active() {
+            self.exit_zoom();
+            return;
+        }
+        self.close_context_menu();
+        if self.board_mode() == BoardMode::Transparent && !self.frozen_active() {
+            log::info!("Freezing the screen for zoom");
+            self.zoom.awaiting_freeze = true;
+            self.zoom.froze_screen = true;
+            self.request_frozen_toggle();
+            return;
+        }
+        self.enter_zoom();
+    }
+
+    fn enter_zoom(&mut self) {
+        let (x, y) = self.last_pointer_position;
+        let view = ZoomView::new((x as f64, y as f64), self.zoom_surface_size());
+        log::info!("Zoom enabled at {:.2}x", view.level);
+        self.zoom.view = Some(view);
+        self.zoom.awaiting_freeze = false;
+        self.dirty_tracker.mark_full();
+        self.needs_redraw = true;
+    }
+
+    /// Leaves zoom mode, unfreezing the screen if zoom froze it.
+    pub fn exit_zoom(&mut self) {
+        if !self.zoom_active() {
+            return;
+        }
+        log::info!("Zoom disabled");
+        self.zoom.view = None;
+        let awaiting = std::mem::take(&mut self.zoom.awaiting_freeze);
+        // A capture still in flight is released once it lands (see `sync_zoom_with_frozen`).
+        if self.zoom.froze_screen && !awaiting {
+            self.zoom.froze_screen = false;
+            if self.frozen_active() {
+                self.request_frozen_toggle();
+            }
+        }
+        self.dirty_tracker.mark_full();
+        self.needs_redraw = true;
+    }
+
+    /// Changes the zoom level by `steps` scroll steps; positive steps zoom out.
+    pub fn adjust_zoom(&mut self, steps: i32) {
+        let size = self.zoom_surface_size();
+        if let Some(view) = self.zoom.view.as_mut()
+            && view.zoom_by(steps, size)
+        {
+            log::debug!("Zoom level {:.2}x", view.level);
+            self.dirty_tracker.mark_full();
+            self.needs_redraw = true;
+        }
+    }
+
+    /// Tracks pointer motion on the overlay and returns the point in canvas coordinates.
+    ///
+    /// The view pans with the pointer except while a stroke or selection drag is in progress,
+    /// so the canvas does not slide under the shape being drawn.
+    pub fn zoom_pointer_motion(&mut self, x: i32, y: i32) -> (i32, i32) {
+        let size = self.zoom_surface_size();
+        let pan = matches!(
+            self.state,
+            DrawingState::Idle | DrawingState::TextInput { .. }
+        );
+        let targets_canvas = self.pointer_targets_canvas();
+        if let Some(view) = self.zoom.view.as_mut()
+            && targets_canvas
+            && view.follow_pointer((x as f64, y as f64), size, pan)
+        {
+            self.dirty_tracker.mark_full();
+            self.needs_redraw = true;
+        }
+        self.zoom_canvas_point(x, y)
+    }
+
+    /// Maps an output point to canvas coordinates while zoomed.
+    ///
+    /// Points are passed through unchanged when zoom is off or an overlay UI (context menu,
+    /// region selector, recovery prompt) is taking pointer input in screen space.
+    pub fn zoom_canvas_point(&self, x: i32, y: i32) -> (i32, i32) {
+        match self.zoom.view.as_ref() {
+            Some(view) if self.pointer_targets_canvas() => {
+                let (cx, cy) = view.to_canvas(x as f64, y as f64);
+                (cx.round() as i32, cy.round() as i32)
+            }
+            _ => (x, y),
+        }
+    }
+
+    fn pointer_targets_canvas(&self) -> bool {
+        !self.is_context_menu_open()
+            && !self.is_selecting_capture_region()
+            && !self.is_recovery_prompt_open()
+    }
+
+    fn zoom_surface_size(&self) -> (f64, f64) {
+        (self.screen_width as f64, self.screen_height as f64)
+    }
+
+    /// Keeps zoom consistent with the frozen background after a freeze change.
+    ///
+    /// Starts a zoom that was waiting for its capture, releases a freeze that landed after zoom
+    /// was already cancelled, and leaves zoom when the transparent board loses its frozen image.
+    pub(super) fn sync_zoom_with_frozen(&mut self) {
+        if self.frozen_active() {
+            if self.zoom.awaiting_freeze {
+                self.enter_zoom();
+            } else if self.zoom.froze_screen && self.zoom.view.is_none() {
+                self.zoom.froze_screen = false;
+                self.request_frozen_toggle();
+            }
+        } else if self.board_mode() == BoardMode::Transparent {
+            if self.zoom_active() {
+                log::info!("Frozen image gone; leaving zoom");
+                self.zoom.view = None;
+                self.zoom.awaiting_freeze = false;
+                self.dirty_tracker.mark_full();
+                self.needs_redraw = true;
+            }
+            self.zoom.froze_screen = false;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SIZE: (f64, f64) = (1000.0, 500.0);
+
+    #[test]
+    fn new_view_keeps_the_pointer_over_the_same_canvas_point() {
+        let view = ZoomView::new((250.0, 100.0), SIZE);
+        assert_eq!(view.level(), ZOOM_DEFAULT_LEVEL);
+        assert_eq!(view.to_canvas(250.0, 100.0), (250.0, 100.0));
+        assert_eq!(view.to_canvas(0.0, 0.0), (125.0, 50.0));
+    }
+
+    #[test]
+    fn panning_reaches_the_canvas_edges_with_the_pointer() {
+        let mut view = ZoomView::new((500.0, 250.0), SIZE);
+        view.follow_pointer((0.0, 0.0), SIZE, true);
+        assert_eq!(view.to_canvas(0.0, 0.0), (0.0, 0.0));
+        view.follow_pointer((1000.0, 500.0), SIZE, true);
+        assert_eq!(view.to_canvas(1000.0, 500.0), (1000.0, 500.0));
+
+        // Without panning only the pointer moves.
+        let before = view.to_canvas(0.0, 0.0);
+        view.follow_pointer((10.0, 10.0), SIZE, false);
+        assert_eq!(view.to_canvas(0.0, 0.0), before);
+    }
+
+    #[test]
+    fn scrolling_zooms_around_the_pointer_within_limits() {
+        let mut view = ZoomView::new((400.0, 200.0), SIZE);
+        let anchor = view.to_canvas(400.0, 200.0);
+        assert!(view.zoom_by(-1, SIZE));
+        assert_eq!(view.level(), ZOOM_DEFAULT_LEVEL * ZOOM_STEP);
+        let (x, y) = view.to_canvas(400.0, 200.0);
+        assert!((x - anchor.0).abs() < 1e-9 && (y - anchor.1).abs() < 1e-9);
+
+        assert!(view.zoom_by(-100, SIZE));
+        assert_eq!(view.level(), ZOOM_MAX_LEVEL);
+        assert!(!view.zoom_by(-1, SIZE));
+        assert!(view.zoom_by(100, SIZE));
+        assert_eq!(view.level(), 1.0);
+        assert_eq!(view.to_canvas(0.0, 0.0), (0.0, 0.0));
+    }
+}
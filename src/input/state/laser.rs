@@ -0,0 +1,221 @@
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+use crate::config::LaserConfig;
+use crate::draw::{Color, DirtyTracker};
+use crate::util::Rect;
+
+/// Trail samples kept at most, so a long fade at high pointer rates stays cheap to draw.
+const MAX_TRAIL_POINTS: usize = 256;
+/// Glow radius relative to the dot radius.
+const GLOW_SCALE: f64 = 2.5;
+
+/// Runtime settings for the laser pointer.
+#[derive(Clone)]
+pub struct LaserSettings {
+    pub radius: f64,
+    pub trail_duration: Duration,
+    pub color: Color,
+}
+
+impl Default for LaserSettings {
+    fn default() -> Self {
+        Self::from(&LaserConfig::default())
+    }
+}
+
+impl From<&LaserConfig> for LaserSettings {
+    fn from(cfg: &LaserConfig) -> Self {
+        LaserSettings {
+            radius: cfg.radius,
+            trail_duration: Duration::from_millis(cfg.trail_duration_ms),
+            color: Color {
+                r: cfg.color[0],
+                g: cfg.color[1],
+                b: cfg.color[2],
+                a: cfg.color[3],
+            },
+        }
+    }
+}
+
+/// Laser pointer dot and its fading trail; never part of the canvas.
+pub struct LaserState {
+    settings: LaserSettings,
+    trail: VecDeque<TrailPoint>,
+    dot: Option<(i32, i32)>,
+}
+
+struct TrailPoint {
+    x: i32,
+    y: i32,
+    at: Instant,
+}
+
+impl LaserState {
+    pub fn new(settings: LaserSettings) -> Self {
+        Self {
+            settings,
+            trail: VecDeque::new(),
+            dot: None,
+        }
+    }
+
+    pub fn set_settings(&mut self, settings: LaserSettings, tracker: &mut DirtyTracker) {
+        self.clear(tracker);
+        self.settings = settings;
+    }
+
+    /// Moves the dot to `(x, y)` and extends the trail.
+    pub fn move_to(&mut self, x: i32, y: i32, now: Instant, tracker: &mut DirtyTracker) {
+        if let Some(bounds) = self.dot.and_then(|(dx, dy)| self.point_bounds(dx, dy)) {
+            tracker.mark_rect(bounds);
+        }
+        self.dot = Some((x, y));
+        if self.trail.len() >= MAX_TRAIL_POINTS {
+            self.trail.pop_front();
+        }
+        self.trail.push_back(TrailPoint { x, y, at: now });
+        tracker.mark_optional_rect(self.trail_bounds());
+    }
+
+    /// Drops the dot and trail, e.g. when another tool is selected.
+    pub fn clear(&mut self, tracker: &mut DirtyTracker) {
+        tracker.mark_optional_rect(self.trail_bounds());
+        if let Some(bounds) = self.dot.and_then(|(x, y)| self.point_bounds(x, y)) {
+            tracker.mark_rect(bounds);
+        }
+        self.trail.clear();
+        self.dot = None;
+    }
+
+    /// Expires old trail samples; returns true while a trail is still fading.
+    pub fn advance(&mut self, now: Instant, tracker: &mut DirtyTracker) -> bool {
+        if self.trail.is_empty() {
+            return false;
+        }
+
+        // Mark the trail before trimming so expired samples are repainted away.
+        tracker.mark_optional_rect(self.trail_bounds());
+        let duration = self.settings.trail_duration;
+        while self
+            .trail
+            .front()
+            .is_some_and(|point| now.saturating_duration_since(point.at) >= duration)
+        {
+            self.trail.pop_front();
+        }
+
+        !self.trail.is_empty()
+    }
+
+    /// Draws the trail, and the dot when `show_dot` is set.
+    pub fn render(&self, ctx: &cairo::Context, now: Instant, show_dot: bool) {
+        let total = self.settings.trail_duration.as_secs_f64().max(f64::EPSILON);
+        let Color { r, g, b, a } = self.settings.color;
+        let radius = self.settings.radius;
+
+        let _ = ctx.save();
+        ctx.set_line_cap(cairo::LineCap::Round);
+        for (from, to) in self.trail.iter().zip(self.trail.iter().skip(1)) {
+            let age = now.saturating_duration_since(to.at).as_secs_f64();
+            let fade = (1.0 - age / total).clamp(0.0, 1.0);
+            if fade <= 0.0 {
+                continue;
+            }
+            ctx.set_source_rgba(r, g, b, a * fade * 0.8);
+            ctx.set_line_width(radius * (0.4 + 1.2 * fade));
+            ctx.move_to(from.x as f64, from.y as f64);
+            ctx.line_to(to.x as f64, to.y as f64);
+            let _ = ctx.stroke();
+        }
+
+        if let Some((x, y)) = self.dot.filter(|_| show_dot) {
+            let (x, y) = (x as f64, y as f64);
+            let glow = radius * GLOW_SCALE;
+            let gradient = cairo::RadialGradient::new(x, y, radius * 0.5, x, y, glow);
+            gradient.add_color_stop_rgba(0.0, r, g, b, a * 0.6);
+            gradient.add_color_stop_rgba(1.0, r, g, b, 0.0);
+            if ctx.set_source(&gradient).is_ok() {
+                ctx.arc(x, y, glow, 0.0, std::f64::consts::TAU);
+                let _ = ctx.fill();
+            }
+
+            ctx.set_source_rgba(r, g, b, a);
+            ctx.arc(x, y, radius, 0.0, std::f64::consts::TAU);
+            let _ = ctx.fill();
+            // Bright core keeps the dot visible on saturated backgrounds
+            ctx.set_source_rgba(1.0, 1.0, 1.0, 0.8 * a);
+            ctx.arc(x, y, radius * 0.4, 0.0, std::f64::consts::TAU);
+            let _ = ctx.fill();
+        }
+        let _ = ctx.restore();
+    }
+
+    fn extent(&self) -> i32 {
+        (self.settings.radius * GLOW_SCALE).ceil() as i32 + 2 // padding for anti-aliased edges
+    }
+
+    fn point_bounds(&self, x: i32, y: i32) -> Option<Rect> {
+        let extent = self.extent();
+        Rect::new(x - extent, y - extent, extent * 2, extent * 2)
+    }
+
+    fn trail_bounds(&self) -> Option<Rect> {
+        let min_x = self.trail.iter().map(|p| p.x).min()?;
+        let min_y = self.trail.iter().map(|p| p.y).min()?;
+        let max_x = self.trail.iter().map(|p| p.x).max()?;
+        let max_y = self.trail.iter().map(|p| p.y).max()?;
+        let extent = self.extent();
+        Rect::from_min_max(
+            min_x - extent,
+            min_y - extent,
+            max_x + extent,
+            max_y + extent,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn settings(ms: u64) -> LaserSettings {
+        LaserSettings {
+            trail_duration: Duration::from_millis(ms),
+            ..LaserSettings::default()
+        }
+    }
+
+    #[test]
+    fn trail_fades_out_after_its_duration() {
+        let mut state = LaserState::new(settings(100));
+        let mut tracker = DirtyTracker::new();
+        let start = Instant::now();
+        state.move_to(10, 10, start, &mut tracker);
+        state.move_to(20, 10, start + Duration::from_millis(50), &mut tracker);
+
+        assert!(state.advance(start + Duration::from_millis(120), &mut tracker));
+        assert_eq!(state.trail.len(), 1);
+        assert!(!state.advance(start + Duration::from_millis(150), &mut tracker));
+        assert!(state.trail.is_empty());
+        // The dot stays where the pointer is.
+        assert_eq!(state.dot, Some((20, 10)));
+    }
+
+    #[test]
+    fn trail_length_is_bounded() {
+        let mut state = LaserState::new(settings(1000));
+        let mut tracker = DirtyTracker::new();
+        let now = Instant::now();
+        for i in 0..(MAX_TRAIL_POINTS as i32 + 10) {
+            state.move_to(i, 0, now, &mut tracker);
+        }
+        assert_eq!(state.trail.len(), MAX_TRAIL_POINTS);
+        assert_eq!(state.trail.front().map(|p| p.x), Some(10));
+
+        state.clear(&mut tracker);
+        assert!(state.trail.is_empty());
+        assert_eq!(state.dot, None);
+    }
+}
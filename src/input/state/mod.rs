@@ -1,6 +1,7 @@
 mod actions;
 mod core;
 mod highlight;
+mod laser;
 mod mouse;
 mod render;
 #[cfg(test)]
@@ -8,7 +9,9 @@ mod tests;
 
 #[allow(unused_imports)]
 pub use core::{
-    ContextMenuEntry, ContextMenuKind, ContextMenuState, DrawingState, InputState,
-    MAX_STROKE_THICKNESS, MIN_STROKE_THICKNESS, SelectionState,
+    CAPTURE_REGION_HANDLE_RADIUS, CaptureRegionState, ContextMenuEntry, ContextMenuKind,
+    ContextMenuState, DrawingState, InputState, MAX_STROKE_THICKNESS, MIN_STROKE_THICKNESS,
+    RegionHandle, SelectionState, SpotlightSettings, ZoomView,
 };
 pub use highlight::ClickHighlightSettings;
+pub use laser::LaserSettings;
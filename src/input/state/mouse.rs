@@ -60,10 +60,31 @@ impl InputState {
     /// # Behavior
     /// - Left click while Idle: Starts drawing with the current tool (based on modifiers)
     /// - Left click during TextInput: Updates text position
-    /// - Right click: Cancels current action
+    /// - Left click while spotlight mode is active: Drags out the spotlight region
+    /// - Right click: Cancels current action, or leaves zoom/spotlight mode while Idle
     pub fn on_mouse_press(&mut self, button: MouseButton, x: i32, y: i32) {
+        if self.is_recovery_prompt_open() {
+            return;
+        }
         self.close_properties_panel();
+        if self.is_selecting_capture_region() {
+            self.update_pointer_position(x, y);
+            match button {
+                MouseButton::Left => self.capture_region_press(x, y),
+                MouseButton::Right => self.cancel_capture_region(),
+                MouseButton::Middle => {}
+            }
+            return;
+        }
         match button {
+            MouseButton::Right
+                if self.zoom_active() && matches!(self.state, DrawingState::Idle) =>
+            {
+                self.exit_zoom();
+            }
+            MouseButton::Right if self.spotlight_takes_pointer() => {
+                self.exit_spotlight();
+            }
             MouseButton::Right => {
                 self.handle_right_click(x, y);
             }
@@ -81,6 +102,11 @@ impl InputState {
                     return;
                 }
 
+                if self.spotlight_takes_pointer() {
+                    self.spotlight_press(x, y);
+                    return;
+                }
+
                 match &mut self.state {
                     DrawingState::Idle => {
                         let selection_click = self.modifiers.alt;
@@ -108,13 +134,19 @@ impl InputState {
                         }
 
                         let tool = self.active_tool();
-                        if tool != Tool::Highlight && tool != Tool::Select {
+                        if tool == Tool::Laser {
+                            self.move_laser(x, y);
+                        } else if tool != Tool::Highlight && tool != Tool::Select {
                             self.state = DrawingState::Drawing {
                                 tool,
                                 start_x: x,
                                 start_y: y,
                                 points: vec![(x, y)],
                             };
+                            self.stroke_widths.clear();
+                            if let Some(width) = self.pressure_width {
+                                self.stroke_widths.push(width);
+                            }
                             self.last_provisional_bounds = None;
                             self.update_provisional_dirty(x, y);
                             self.needs_redraw = true;
@@ -145,6 +177,11 @@ impl InputState {
     pub fn on_mouse_motion(&mut self, x: i32, y: i32) {
         self.update_pointer_position(x, y);
 
+        if self.is_selecting_capture_region() {
+            self.capture_region_motion(x, y);
+            return;
+        }
+
         if let DrawingState::MovingSelection { last_x, last_y, .. } = &self.state {
             let dx = x - *last_x;
             let dy = y - *last_y;
@@ -169,10 +206,31 @@ impl InputState {
             return;
         }
 
+        if self.spotlight_active() {
+            self.refresh_spotlight();
+        }
+
+        if matches!(self.state, DrawingState::Idle) && self.active_tool() == Tool::Laser {
+            self.move_laser(x, y);
+        }
+
         let mut drawing = false;
         if let DrawingState::Drawing { tool, points, .. } = &mut self.state {
             if *tool == Tool::Pen || *tool == Tool::Marker || *tool == Tool::Eraser {
                 points.push((x, y));
+                if *tool == Tool::Pen {
+                    match (self.pressure_width, self.stroke_widths.last().copied()) {
+                        (Some(width), last) => {
+                            // Backfill samples recorded before pressure input arrived
+                            let fill = last.unwrap_or(width);
+                            self.stroke_widths.resize(points.len() - 1, fill);
+                            self.stroke_widths.push(width);
+                        }
+                        // Pressure dropped out mid-stroke; carry the previous width forward
+                        (None, Some(last)) => self.stroke_widths.resize(points.len(), last),
+                        (None, None) => {}
+                    }
+                }
             }
             drawing = true;
         }
@@ -197,6 +255,15 @@ impl InputState {
     /// - Returns to Idle state
     pub fn on_mouse_release(&mut self, button: MouseButton, x: i32, y: i32) {
         self.update_pointer_position(x, y);
+        if self.is_recovery_prompt_open() {
+            return;
+        }
+        if self.is_selecting_capture_region() {
+            if button == MouseButton::Left {
+                self.capture_region_release(x, y);
+            }
+            return;
+        }
         if button == MouseButton::Left && self.is_context_menu_open() {
             if let Some(index) = self.context_menu_index_at(x, y) {
                 let entries = self.context_menu_entries();
@@ -217,6 +284,9 @@ impl InputState {
             self.needs_redraw = true;
             return;
         }
+        if button == MouseButton::Left && self.spotlight_release(x, y) {
+            return;
+        }
 
         if button != MouseButton::Left {
             return;
@@ -256,11 +326,13 @@ impl InputState {
                         } else {
                             None
                         };
+                        let per_point_widths = self.take_stroke_widths(points.len());
                         Shape::Freehand {
                             points,
                             color: self.current_color,
                             thick: self.current_thickness,
                             per_point_colors,
+                            per_point_widths,
                         }
                     }
                     Tool::Line => {
@@ -269,7 +341,8 @@ impl InputState {
                             let dy = (y - start_y) as f64;
                             let distance = (dx * dx + dy * dy).sqrt();
                             let start_hue = self.get_rainbow_hue();
-                            let end_hue = self.get_rainbow_hue() + distance * self.rainbow_hue_step_per_pixel;
+                            let end_hue =
+                                self.get_rainbow_hue() + distance * self.rainbow_hue_step_per_pixel;
                             let start_c = self.rainbow_color_from_hue(start_hue);
                             let end_c = self.rainbow_color_from_hue(end_hue);
                             // Update hue for next shape
@@ -303,7 +376,8 @@ impl InputState {
                         let (start_color, end_color) = if self.rainbow_mode_enabled {
                             let diagonal = ((width * width + height * height) as f64).sqrt();
                             let start_hue = self.get_rainbow_hue();
-                            let end_hue = self.get_rainbow_hue() + diagonal * self.rainbow_hue_step_per_pixel;
+                            let end_hue =
+                                self.get_rainbow_hue() + diagonal * self.rainbow_hue_step_per_pixel;
                             let start_c = self.rainbow_color_from_hue(start_hue);
                             let end_c = self.rainbow_color_from_hue(end_hue);
                             // Update hue for next shape
@@ -330,7 +404,8 @@ impl InputState {
                             // Use the horizontal diameter for gradient
                             let diameter = (rx * 2) as f64;
                             let start_hue = self.get_rainbow_hue();
-                            let end_hue = self.get_rainbow_hue() + diameter * self.rainbow_hue_step_per_pixel;
+                            let end_hue =
+                                self.get_rainbow_hue() + diameter * self.rainbow_hue_step_per_pixel;
                             let start_c = self.rainbow_color_from_hue(start_hue);
                             let end_c = self.rainbow_color_from_hue(end_hue);
                             // Update hue for next shape
@@ -357,7 +432,8 @@ impl InputState {
                             let dy = (y - start_y) as f64;
                             let distance = (dx * dx + dy * dy).sqrt();
                             let start_hue = self.get_rainbow_hue();
-                            let end_hue = self.get_rainbow_hue() + distance * self.rainbow_hue_step_per_pixel;
+                            let end_hue =
+                                self.get_rainbow_hue() + distance * self.rainbow_hue_step_per_pixel;
                             let start_c = self.rainbow_color_from_hue(start_hue);
                             let end_c = self.rainbow_color_from_hue(end_hue);
                             // Update hue for next shape
@@ -381,7 +457,8 @@ impl InputState {
                     }
                     Tool::Marker => {
                         let per_point_colors = if self.rainbow_mode_enabled {
-                            let colors = self.generate_rainbow_colors_for_points(&points)
+                            let colors = self
+                                .generate_rainbow_colors_for_points(&points)
                                 .into_iter()
                                 .map(|mut c| {
                                     // Apply marker opacity to rainbow colors
@@ -417,7 +494,17 @@ impl InputState {
                             kind: self.eraser_kind,
                         },
                     },
-                    Tool::Highlight => {
+                    Tool::Redact => match util::Rect::from_corners((start_x, start_y), (x, y)) {
+                        Some(rect) => Shape::Redact {
+                            rect,
+                            mode: self.redact_mode,
+                        },
+                        None => {
+                            self.clear_provisional_dirty();
+                            return;
+                        }
+                    },
+                    Tool::Highlight | Tool::Laser => {
                         self.clear_provisional_dirty();
                         return;
                     }
@@ -459,7 +546,13 @@ impl InputState {
                     }
                 };
 
-                if let Some((new_id, _snapshot)) = addition {
+                if let Some((new_id, snapshot)) = addition {
+                    if !matches!(
+                        snapshot.shape,
+                        Shape::EraserStroke { .. } | Shape::Redact { .. }
+                    ) {
+                        self.schedule_vanishing(new_id);
+                    }
                     self.invalidate_hit_cache_for(new_id);
                     self.dirty_tracker.mark_optional_rect(bounds);
                     self.clear_selection();
@@ -38,6 +38,9 @@ impl InputState {
                     color: self.current_color,
                     thick: self.current_thickness,
                     per_point_colors: None,
+                    per_point_widths: self
+                        .sampled_stroke_widths(points.len())
+                        .map(<[f64]>::to_vec),
                 }),
                 Tool::Line => {
                     let (start_color, end_color) = if self.rainbow_mode_enabled {
@@ -150,8 +153,16 @@ impl InputState {
                     thick: self.current_thickness,
                     per_point_colors: None,
                 }),
+                Tool::Redact => {
+                    util::Rect::from_corners((*start_x, *start_y), (current_x, current_y)).map(
+                        |rect| Shape::Redact {
+                            rect,
+                            mode: self.redact_mode,
+                        },
+                    )
+                }
                 Tool::Eraser => None, // Preview handled separately to avoid clearing the buffer
-                Tool::Highlight => None,
+                Tool::Highlight | Tool::Laser => None,
                 Tool::Select => None,
                 // No provisional shape for other tools
             }
@@ -199,10 +210,11 @@ impl InputState {
                         self.current_color,
                         self.current_thickness,
                         colors.as_deref(),
+                        self.sampled_stroke_widths(points.len()),
                     );
                     true
                 }
-                Tool::Highlight => false,
+                Tool::Highlight | Tool::Laser => false,
                 Tool::Marker => {
                     let colors = if self.rainbow_mode_enabled {
                         Some(
@@ -234,7 +246,14 @@ impl InputState {
                         b: 1.0,
                         a: 0.35,
                     };
-                    render_freehand_borrowed(ctx, points, preview_color, self.eraser_size, None);
+                    render_freehand_borrowed(
+                        ctx,
+                        points,
+                        preview_color,
+                        self.eraser_size,
+                        None,
+                        None,
+                    );
                     true
                 }
                 _ => {
@@ -566,6 +566,51 @@ fn capture_action_sets_pending_and_clears_modifiers() {
     assert!(state.take_pending_capture_action().is_none());
 }
 
+#[test]
+fn region_capture_waits_for_confirmed_selection() {
+    let mut state = create_test_input_state();
+    state.update_screen_dimensions(800, 600);
+
+    state.handle_action(Action::CaptureClipboardRegion);
+    assert!(state.is_selecting_capture_region());
+    assert!(state.take_pending_capture_action().is_none());
+
+    state.on_mouse_press(MouseButton::Left, 100, 120);
+    state.on_mouse_motion(300, 220);
+    state.on_mouse_release(MouseButton::Left, 300, 220);
+
+    // Drag the bottom-right handle to grow the selection.
+    state.on_mouse_press(MouseButton::Left, 300, 220);
+    state.on_mouse_release(MouseButton::Left, 340, 260);
+
+    state.on_key_press(Key::Return);
+    assert!(!state.is_selecting_capture_region());
+    assert_eq!(
+        state.take_pending_capture_action(),
+        Some(Action::CaptureClipboardRegion)
+    );
+    assert_eq!(
+        state.take_pending_capture_region(),
+        util::Rect::new(100, 120, 240, 140)
+    );
+}
+
+#[test]
+fn region_capture_escape_cancels_without_capturing() {
+    let mut state = create_test_input_state();
+    state.update_screen_dimensions(800, 600);
+
+    state.handle_action(Action::CaptureFileSelection);
+    state.on_mouse_press(MouseButton::Left, 10, 10);
+    state.on_mouse_release(MouseButton::Left, 50, 50);
+    state.on_key_press(Key::Escape);
+
+    assert!(!state.is_selecting_capture_region());
+    assert!(state.take_pending_capture_action().is_none());
+    assert!(state.take_pending_capture_region().is_none());
+    assert!(!state.should_exit);
+}
+
 #[test]
 fn board_mode_toggle_restores_previous_color() {
     let mut state = create_test_input_state();
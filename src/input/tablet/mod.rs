@@ -50,5 +50,6 @@ pub fn apply_pressure_to_state(pressure01: f64, state: &mut InputState, settings
     }
 
     state.current_thickness = new_thickness;
+    state.set_pressure_width(Some(new_thickness));
     state.needs_redraw = true;
 }
@@ -1,10 +1,12 @@
 //! Drawing tool selection.
 
+use serde::{Deserialize, Serialize};
+
 /// Drawing tool selection.
 ///
 /// The active tool determines what shape is created when the user drags the mouse.
 /// Tools are selected by holding modifier keys (Shift, Ctrl, Tab) while dragging.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Tool {
     /// Select/cursor tool - interact with UI without drawing
     Select,
@@ -24,5 +26,30 @@ pub enum Tool {
     Highlight,
     /// Eraser brush that removes content within its stroke
     Eraser,
+    /// Laser pointer: a glowing dot with a fading trail, never committed to the frame
+    Laser,
+    /// Redaction box that blurs, pixelates or blanks out the region it covers
+    Redact,
     // Note: Text mode uses DrawingState::TextInput instead of Tool::Text
 }
+
+impl std::str::FromStr for Tool {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "select" => Ok(Self::Select),
+            "pen" => Ok(Self::Pen),
+            "line" => Ok(Self::Line),
+            "rect" | "rectangle" => Ok(Self::Rect),
+            "ellipse" | "circle" => Ok(Self::Ellipse),
+            "arrow" => Ok(Self::Arrow),
+            "marker" => Ok(Self::Marker),
+            "highlight" => Ok(Self::Highlight),
+            "eraser" => Ok(Self::Eraser),
+            "laser" => Ok(Self::Laser),
+            "redact" | "redaction" => Ok(Self::Redact),
+            _ => Err(()),
+        }
+    }
+}
@@ -1,4 +1,6 @@
-use clap::{ArgAction, Parser};
+use clap::{ArgAction, Parser, Subcommand};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
 use std::sync::atomic::{AtomicU8, Ordering};
 
 pub const RESUME_SESSION_ENV: &str = "WAYSCRIBER_RESUME_SESSION";
@@ -32,9 +34,26 @@ pub fn runtime_session_override() -> Option<bool> {
     decode_session_override(SESSION_RESUME_OVERRIDE.load(Ordering::Acquire))
 }
 
+/// Named session profile selected with `--session` (or from the tray) for this process.
+pub static SESSION_PROFILE: Mutex<Option<String>> = Mutex::new(None);
+
+pub fn set_runtime_session_profile(name: Option<String>) {
+    *SESSION_PROFILE
+        .lock()
+        .unwrap_or_else(|err| err.into_inner()) = name;
+}
+
+pub fn runtime_session_profile() -> Option<String> {
+    SESSION_PROFILE
+        .lock()
+        .unwrap_or_else(|err| err.into_inner())
+        .clone()
+}
+
 mod backend;
 mod capture;
 mod config;
+mod control;
 mod daemon;
 mod draw;
 mod input;
@@ -48,7 +67,11 @@ mod util;
 #[derive(Parser, Debug)]
 #[command(name = "wayscriber")]
 #[command(version, about = "Screen annotation tool for Wayland compositors")]
+#[command(args_conflicts_with_subcommands = true)]
 struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+
     /// Run as daemon (background service; bind a toggle like Super+D)
     #[arg(long, short = 'd', action = ArgAction::SetTrue)]
     daemon: bool,
@@ -88,11 +111,89 @@ struct Cli {
     )]
     session_info: bool,
 
+    /// List rotated session backups, newest first
+    #[arg(
+        long,
+        action = ArgAction::SetTrue,
+        conflicts_with_all = [
+            "daemon",
+            "active",
+            "clear_session",
+            "session_info"
+        ]
+    )]
+    list_session_backups: bool,
+
+    /// Replace the saved session with backup N from --list-session-backups (1 = newest)
+    #[arg(
+        long,
+        value_name = "N",
+        conflicts_with_all = [
+            "daemon",
+            "active",
+            "clear_session",
+            "session_info",
+            "list_session_backups"
+        ]
+    )]
+    restore_session_backup: Option<usize>,
+
+    /// Export the persisted session to an SVG file without opening the overlay
+    /// (uses --mode to pick the board, defaulting to the last active one)
+    #[arg(
+        long,
+        value_name = "FILE",
+        conflicts_with_all = [
+            "daemon",
+            "active",
+            "clear_session",
+            "session_info"
+        ]
+    )]
+    export_svg: Option<PathBuf>,
+
+    /// Export every saved board to a multi-page PDF file without opening the overlay
+    #[arg(
+        long,
+        value_name = "FILE",
+        conflicts_with_all = [
+            "daemon",
+            "active",
+            "clear_session",
+            "session_info",
+            "export_svg"
+        ]
+    )]
+    export_pdf: Option<PathBuf>,
+
+    /// Open a .wayscriber document in the overlay (the saved session is left untouched)
+    #[arg(
+        long,
+        value_name = "FILE",
+        conflicts_with_all = [
+            "daemon",
+            "clear_session",
+            "session_info",
+            "list_session_backups",
+            "restore_session_backup",
+            "export_svg",
+            "export_pdf",
+            "freeze"
+        ]
+    )]
+    open: Option<PathBuf>,
+
     /// Start with frozen mode active (freeze the screen immediately)
     #[arg(
         long,
         action = ArgAction::SetTrue,
-        conflicts_with_all = ["daemon", "clear_session", "session_info"]
+        conflicts_with_all = [
+            "daemon",
+            "clear_session",
+            "session_info",
+            "export_svg",
+            "export_pdf"
+        ]
     )]
     freeze: bool,
 
@@ -103,12 +204,205 @@ struct Cli {
     /// Force session resume off (ignore persisted session data for this run)
     #[arg(long, action = ArgAction::SetTrue, conflicts_with = "resume_session")]
     no_resume_session: bool,
+
+    /// Use the named session (e.g. "standup") instead of the default one; created on first save
+    #[arg(long, value_name = "NAME")]
+    session: Option<String>,
+
+    /// Show the overlay and log its input events to FILE, to attach to bug reports
+    #[arg(long, value_name = "FILE", conflicts_with = "daemon")]
+    record_input: Option<PathBuf>,
+
+    /// Replay input recorded with --record-input without opening the overlay
+    /// (starts from a blank overlay using the current config; the saved session is not touched)
+    #[arg(
+        long,
+        value_name = "FILE",
+        conflicts_with_all = [
+            "daemon",
+            "active",
+            "freeze",
+            "open",
+            "record_input",
+            "clear_session",
+            "session_info",
+            "export_svg",
+            "export_pdf"
+        ]
+    )]
+    replay_input: Option<PathBuf>,
+
+    /// Write the last frame of --replay-input to a PNG file
+    #[arg(long, value_name = "FILE", requires = "replay_input")]
+    replay_output: Option<PathBuf>,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Send a command to the running daemon and print its reply
+    Ctl {
+        /// Print the raw JSON reply
+        #[arg(long, global = true, action = ArgAction::SetTrue)]
+        json: bool,
+
+        #[command(subcommand)]
+        command: CtlCommand,
+    },
+    /// Manage named sessions selected with `--session <NAME>`
+    Session {
+        #[command(subcommand)]
+        command: SessionCommand,
+    },
+    /// Render a board of the saved session to a PNG file without opening the overlay
+    Render {
+        /// Board to render (transparent, whiteboard, or blackboard); defaults to the last active one
+        #[arg(long, value_name = "BOARD")]
+        board: Option<String>,
+        /// PNG file to write
+        #[arg(long, value_name = "FILE")]
+        out: PathBuf,
+        /// Canvas width in logical pixels (defaults to the extent of the drawing)
+        #[arg(long, value_name = "PX")]
+        width: Option<u32>,
+        /// Canvas height in logical pixels (defaults to the extent of the drawing)
+        #[arg(long, value_name = "PX")]
+        height: Option<u32>,
+        /// Scale factor applied to the canvas size, e.g. 0.25 for thumbnails or 2 for HiDPI
+        #[arg(long, value_name = "FACTOR", default_value_t = 1.0)]
+        scale: f64,
+        /// Render from the named session instead of the default one
+        #[arg(long, value_name = "NAME")]
+        session: Option<String>,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum SessionCommand {
+    /// List named sessions
+    List,
+    /// Rename a named session
+    Rename { from: String, to: String },
+    /// Copy a named session (boards, tool state and backups) under a new name
+    Duplicate { from: String, to: String },
+    /// Delete a named session and its backups
+    Delete { name: String },
+}
+
+#[derive(Subcommand, Debug)]
+enum CtlCommand {
+    /// Show the overlay if hidden, hide it if visible
+    Toggle,
+    /// Show the overlay
+    Show,
+    /// Hide the overlay
+    Hide,
+    /// Select a tool (pen, line, rect, ellipse, arrow, marker, highlight, eraser, select)
+    Tool { name: String },
+    /// Set the pen color by name (red, blue, ...) or as #rrggbb
+    Color { color: String },
+    /// Switch board (transparent, whiteboard, or blackboard)
+    Board { mode: String },
+    /// Take a screenshot of the full screen, the active window, or a selected region
+    Capture {
+        #[arg(value_enum)]
+        target: control::CaptureTarget,
+        /// Copy the screenshot to the clipboard only
+        #[arg(long, action = ArgAction::SetTrue, conflicts_with = "file")]
+        clipboard: bool,
+        /// Save the screenshot to a file only
+        #[arg(long, action = ArgAction::SetTrue)]
+        file: bool,
+        /// Burn the current annotations into the screenshot
+        #[arg(long, action = ArgAction::SetTrue)]
+        annotated: bool,
+    },
+    /// Toggle frozen mode
+    Freeze,
+    /// Toggle the in-overlay help
+    HelpOverlay,
+    /// Undo the last change on the active board
+    Undo,
+    /// Clear the active board
+    Clear,
+    /// Print overlay visibility and drawing state
+    Status,
+    /// Print one JSON status line per state change until the daemon exits
+    Watch {
+        /// Format lines for a Waybar custom module (`return-type: json`)
+        #[arg(long, action = ArgAction::SetTrue)]
+        waybar: bool,
+    },
+}
+
+impl CtlCommand {
+    fn into_request(self) -> control::ControlRequest {
+        use control::ControlRequest;
+        match self {
+            CtlCommand::Toggle => ControlRequest::Toggle,
+            CtlCommand::Show => ControlRequest::Show,
+            CtlCommand::Hide => ControlRequest::Hide,
+            CtlCommand::Tool { name } => ControlRequest::Tool { tool: name },
+            CtlCommand::Color { color } => ControlRequest::Color { color },
+            CtlCommand::Board { mode } => ControlRequest::Board { board: mode },
+            CtlCommand::Capture {
+                target,
+                clipboard,
+                file,
+                annotated,
+            } => ControlRequest::Capture {
+                target,
+                destination: if clipboard {
+                    Some(control::CaptureOutput::Clipboard)
+                } else if file {
+                    Some(control::CaptureOutput::File)
+                } else {
+                    None
+                },
+                annotated,
+            },
+            CtlCommand::Freeze => ControlRequest::Freeze,
+            CtlCommand::HelpOverlay => ControlRequest::Help,
+            CtlCommand::Undo => ControlRequest::Undo,
+            CtlCommand::Clear => ControlRequest::Clear,
+            CtlCommand::Status => ControlRequest::Status,
+            CtlCommand::Watch { .. } => ControlRequest::Watch,
+        }
+    }
 }
 
+/// `wayscriber ctl` exit code when the daemon or overlay rejected the command.
+const CTL_EXIT_REJECTED: i32 = 1;
+/// `wayscriber ctl` exit code when no daemon or overlay could be reached.
+const CTL_EXIT_UNREACHABLE: i32 = 3;
+/// Covers the daemon starting a hidden overlay before it can apply the command.
+const CTL_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(10);
+const CTL_NO_LISTENER: &str =
+    "no running wayscriber daemon or overlay found (start one with `wayscriber --daemon`)";
+
 fn main() -> anyhow::Result<()> {
     env_logger::init();
 
-    let cli = Cli::parse();
+    let mut cli = Cli::parse();
+    match cli.command.take() {
+        Some(Command::Ctl { json, command }) => std::process::exit(run_ctl(json, command)),
+        Some(Command::Session { command }) => return run_session_command(command),
+        Some(Command::Render {
+            board,
+            out,
+            width,
+            height,
+            scale,
+            session,
+        }) => {
+            let size = (width, height);
+            return run_render(board.as_deref(), &out, size, scale, session.as_deref());
+        }
+        None => {}
+    }
+    if let Some(name) = cli.session.as_deref() {
+        session::validate_profile_name(name)?;
+    }
+
     let session_override = if cli.resume_session {
         Some(true)
     } else if cli.no_resume_session {
@@ -117,13 +411,38 @@ fn main() -> anyhow::Result<()> {
         None
     };
 
-    if cli.clear_session || cli.session_info {
+    if cli.clear_session
+        || cli.session_info
+        || cli.list_session_backups
+        || cli.restore_session_backup.is_some()
+    {
         run_session_cli_commands(&cli)?;
         return Ok(());
     }
 
+    if let Some(path) = cli.export_svg.as_deref() {
+        run_export_svg(&cli, path)?;
+        return Ok(());
+    }
+
+    if let Some(path) = cli.export_pdf.as_deref() {
+        run_export_pdf(path, cli.session.as_deref())?;
+        return Ok(());
+    }
+
+    if let Some(path) = cli.replay_input.as_deref() {
+        return run_replay_input(path, cli.replay_output.as_deref());
+    }
+
+    if let Some(path) = cli.open.as_deref() {
+        // Fail before the overlay grabs the screen when the file is missing or unreadable.
+        let config = config::Config::load()?.config;
+        session::load_document(path, config.session.max_shapes_per_frame)?;
+    }
+
     // Check for Wayland environment
-    if std::env::var("WAYLAND_DISPLAY").is_err() && (cli.daemon || cli.active) {
+    let show_overlay = cli.active || cli.freeze || cli.open.is_some() || cli.record_input.is_some();
+    if std::env::var("WAYLAND_DISPLAY").is_err() && (cli.daemon || show_overlay) {
         log::error!("WAYLAND_DISPLAY not set - this application requires Wayland.");
         log::error!("Please run on a Wayland compositor (Hyprland, Sway, etc.).");
         return Err(anyhow::anyhow!("Wayland environment required"));
@@ -136,9 +455,14 @@ fn main() -> anyhow::Result<()> {
         if tray_disabled {
             log::info!("Tray disabled via --no-tray / WAYSCRIBER_NO_TRAY");
         }
-        let mut daemon = daemon::Daemon::new(cli.mode, !tray_disabled, session_override);
+        let mut daemon = daemon::Daemon::new(
+            cli.mode,
+            !tray_disabled,
+            session_override,
+            cli.session.clone(),
+        );
         daemon.run()?;
-    } else if cli.active || cli.freeze {
+    } else if show_overlay {
         // One-shot mode: show overlay immediately and exit when done
         log::info!("Starting Wayland overlay...");
         log::info!("Starting annotation overlay...");
@@ -168,9 +492,10 @@ fn main() -> anyhow::Result<()> {
         log::info!("");
 
         set_runtime_session_override(session_override);
+        set_runtime_session_profile(cli.session.clone());
 
         // Run Wayland backend
-        backend::run_wayland(cli.mode, cli.freeze)?;
+        backend::run_wayland(cli.mode, cli.freeze, cli.open, cli.record_input)?;
 
         log::info!("Annotation overlay closed.");
     } else {
@@ -188,13 +513,30 @@ fn main() -> anyhow::Result<()> {
         );
         println!("  wayscriber --no-resume-session  Disable session resume for this run");
         println!("  wayscriber --freeze          Start overlay already frozen");
+        println!("  wayscriber --session NAME    Use a named session (with -a or -d)");
+        println!(
+            "  wayscriber session list      List named sessions (also rename/duplicate/delete)"
+        );
+        println!("  wayscriber --export-svg FILE Export the saved session as SVG");
+        println!("  wayscriber --export-pdf FILE Export all saved boards as a multi-page PDF");
+        println!(
+            "  wayscriber render --out FILE Render a saved board to PNG (--board, --width, --scale)"
+        );
+        println!("  wayscriber --open FILE       Open a .wayscriber document in the overlay");
+        println!("  wayscriber --record-input FILE  Show the overlay and record its input");
+        println!(
+            "  wayscriber --replay-input FILE  Replay recorded input (--replay-output FRAME.png)"
+        );
+        println!(
+            "  wayscriber ctl COMMAND       Control the running daemon (e.g. ctl toggle, ctl status)"
+        );
         println!("  wayscriber -h, --help        Show help");
         println!();
         println!("Daemon mode (recommended). Example Hyprland setup:");
         println!("  1. Run: wayscriber --daemon");
         println!("  2. Add to Hyprland config:");
         println!("     exec-once = wayscriber --daemon");
-        println!("     bind = SUPER, D, exec, pkill -SIGUSR1 wayscriber");
+        println!("     bind = SUPER, D, exec, wayscriber ctl toggle");
         println!("  3. Press your bound shortcut (e.g. Super+D) to toggle overlay on/off");
         println!();
         println!("Requirements:");
@@ -221,8 +563,11 @@ fn run_session_cli_commands(cli: &Cli) -> anyhow::Result<()> {
     let config_dir = config::Config::config_directory_from_source(&loaded.source)?;
     let display_env = std::env::var("WAYLAND_DISPLAY").ok();
 
-    let options =
+    let mut options =
         session::options_from_config(&loaded.config.session, &config_dir, display_env.as_deref())?;
+    if let Some(name) = cli.session.as_deref() {
+        session::apply_profile(&mut options, name)?;
+    }
 
     if cli.clear_session {
         let outcome = session::clear_session(&options)?;
@@ -235,10 +580,17 @@ fn run_session_cli_commands(cli: &Cli) -> anyhow::Result<()> {
         if outcome.removed_backup {
             println!("  Removed backup file");
         }
+        if outcome.removed_autosave {
+            println!("  Removed autosave file");
+        }
         if outcome.removed_lock {
             println!("  Removed lock file");
         }
-        if !outcome.removed_session && !outcome.removed_backup && !outcome.removed_lock {
+        if !outcome.removed_session
+            && !outcome.removed_backup
+            && !outcome.removed_autosave
+            && !outcome.removed_lock
+        {
             println!("  No session artefacts found");
         }
         return Ok(());
@@ -279,15 +631,35 @@ fn run_session_cli_commands(cli: &Cli) -> anyhow::Result<()> {
                 println!("    Modified : {}", ts);
             }
             println!("    Compressed: {}", inspection.compressed);
+            println!(
+                "    Encoding : {}",
+                if inspection.compact {
+                    "compact"
+                } else {
+                    "json"
+                }
+            );
             if let Some(version) = inspection.file_version {
                 println!("    File version: {}", version);
             }
+            for step in &inspection.pending_migrations {
+                println!(
+                    "    Pending upgrade: v{} -> v{} ({})",
+                    step.from, step.to, step.description
+                );
+            }
             if let Some(counts) = inspection.frame_counts {
                 println!(
                     "    Shapes   : transparent {}, whiteboard {}, blackboard {}",
                     counts.transparent, counts.whiteboard, counts.blackboard
                 );
             }
+            if let Some(pages) = inspection.page_counts {
+                println!(
+                    "    Pages    : transparent {}, whiteboard {}, blackboard {}",
+                    pages.transparent, pages.whiteboard, pages.blackboard
+                );
+            }
             println!("    History present: {}", inspection.history_present);
             if let Some(hist) = &inspection.history_counts {
                 println!(
@@ -305,11 +677,20 @@ fn run_session_cli_commands(cli: &Cli) -> anyhow::Result<()> {
             println!("    (not found)");
         }
 
+        for path in &inspection.migration_backups {
+            println!("  Pre-upgrade copy  : {}", path.display());
+        }
         println!("  Backup file       : {}", inspection.backup_path.display());
         if inspection.backup_exists {
             if let Some(size) = inspection.backup_size_bytes {
                 println!("    Size     : {} bytes", size);
             }
+            if inspection.backup_count > 0 {
+                println!(
+                    "    Backups  : {} kept (see --list-session-backups)",
+                    inspection.backup_count
+                );
+            }
         } else {
             println!("    (not found)");
         }
@@ -319,12 +700,434 @@ fn run_session_cli_commands(cli: &Cli) -> anyhow::Result<()> {
         return Ok(());
     }
 
+    if cli.list_session_backups {
+        let backups = session::list_session_backups(&options)?;
+        if backups.is_empty() {
+            println!(
+                "No backups found for {}",
+                options.session_file_path().display()
+            );
+            return Ok(());
+        }
+        println!(
+            "Backups of {} (newest first):",
+            options.session_file_path().display()
+        );
+        for backup in &backups {
+            let saved = backup
+                .modified
+                .and_then(|m| crate::time_utils::format_system_time(m, "%Y-%m-%d %H:%M:%S"))
+                .unwrap_or_else(|| "unknown time".to_string());
+            println!(
+                "  {:>2}. {}  {} bytes",
+                backup.index, saved, backup.size_bytes
+            );
+            match backup
+                .summary
+                .as_ref()
+                .and_then(|summary| summary.frame_counts)
+            {
+                Some(counts) => println!(
+                    "      Shapes: transparent {}, whiteboard {}, blackboard {}",
+                    counts.transparent, counts.whiteboard, counts.blackboard
+                ),
+                None => println!("      (empty or unreadable)"),
+            }
+            println!("      {}", backup.path.display());
+        }
+        println!("Restore one with: wayscriber --restore-session-backup <N>");
+        return Ok(());
+    }
+
+    if let Some(index) = cli.restore_session_backup {
+        let outcome = session::restore_session_backup(&options, index)?;
+        println!(
+            "Restored {} to {}",
+            outcome.restored_from.display(),
+            outcome.session_path.display()
+        );
+        if outcome.previous_session_backed_up {
+            println!("  The replaced session is now backup 1");
+        }
+        return Ok(());
+    }
+
+    Ok(())
+}
+
+fn run_session_command(command: SessionCommand) -> anyhow::Result<()> {
+    let loaded = config::Config::load()?;
+    let config_dir = config::Config::config_directory_from_source(&loaded.source)?;
+    let display_env = std::env::var("WAYLAND_DISPLAY").ok();
+    let options =
+        session::options_from_config(&loaded.config.session, &config_dir, display_env.as_deref())?;
+
+    match command {
+        SessionCommand::List => {
+            let profiles = session::list_profiles(&options)?;
+            if profiles.is_empty() {
+                println!("No named sessions yet (create one with --session <NAME>)");
+                return Ok(());
+            }
+            for profile in profiles {
+                let saved = profile
+                    .last_saved
+                    .and_then(|m| crate::time_utils::format_system_time(m, "%Y-%m-%d %H:%M:%S"))
+                    .unwrap_or_else(|| "never saved".to_string());
+                println!(
+                    "{:<24} {:<20} {}",
+                    profile.name,
+                    saved,
+                    profile.path.display()
+                );
+            }
+        }
+        SessionCommand::Rename { from, to } => {
+            session::rename_profile(&options, &from, &to)?;
+            println!("Renamed session '{}' to '{}'", from, to);
+        }
+        SessionCommand::Duplicate { from, to } => {
+            session::duplicate_profile(&options, &from, &to)?;
+            println!("Copied session '{}' to '{}'", from, to);
+        }
+        SessionCommand::Delete { name } => {
+            session::delete_profile(&options, &name)?;
+            println!("Deleted session '{}'", name);
+        }
+    }
+    Ok(())
+}
+
+fn run_export_svg(cli: &Cli, path: &Path) -> anyhow::Result<()> {
+    let (loaded, snapshot) = load_saved_snapshot(cli.session.as_deref())?;
+
+    let mode = match cli.mode.as_deref() {
+        Some(raw) => raw
+            .parse::<input::BoardMode>()
+            .map_err(|_| anyhow::anyhow!("Unknown board mode '{}'", raw))?,
+        None => snapshot.active_mode,
+    };
+    // Export the page that was active when the session was saved.
+    let frame = snapshot
+        .board(mode)
+        .map(|board| board.active_page().clone())
+        .unwrap_or_default();
+
+    let view = draw::svg::shapes_bounds(&frame.shapes)
+        .ok_or_else(|| anyhow::anyhow!("The {:?} board is empty; nothing to export", mode))?;
+    let background = mode.background_color(&loaded.config.board);
+    let svg = draw::svg::render_svg(&frame.shapes, view, background);
+    std::fs::write(path, svg)
+        .map_err(|err| anyhow::anyhow!("Failed to write {}: {}", path.display(), err))?;
+
+    println!(
+        "Exported {} shapes from the {:?} board to {}",
+        frame.shapes.len(),
+        mode,
+        path.display()
+    );
+    Ok(())
+}
+
+fn run_export_pdf(path: &Path, profile: Option<&str>) -> anyhow::Result<()> {
+    let (loaded, snapshot) = load_saved_snapshot(profile)?;
+
+    let pages = draw::pdf::board_pages(|mode| snapshot.board(mode));
+    // The overlay size is unknown offline, so size pages to fit the furthest shape.
+    let (width, height) = draw::pdf::pages_extent(&pages)
+        .ok_or_else(|| anyhow::anyhow!("All boards are empty; nothing to export"))?;
+    draw::pdf::write_pdf(path, width, height, &pages, &loaded.config.board)
+        .map_err(|err| anyhow::anyhow!("Failed to write {}: {}", path.display(), err))?;
+
+    println!(
+        "Exported {} board page(s) to {}",
+        pages.len(),
+        path.display()
+    );
+    Ok(())
+}
+
+fn run_render(
+    board: Option<&str>,
+    path: &Path,
+    (width, height): (Option<u32>, Option<u32>),
+    scale: f64,
+    profile: Option<&str>,
+) -> anyhow::Result<()> {
+    if !(scale.is_finite() && scale > 0.0) {
+        return Err(anyhow::anyhow!("--scale must be a positive number"));
+    }
+    let (loaded, snapshot) = load_saved_snapshot(profile)?;
+
+    let mode = match board {
+        Some(raw) => raw
+            .parse::<input::BoardMode>()
+            .map_err(|_| anyhow::anyhow!("Unknown board '{}'", raw))?,
+        None => snapshot.active_mode,
+    };
+    // Render the page that was active when the session was saved, like --export-svg.
+    let frame = snapshot
+        .board(mode)
+        .map(|board| board.active_page().clone())
+        .unwrap_or_default();
+
+    let size = match (width, height, draw::raster::shapes_extent(&frame.shapes)) {
+        (Some(width), Some(height), _) => (width, height),
+        (width, height, Some((extent_w, extent_h))) => {
+            (width.unwrap_or(extent_w), height.unwrap_or(extent_h))
+        }
+        _ => {
+            return Err(anyhow::anyhow!(
+                "The {:?} board is empty; pass --width and --height to render a blank image",
+                mode
+            ));
+        }
+    };
+    let pixels = (
+        (f64::from(size.0) * scale).ceil(),
+        (f64::from(size.1) * scale).ceil(),
+    );
+    let max = f64::from(draw::raster::MAX_IMAGE_DIMENSION);
+    if pixels.0 < 1.0 || pixels.1 < 1.0 || pixels.0 > max || pixels.1 > max {
+        return Err(anyhow::anyhow!(
+            "A {}x{} image is out of range; each side must be between 1 and {} pixels",
+            pixels.0,
+            pixels.1,
+            draw::raster::MAX_IMAGE_DIMENSION
+        ));
+    }
+
+    let image = draw::raster::render_image(&frame.shapes, mode, size, scale, &loaded.config.board)
+        .map_err(|err| anyhow::anyhow!("Failed to render the {:?} board: {}", mode, err))?;
+    draw::raster::write_png(&image, path)
+        .map_err(|err| anyhow::anyhow!("Failed to write {}: {}", path.display(), err))?;
+
+    println!(
+        "Rendered {} shapes from the {:?} board to {} ({}x{})",
+        frame.shapes.len(),
+        mode,
+        path.display(),
+        image.width(),
+        image.height()
+    );
     Ok(())
 }
 
+/// Logical size used until a replayed script reports the overlay size.
+const REPLAY_DEFAULT_SIZE: (u32, u32) = (1920, 1080);
+
+fn run_replay_input(path: &Path, output: Option<&Path>) -> anyhow::Result<()> {
+    let text = std::fs::read_to_string(path)
+        .map_err(|err| anyhow::anyhow!("Failed to read {}: {}", path.display(), err))?;
+    let events = backend::headless::parse_script(&text)
+        .map_err(|err| anyhow::anyhow!("{}: {:#}", path.display(), err))?;
+    let config = match config::Config::load() {
+        Ok(loaded) => loaded.config,
+        Err(err) => {
+            log::warn!("Failed to load config: {}. Using defaults.", err);
+            config::Config::default()
+        }
+    };
+
+    let (width, height) = REPLAY_DEFAULT_SIZE;
+    let mut replay = backend::headless::HeadlessBackend::new(config, width, height);
+    replay.run(&events)?;
+
+    let state = &replay.input_state;
+    let recorded = events
+        .iter()
+        .filter(|event| {
+            !matches!(
+                event,
+                backend::headless::ScriptEvent::Wait { .. }
+                    | backend::headless::ScriptEvent::Session { .. }
+            )
+        })
+        .count();
+    println!(
+        "Replayed {} events from {} ({} frames rendered)",
+        recorded,
+        path.display(),
+        replay.frames_rendered()
+    );
+    let profile = events.iter().find_map(|event| match event {
+        backend::headless::ScriptEvent::Session { profile, .. } => Some(profile),
+        _ => None,
+    });
+    match profile {
+        Some(Some(profile)) => println!("  Started from the recorded '{}' session", profile),
+        Some(None) => println!("  Started from the recorded session"),
+        None => {}
+    }
+    let (page, pages) = state.canvas_set.active_page_position();
+    println!(
+        "  {:?} board, page {} of {}: {} shapes",
+        state.board_mode(),
+        page,
+        pages,
+        state.canvas_set.active_frame().shapes.len()
+    );
+    for request in replay.requests() {
+        println!("  Overlay requested {:?}", request);
+    }
+    if state.should_exit {
+        println!("  Overlay exited");
+    }
+
+    if let Some(output) = output {
+        let frame = replay
+            .frame()
+            .ok_or_else(|| anyhow::anyhow!("Nothing was rendered"))?;
+        draw::raster::write_png(frame, output)
+            .map_err(|err| anyhow::anyhow!("Failed to write {}: {}", output.display(), err))?;
+        println!("  Last frame written to {}", output.display());
+    }
+    Ok(())
+}
+
+fn run_ctl(json: bool, command: CtlCommand) -> i32 {
+    if let CtlCommand::Watch { waybar } = command {
+        return run_ctl_watch(waybar);
+    }
+    let request = command.into_request();
+    let reply = match send_ctl_request(&request) {
+        Ok(reply) => reply,
+        Err(err) => {
+            eprintln!("wayscriber ctl: {}", err);
+            return CTL_EXIT_UNREACHABLE;
+        }
+    };
+
+    if json {
+        match serde_json::to_string(&reply) {
+            Ok(line) => println!("{}", line),
+            Err(err) => eprintln!("wayscriber ctl: failed to encode reply: {}", err),
+        }
+    } else if let Some(error) = &reply.error {
+        eprintln!("wayscriber ctl: {}", error);
+    } else if let (control::ControlRequest::Status, Some(status)) = (&request, &reply.status) {
+        print_ctl_status(status);
+    }
+
+    if reply.ok { 0 } else { CTL_EXIT_REJECTED }
+}
+
+/// Sends `request` to the daemon, falling back to an overlay started with `--active`.
+fn send_ctl_request(request: &control::ControlRequest) -> anyhow::Result<control::ControlResponse> {
+    for socket in [paths::control_socket(), paths::overlay_socket()] {
+        match control::send_request(&socket, request, CTL_TIMEOUT) {
+            Ok(reply) => return Ok(reply),
+            Err(err)
+                if matches!(
+                    err.kind(),
+                    std::io::ErrorKind::NotFound | std::io::ErrorKind::ConnectionRefused
+                ) =>
+            {
+                log::debug!("No listener on {}: {}", socket.display(), err);
+            }
+            Err(err) => {
+                return Err(anyhow::anyhow!("{}: {}", socket.display(), err));
+            }
+        }
+    }
+    Err(anyhow::anyhow!(CTL_NO_LISTENER))
+}
+
+/// Streams status updates to stdout until the server closes the stream or stdout goes away.
+fn run_ctl_watch(waybar: bool) -> i32 {
+    use std::io::Write;
+
+    for socket in [paths::control_socket(), paths::overlay_socket()] {
+        let result = control::watch_status(&socket, |status| {
+            let line = if waybar {
+                control::waybar_status(&status).to_string()
+            } else {
+                match serde_json::to_string(&status) {
+                    Ok(line) => line,
+                    Err(err) => {
+                        eprintln!("wayscriber ctl: failed to encode status: {}", err);
+                        return false;
+                    }
+                }
+            };
+            let mut stdout = std::io::stdout().lock();
+            writeln!(stdout, "{}", line)
+                .and_then(|_| stdout.flush())
+                .is_ok()
+        });
+        match result {
+            Ok(()) => return 0,
+            Err(err)
+                if matches!(
+                    err.kind(),
+                    std::io::ErrorKind::NotFound | std::io::ErrorKind::ConnectionRefused
+                ) =>
+            {
+                log::debug!("No listener on {}: {}", socket.display(), err);
+            }
+            Err(err) => {
+                eprintln!("wayscriber ctl: {}: {}", socket.display(), err);
+                return CTL_EXIT_UNREACHABLE;
+            }
+        }
+    }
+    eprintln!("wayscriber ctl: {}", CTL_NO_LISTENER);
+    CTL_EXIT_UNREACHABLE
+}
+
+fn print_ctl_status(status: &control::ControlStatus) {
+    let yes_no = |value: bool| if value { "yes" } else { "no" };
+    println!(
+        "Overlay: {}",
+        if status.visible { "visible" } else { "hidden" }
+    );
+    if let Some(overlay) = &status.overlay {
+        println!("  Board    : {}", overlay.board);
+        println!("  Tool     : {}", overlay.tool);
+        println!("  Color    : {}", overlay.color);
+        println!("  Thickness: {:.1}", overlay.thickness);
+        println!("  Frozen   : {}", yes_no(overlay.frozen));
+        println!("  Capturing: {}", yes_no(overlay.capture_in_progress));
+    }
+}
+
+/// Loads the configuration and the persisted session for offline export commands.
+fn load_saved_snapshot(
+    profile: Option<&str>,
+) -> anyhow::Result<(config::LoadedConfig, session::SessionSnapshot)> {
+    let loaded = config::Config::load()?;
+    let config_dir = config::Config::config_directory_from_source(&loaded.source)?;
+    let display_env = std::env::var("WAYLAND_DISPLAY").ok();
+
+    let mut options =
+        session::options_from_config(&loaded.config.session, &config_dir, display_env.as_deref())?;
+    if let Some(name) = profile {
+        session::apply_profile(&mut options, name)?;
+    }
+
+    // Without a running overlay we don't know the output; pick whichever session file exists.
+    let inspection = session::inspect_session(&options)?;
+    if !inspection.exists {
+        return Err(anyhow::anyhow!(
+            "No saved session found at {}",
+            inspection.session_path.display()
+        ));
+    }
+    options.set_output_identity(inspection.active_identity.as_deref());
+
+    let snapshot = session::load_snapshot(&options)?.ok_or_else(|| {
+        anyhow::anyhow!(
+            "Session file {} could not be loaded",
+            inspection.session_path.display()
+        )
+    })?;
+    Ok((loaded, snapshot))
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{Cli, env_flag_enabled};
+    use super::{Cli, Command, CtlCommand, SessionCommand, env_flag_enabled};
+    use crate::control::{CaptureOutput, CaptureTarget, ControlRequest};
     use clap::Parser;
     use std::env;
     use std::sync::Mutex;
@@ -382,6 +1185,91 @@ mod tests {
         }
     }
 
+    #[test]
+    fn export_svg_conflicts_with_overlay_modes() {
+        let cli =
+            Cli::try_parse_from(["wayscriber", "--export-svg", "out.svg", "-m", "whiteboard"])
+                .unwrap();
+        assert_eq!(
+            cli.export_svg.as_deref(),
+            Some(std::path::Path::new("out.svg"))
+        );
+        assert!(
+            Cli::try_parse_from(["wayscriber", "--export-svg", "out.svg", "--daemon"]).is_err()
+        );
+    }
+
+    #[test]
+    fn export_pdf_conflicts_with_other_exports() {
+        let cli = Cli::try_parse_from(["wayscriber", "--export-pdf", "boards.pdf"]).unwrap();
+        assert_eq!(
+            cli.export_pdf.as_deref(),
+            Some(std::path::Path::new("boards.pdf"))
+        );
+        assert!(
+            Cli::try_parse_from([
+                "wayscriber",
+                "--export-pdf",
+                "boards.pdf",
+                "--export-svg",
+                "out.svg"
+            ])
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn ctl_capture_maps_flags_to_request() {
+        let cli =
+            Cli::try_parse_from(["wayscriber", "ctl", "capture", "region", "--clipboard"]).unwrap();
+        let Some(Command::Ctl { json, command }) = cli.command else {
+            panic!("expected ctl subcommand");
+        };
+        assert!(!json);
+        assert_eq!(
+            command.into_request(),
+            ControlRequest::Capture {
+                target: CaptureTarget::Region,
+                destination: Some(CaptureOutput::Clipboard),
+                annotated: false,
+            }
+        );
+        assert!(
+            Cli::try_parse_from([
+                "wayscriber",
+                "ctl",
+                "capture",
+                "full",
+                "--clipboard",
+                "--file"
+            ])
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn ctl_accepts_json_after_subcommand() {
+        let cli = Cli::try_parse_from(["wayscriber", "ctl", "status", "--json"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Some(Command::Ctl {
+                json: true,
+                command: CtlCommand::Status
+            })
+        ));
+        assert!(Cli::try_parse_from(["wayscriber", "--daemon", "ctl", "toggle"]).is_err());
+    }
+
+    #[test]
+    fn ctl_watch_accepts_waybar_flag() {
+        let cli = Cli::try_parse_from(["wayscriber", "ctl", "watch", "--waybar"]).unwrap();
+        let Some(Command::Ctl { command, .. }) = cli.command else {
+            panic!("expected ctl subcommand");
+        };
+        assert!(matches!(command, CtlCommand::Watch { waybar: true }));
+        assert_eq!(command.into_request(), ControlRequest::Watch);
+    }
+
     #[test]
     fn cli_conflicting_flags_fail() {
         let result = Cli::try_parse_from(["wayscriber", "--active", "--clear-session"]);
@@ -390,4 +1278,117 @@ mod tests {
             "expected conflicting flags (--active and --clear-session) to error"
         );
     }
+
+    #[test]
+    fn restore_session_backup_takes_a_number() {
+        let cli = Cli::try_parse_from(["wayscriber", "--restore-session-backup", "2"]).unwrap();
+        assert_eq!(cli.restore_session_backup, Some(2));
+        assert!(Cli::try_parse_from(["wayscriber", "--restore-session-backup", "newest"]).is_err());
+        assert!(
+            Cli::try_parse_from([
+                "wayscriber",
+                "--list-session-backups",
+                "--restore-session-backup",
+                "1"
+            ])
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn session_flag_and_subcommands_parse() {
+        let cli = Cli::try_parse_from(["wayscriber", "--active", "--session", "standup"]).unwrap();
+        assert_eq!(cli.session.as_deref(), Some("standup"));
+
+        let cli =
+            Cli::try_parse_from(["wayscriber", "session", "rename", "standup", "retro"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Some(Command::Session {
+                command: SessionCommand::Rename { ref from, ref to }
+            }) if from == "standup" && to == "retro"
+        ));
+        assert!(Cli::try_parse_from(["wayscriber", "session", "delete"]).is_err());
+    }
+
+    #[test]
+    fn open_takes_a_document_and_conflicts_with_daemon() {
+        let cli = Cli::try_parse_from(["wayscriber", "--open", "talk.wayscriber"]).unwrap();
+        assert_eq!(cli.open, Some(std::path::PathBuf::from("talk.wayscriber")));
+        assert!(
+            Cli::try_parse_from(["wayscriber", "--daemon", "--open", "talk.wayscriber"]).is_err()
+        );
+    }
+
+    #[test]
+    fn render_requires_an_output_and_defaults_the_scale() {
+        let cli = Cli::try_parse_from([
+            "wayscriber",
+            "render",
+            "--board",
+            "whiteboard",
+            "--out",
+            "board.png",
+            "--width",
+            "800",
+        ])
+        .unwrap();
+        let Some(Command::Render {
+            board,
+            out,
+            width,
+            height,
+            scale,
+            session,
+        }) = cli.command
+        else {
+            panic!("expected render subcommand");
+        };
+        assert_eq!(board.as_deref(), Some("whiteboard"));
+        assert_eq!(out, std::path::PathBuf::from("board.png"));
+        assert_eq!((width, height), (Some(800), None));
+        assert_eq!(scale, 1.0);
+        assert_eq!(session, None);
+        assert!(Cli::try_parse_from(["wayscriber", "render", "--board", "whiteboard"]).is_err());
+    }
+
+    #[test]
+    fn render_accepts_a_session_profile() {
+        let cli =
+            Cli::try_parse_from(["wayscriber", "render", "--session", "foo", "--out", "x.png"])
+                .unwrap();
+        let Some(Command::Render { session, out, .. }) = cli.command else {
+            panic!("expected render subcommand");
+        };
+        assert_eq!(session.as_deref(), Some("foo"));
+        assert_eq!(out, std::path::PathBuf::from("x.png"));
+    }
+
+    #[test]
+    fn replay_output_requires_a_replay_and_excludes_the_overlay() {
+        let cli = Cli::try_parse_from([
+            "wayscriber",
+            "--replay-input",
+            "bug.jsonl",
+            "--replay-output",
+            "last.png",
+        ])
+        .unwrap();
+        assert_eq!(
+            cli.replay_input,
+            Some(std::path::PathBuf::from("bug.jsonl"))
+        );
+        assert_eq!(
+            cli.replay_output,
+            Some(std::path::PathBuf::from("last.png"))
+        );
+
+        assert!(Cli::try_parse_from(["wayscriber", "--replay-output", "last.png"]).is_err());
+        assert!(
+            Cli::try_parse_from(["wayscriber", "--active", "--replay-input", "bug.jsonl"]).is_err()
+        );
+        assert!(
+            Cli::try_parse_from(["wayscriber", "--daemon", "--record-input", "bug.jsonl"]).is_err()
+        );
+    }
 }
@@ -67,10 +67,15 @@ fn runtime_root() -> PathBuf {
         .join("wayscriber")
 }
 
-/// Location for transient tray commands.
+/// Control socket served by the daemon for `toggle`, `tool`, `capture`, ... requests.
 /// Uses XDG_RUNTIME_DIR when available; falls back to data/home/temp.
-pub fn tray_action_file() -> PathBuf {
-    runtime_root().join("tray_action")
+pub fn control_socket() -> PathBuf {
+    runtime_root().join("control.sock")
+}
+
+/// Socket served by the running overlay; the daemon forwards drawing commands here.
+pub fn overlay_socket() -> PathBuf {
+    runtime_root().join("overlay.sock")
 }
 
 /// Location to open when showing logs or runtime artifacts.
@@ -88,7 +93,7 @@ mod tests {
 
     #[test]
     #[cfg(unix)]
-    fn tray_action_prefers_runtime_dir_when_set() {
+    fn control_sockets_prefer_runtime_dir_when_set() {
         let _guard = ENV_MUTEX
             .lock()
             .unwrap_or_else(|poisoned| poisoned.into_inner());
@@ -99,8 +104,8 @@ mod tests {
             env::set_var("XDG_RUNTIME_DIR", tmp.path());
         }
 
-        let path = tray_action_file();
-        assert!(path.starts_with(tmp.path()));
+        assert!(control_socket().starts_with(tmp.path()));
+        assert!(overlay_socket().starts_with(tmp.path()));
 
         if let Some(prev) = prev {
             unsafe {
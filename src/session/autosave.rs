@@ -0,0 +1,370 @@
+//! Background autosave and crash detection.
+//!
+//! While the overlay runs, [`Autosaver`] writes a recovery copy of the session
+//! (`*.json.autosave`) from a worker thread, so serialisation and disk I/O never
+//! block rendering. The overlay also records its PID in the session lock file via
+//! [`claim_session`]; a clean shutdown clears it again with [`release_session`].
+//! Finding a dead PID there on the next start means the previous overlay crashed,
+//! and the autosave can be offered for recovery.
+
+use super::options::SessionOptions;
+use super::snapshot::{
+    SessionSnapshot, encode_snapshot, load_snapshot_inner, open_lock_file, write_temp_file,
+};
+use crate::session::lock::{lock_exclusive, lock_shared, unlock};
+use anyhow::{Context, Result};
+use log::{debug, info, warn};
+use std::fs::{self, File};
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+enum AutosaveJob {
+    /// Write the snapshot, or remove the autosave when there is nothing left to persist.
+    Save(Option<Box<SessionSnapshot>>, Box<SessionOptions>),
+    /// Call the wake hook at this instant so the event loop re-checks the timer.
+    WakeAt(Instant),
+}
+
+/// Decides when to autosave and hands the writes to a background thread.
+///
+/// Edits are counted through [`CanvasSet::revision`](crate::draw::CanvasSet::revision): a save
+/// is due once `autosave_after_actions` edits have piled up, or `autosave_interval` after the
+/// first unsaved edit. Because the Wayland loop only wakes for events, the worker calls the
+/// supplied `wake` hook when a timed save falls due.
+pub struct Autosaver {
+    interval: Option<Duration>,
+    after_actions: u64,
+    saved_revision: u64,
+    dirty_since: Option<Instant>,
+    wake_scheduled: Option<Instant>,
+    jobs: Option<mpsc::Sender<AutosaveJob>>,
+    worker: Option<JoinHandle<()>>,
+}
+
+impl Autosaver {
+    /// Starts the worker thread, or returns `None` when autosave is disabled for `options`.
+    pub fn start(options: &SessionOptions, wake: impl Fn() + Send + 'static) -> Option<Self> {
+        if !options.autosave_enabled() {
+            return None;
+        }
+        let (jobs, receiver) = mpsc::channel();
+        let worker = match thread::Builder::new()
+            .name("wayscriber-autosave".into())
+            .spawn(move || run_worker(receiver, wake))
+        {
+            Ok(worker) => worker,
+            Err(err) => {
+                warn!("Failed to start autosave thread: {}", err);
+                return None;
+            }
+        };
+        Some(Self {
+            interval: options.autosave_interval,
+            after_actions: options.autosave_after_actions as u64,
+            saved_revision: 0,
+            dirty_since: None,
+            wake_scheduled: None,
+            jobs: Some(jobs),
+            worker: Some(worker),
+        })
+    }
+
+    /// Returns true when `revision` has unsaved edits and a save threshold has been reached.
+    pub fn is_due(&mut self, revision: u64, now: Instant) -> bool {
+        if revision == self.saved_revision {
+            self.dirty_since = None;
+            return false;
+        }
+        let dirty_since = *self.dirty_since.get_or_insert(now);
+
+        if self.after_actions > 0 && revision.abs_diff(self.saved_revision) >= self.after_actions {
+            return true;
+        }
+        let Some(interval) = self.interval else {
+            return false;
+        };
+        let deadline = dirty_since + interval;
+        if now >= deadline {
+            return true;
+        }
+        if self.wake_scheduled != Some(deadline) {
+            self.wake_scheduled = Some(deadline);
+            self.send(AutosaveJob::WakeAt(deadline));
+        }
+        false
+    }
+
+    /// Queues `snapshot` for writing and treats `revision` as saved.
+    ///
+    /// `None` removes the autosave, e.g. after the boards were cleared.
+    pub fn save(
+        &mut self,
+        snapshot: Option<SessionSnapshot>,
+        options: &SessionOptions,
+        revision: u64,
+    ) {
+        self.saved_revision = revision;
+        self.dirty_since = None;
+        self.send(AutosaveJob::Save(
+            snapshot.map(Box::new),
+            Box::new(options.clone()),
+        ));
+    }
+
+    fn send(&self, job: AutosaveJob) {
+        if let Some(Err(_)) = self.jobs.as_ref().map(|jobs| jobs.send(job)) {
+            warn!("Autosave thread exited unexpectedly");
+        }
+    }
+}
+
+impl Drop for Autosaver {
+    /// Finishes any queued write before returning, so a final save cannot race the worker.
+    fn drop(&mut self) {
+        self.jobs.take();
+        if let Some(Err(_)) = self.worker.take().map(JoinHandle::join) {
+            warn!("Autosave thread panicked");
+        }
+    }
+}
+
+fn run_worker(jobs: mpsc::Receiver<AutosaveJob>, wake: impl Fn()) {
+    let mut wake_at: Option<Instant> = None;
+    loop {
+        let job = match wake_at {
+            Some(deadline) => {
+                match jobs.recv_timeout(deadline.saturating_duration_since(Instant::now())) {
+                    Ok(job) => job,
+                    Err(RecvTimeoutError::Timeout) => {
+                        wake_at = None;
+                        wake();
+                        continue;
+                    }
+                    Err(RecvTimeoutError::Disconnected) => break,
+                }
+            }
+            None => match jobs.recv() {
+                Ok(job) => job,
+                Err(_) => break,
+            },
+        };
+
+        match job {
+            AutosaveJob::Save(Some(snapshot), options) => {
+                match save_autosave_snapshot(&snapshot, &options) {
+                    Ok(()) => debug!(
+                        "Autosaved session to {}",
+                        options.autosave_file_path().display()
+                    ),
+                    Err(err) => warn!("Autosave failed: {:#}", err),
+                }
+            }
+            AutosaveJob::Save(None, options) => {
+                if let Err(err) = discard_autosave(&options) {
+                    warn!("Failed to remove stale autosave: {:#}", err);
+                }
+            }
+            AutosaveJob::WakeAt(deadline) => {
+                wake_at = Some(wake_at.map_or(deadline, |current| current.min(deadline)));
+            }
+        }
+    }
+}
+
+/// Writes `snapshot` to the autosave file next to the session file.
+pub fn save_autosave_snapshot(snapshot: &SessionSnapshot, options: &SessionOptions) -> Result<()> {
+    fs::create_dir_all(&options.base_dir).with_context(|| {
+        format!(
+            "failed to create session directory {}",
+            options.base_dir.display()
+        )
+    })?;
+
+    let lock_path = options.lock_file_path();
+    let lock_file = open_lock_file(&lock_path)?;
+    lock_exclusive(&lock_file)
+        .with_context(|| format!("failed to lock session file {}", lock_path.display()))?;
+
+    let autosave_path = options.autosave_file_path();
+    let result = encode_snapshot(snapshot, options).and_then(|encoded| {
+        let Some((bytes, _)) = encoded else {
+            return Ok(());
+        };
+        let tmp_path = write_temp_file(&autosave_path, &bytes)?;
+        fs::rename(&tmp_path, &autosave_path).with_context(|| {
+            format!(
+                "failed to move temporary autosave file {} -> {}",
+                tmp_path.display(),
+                autosave_path.display()
+            )
+        })
+    });
+
+    if let Err(err) = unlock(&lock_file) {
+        warn!(
+            "failed to unlock session file {}: {}",
+            lock_path.display(),
+            err
+        );
+    }
+    result
+}
+
+/// Loads the autosave left behind by a previous run, if there is one.
+pub fn load_autosave_snapshot(options: &SessionOptions) -> Result<Option<SessionSnapshot>> {
+    let autosave_path = options.autosave_file_path();
+    if !autosave_path.exists() {
+        return Ok(None);
+    }
+
+    let lock_path = options.lock_file_path();
+    let lock_file = open_lock_file(&lock_path)?;
+    lock_shared(&lock_file)
+        .with_context(|| format!("failed to acquire shared lock {}", lock_path.display()))?;
+
+    let result = load_snapshot_inner(&autosave_path, options);
+
+    if let Err(err) = unlock(&lock_file) {
+        warn!(
+            "failed to unlock session file {}: {}",
+            lock_path.display(),
+            err
+        );
+    }
+    Ok(result?.map(|loaded| loaded.snapshot))
+}
+
+/// Removes the autosave file, if present.
+pub fn discard_autosave(options: &SessionOptions) -> Result<()> {
+    let autosave_path = options.autosave_file_path();
+    match fs::remove_file(&autosave_path) {
+        Ok(()) => {
+            debug!("Removed autosave {}", autosave_path.display());
+            Ok(())
+        }
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(err) => Err(err)
+            .with_context(|| format!("failed to remove autosave {}", autosave_path.display())),
+    }
+}
+
+/// Records this process as the owner of the session.
+///
+/// Returns `true` when the previous owner exited without calling [`release_session`], i.e. the
+/// last shutdown was unclean.
+pub fn claim_session(options: &SessionOptions) -> Result<bool> {
+    fs::create_dir_all(&options.base_dir).with_context(|| {
+        format!(
+            "failed to create session directory {}",
+            options.base_dir.display()
+        )
+    })?;
+
+    let lock_path = options.lock_file_path();
+    let mut lock_file = open_lock_file(&lock_path)?;
+    lock_exclusive(&lock_file)
+        .with_context(|| format!("failed to lock session file {}", lock_path.display()))?;
+
+    let own_pid = std::process::id();
+    let result = read_owner(&mut lock_file).and_then(|owner| {
+        let unclean = match owner {
+            Some(pid) if pid == own_pid => false,
+            Some(pid) if process_alive(pid) => {
+                warn!(
+                    "Session {} is also in use by process {}",
+                    lock_path.display(),
+                    pid
+                );
+                false
+            }
+            Some(pid) => {
+                info!("Previous overlay (pid {}) did not shut down cleanly", pid);
+                true
+            }
+            None => false,
+        };
+        write_owner(&mut lock_file, Some(own_pid))?;
+        Ok(unclean)
+    });
+
+    if let Err(err) = unlock(&lock_file) {
+        warn!(
+            "failed to unlock session file {}: {}",
+            lock_path.display(),
+            err
+        );
+    }
+    result
+}
+
+/// Clears the ownership record written by [`claim_session`] after a clean shutdown.
+pub fn release_session(options: &SessionOptions) -> Result<()> {
+    let lock_path = options.lock_file_path();
+    if !lock_path.exists() {
+        return Ok(());
+    }
+    let mut lock_file = open_lock_file(&lock_path)?;
+    lock_exclusive(&lock_file)
+        .with_context(|| format!("failed to lock session file {}", lock_path.display()))?;
+
+    let result = read_owner(&mut lock_file).and_then(|owner| {
+        if owner == Some(std::process::id()) {
+            write_owner(&mut lock_file, None)?;
+        }
+        Ok(())
+    });
+
+    if let Err(err) = unlock(&lock_file) {
+        warn!(
+            "failed to unlock session file {}: {}",
+            lock_path.display(),
+            err
+        );
+    }
+    result
+}
+
+/// Returns the PID of another running overlay that owns the session, if any.
+pub(crate) fn live_owner(lock_file: &mut File) -> Result<Option<u32>> {
+    Ok(read_owner(lock_file)?.filter(|&pid| pid != std::process::id() && process_alive(pid)))
+}
+
+fn read_owner(file: &mut File) -> Result<Option<u32>> {
+    let mut contents = String::new();
+    file.seek(SeekFrom::Start(0))
+        .and_then(|_| file.read_to_string(&mut contents))
+        .context("failed to read session lock file")?;
+    Ok(contents.trim().parse().ok())
+}
+
+fn write_owner(file: &mut File, pid: Option<u32>) -> Result<()> {
+    file.set_len(0)
+        .and_then(|_| file.seek(SeekFrom::Start(0)))
+        .context("failed to reset session lock file")?;
+    if let Some(pid) = pid {
+        write!(file, "{pid}").context("failed to record session owner")?;
+    }
+    file.sync_all().context("failed to sync session lock file")
+}
+
+#[cfg(unix)]
+fn process_alive(pid: u32) -> bool {
+    // Never hand 0 or negative values to kill(2); those address process groups.
+    let Ok(pid) = libc::pid_t::try_from(pid) else {
+        return false;
+    };
+    if pid <= 0 {
+        return false;
+    }
+    if unsafe { libc::kill(pid, 0) } == 0 {
+        return true;
+    }
+    std::io::Error::last_os_error().raw_os_error() == Some(libc::EPERM)
+}
+
+#[cfg(not(unix))]
+fn process_alive(_pid: u32) -> bool {
+    false
+}
@@ -0,0 +1,220 @@
+//! Rotating, timestamped session backups.
+//!
+//! Every save that replaces the session file first moves the previous file to
+//! `<session>.json.bak.<UTC timestamp>`, keeping the newest `backup_retention` generations. The
+//! timestamps sort lexically, so the newest backup is always the last name in the directory.
+
+use super::autosave;
+use super::options::SessionOptions;
+use super::snapshot::{self, open_lock_file, write_temp_file};
+use super::storage::{SessionFileSummary, summarize_session_file};
+use crate::session::lock::{lock_exclusive, lock_shared, unlock};
+use crate::time_utils;
+use anyhow::{Context, Result, anyhow, bail};
+use log::{debug, warn};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+use time::OffsetDateTime;
+
+/// One backup generation as shown by `--list-session-backups`.
+#[derive(Debug, Clone)]
+pub struct SessionBackup {
+    /// 1-based position, newest first; the number passed to `--restore-session-backup`.
+    pub index: usize,
+    pub path: PathBuf,
+    pub size_bytes: u64,
+    /// When the backed-up session was saved.
+    pub modified: Option<SystemTime>,
+    /// `None` when the file is empty or could not be parsed.
+    pub summary: Option<SessionFileSummary>,
+}
+
+/// Result of restoring a backup over the session file.
+#[derive(Debug, Clone)]
+pub struct RestoreOutcome {
+    pub restored_from: PathBuf,
+    pub session_path: PathBuf,
+    /// Whether the session file that was replaced became a new backup generation.
+    pub previous_session_backed_up: bool,
+}
+
+/// Returns the backup generations of the session, newest first.
+pub fn backup_generations(options: &SessionOptions) -> Vec<PathBuf> {
+    let prefix = options.backup_generation_prefix();
+    let Ok(entries) = fs::read_dir(&options.base_dir) else {
+        return Vec::new();
+    };
+    let mut generations: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| {
+            path.is_file()
+                && path
+                    .file_name()
+                    .and_then(|name| name.to_str())
+                    .is_some_and(|name| name.starts_with(&prefix))
+        })
+        .collect();
+    generations.sort_by(|a, b| b.file_name().cmp(&a.file_name()));
+    generations
+}
+
+/// Moves the current session file into a new backup generation and prunes old ones.
+///
+/// The caller holds the exclusive session lock.
+pub(crate) fn rotate_session_file(session_path: &Path, options: &SessionOptions) -> Result<()> {
+    let backup_path = next_generation_path(options, OffsetDateTime::now_utc());
+    fs::rename(session_path, &backup_path).with_context(|| {
+        format!(
+            "failed to rotate previous session file {} -> {}",
+            session_path.display(),
+            backup_path.display()
+        )
+    })?;
+    debug!("Backed up previous session to {}", backup_path.display());
+
+    for stale in backup_generations(options)
+        .into_iter()
+        .skip(options.backup_retention)
+    {
+        if let Err(err) = fs::remove_file(&stale) {
+            warn!(
+                "Failed to prune session backup {}: {}",
+                stale.display(),
+                err
+            );
+        }
+    }
+    Ok(())
+}
+
+fn next_generation_path(options: &SessionOptions, now: OffsetDateTime) -> PathBuf {
+    let stamp = format!(
+        "{}.{:03}Z",
+        time_utils::format_with_template(now, "%Y%m%dT%H%M%S"),
+        now.millisecond()
+    );
+    let mut path = options.backup_generation_path(&stamp);
+    // Saves within the same millisecond get a counter; `…Z-1` still sorts after `…Z`.
+    let mut counter = 1;
+    while path.exists() {
+        path = options.backup_generation_path(&format!("{stamp}-{counter}"));
+        counter += 1;
+    }
+    path
+}
+
+/// Lists the session's backups, newest first, with their sizes and contents.
+pub fn list_session_backups(options: &SessionOptions) -> Result<Vec<SessionBackup>> {
+    let generations = backup_generations(options);
+    if generations.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let lock_path = options.lock_file_path();
+    let lock_file = open_lock_file(&lock_path)?;
+    lock_shared(&lock_file)
+        .with_context(|| format!("failed to acquire shared lock {}", lock_path.display()))?;
+
+    let backups = generations
+        .into_iter()
+        .enumerate()
+        .filter_map(|(position, path)| {
+            let metadata = fs::metadata(&path).ok()?;
+            let summary = match summarize_session_file(&path, options) {
+                Ok(summary) => summary,
+                Err(err) => {
+                    warn!(
+                        "Failed to read session backup {}: {:#}",
+                        path.display(),
+                        err
+                    );
+                    None
+                }
+            };
+            Some(SessionBackup {
+                index: position + 1,
+                size_bytes: metadata.len(),
+                modified: metadata.modified().ok(),
+                path,
+                summary,
+            })
+        })
+        .collect();
+
+    if let Err(err) = unlock(&lock_file) {
+        warn!(
+            "failed to unlock session file {}: {}",
+            lock_path.display(),
+            err
+        );
+    }
+    Ok(backups)
+}
+
+/// Replaces the session file with backup number `index` (1 = newest).
+///
+/// The session file being replaced is rotated into a new backup first, so a restore can itself be
+/// undone. Refuses while an overlay that owns the session is still running, since it would
+/// overwrite the restored file on exit.
+pub fn restore_session_backup(options: &SessionOptions, index: usize) -> Result<RestoreOutcome> {
+    let generations = backup_generations(options);
+    let source = index
+        .checked_sub(1)
+        .and_then(|position| generations.get(position))
+        .cloned()
+        .ok_or_else(|| match generations.len() {
+            0 => anyhow!("no session backups found"),
+            count => anyhow!("no backup #{index}; choose a number between 1 and {count}"),
+        })?;
+
+    let lock_path = options.lock_file_path();
+    let mut lock_file = open_lock_file(&lock_path)?;
+    lock_exclusive(&lock_file)
+        .with_context(|| format!("failed to lock session file {}", lock_path.display()))?;
+
+    let result = restore_locked(&mut lock_file, &source, options);
+
+    if let Err(err) = unlock(&lock_file) {
+        warn!(
+            "failed to unlock session file {}: {}",
+            lock_path.display(),
+            err
+        );
+    }
+    result
+}
+
+fn restore_locked(
+    lock_file: &mut fs::File,
+    source: &Path,
+    options: &SessionOptions,
+) -> Result<RestoreOutcome> {
+    if let Some(pid) = autosave::live_owner(lock_file)? {
+        bail!("the session is in use by a running overlay (pid {pid}); close it first");
+    }
+    snapshot::load_snapshot_inner(source, options)
+        .with_context(|| format!("backup {} is unreadable", source.display()))?;
+    let bytes =
+        fs::read(source).with_context(|| format!("failed to read backup {}", source.display()))?;
+
+    let session_path = options.session_file_path();
+    let previous_session_backed_up = session_path.exists() && options.backup_retention > 0;
+    if previous_session_backed_up {
+        rotate_session_file(&session_path, options)?;
+    }
+    let tmp_path = write_temp_file(&session_path, &bytes)?;
+    fs::rename(&tmp_path, &session_path).with_context(|| {
+        format!(
+            "failed to move temporary session file {} -> {}",
+            tmp_path.display(),
+            session_path.display()
+        )
+    })?;
+
+    Ok(RestoreOutcome {
+        restored_from: source.to_path_buf(),
+        session_path,
+        previous_session_backed_up,
+    })
+}
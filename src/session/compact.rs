@@ -0,0 +1,514 @@
+//! Compact binary encoding of session files.
+//!
+//! The compact format stores the same JSON document as the plain format, so versioning,
+//! migrations and history trimming work unchanged on either. It shrinks large boards by
+//! interning every string, colour and font descriptor into tables written once at the start of
+//! the file, and by storing point lists as zig-zag varint deltas between neighbouring points.
+//! Files start with [`MAGIC`], which lets loading pick the right decoder automatically.
+
+use anyhow::{Result, anyhow, bail};
+use serde_json::{Map, Number, Value};
+use std::collections::HashMap;
+
+/// Leading bytes of every compact session file.
+const MAGIC: &[u8; 4] = b"WSCB";
+/// Revision of the binary layout below (independent of the session file version).
+const LAYOUT_VERSION: u8 = 1;
+/// Nesting limit when decoding, so corrupt files cannot exhaust the stack.
+const MAX_DEPTH: usize = 512;
+
+const TAG_NULL: u8 = 0;
+const TAG_FALSE: u8 = 1;
+const TAG_TRUE: u8 = 2;
+const TAG_UINT: u8 = 3;
+const TAG_NEG_INT: u8 = 4;
+const TAG_F32: u8 = 5;
+const TAG_F64: u8 = 6;
+const TAG_STRING: u8 = 7;
+const TAG_ARRAY: u8 = 8;
+const TAG_OBJECT: u8 = 9;
+const TAG_COLOR: u8 = 10;
+const TAG_FONT: u8 = 11;
+const TAG_POINTS: u8 = 12;
+
+const COLOR_KEYS: [&str; 4] = ["r", "g", "b", "a"];
+const FONT_KEYS: [&str; 3] = ["family", "weight", "style"];
+
+/// Returns true when `bytes` (after any gzip layer) hold a compact session file.
+pub(crate) fn is_compact(bytes: &[u8]) -> bool {
+    bytes.starts_with(MAGIC)
+}
+
+/// Encodes a session document in the compact format.
+pub(crate) fn encode(value: &Value) -> Vec<u8> {
+    let mut encoder = Encoder::default();
+    let mut body = Vec::new();
+    encoder.value(value, &mut body);
+
+    let mut out = Vec::with_capacity(body.len() + 64);
+    out.extend_from_slice(MAGIC);
+    out.push(LAYOUT_VERSION);
+    write_varint(&mut out, encoder.strings.len() as u64);
+    for string in &encoder.strings {
+        write_varint(&mut out, string.len() as u64);
+        out.extend_from_slice(string.as_bytes());
+    }
+    write_varint(&mut out, encoder.colors.len() as u64);
+    for color in &encoder.colors {
+        for bits in color {
+            out.extend_from_slice(&bits.to_le_bytes());
+        }
+    }
+    write_varint(&mut out, encoder.fonts.len() as u64);
+    for font in &encoder.fonts {
+        for index in font {
+            write_varint(&mut out, *index as u64);
+        }
+    }
+    out.extend_from_slice(&body);
+    out
+}
+
+/// Decodes a compact session file back into its JSON document.
+pub(crate) fn decode(bytes: &[u8]) -> Result<Value> {
+    let rest = bytes
+        .strip_prefix(MAGIC)
+        .ok_or_else(|| anyhow!("not a compact session file"))?;
+    let mut reader = Reader { bytes: rest };
+    let layout = reader.byte()?;
+    if layout != LAYOUT_VERSION {
+        bail!("unsupported compact session layout {layout}");
+    }
+
+    let string_count = reader.len()?;
+    let mut strings = Vec::with_capacity(string_count.min(4096));
+    for _ in 0..string_count {
+        let len = reader.len()?;
+        let raw = reader.take(len)?;
+        let string = std::str::from_utf8(raw)
+            .map_err(|_| anyhow!("compact session contains invalid UTF-8"))?;
+        strings.push(string.to_string());
+    }
+
+    let color_count = reader.len()?;
+    let mut colors = Vec::with_capacity(color_count.min(4096));
+    for _ in 0..color_count {
+        let mut color = [0.0; 4];
+        for channel in &mut color {
+            *channel = f64::from_bits(u64::from_le_bytes(reader.array()?));
+        }
+        colors.push(color);
+    }
+
+    let font_count = reader.len()?;
+    let mut fonts = Vec::with_capacity(font_count.min(4096));
+    for _ in 0..font_count {
+        let mut font = [0usize; 3];
+        for index in &mut font {
+            *index = reader.table_index(strings.len(), "string")?;
+        }
+        fonts.push(font);
+    }
+
+    let tables = Tables {
+        strings,
+        colors,
+        fonts,
+    };
+    let value = reader.value(&tables, 0)?;
+    if !reader.bytes.is_empty() {
+        bail!("trailing data after compact session");
+    }
+    Ok(value)
+}
+
+#[derive(Default)]
+struct Encoder {
+    strings: Vec<String>,
+    string_ids: HashMap<String, usize>,
+    colors: Vec<[u64; 4]>,
+    color_ids: HashMap<[u64; 4], usize>,
+    fonts: Vec<[usize; 3]>,
+    font_ids: HashMap<[usize; 3], usize>,
+}
+
+impl Encoder {
+    fn value(&mut self, value: &Value, out: &mut Vec<u8>) {
+        match value {
+            Value::Null => out.push(TAG_NULL),
+            Value::Bool(false) => out.push(TAG_FALSE),
+            Value::Bool(true) => out.push(TAG_TRUE),
+            Value::Number(number) => write_number(number, out),
+            Value::String(string) => {
+                out.push(TAG_STRING);
+                let id = self.string(string);
+                write_varint(out, id as u64);
+            }
+            Value::Array(items) => {
+                if let Some(points) = as_points(items) {
+                    out.push(TAG_POINTS);
+                    write_varint(out, points.len() as u64);
+                    let mut previous = (0i64, 0i64);
+                    for (x, y) in points {
+                        write_varint(out, zigzag(x - previous.0));
+                        write_varint(out, zigzag(y - previous.1));
+                        previous = (x, y);
+                    }
+                    return;
+                }
+                out.push(TAG_ARRAY);
+                write_varint(out, items.len() as u64);
+                for item in items {
+                    self.value(item, out);
+                }
+            }
+            Value::Object(map) => {
+                if let Some(color) = as_color(map) {
+                    out.push(TAG_COLOR);
+                    let id = intern(&mut self.colors, &mut self.color_ids, color);
+                    write_varint(out, id as u64);
+                    return;
+                }
+                if let Some([family, weight, style]) = as_font(map) {
+                    let font = [self.string(family), self.string(weight), self.string(style)];
+                    out.push(TAG_FONT);
+                    let id = intern(&mut self.fonts, &mut self.font_ids, font);
+                    write_varint(out, id as u64);
+                    return;
+                }
+                out.push(TAG_OBJECT);
+                write_varint(out, map.len() as u64);
+                for (key, item) in map {
+                    let id = self.string(key);
+                    write_varint(out, id as u64);
+                    self.value(item, out);
+                }
+            }
+        }
+    }
+
+    fn string(&mut self, string: &str) -> usize {
+        if let Some(&id) = self.string_ids.get(string) {
+            return id;
+        }
+        let id = self.strings.len();
+        self.strings.push(string.to_string());
+        self.string_ids.insert(string.to_string(), id);
+        id
+    }
+}
+
+fn intern<T: Copy + Eq + std::hash::Hash>(
+    table: &mut Vec<T>,
+    ids: &mut HashMap<T, usize>,
+    entry: T,
+) -> usize {
+    *ids.entry(entry).or_insert_with(|| {
+        table.push(entry);
+        table.len() - 1
+    })
+}
+
+fn write_number(number: &Number, out: &mut Vec<u8>) {
+    if let Some(value) = number.as_u64() {
+        out.push(TAG_UINT);
+        write_varint(out, value);
+    } else if let Some(value) = number.as_i64() {
+        out.push(TAG_NEG_INT);
+        // Store -1 as 0, -2 as 1, ... so the varint stays short.
+        write_varint(out, !(value as u64));
+    } else {
+        let value = number.as_f64().unwrap_or(0.0);
+        let narrow = value as f32;
+        if f64::from(narrow).to_bits() == value.to_bits() {
+            out.push(TAG_F32);
+            out.extend_from_slice(&narrow.to_le_bytes());
+        } else {
+            out.push(TAG_F64);
+            out.extend_from_slice(&value.to_le_bytes());
+        }
+    }
+}
+
+/// Recognises arrays of `[x, y]` integer pairs, the JSON form of shape point lists.
+fn as_points(items: &[Value]) -> Option<Vec<(i64, i64)>> {
+    if items.is_empty() {
+        return None;
+    }
+    items
+        .iter()
+        .map(|item| match item.as_array()?.as_slice() {
+            [x, y] => Some((point_coordinate(x)?, point_coordinate(y)?)),
+            _ => None,
+        })
+        .collect()
+}
+
+fn point_coordinate(value: &Value) -> Option<i64> {
+    let value = value.as_i64()?;
+    i32::try_from(value).ok().map(i64::from)
+}
+
+fn as_color(map: &Map<String, Value>) -> Option<[u64; 4]> {
+    if map.len() != COLOR_KEYS.len() {
+        return None;
+    }
+    let mut color = [0u64; 4];
+    for (bits, key) in color.iter_mut().zip(COLOR_KEYS) {
+        let Value::Number(number) = map.get(key)? else {
+            return None;
+        };
+        // Integers would come back as floats; leave such objects to the generic path.
+        if !number.is_f64() {
+            return None;
+        }
+        *bits = number.as_f64()?.to_bits();
+    }
+    Some(color)
+}
+
+fn as_font(map: &Map<String, Value>) -> Option<[&str; 3]> {
+    if map.len() != FONT_KEYS.len() {
+        return None;
+    }
+    let field = |key| map.get(key).and_then(Value::as_str);
+    Some([field("family")?, field("weight")?, field("style")?])
+}
+
+struct Tables {
+    strings: Vec<String>,
+    colors: Vec<[f64; 4]>,
+    fonts: Vec<[usize; 3]>,
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl Reader<'_> {
+    fn take(&mut self, len: usize) -> Result<&[u8]> {
+        if len > self.bytes.len() {
+            bail!("compact session is truncated");
+        }
+        let (head, tail) = self.bytes.split_at(len);
+        self.bytes = tail;
+        Ok(head)
+    }
+
+    fn byte(&mut self) -> Result<u8> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn array<const N: usize>(&mut self) -> Result<[u8; N]> {
+        let mut out = [0u8; N];
+        out.copy_from_slice(self.take(N)?);
+        Ok(out)
+    }
+
+    fn varint(&mut self) -> Result<u64> {
+        let mut value = 0u64;
+        for shift in (0..64).step_by(7) {
+            let byte = self.byte()?;
+            value |= u64::from(byte & 0x7f) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        bail!("compact session contains an overlong varint")
+    }
+
+    /// Reads a count or table index; anything larger than the remaining input is corrupt.
+    fn len(&mut self) -> Result<usize> {
+        let value = self.varint()?;
+        usize::try_from(value)
+            .ok()
+            .filter(|&len| len <= self.bytes.len().max(1) * 8)
+            .ok_or_else(|| anyhow!("compact session length {value} is out of range"))
+    }
+
+    fn table_index(&mut self, table_len: usize, what: &str) -> Result<usize> {
+        let index = self.varint()?;
+        usize::try_from(index)
+            .ok()
+            .filter(|&index| index < table_len)
+            .ok_or_else(|| anyhow!("compact session refers to missing {what} {index}"))
+    }
+
+    fn index<'t, T>(&mut self, table: &'t [T], what: &str) -> Result<&'t T> {
+        let index = self.table_index(table.len(), what)?;
+        Ok(&table[index])
+    }
+
+    fn value(&mut self, tables: &Tables, depth: usize) -> Result<Value> {
+        if depth > MAX_DEPTH {
+            bail!("compact session is nested too deeply");
+        }
+        let value = match self.byte()? {
+            TAG_NULL => Value::Null,
+            TAG_FALSE => Value::Bool(false),
+            TAG_TRUE => Value::Bool(true),
+            TAG_UINT => Value::from(self.varint()?),
+            TAG_NEG_INT => Value::from(!self.varint()? as i64),
+            TAG_F32 => float(f64::from(f32::from_le_bytes(self.array()?)))?,
+            TAG_F64 => float(f64::from_le_bytes(self.array()?))?,
+            TAG_STRING => Value::String(self.index(&tables.strings, "string")?.clone()),
+            TAG_ARRAY => {
+                let len = self.len()?;
+                let mut items = Vec::with_capacity(len.min(4096));
+                for _ in 0..len {
+                    items.push(self.value(tables, depth + 1)?);
+                }
+                Value::Array(items)
+            }
+            TAG_OBJECT => {
+                let len = self.len()?;
+                let mut map = Map::new();
+                for _ in 0..len {
+                    let key = self.index(&tables.strings, "string")?.clone();
+                    let item = self.value(tables, depth + 1)?;
+                    map.insert(key, item);
+                }
+                Value::Object(map)
+            }
+            TAG_COLOR => {
+                let color = *self.index(&tables.colors, "color")?;
+                let mut map = Map::new();
+                for (key, channel) in COLOR_KEYS.iter().zip(color) {
+                    map.insert((*key).to_string(), float(channel)?);
+                }
+                Value::Object(map)
+            }
+            TAG_FONT => {
+                let font = *self.index(&tables.fonts, "font")?;
+                let mut map = Map::new();
+                for (key, index) in FONT_KEYS.iter().zip(font) {
+                    map.insert(
+                        (*key).to_string(),
+                        Value::String(tables.strings[index].clone()),
+                    );
+                }
+                Value::Object(map)
+            }
+            TAG_POINTS => {
+                let len = self.len()?;
+                let mut points = Vec::with_capacity(len.min(4096));
+                let mut previous = (0i64, 0i64);
+                for _ in 0..len {
+                    let x = previous.0.wrapping_add(unzigzag(self.varint()?));
+                    let y = previous.1.wrapping_add(unzigzag(self.varint()?));
+                    points.push(Value::Array(vec![Value::from(x), Value::from(y)]));
+                    previous = (x, y);
+                }
+                Value::Array(points)
+            }
+            tag => bail!("compact session contains unknown tag {tag}"),
+        };
+        Ok(value)
+    }
+}
+
+fn float(value: f64) -> Result<Value> {
+    Number::from_f64(value)
+        .map(Value::Number)
+        .ok_or_else(|| anyhow!("compact session contains a non-finite number"))
+}
+
+fn write_varint(out: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        out.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
+fn zigzag(value: i64) -> u64 {
+    ((value << 1) ^ (value >> 63)) as u64
+}
+
+fn unzigzag(value: u64) -> i64 {
+    ((value >> 1) as i64) ^ -((value & 1) as i64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn compact_roundtrips_session_documents() {
+        let red = json!({ "r": 1.0, "g": 0.0, "b": 0.0, "a": 0.35 });
+        let font = json!({ "family": "Sans", "weight": "bold", "style": "normal" });
+        let doc = json!({
+            "version": 4,
+            "active_mode": "transparent",
+            "transparent": {
+                "pages": [{
+                    "shapes": [
+                        { "id": 1, "locked": false, "created_at": 1_759_220_100_000u64, "shape": {
+                            "Freehand": {
+                                "points": [[10, 20], [12, 19], [-5, 40], [2_147_483_647, -2_147_483_648]],
+                                "color": red,
+                                "thick": 3.0,
+                                "per_point_widths": [1.5, 2.25, 0.1, 4.0],
+                            }
+                        }},
+                        { "id": 2, "locked": true, "created_at": 1, "shape": {
+                            "Text": { "x": -4, "y": 8, "text": "Ünïcode ✓", "color": red,
+                                      "size": 32.0, "font_descriptor": font,
+                                      "background_enabled": true }
+                        }},
+                    ],
+                    "undo_stack": [{ "kind": "create", "shapes": [[0, null]] }],
+                }],
+                "active_page": 0,
+            },
+            "tool_state": { "board_previous_color": null, "current_color": red, "eraser_kind": "Circle" },
+        });
+
+        let bytes = encode(&doc);
+        assert!(is_compact(&bytes));
+        assert_eq!(decode(&bytes).unwrap(), doc);
+    }
+
+    #[test]
+    fn colors_fonts_and_points_are_compacted() {
+        let stroke = json!({
+            "points": (0..100).map(|i| json!([1000 + i, 500 - i])).collect::<Vec<_>>(),
+            "color": { "r": 0.2, "g": 0.4, "b": 0.6, "a": 1.0 },
+            "font": { "family": "JetBrains Mono", "weight": "normal", "style": "italic" },
+        });
+        let doc = Value::Array(vec![stroke; 50]);
+        let compact = encode(&doc);
+        let json = serde_json::to_vec(&doc).unwrap();
+        assert!(
+            compact.len() * 4 < json.len(),
+            "{} vs {}",
+            compact.len(),
+            json.len()
+        );
+        assert_eq!(decode(&compact).unwrap(), doc);
+    }
+
+    #[test]
+    fn corrupt_compact_data_is_rejected() {
+        let bytes = encode(&json!({ "shapes": [[1, 2], [3, 4]], "name": "x" }));
+        for len in 0..bytes.len() {
+            assert!(decode(&bytes[..len]).is_err(), "truncated at {len}");
+        }
+        assert!(decode(b"{\"version\": 4}").is_err());
+
+        let mut unknown_tag = MAGIC.to_vec();
+        unknown_tag.extend_from_slice(&[LAYOUT_VERSION, 0, 0, 0, 0xff]);
+        assert!(decode(&unknown_tag).is_err());
+
+        let mut missing_string = MAGIC.to_vec();
+        missing_string.extend_from_slice(&[LAYOUT_VERSION, 0, 0, 0, TAG_STRING, 0]);
+        assert!(decode(&missing_string).is_err());
+
+        let mut nested = MAGIC.to_vec();
+        nested.extend_from_slice(&[LAYOUT_VERSION, 0, 0, 0]);
+        nested.extend(std::iter::repeat_n([TAG_ARRAY, 1], MAX_DEPTH + 2).flatten());
+        nested.push(TAG_NULL);
+        assert!(decode(&nested).is_err());
+    }
+}
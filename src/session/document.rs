@@ -0,0 +1,180 @@
+//! Standalone `.wayscriber` annotation documents.
+//!
+//! A document is the versioned session file format written on request to a file of the user's
+//! choosing: all three boards with every page and their undo history, the tool state, and
+//! optionally the frozen screenshot the drawings were made over. Unlike the session file it is
+//! not tied to a display or profile and is never rotated or autosaved, so it can be archived or
+//! handed to someone else and opened with `wayscriber --open`.
+
+use super::options::{CompressionMode, SessionOptions};
+use super::snapshot::{self, SessionSnapshot, write_temp_file};
+use anyhow::{Context, Result, anyhow};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// File extension used for documents.
+pub const DOCUMENT_EXTENSION: &str = "wayscriber";
+
+/// Screenshot embedded in a document as the background of the transparent board.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DocumentBackground {
+    pub width: u32,
+    pub height: u32,
+    /// PNG-encoded image; stored as base64 inside the JSON.
+    #[serde(with = "base64_bytes")]
+    pub png: Vec<u8>,
+}
+
+/// Contents of a `.wayscriber` document.
+#[derive(Debug, Clone)]
+pub struct SessionDocument {
+    pub snapshot: SessionSnapshot,
+    pub background: Option<DocumentBackground>,
+}
+
+/// Session options that capture and restore everything a document holds.
+///
+/// Use them with [`snapshot_from_input`](super::snapshot_from_input) and
+/// [`apply_snapshot`](super::apply_snapshot) so documents ignore the persistence settings of the
+/// regular session.
+pub fn document_options(max_shapes_per_frame: usize) -> SessionOptions {
+    let mut options = SessionOptions::new(PathBuf::new(), "document");
+    options.persist_transparent = true;
+    options.persist_whiteboard = true;
+    options.persist_blackboard = true;
+    options.persist_history = true;
+    options.restore_tool_state = true;
+    options.max_shapes_per_frame = max_shapes_per_frame;
+    options.max_persisted_undo_depth = None;
+    options.max_file_size_bytes = u64::MAX;
+    options.compression = CompressionMode::On;
+    options
+}
+
+/// Writes `document` to `path`, replacing any existing file.
+pub fn save_document(path: &Path, document: &SessionDocument) -> Result<()> {
+    let bytes = snapshot::encode_document(&document.snapshot, document.background.as_ref())?;
+    let tmp_path = write_temp_file(path, &bytes)?;
+    fs::rename(&tmp_path, path).with_context(|| {
+        format!(
+            "failed to move temporary document {} -> {}",
+            tmp_path.display(),
+            path.display()
+        )
+    })
+}
+
+/// Reads the document at `path`.
+pub fn load_document(path: &Path, max_shapes_per_frame: usize) -> Result<SessionDocument> {
+    let options = document_options(max_shapes_per_frame);
+    let loaded = snapshot::load_snapshot_inner(path, &options)
+        .with_context(|| format!("failed to read document {}", path.display()))?
+        .ok_or_else(|| {
+            anyhow!(
+                "{} is empty or was written by a newer version of wayscriber",
+                path.display()
+            )
+        })?;
+    Ok(SessionDocument {
+        snapshot: loaded.snapshot,
+        background: loaded.background,
+    })
+}
+
+/// Returns the most recently modified document in `dir`.
+pub fn latest_document(dir: &Path) -> Option<PathBuf> {
+    fs::read_dir(dir)
+        .ok()?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.is_file()
+                && path.extension().and_then(|ext| ext.to_str()) == Some(DOCUMENT_EXTENSION)
+        })
+        .filter_map(|path| Some((fs::metadata(&path).ok()?.modified().ok()?, path)))
+        .max_by(|a, b| a.0.cmp(&b.0))
+        .map(|(_, path)| path)
+}
+
+/// Standard-alphabet base64 with padding, enough to embed PNG bytes in JSON.
+mod base64_bytes {
+    use serde::{Deserialize, Deserializer, Serializer, de::Error};
+
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    pub fn serialize<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&encode(bytes))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        let text = String::deserialize(deserializer)?;
+        decode(&text).ok_or_else(|| D::Error::custom("invalid base64 data"))
+    }
+
+    pub(super) fn encode(bytes: &[u8]) -> String {
+        let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+        for chunk in bytes.chunks(3) {
+            let b = [
+                chunk[0],
+                chunk.get(1).copied().unwrap_or(0),
+                chunk.get(2).copied().unwrap_or(0),
+            ];
+            let n = (u32::from(b[0]) << 16) | (u32::from(b[1]) << 8) | u32::from(b[2]);
+            for i in 0..4 {
+                if i <= chunk.len() {
+                    out.push(ALPHABET[((n >> (18 - 6 * i)) & 0x3f) as usize] as char);
+                } else {
+                    out.push('=');
+                }
+            }
+        }
+        out
+    }
+
+    pub(super) fn decode(text: &str) -> Option<Vec<u8>> {
+        let text = text.as_bytes();
+        if !text.len().is_multiple_of(4) {
+            return None;
+        }
+        let mut out = Vec::with_capacity(text.len() / 4 * 3);
+        for chunk in text.chunks(4) {
+            let padding = chunk.iter().rev().take_while(|&&c| c == b'=').count();
+            if padding > 2 {
+                return None;
+            }
+            let mut n = 0u32;
+            for &c in &chunk[..4 - padding] {
+                let value = ALPHABET.iter().position(|&a| a == c)? as u32;
+                n = (n << 6) | value;
+            }
+            n <<= 6 * padding as u32;
+            let decoded = [(n >> 16) as u8, (n >> 8) as u8, n as u8];
+            out.extend_from_slice(&decoded[..3 - padding]);
+        }
+        Some(out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::base64_bytes::{decode, encode};
+
+    #[test]
+    fn base64_roundtrips_every_padding_length() {
+        assert_eq!(encode(b""), "");
+        assert_eq!(encode(b"f"), "Zg==");
+        assert_eq!(encode(b"fo"), "Zm8=");
+        assert_eq!(encode(b"foo"), "Zm9v");
+        assert_eq!(encode(b"foobar"), "Zm9vYmFy");
+
+        let bytes: Vec<u8> = (0..=255).collect();
+        for len in 0..8 {
+            let slice = &bytes[..bytes.len() - len];
+            assert_eq!(decode(&encode(slice)).as_deref(), Some(slice));
+        }
+        assert!(decode("Zg=").is_none());
+        assert!(decode("Z===").is_none());
+        assert!(decode("Zm9*").is_none());
+    }
+}
@@ -0,0 +1,192 @@
+//! Step-by-step upgrades of older session file formats.
+//!
+//! Every change to the on-disk format bumps the session file version and registers a migration
+//! here that rewrites the JSON of the previous version into the new shape. Loading runs the
+//! registered steps in order until the payload reaches the current version, so the typed
+//! deserializers only ever see current files.
+
+use anyhow::{Result, anyhow, bail};
+use serde_json::{Map, Value, json};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Version assumed for files written before the `version` field existed.
+pub(crate) const LEGACY_VERSION: u32 = 1;
+
+const BOARD_KEYS: [&str; 3] = ["transparent", "whiteboard", "blackboard"];
+
+/// A registered upgrade from one session file version to the next.
+struct Migration {
+    from: u32,
+    description: &'static str,
+    apply: fn(&mut Map<String, Value>),
+}
+
+/// Registry of all known upgrades, ordered by source version.
+const MIGRATIONS: &[Migration] = &[
+    Migration {
+        from: 1,
+        description: "give every shape an id, creation time and lock flag",
+        apply: add_shape_metadata,
+    },
+    Migration {
+        from: 2,
+        description: "store eraser size and shape in the tool state",
+        apply: add_eraser_tool_state,
+    },
+    Migration {
+        from: 3,
+        description: "store each board as a list of pages",
+        apply: wrap_boards_in_pages,
+    },
+    Migration {
+        from: 4,
+        description: "allow redaction shapes",
+        apply: allow_redactions,
+    },
+];
+
+/// Describes one migration applied to (or pending for) a session file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MigrationStep {
+    pub from: u32,
+    pub to: u32,
+    pub description: &'static str,
+}
+
+/// Returns the version recorded in a parsed session file.
+pub(crate) fn file_version(doc: &Value) -> u32 {
+    doc.get("version")
+        .and_then(Value::as_u64)
+        .map_or(LEGACY_VERSION, |version| version as u32)
+}
+
+/// Upgrades `doc` in place to `target`, returning the steps that were applied.
+///
+/// Files that already are at `target` or newer are left untouched.
+pub(crate) fn migrate(doc: &mut Value, target: u32) -> Result<Vec<MigrationStep>> {
+    let mut version = file_version(doc);
+    let mut applied = Vec::new();
+    if version >= target {
+        return Ok(applied);
+    }
+
+    let obj = doc
+        .as_object_mut()
+        .ok_or_else(|| anyhow!("session file is not a JSON object"))?;
+    while version < target {
+        let Some(migration) = MIGRATIONS.iter().find(|m| m.from == version) else {
+            bail!("no migration registered for session file version {version}");
+        };
+        (migration.apply)(obj);
+        version += 1;
+        obj.insert("version".to_string(), json!(version));
+        applied.push(MigrationStep {
+            from: migration.from,
+            to: version,
+            description: migration.description,
+        });
+    }
+    Ok(applied)
+}
+
+/// Calls `f` for every frame object stored in the boards of a session file.
+fn for_each_frame(doc: &mut Map<String, Value>, mut f: impl FnMut(&mut Map<String, Value>)) {
+    for key in BOARD_KEYS {
+        let Some(Value::Object(board)) = doc.get_mut(key) else {
+            continue;
+        };
+        if let Some(Value::Array(pages)) = board.get_mut("pages") {
+            for page in pages {
+                if let Value::Object(frame) = page {
+                    f(frame);
+                }
+            }
+        } else {
+            f(board);
+        }
+    }
+}
+
+/// Version 1 stored bare shapes; version 2 wraps them with their metadata.
+fn add_shape_metadata(doc: &mut Map<String, Value>) {
+    let created_at = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|dur| dur.as_millis() as u64)
+        .unwrap_or(0);
+    for_each_frame(doc, |frame| {
+        let Some(Value::Array(shapes)) = frame.get_mut("shapes") else {
+            return;
+        };
+        let mut next_id = shapes
+            .iter()
+            .filter_map(|entry| entry.get("id").and_then(Value::as_u64))
+            .max()
+            .unwrap_or(0)
+            + 1;
+        for entry in shapes.iter_mut() {
+            if entry.get("shape").is_some() {
+                continue;
+            }
+            let shape = entry.take();
+            *entry = json!({
+                "id": next_id,
+                "shape": shape,
+                "created_at": created_at,
+                "locked": false,
+            });
+            next_id += 1;
+        }
+    });
+}
+
+/// Version 3 added the eraser settings to the restored tool state.
+fn add_eraser_tool_state(doc: &mut Map<String, Value>) {
+    if let Some(Value::Object(tool_state)) = doc.get_mut("tool_state") {
+        tool_state
+            .entry("eraser_size")
+            .or_insert_with(|| json!(12.0));
+        tool_state
+            .entry("eraser_kind")
+            .or_insert_with(|| json!("Circle"));
+    }
+}
+
+/// Version 4 replaced the single frame per board with a list of pages.
+fn wrap_boards_in_pages(doc: &mut Map<String, Value>) {
+    for key in BOARD_KEYS {
+        let Some(board) = doc.get_mut(key) else {
+            continue;
+        };
+        if board.is_object() && board.get("pages").is_none() {
+            let frame = board.take();
+            *board = json!({ "pages": [frame], "active_page": 0 });
+        }
+    }
+}
+
+/// Version 5 added redaction shapes; older files contain none, so there is nothing to rewrite.
+fn allow_redactions(_doc: &mut Map<String, Value>) {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn registry_covers_every_version_up_to_current() {
+        for (index, migration) in MIGRATIONS.iter().enumerate() {
+            assert_eq!(migration.from, LEGACY_VERSION + index as u32);
+        }
+        assert_eq!(
+            LEGACY_VERSION + MIGRATIONS.len() as u32,
+            super::super::snapshot::CURRENT_VERSION
+        );
+    }
+
+    #[test]
+    fn migrate_leaves_current_and_newer_files_alone() {
+        let mut doc = json!({ "version": 9, "transparent": { "shapes": [] } });
+        let before = doc.clone();
+        assert!(migrate(&mut doc, 4).unwrap().is_empty());
+        assert_eq!(doc, before);
+    }
+}
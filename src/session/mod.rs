@@ -1,25 +1,60 @@
 //! Session persistence (save/restore) support.
 //!
-//! Converts in-memory drawing state into a serialised representation, writes it
-//! to disk with locking, optional compression, and backup rotation, and restores
-//! the state on startup when requested.
+//! Converts in-memory drawing state into a serialised representation (JSON or a
+//! compact binary encoding), writes it to disk with locking, optional compression,
+//! and backup rotation, and restores the state on startup when requested. While the
+//! overlay runs, autosave keeps a crash-recovery copy up to date. Named profiles
+//! (`--session <name>`) keep separate sets of session files, and `.wayscriber`
+//! documents save and open annotations explicitly. Files written by older versions
+//! are migrated to the current format on load, keeping the original as a backup.
 
+mod autosave;
+mod backups;
+mod compact;
+mod document;
 mod lock;
+mod migrations;
 mod options;
+mod profiles;
 mod snapshot;
 mod storage;
 
+#[allow(unused_imports)]
+pub use autosave::{
+    Autosaver, claim_session, discard_autosave, load_autosave_snapshot, release_session,
+    save_autosave_snapshot,
+};
+#[allow(unused_imports)]
+pub use backups::{
+    RestoreOutcome, SessionBackup, backup_generations, list_session_backups, restore_session_backup,
+};
+#[allow(unused_imports)]
+pub use document::{
+    DOCUMENT_EXTENSION, DocumentBackground, SessionDocument, document_options, latest_document,
+    load_document, save_document,
+};
+#[allow(unused_imports)]
+pub use migrations::MigrationStep;
 #[allow(unused_imports)]
 pub use options::{
-    CompressionMode, DEFAULT_AUTO_COMPRESS_THRESHOLD_BYTES, SessionOptions, options_from_config,
+    CompressionMode, DEFAULT_AUTO_COMPRESS_THRESHOLD_BYTES, SessionEncoding, SessionOptions,
+    options_from_config,
+};
+#[allow(unused_imports)]
+pub use profiles::{
+    SessionProfile, apply_profile, delete_profile, duplicate_profile, list_profiles, profiles_dir,
+    rename_profile, validate_profile_name,
 };
 #[allow(unused_imports)]
 pub use snapshot::{
     SessionSnapshot, ToolStateSnapshot, apply_snapshot, load_snapshot, save_snapshot,
-    snapshot_from_input,
+    snapshot_from_input, snapshot_from_value, snapshot_to_value,
 };
 #[allow(unused_imports)]
-pub use storage::{ClearOutcome, FrameCounts, SessionInspection, clear_session, inspect_session};
+pub use storage::{
+    ClearOutcome, FrameCounts, SessionFileSummary, SessionInspection, clear_session,
+    inspect_session,
+};
 
 #[cfg(test)]
 mod tests;
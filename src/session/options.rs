@@ -1,9 +1,10 @@
-use crate::config::{SessionCompression, SessionConfig, SessionStorageMode};
+use crate::config::{SessionCompression, SessionConfig, SessionFormat, SessionStorageMode};
 use crate::paths::{data_dir, expand_tilde};
 use anyhow::{Result, anyhow};
 use std::{
     env,
     path::{Path, PathBuf},
+    time::Duration,
 };
 
 pub const DEFAULT_AUTO_COMPRESS_THRESHOLD_BYTES: u64 = 100 * 1024; // 100 KiB
@@ -19,6 +20,15 @@ pub enum CompressionMode {
     Auto,
 }
 
+/// Encoding used when writing session files.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SessionEncoding {
+    /// Pretty-printed JSON.
+    Json,
+    /// Compact binary encoding (see `session::compact`).
+    Compact,
+}
+
 /// Runtime options derived from configuration for session persistence.
 #[derive(Debug, Clone)]
 pub struct SessionOptions {
@@ -32,11 +42,18 @@ pub struct SessionOptions {
     pub max_persisted_undo_depth: Option<usize>,
     pub max_file_size_bytes: u64,
     pub compression: CompressionMode,
+    pub encoding: SessionEncoding,
     pub auto_compress_threshold_bytes: u64,
     pub display_id: String,
     pub backup_retention: usize,
     pub output_identity: Option<String>,
     pub per_output: bool,
+    /// Autosave once unsaved edits are this old (`None` disables the timer).
+    pub autosave_interval: Option<Duration>,
+    /// Autosave after this many undoable actions (0 disables the counter).
+    pub autosave_after_actions: usize,
+    /// Named profile selected with `--session`; `base_dir` then points into its directory.
+    pub profile: Option<String>,
 }
 
 impl SessionOptions {
@@ -55,11 +72,15 @@ impl SessionOptions {
             max_persisted_undo_depth: None,
             max_file_size_bytes: 10 * 1024 * 1024,
             compression: CompressionMode::Auto,
+            encoding: SessionEncoding::Json,
             auto_compress_threshold_bytes: DEFAULT_AUTO_COMPRESS_THRESHOLD_BYTES,
             display_id,
             backup_retention: 1,
             output_identity: None,
             per_output: true,
+            autosave_interval: None,
+            autosave_after_actions: 0,
+            profile: None,
         }
     }
 
@@ -67,6 +88,11 @@ impl SessionOptions {
         self.persist_transparent || self.persist_whiteboard || self.persist_blackboard
     }
 
+    /// Returns true when autosave should run for this session.
+    pub fn autosave_enabled(&self) -> bool {
+        self.any_enabled() && (self.autosave_interval.is_some() || self.autosave_after_actions > 0)
+    }
+
     pub fn effective_history_limit(&self, runtime_limit: usize) -> usize {
         if !self.persist_history {
             return 0;
@@ -87,6 +113,34 @@ impl SessionOptions {
             .join(format!("{}.json.bak", self.session_file_stem()))
     }
 
+    /// Timestamped backup generation created when a save replaces the session file.
+    pub fn backup_generation_path(&self, stamp: &str) -> PathBuf {
+        self.base_dir
+            .join(format!("{}{}", self.backup_generation_prefix(), stamp))
+    }
+
+    /// File-name prefix shared by every backup generation of this session.
+    pub fn backup_generation_prefix(&self) -> String {
+        format!("{}.json.bak.", self.session_file_stem())
+    }
+
+    /// Copy of a session file kept before it was upgraded from `version` to the current format.
+    pub fn migration_backup_path(&self, version: u32) -> PathBuf {
+        self.base_dir
+            .join(format!("{}{}.bak", self.migration_backup_prefix(), version))
+    }
+
+    /// File-name prefix shared by every pre-migration backup of this session.
+    pub fn migration_backup_prefix(&self) -> String {
+        format!("{}.json.v", self.session_file_stem())
+    }
+
+    /// Crash-recovery copy written by autosave and removed again on clean shutdown.
+    pub fn autosave_file_path(&self) -> PathBuf {
+        self.base_dir
+            .join(format!("{}.json.autosave", self.session_file_stem()))
+    }
+
     pub fn lock_file_path(&self) -> PathBuf {
         self.base_dir
             .join(format!("{}.lock", self.session_file_stem()))
@@ -175,8 +229,15 @@ pub fn options_from_config(
         SessionCompression::On => CompressionMode::On,
         SessionCompression::Off => CompressionMode::Off,
     };
+    options.encoding = match session_cfg.format {
+        SessionFormat::Json => SessionEncoding::Json,
+        SessionFormat::Compact => SessionEncoding::Compact,
+    };
     options.backup_retention = session_cfg.backup_retention;
     options.per_output = session_cfg.per_output;
+    options.autosave_interval = (session_cfg.autosave_interval_secs > 0)
+        .then(|| Duration::from_secs(session_cfg.autosave_interval_secs));
+    options.autosave_after_actions = session_cfg.autosave_after_actions;
 
     Ok(options)
 }
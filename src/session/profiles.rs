@@ -0,0 +1,187 @@
+//! Named session profiles.
+//!
+//! A profile is a directory under `<base_dir>/profiles/<name>` holding its own session files,
+//! backups and lock, laid out exactly like the default session. Pointing
+//! [`SessionOptions::base_dir`] at that directory is all it takes to load and save a profile.
+
+use super::autosave;
+use super::options::SessionOptions;
+use super::snapshot::open_lock_file;
+use anyhow::{Context, Result, anyhow, bail};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+const PROFILES_DIR: &str = "profiles";
+const MAX_PROFILE_NAME_LEN: usize = 64;
+
+/// A named profile found on disk.
+#[derive(Debug, Clone)]
+pub struct SessionProfile {
+    pub name: String,
+    pub path: PathBuf,
+    /// Most recent save of any session file in the profile.
+    pub last_saved: Option<SystemTime>,
+}
+
+/// Checks that `name` can be used as a profile (and directory) name.
+pub fn validate_profile_name(name: &str) -> Result<()> {
+    if name.is_empty() || name.len() > MAX_PROFILE_NAME_LEN {
+        bail!("session name must be 1 to {MAX_PROFILE_NAME_LEN} characters long");
+    }
+    if name.starts_with('.') {
+        bail!("session name '{name}' must not start with '.'");
+    }
+    if let Some(bad) = name
+        .chars()
+        .find(|c| !(c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.')))
+    {
+        bail!("session name '{name}' contains '{bad}'; use letters, digits, '-', '_' or '.'");
+    }
+    Ok(())
+}
+
+/// Switches `options` to the named profile.
+pub fn apply_profile(options: &mut SessionOptions, name: &str) -> Result<()> {
+    validate_profile_name(name)?;
+    options.base_dir = profiles_dir(options).join(name);
+    options.profile = Some(name.to_string());
+    Ok(())
+}
+
+/// Directory holding every profile for the storage location of `options`.
+pub fn profiles_dir(options: &SessionOptions) -> PathBuf {
+    match (&options.profile, options.base_dir.parent()) {
+        (Some(_), Some(parent)) => parent.to_path_buf(),
+        _ => options.base_dir.join(PROFILES_DIR),
+    }
+}
+
+/// Lists the profiles stored next to `options`, sorted by name.
+pub fn list_profiles(options: &SessionOptions) -> Result<Vec<SessionProfile>> {
+    let dir = profiles_dir(options);
+    let entries = match fs::read_dir(&dir) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => {
+            return Err(err).with_context(|| format!("failed to read {}", dir.display()));
+        }
+    };
+
+    let mut profiles = Vec::new();
+    for entry in entries {
+        let path = entry?.path();
+        let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+            continue;
+        };
+        if !path.is_dir() || validate_profile_name(name).is_err() {
+            continue;
+        }
+        profiles.push(SessionProfile {
+            name: name.to_string(),
+            last_saved: last_saved(&path),
+            path,
+        });
+    }
+    profiles.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(profiles)
+}
+
+/// Renames profile `from` to `to`.
+pub fn rename_profile(options: &SessionOptions, from: &str, to: &str) -> Result<()> {
+    let source = existing_profile_dir(options, from)?;
+    let target = new_profile_dir(options, to)?;
+    ensure_not_in_use(&source, from)?;
+    fs::rename(&source, &target).with_context(|| {
+        format!(
+            "failed to rename {} -> {}",
+            source.display(),
+            target.display()
+        )
+    })
+}
+
+/// Copies profile `from` into a new profile `to`.
+///
+/// Session files and backups are copied; locks and autosaves stay with the original.
+pub fn duplicate_profile(options: &SessionOptions, from: &str, to: &str) -> Result<()> {
+    let source = existing_profile_dir(options, from)?;
+    let target = new_profile_dir(options, to)?;
+    fs::create_dir_all(&target)
+        .with_context(|| format!("failed to create {}", target.display()))?;
+
+    copy_profile_files(&source, &target).inspect_err(|_| {
+        let _ = fs::remove_dir_all(&target);
+    })
+}
+
+/// Deletes profile `name` with all of its session files and backups.
+pub fn delete_profile(options: &SessionOptions, name: &str) -> Result<()> {
+    let dir = existing_profile_dir(options, name)?;
+    ensure_not_in_use(&dir, name)?;
+    fs::remove_dir_all(&dir).with_context(|| format!("failed to delete {}", dir.display()))
+}
+
+fn existing_profile_dir(options: &SessionOptions, name: &str) -> Result<PathBuf> {
+    validate_profile_name(name)?;
+    let dir = profiles_dir(options).join(name);
+    if !dir.is_dir() {
+        return Err(anyhow!("no session named '{name}'"));
+    }
+    Ok(dir)
+}
+
+fn new_profile_dir(options: &SessionOptions, name: &str) -> Result<PathBuf> {
+    validate_profile_name(name)?;
+    let dir = profiles_dir(options).join(name);
+    if dir.exists() {
+        bail!("a session named '{name}' already exists");
+    }
+    Ok(dir)
+}
+
+/// Fails when a running overlay has claimed any session file in `dir`.
+fn ensure_not_in_use(dir: &Path, name: &str) -> Result<()> {
+    for entry in fs::read_dir(dir).with_context(|| format!("failed to read {}", dir.display()))? {
+        let path = entry?.path();
+        if path.extension().and_then(|ext| ext.to_str()) != Some("lock") {
+            continue;
+        }
+        let mut lock_file = open_lock_file(&path)?;
+        if let Some(pid) = autosave::live_owner(&mut lock_file)? {
+            bail!("session '{name}' is open in a running overlay (pid {pid}); close it first");
+        }
+    }
+    Ok(())
+}
+
+fn copy_profile_files(source: &Path, target: &Path) -> Result<()> {
+    for entry in
+        fs::read_dir(source).with_context(|| format!("failed to read {}", source.display()))?
+    {
+        let path = entry?.path();
+        let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+            continue;
+        };
+        if !path.is_file() || name.ends_with(".lock") || name.ends_with(".json.autosave") {
+            continue;
+        }
+        fs::copy(&path, target.join(name))
+            .with_context(|| format!("failed to copy {}", path.display()))?;
+    }
+    Ok(())
+}
+
+fn last_saved(dir: &Path) -> Option<SystemTime> {
+    fs::read_dir(dir)
+        .ok()?
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| {
+            entry
+                .file_name()
+                .to_str()
+                .is_some_and(|name| name.ends_with(".json"))
+        })
+        .filter_map(|entry| entry.metadata().ok()?.modified().ok())
+        .max()
+}
@@ -1,6 +1,10 @@
-use super::options::{CompressionMode, SessionOptions};
+use super::backups;
+use super::compact;
+use super::document::DocumentBackground;
+use super::migrations::{self, LEGACY_VERSION, MigrationStep};
+use super::options::{CompressionMode, SessionEncoding, SessionOptions};
 use crate::draw::frame::{MAX_COMPOUND_DEPTH, ShapeId};
-use crate::draw::{Color, EraserKind, Frame};
+use crate::draw::{BoardPages, Color, EraserKind, Frame};
 use crate::input::{
     InputState,
     board_mode::BoardMode,
@@ -8,7 +12,7 @@ use crate::input::{
 };
 use crate::session::lock::{lock_exclusive, lock_shared, unlock};
 use crate::time_utils::now_rfc3339;
-use anyhow::{Context, Result};
+use anyhow::{Context, Result, bail};
 use flate2::{Compression, bufread::GzDecoder, write::GzEncoder};
 use log::{debug, info, warn};
 use serde::{Deserialize, Serialize};
@@ -19,28 +23,37 @@ use std::io::{Read, Write};
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
 
-const CURRENT_VERSION: u32 = 3;
+pub(super) const CURRENT_VERSION: u32 = 5;
 
 /// Captured state suitable for serialisation or restoration.
 #[derive(Debug, Clone)]
 pub struct SessionSnapshot {
     pub active_mode: BoardMode,
-    pub transparent: Option<Frame>,
-    pub whiteboard: Option<Frame>,
-    pub blackboard: Option<Frame>,
+    pub transparent: Option<BoardPages>,
+    pub whiteboard: Option<BoardPages>,
+    pub blackboard: Option<BoardPages>,
     pub tool_state: Option<ToolStateSnapshot>,
 }
 
 impl SessionSnapshot {
     fn is_empty(&self) -> bool {
-        let empty_frame = |frame: &Option<Frame>| {
-            frame
+        let empty_board = |board: &Option<BoardPages>| {
+            board
                 .as_ref()
                 .is_none_or(|data| !data.has_persistable_data())
         };
-        empty_frame(&self.transparent)
-            && empty_frame(&self.whiteboard)
-            && empty_frame(&self.blackboard)
+        empty_board(&self.transparent)
+            && empty_board(&self.whiteboard)
+            && empty_board(&self.blackboard)
+    }
+
+    /// Returns the stored pages for the requested board, if any.
+    pub fn board(&self, mode: BoardMode) -> Option<&BoardPages> {
+        match mode {
+            BoardMode::Transparent => self.transparent.as_ref(),
+            BoardMode::Whiteboard => self.whiteboard.as_ref(),
+            BoardMode::Blackboard => self.blackboard.as_ref(),
+        }
     }
 }
 
@@ -93,19 +106,79 @@ struct SessionFile {
     last_modified: String,
     active_mode: String,
     #[serde(default)]
-    transparent: Option<Frame>,
+    transparent: Option<BoardFile>,
     #[serde(default)]
-    whiteboard: Option<Frame>,
+    whiteboard: Option<BoardFile>,
     #[serde(default)]
-    blackboard: Option<Frame>,
+    blackboard: Option<BoardFile>,
     #[serde(default)]
     tool_state: Option<ToolStateSnapshot>,
+    /// Frozen screenshot embedded by `.wayscriber` documents; session files never carry one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    background: Option<DocumentBackground>,
+}
+
+impl SessionFile {
+    fn new(snapshot: &SessionSnapshot, background: Option<&DocumentBackground>) -> Self {
+        Self {
+            version: CURRENT_VERSION,
+            last_modified: now_rfc3339(),
+            active_mode: board_mode_to_str(snapshot.active_mode).to_string(),
+            transparent: snapshot.transparent.as_ref().map(BoardFile::from_pages),
+            whiteboard: snapshot.whiteboard.as_ref().map(BoardFile::from_pages),
+            blackboard: snapshot.blackboard.as_ref().map(BoardFile::from_pages),
+            tool_state: snapshot.tool_state.clone(),
+            background: background.cloned(),
+        }
+    }
+
+    /// Splits the file into the snapshot it describes and the document background, if any.
+    fn into_snapshot(self) -> (SessionSnapshot, Option<DocumentBackground>) {
+        let active_mode = BoardMode::from_str(&self.active_mode).unwrap_or(BoardMode::Transparent);
+        let snapshot = SessionSnapshot {
+            active_mode,
+            transparent: self.transparent.map(BoardFile::into_pages),
+            whiteboard: self.whiteboard.map(BoardFile::into_pages),
+            blackboard: self.blackboard.map(BoardFile::into_pages),
+            tool_state: self.tool_state,
+        };
+        (snapshot, self.background)
+    }
+}
+
+/// On-disk representation of a board: its pages and which one is active.
+///
+/// Files older than version 4 held a single frame per board; the migrations wrap those in a
+/// one-page list before this type sees them.
+#[derive(Debug, Serialize, Deserialize)]
+struct BoardFile {
+    pages: Vec<Frame>,
+    #[serde(default)]
+    active_page: usize,
+}
+
+impl BoardFile {
+    fn from_pages(board: &BoardPages) -> Self {
+        Self {
+            pages: board.pages().to_vec(),
+            active_page: board.active_index(),
+        }
+    }
+
+    fn into_pages(self) -> BoardPages {
+        BoardPages::from_pages(self.pages, self.active_page)
+    }
 }
 
 pub struct LoadedSnapshot {
     pub snapshot: SessionSnapshot,
     pub compressed: bool,
+    /// Whether the file used the compact binary encoding rather than JSON.
+    pub compact: bool,
     pub version: u32,
+    pub background: Option<DocumentBackground>,
+    /// Upgrades applied to bring an older file to [`CURRENT_VERSION`] while loading.
+    pub migrations: Vec<MigrationStep>,
 }
 
 /// Capture a snapshot from the current input state if persistence is enabled.
@@ -127,13 +200,15 @@ pub fn snapshot_from_input(
 
     let history_limit = options.effective_history_limit(input.undo_stack_limit);
 
-    let capture_frame = |mode: BoardMode| -> Option<Frame> {
-        let frame = input.canvas_set.frame(mode)?;
-        let mut cloned = frame.clone();
-        if history_limit == 0 {
-            cloned.clamp_history_depth(0);
-        } else if history_limit < usize::MAX {
-            cloned.clamp_history_depth(history_limit);
+    let capture_frame = |mode: BoardMode| -> Option<BoardPages> {
+        let board = input.canvas_set.pages(mode)?;
+        let mut cloned = board.clone();
+        for page in cloned.pages_mut() {
+            // Vanishing ink is meant to disappear, not to come back next session
+            page.remove_all_vanishing();
+            if history_limit < usize::MAX {
+                page.clamp_history_depth(history_limit);
+            }
         }
         if cloned.has_persistable_data() {
             Some(cloned)
@@ -180,13 +255,7 @@ pub fn save_snapshot(snapshot: &SessionSnapshot, options: &SessionOptions) -> Re
     })?;
 
     let lock_path = options.lock_file_path();
-    let lock_file = OpenOptions::new()
-        .read(true)
-        .write(true)
-        .create(true)
-        .truncate(true)
-        .open(&lock_path)
-        .with_context(|| format!("failed to open session lock file {}", lock_path.display()))?;
+    let lock_file = open_lock_file(&lock_path)?;
     lock_exclusive(&lock_file)
         .with_context(|| format!("failed to lock session file {}", lock_path.display()))?;
 
@@ -203,9 +272,22 @@ pub fn save_snapshot(snapshot: &SessionSnapshot, options: &SessionOptions) -> Re
     result
 }
 
+/// Opens (creating if needed) the lock file guarding a session.
+///
+/// The file is never truncated here: besides serving as the `flock` target it records the PID of
+/// the overlay that currently owns the session, which crash recovery relies on.
+pub(crate) fn open_lock_file(lock_path: &Path) -> Result<File> {
+    OpenOptions::new()
+        .read(true)
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(lock_path)
+        .with_context(|| format!("failed to open session lock file {}", lock_path.display()))
+}
+
 fn save_snapshot_inner(snapshot: &SessionSnapshot, options: &SessionOptions) -> Result<()> {
     let session_path = options.session_file_path();
-    let backup_path = options.backup_file_path();
 
     if snapshot.is_empty() && snapshot.tool_state.is_none() {
         if session_path.exists() {
@@ -223,70 +305,14 @@ fn save_snapshot_inner(snapshot: &SessionSnapshot, options: &SessionOptions) ->
         return Ok(());
     }
 
-    let file_payload = SessionFile {
-        version: CURRENT_VERSION,
-        last_modified: now_rfc3339(),
-        active_mode: board_mode_to_str(snapshot.active_mode).to_string(),
-        transparent: snapshot.transparent.clone(),
-        whiteboard: snapshot.whiteboard.clone(),
-        blackboard: snapshot.blackboard.clone(),
-        tool_state: snapshot.tool_state.clone(),
-    };
-
-    let mut json_bytes =
-        serde_json::to_vec_pretty(&file_payload).context("failed to serialise session payload")?;
-
-    if json_bytes.len() as u64 > options.max_file_size_bytes {
-        warn!(
-            "Session data size {} bytes exceeds the configured limit of {} bytes; skipping save",
-            json_bytes.len(),
-            options.max_file_size_bytes
-        );
+    let Some((json_bytes, should_compress)) = encode_snapshot(snapshot, options)? else {
         return Ok(());
-    }
-
-    let should_compress = match options.compression {
-        CompressionMode::Off => false,
-        CompressionMode::On => true,
-        CompressionMode::Auto => (json_bytes.len() as u64) >= options.auto_compress_threshold_bytes,
     };
-
-    if should_compress {
-        json_bytes = compress_bytes(&json_bytes)?;
-    }
-
-    let tmp_path = temp_path(&session_path)?;
-    {
-        let mut tmp_file = OpenOptions::new()
-            .write(true)
-            .create_new(true)
-            .open(&tmp_path)
-            .with_context(|| {
-                format!(
-                    "failed to open temporary session file {}",
-                    tmp_path.display()
-                )
-            })?;
-        tmp_file
-            .write_all(&json_bytes)
-            .context("failed to write session payload")?;
-        tmp_file
-            .sync_all()
-            .context("failed to sync temporary session file")?;
-    }
+    let tmp_path = write_temp_file(&session_path, &json_bytes)?;
 
     if session_path.exists() {
         if options.backup_retention > 0 {
-            if backup_path.exists() {
-                fs::remove_file(&backup_path).ok();
-            }
-            fs::rename(&session_path, &backup_path).with_context(|| {
-                format!(
-                    "failed to rotate previous session file {} -> {}",
-                    session_path.display(),
-                    backup_path.display()
-                )
-            })?;
+            backups::rotate_session_file(&session_path, options)?;
         } else {
             fs::remove_file(&session_path).ok();
         }
@@ -310,6 +336,100 @@ fn save_snapshot_inner(snapshot: &SessionSnapshot, options: &SessionOptions) ->
     Ok(())
 }
 
+/// Serialises `snapshot` with the configured encoding and compression.
+///
+/// Returns the bytes and whether they are gzip-compressed, or `None` when the payload exceeds
+/// `max_file_size_bytes`.
+pub(crate) fn encode_snapshot(
+    snapshot: &SessionSnapshot,
+    options: &SessionOptions,
+) -> Result<Option<(Vec<u8>, bool)>> {
+    let file_payload = SessionFile::new(snapshot, None);
+
+    let mut json_bytes = match options.encoding {
+        SessionEncoding::Json => serde_json::to_vec_pretty(&file_payload)
+            .context("failed to serialise session payload")?,
+        SessionEncoding::Compact => compact::encode(
+            &serde_json::to_value(&file_payload).context("failed to serialise session payload")?,
+        ),
+    };
+
+    if json_bytes.len() as u64 > options.max_file_size_bytes {
+        warn!(
+            "Session data size {} bytes exceeds the configured limit of {} bytes; skipping save",
+            json_bytes.len(),
+            options.max_file_size_bytes
+        );
+        return Ok(None);
+    }
+
+    let should_compress = match options.compression {
+        CompressionMode::Off => false,
+        CompressionMode::On => true,
+        CompressionMode::Auto => (json_bytes.len() as u64) >= options.auto_compress_threshold_bytes,
+    };
+
+    if should_compress {
+        json_bytes = compress_bytes(&json_bytes)?;
+    }
+
+    Ok(Some((json_bytes, should_compress)))
+}
+
+/// Serialises a `.wayscriber` document: the session format, always gzip-compressed and without a
+/// size limit.
+pub(crate) fn encode_document(
+    snapshot: &SessionSnapshot,
+    background: Option<&DocumentBackground>,
+) -> Result<Vec<u8>> {
+    let json_bytes = serde_json::to_vec(&SessionFile::new(snapshot, background))
+        .context("failed to serialise document")?;
+    compress_bytes(&json_bytes)
+}
+
+/// Serialises `snapshot` in the session file format, for embedding in other files.
+pub fn snapshot_to_value(snapshot: &SessionSnapshot) -> Result<Value> {
+    serde_json::to_value(SessionFile::new(snapshot, None)).context("failed to serialise session")
+}
+
+/// Reads a snapshot written by [`snapshot_to_value`], upgrading older versions.
+pub fn snapshot_from_value(mut value: Value) -> Result<SessionSnapshot> {
+    migrations::migrate(&mut value, CURRENT_VERSION).context("failed to upgrade session")?;
+    let session_file: SessionFile =
+        serde_json::from_value(value).context("failed to parse session")?;
+    if session_file.version > CURRENT_VERSION {
+        bail!(
+            "session version {} is newer than supported version {}",
+            session_file.version,
+            CURRENT_VERSION
+        );
+    }
+    Ok(session_file.into_snapshot().0)
+}
+
+/// Writes `bytes` to a fresh temporary file next to `target` and syncs it, returning its path so
+/// the caller can rename it into place.
+pub(crate) fn write_temp_file(target: &Path, bytes: &[u8]) -> Result<PathBuf> {
+    let tmp_path = temp_path(target)?;
+    let mut tmp_file = OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&tmp_path)
+        .with_context(|| {
+            format!(
+                "failed to open temporary session file {}",
+                tmp_path.display()
+            )
+        })?;
+    tmp_file
+        .write_all(bytes)
+        .context("failed to write session payload")?;
+    tmp_file
+        .sync_all()
+        .context("failed to sync temporary session file")?;
+    Ok(tmp_path)
+}
+
 /// Attempt to load a previously saved session.
 pub fn load_snapshot(options: &SessionOptions) -> Result<Option<SessionSnapshot>> {
     if !options.any_enabled() && !options.restore_tool_state {
@@ -339,13 +459,7 @@ pub fn load_snapshot(options: &SessionOptions) -> Result<Option<SessionSnapshot>
     }
 
     let lock_path = options.lock_file_path();
-    let lock_file = OpenOptions::new()
-        .read(true)
-        .write(true)
-        .create(true)
-        .truncate(true)
-        .open(&lock_path)
-        .with_context(|| format!("failed to open session lock file {}", lock_path.display()))?;
+    let lock_file = open_lock_file(&lock_path)?;
     lock_shared(&lock_file)
         .with_context(|| format!("failed to acquire shared lock {}", lock_path.display()))?;
 
@@ -360,7 +474,26 @@ pub fn load_snapshot(options: &SessionOptions) -> Result<Option<SessionSnapshot>
     }
 
     match result {
-        Ok(Some(loaded)) => Ok(Some(loaded.snapshot)),
+        Ok(Some(loaded)) => {
+            if !loaded.migrations.is_empty() {
+                match upgrade_session_file(&session_path, options) {
+                    Ok(Some(backup_path)) => info!(
+                        "Upgraded session {} from version {} to {}; original kept at {}",
+                        session_path.display(),
+                        loaded.version,
+                        CURRENT_VERSION,
+                        backup_path.display()
+                    ),
+                    Ok(None) => {}
+                    Err(err) => warn!(
+                        "Failed to rewrite upgraded session {}: {:#}",
+                        session_path.display(),
+                        err
+                    ),
+                }
+            }
+            Ok(Some(loaded.snapshot))
+        }
         Ok(None) => Ok(None),
         Err(err) => {
             warn!(
@@ -384,28 +517,14 @@ pub(crate) fn load_snapshot_inner(
     session_path: &Path,
     options: &SessionOptions,
 ) -> Result<Option<LoadedSnapshot>> {
-    let mut file_bytes = Vec::new();
-    {
-        let mut file = File::open(session_path)
-            .with_context(|| format!("failed to open session file {}", session_path.display()))?;
-        file.read_to_end(&mut file_bytes)
-            .context("failed to read session file")?;
-    }
-
-    let compressed = is_gzip(&file_bytes);
-    let decompressed = if compressed {
-        let mut decoder = GzDecoder::new(&file_bytes[..]);
-        let mut out = Vec::new();
-        decoder
-            .read_to_end(&mut out)
-            .context("failed to decompress session file")?;
-        out
-    } else {
-        file_bytes
-    };
-
-    let original_value: serde_json::Value =
-        serde_json::from_slice(&decompressed).context("failed to parse session json")?;
+    let file_bytes = fs::read(session_path)
+        .with_context(|| format!("failed to read session file {}", session_path.display()))?;
+    let (file_bytes, compressed) = decompress_session_bytes(file_bytes)?;
+    let is_compact = compact::is_compact(&file_bytes);
+    let mut original_value = parse_session_bytes(&file_bytes)?;
+    let original_version = migrations::file_version(&original_value);
+    let applied_migrations = migrations::migrate(&mut original_value, CURRENT_VERSION)
+        .context("failed to upgrade session file")?;
 
     let max_depth = max_history_depth(&original_value);
     let mut working_value = original_value.clone();
@@ -439,16 +558,7 @@ pub(crate) fn load_snapshot_inner(
         return Ok(None);
     }
 
-    let active_mode =
-        BoardMode::from_str(&session_file.active_mode).unwrap_or(BoardMode::Transparent);
-
-    let mut snapshot = SessionSnapshot {
-        active_mode,
-        transparent: session_file.transparent,
-        whiteboard: session_file.whiteboard,
-        blackboard: session_file.blackboard,
-        tool_state: session_file.tool_state,
-    };
+    let (mut snapshot, background) = session_file.into_snapshot();
 
     enforce_shape_limits(&mut snapshot, options.max_shapes_per_frame);
     let disk_history_limit = if options.persist_history {
@@ -471,23 +581,126 @@ pub(crate) fn load_snapshot_inner(
     Ok(Some(LoadedSnapshot {
         snapshot,
         compressed,
-        version: session_file.version,
+        compact: is_compact,
+        version: original_version,
+        background,
+        migrations: applied_migrations,
     }))
 }
 
-fn backup_corrupt_session(session_path: &Path, options: &SessionOptions) -> Result<()> {
-    let bytes = fs::read(session_path)
-        .with_context(|| format!("failed to read corrupt session {}", session_path.display()))?;
-    let backup_path = options.backup_file_path();
-    fs::write(&backup_path, &bytes)
-        .with_context(|| format!("failed to write session backup {}", backup_path.display()))?;
-    fs::remove_file(session_path).with_context(|| {
+/// Parses the (decompressed) contents of a session file in either encoding.
+fn parse_session_bytes(bytes: &[u8]) -> Result<Value> {
+    if compact::is_compact(bytes) {
+        compact::decode(bytes).context("failed to decode compact session")
+    } else {
+        serde_json::from_slice(bytes).context("failed to parse session json")
+    }
+}
+
+/// Returns the uncompressed bytes of a session file and whether they were gzip-compressed.
+fn decompress_session_bytes(file_bytes: Vec<u8>) -> Result<(Vec<u8>, bool)> {
+    if !is_gzip(&file_bytes) {
+        return Ok((file_bytes, false));
+    }
+    let mut decoder = GzDecoder::new(&file_bytes[..]);
+    let mut out = Vec::new();
+    decoder
+        .read_to_end(&mut out)
+        .context("failed to decompress session file")?;
+    Ok((out, true))
+}
+
+/// Rewrites an older session file in the current format after keeping the original as a backup.
+///
+/// Returns the backup path, or `None` when the file no longer needs upgrading.
+fn upgrade_session_file(session_path: &Path, options: &SessionOptions) -> Result<Option<PathBuf>> {
+    let lock_path = options.lock_file_path();
+    let lock_file = open_lock_file(&lock_path)?;
+    lock_exclusive(&lock_file)
+        .with_context(|| format!("failed to acquire exclusive lock {}", lock_path.display()))?;
+
+    let result = upgrade_session_file_inner(session_path, options);
+
+    if let Err(err) = unlock(&lock_file) {
+        warn!(
+            "failed to unlock session file {}: {}",
+            lock_path.display(),
+            err
+        );
+    }
+    result
+}
+
+fn upgrade_session_file_inner(
+    session_path: &Path,
+    options: &SessionOptions,
+) -> Result<Option<PathBuf>> {
+    let original_bytes = fs::read(session_path)
+        .with_context(|| format!("failed to read session file {}", session_path.display()))?;
+    let (file_bytes, compressed) = decompress_session_bytes(original_bytes.clone())?;
+    let mut value = parse_session_bytes(&file_bytes)?;
+    let from_version = migrations::file_version(&value);
+    if migrations::migrate(&mut value, CURRENT_VERSION)?.is_empty() {
+        return Ok(None);
+    }
+
+    // Never replace an existing backup: it holds the oldest original of that version.
+    let backup_path = options.migration_backup_path(from_version);
+    if !backup_path.exists() {
+        fs::write(&backup_path, &original_bytes).with_context(|| {
+            format!(
+                "failed to write pre-migration backup {}",
+                backup_path.display()
+            )
+        })?;
+    }
+
+    // Keep the file's encoding; the configured one applies from the next save.
+    let mut bytes = if compact::is_compact(&file_bytes) {
+        compact::encode(&value)
+    } else {
+        serde_json::to_vec_pretty(&value).context("failed to serialise upgraded session")?
+    };
+    if compressed {
+        bytes = compress_bytes(&bytes)?;
+    }
+    let tmp_path = write_temp_file(session_path, &bytes)?;
+    fs::rename(&tmp_path, session_path).with_context(|| {
         format!(
-            "failed to remove corrupt session {}",
+            "failed to move upgraded session {} -> {}",
+            tmp_path.display(),
             session_path.display()
         )
     })?;
-    Ok(())
+    Ok(Some(backup_path))
+}
+
+/// Moves a session file that failed to load into a backup generation, like a save would.
+fn backup_corrupt_session(session_path: &Path, options: &SessionOptions) -> Result<()> {
+    let lock_path = options.lock_file_path();
+    let lock_file = open_lock_file(&lock_path)?;
+    lock_exclusive(&lock_file)
+        .with_context(|| format!("failed to acquire exclusive lock {}", lock_path.display()))?;
+
+    let result = if options.backup_retention > 0 {
+        backups::rotate_session_file(session_path, options)
+    } else {
+        fs::remove_file(session_path).with_context(|| {
+            format!(
+                "failed to remove corrupt session {}",
+                session_path.display()
+            )
+        })
+    };
+
+    if let Err(err) = unlock(&lock_file) {
+        warn!(
+            "failed to unlock session file {}: {}",
+            lock_path.display(),
+            err
+        );
+    }
+    result
 }
 
 /// Apply a session snapshot to the live [`InputState`].
@@ -497,7 +710,7 @@ pub fn apply_snapshot(input: &mut InputState, snapshot: SessionSnapshot, options
     if options.persist_transparent {
         input
             .canvas_set
-            .set_frame(BoardMode::Transparent, snapshot.transparent);
+            .set_pages(BoardMode::Transparent, snapshot.transparent);
         clamp_runtime_history(
             &mut input.canvas_set,
             BoardMode::Transparent,
@@ -507,7 +720,7 @@ pub fn apply_snapshot(input: &mut InputState, snapshot: SessionSnapshot, options
     if options.persist_whiteboard {
         input
             .canvas_set
-            .set_frame(BoardMode::Whiteboard, snapshot.whiteboard);
+            .set_pages(BoardMode::Whiteboard, snapshot.whiteboard);
         clamp_runtime_history(
             &mut input.canvas_set,
             BoardMode::Whiteboard,
@@ -517,7 +730,7 @@ pub fn apply_snapshot(input: &mut InputState, snapshot: SessionSnapshot, options
     if options.persist_blackboard {
         input
             .canvas_set
-            .set_frame(BoardMode::Blackboard, snapshot.blackboard);
+            .set_pages(BoardMode::Blackboard, snapshot.blackboard);
         clamp_runtime_history(
             &mut input.canvas_set,
             BoardMode::Blackboard,
@@ -550,8 +763,10 @@ pub fn apply_snapshot(input: &mut InputState, snapshot: SessionSnapshot, options
 }
 
 fn clamp_runtime_history(canvas: &mut crate::draw::CanvasSet, mode: BoardMode, limit: usize) {
-    if let Some(frame) = canvas.frame_mut(mode) {
-        frame.clamp_history_depth(limit);
+    if let Some(board) = canvas.pages_mut(mode) {
+        for page in board.pages_mut() {
+            page.clamp_history_depth(limit);
+        }
     }
 }
 
@@ -560,8 +775,11 @@ fn enforce_shape_limits(snapshot: &mut SessionSnapshot, max_shapes: usize) {
         return;
     }
 
-    let truncate = |frame: &mut Option<Frame>, mode: &str| {
-        if let Some(frame_data) = frame {
+    let truncate = |board: &mut Option<BoardPages>, mode: &str| {
+        let Some(board) = board else {
+            return;
+        };
+        for frame_data in board.pages_mut() {
             if frame_data.shapes.len() > max_shapes {
                 let removed: Vec<_> = frame_data.shapes.drain(max_shapes..).collect();
                 warn!(
@@ -590,8 +808,11 @@ fn enforce_shape_limits(snapshot: &mut SessionSnapshot, max_shapes: usize) {
     truncate(&mut snapshot.blackboard, "blackboard");
 }
 
-fn apply_history_policies(frame: &mut Option<Frame>, mode: &str, depth_limit: Option<usize>) {
-    if let Some(frame_data) = frame {
+fn apply_history_policies(board: &mut Option<BoardPages>, mode: &str, depth_limit: Option<usize>) {
+    let Some(board) = board else {
+        return;
+    };
+    for frame_data in board.pages_mut() {
         let depth_trim = frame_data.validate_history(MAX_COMPOUND_DEPTH);
         if !depth_trim.is_empty() {
             warn!(
@@ -621,7 +842,10 @@ fn apply_history_policies(frame: &mut Option<Frame>, mode: &str, depth_limit: Op
 fn max_history_depth(doc: &Value) -> usize {
     let mut max_depth = 0;
     for key in ["transparent", "whiteboard", "blackboard"] {
-        if let Some(frame) = doc.get(key) {
+        let Some(board) = doc.get(key) else {
+            continue;
+        };
+        for frame in board_frame_values(board) {
             if let Some(obj) = frame.as_object() {
                 for stack_key in ["undo_stack", "redo_stack"] {
                     if let Some(Value::Array(arr)) = obj.get(stack_key) {
@@ -634,6 +858,14 @@ fn max_history_depth(doc: &Value) -> usize {
     max_depth
 }
 
+/// Returns the frame objects of a serialized board (all pages, or the legacy single frame).
+fn board_frame_values(board: &Value) -> Vec<&Value> {
+    match board.get("pages") {
+        Some(Value::Array(pages)) => pages.iter().collect(),
+        _ => vec![board],
+    }
+}
+
 fn depth_array(arr: &[Value]) -> usize {
     arr.iter().map(depth_action).max().unwrap_or(1)
 }
@@ -657,9 +889,21 @@ fn depth_action(action: &Value) -> usize {
 fn strip_history_fields(doc: &mut Value) {
     if let Some(obj) = doc.as_object_mut() {
         for key in ["transparent", "whiteboard", "blackboard"] {
-            if let Some(Value::Object(frame)) = obj.get_mut(key) {
-                frame.remove("undo_stack");
-                frame.remove("redo_stack");
+            let Some(board) = obj.get_mut(key) else {
+                continue;
+            };
+            let frames: Vec<&mut Value> = match board {
+                Value::Object(map) if map.contains_key("pages") => match map.get_mut("pages") {
+                    Some(Value::Array(pages)) => pages.iter_mut().collect(),
+                    _ => Vec::new(),
+                },
+                other => vec![other],
+            };
+            for frame in frames {
+                if let Value::Object(frame) = frame {
+                    frame.remove("undo_stack");
+                    frame.remove("redo_stack");
+                }
             }
         }
     }
@@ -674,7 +918,7 @@ fn board_mode_to_str(mode: BoardMode) -> &'static str {
 }
 
 fn default_file_version() -> u32 {
-    1
+    LEGACY_VERSION
 }
 
 fn compress_bytes(data: &[u8]) -> Result<Vec<u8>> {
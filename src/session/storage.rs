@@ -1,10 +1,12 @@
+use super::backups;
+use super::migrations::MigrationStep;
 use super::options::SessionOptions;
 use super::snapshot;
-use crate::draw::Frame;
+use crate::draw::BoardPages;
 use crate::session::lock::{lock_shared, unlock};
 use anyhow::{Context, Result};
 use log::warn;
-use std::fs::{self, OpenOptions};
+use std::fs;
 use std::path::{Path, PathBuf};
 use std::time::SystemTime;
 
@@ -13,6 +15,7 @@ use std::time::SystemTime;
 pub struct ClearOutcome {
     pub removed_session: bool,
     pub removed_backup: bool,
+    pub removed_autosave: bool,
     pub removed_lock: bool,
 }
 
@@ -23,9 +26,12 @@ pub struct SessionInspection {
     pub exists: bool,
     pub size_bytes: Option<u64>,
     pub modified: Option<SystemTime>,
+    /// Newest backup generation, or the legacy single `.bak` file when there is none.
     pub backup_path: PathBuf,
     pub backup_exists: bool,
     pub backup_size_bytes: Option<u64>,
+    /// Number of rotated backup generations on disk.
+    pub backup_count: usize,
     pub active_identity: Option<String>,
     pub per_output: bool,
     pub persist_transparent: bool,
@@ -35,14 +41,35 @@ pub struct SessionInspection {
     pub restore_tool_state: bool,
     pub history_limit: Option<usize>,
     pub frame_counts: Option<FrameCounts>,
+    pub page_counts: Option<FrameCounts>,
     pub history_counts: Option<HistoryCounts>,
     pub history_present: bool,
     pub tool_state_present: bool,
     pub compressed: bool,
+    /// Whether the session file uses the compact binary encoding.
+    pub compact: bool,
     pub file_version: Option<u32>,
+    /// Upgrades the next load will apply because the file uses an older format.
+    pub pending_migrations: Vec<MigrationStep>,
+    /// Originals kept when the session file was upgraded from an older format.
+    pub migration_backups: Vec<PathBuf>,
 }
 
-/// Frame counts for each board stored in the session.
+/// Contents of a single session or backup file.
+#[derive(Debug, Clone, Default)]
+pub struct SessionFileSummary {
+    pub frame_counts: Option<FrameCounts>,
+    pub page_counts: Option<FrameCounts>,
+    pub history_counts: Option<HistoryCounts>,
+    pub history_present: bool,
+    pub tool_state_present: bool,
+    pub compressed: bool,
+    pub compact: bool,
+    pub file_version: Option<u32>,
+    pub pending_migrations: Vec<MigrationStep>,
+}
+
+/// Per-board counts (shapes or pages) stored in the session.
 #[derive(Debug, Clone, Copy)]
 pub struct FrameCounts {
     pub transparent: usize,
@@ -78,14 +105,21 @@ impl HistoryDepth {
     }
 }
 
-/// Remove persisted session files (session, backup, and lock).
+/// Remove persisted session files (session, backup, autosave, and lock).
 pub fn clear_session(options: &SessionOptions) -> Result<ClearOutcome> {
     let session_path = options.session_file_path();
     let backup_path = options.backup_file_path();
+    let autosave_path = options.autosave_file_path();
     let lock_path = options.lock_file_path();
 
     let mut removed_session = remove_file_if_exists(&session_path)?;
     let mut removed_backup = remove_file_if_exists(&backup_path)?;
+    removed_backup |=
+        remove_backup_generations(&options.base_dir, &options.backup_generation_prefix())?;
+    for path in migration_backups(options) {
+        removed_backup |= remove_file_if_exists(&path)?;
+    }
+    let mut removed_autosave = remove_file_if_exists(&autosave_path)?;
     let mut removed_lock = remove_file_if_exists(&lock_path)?;
 
     if options.per_output && options.output_identity().is_none() {
@@ -100,6 +134,12 @@ pub fn clear_session(options: &SessionOptions) -> Result<ClearOutcome> {
             removed_backup =
                 remove_matching_files(base_dir, &prefix, ".json.bak")? || removed_backup;
         }
+        removed_backup = remove_backup_generations(base_dir, &prefix)? || removed_backup;
+
+        if !removed_autosave {
+            removed_autosave =
+                remove_matching_files(base_dir, &prefix, ".json.autosave")? || removed_autosave;
+        }
 
         if !removed_lock {
             removed_lock = remove_matching_files(base_dir, &prefix, ".lock")? || removed_lock;
@@ -109,6 +149,7 @@ pub fn clear_session(options: &SessionOptions) -> Result<ClearOutcome> {
     Ok(ClearOutcome {
         removed_session,
         removed_backup,
+        removed_autosave,
         removed_lock,
     })
 }
@@ -132,7 +173,12 @@ pub fn inspect_session(options: &SessionOptions) -> Result<SessionInspection> {
     let size_bytes = metadata.as_ref().map(|m| m.len());
     let modified = metadata.as_ref().and_then(|m| m.modified().ok());
 
-    let mut backup_path = options.backup_file_path();
+    let generations = backups::backup_generations(options);
+    let backup_count = generations.len();
+    let mut backup_path = generations
+        .into_iter()
+        .next()
+        .unwrap_or_else(|| options.backup_file_path());
     let mut backup_meta = fs::metadata(&backup_path).ok();
     if backup_meta.is_none() && options.per_output && options.output_identity().is_none() {
         if let Some((path, _)) = find_existing_variant(&options.base_dir, &prefix, ".json.bak") {
@@ -144,26 +190,14 @@ pub fn inspect_session(options: &SessionOptions) -> Result<SessionInspection> {
     let backup_exists = backup_meta.is_some();
     let backup_size = backup_meta.as_ref().map(|m| m.len());
 
-    let mut frame_counts = None;
-    let mut tool_state_present = false;
-    let mut compressed = false;
-    let mut history_counts = None;
-    let mut history_present = false;
-    let mut file_version = None;
-
+    let mut summary = SessionFileSummary::default();
     if exists {
         let lock_path = session_path.with_extension("lock");
-        let lock_file = OpenOptions::new()
-            .read(true)
-            .write(true)
-            .create(true)
-            .truncate(true)
-            .open(&lock_path)
-            .with_context(|| format!("failed to open session lock file {}", lock_path.display()))?;
+        let lock_file = snapshot::open_lock_file(&lock_path)?;
         lock_shared(&lock_file)
             .with_context(|| format!("failed to acquire shared lock {}", lock_path.display()))?;
 
-        let loaded = snapshot::load_snapshot_inner(&session_path, options);
+        let loaded = summarize_session_file(&session_path, options);
 
         if let Err(err) = unlock(&lock_file) {
             warn!(
@@ -174,22 +208,7 @@ pub fn inspect_session(options: &SessionOptions) -> Result<SessionInspection> {
         }
 
         if let Some(loaded) = loaded? {
-            let snapshot = loaded.snapshot;
-            frame_counts = Some(FrameCounts {
-                transparent: snapshot.transparent.as_ref().map_or(0, |f| f.shapes.len()),
-                whiteboard: snapshot.whiteboard.as_ref().map_or(0, |f| f.shapes.len()),
-                blackboard: snapshot.blackboard.as_ref().map_or(0, |f| f.shapes.len()),
-            });
-            let counts = HistoryCounts {
-                transparent: history_depth_from_frame(snapshot.transparent.as_ref()),
-                whiteboard: history_depth_from_frame(snapshot.whiteboard.as_ref()),
-                blackboard: history_depth_from_frame(snapshot.blackboard.as_ref()),
-            };
-            history_present = counts.has_history();
-            history_counts = Some(counts);
-            tool_state_present = snapshot.tool_state.is_some();
-            compressed = loaded.compressed;
-            file_version = Some(loaded.version);
+            summary = loaded;
         }
     }
 
@@ -201,6 +220,7 @@ pub fn inspect_session(options: &SessionOptions) -> Result<SessionInspection> {
         backup_path,
         backup_exists,
         backup_size_bytes: backup_size,
+        backup_count,
         active_identity: session_identity,
         per_output: options.per_output,
         persist_transparent: options.persist_transparent,
@@ -209,15 +229,89 @@ pub fn inspect_session(options: &SessionOptions) -> Result<SessionInspection> {
         persist_history: options.persist_history,
         restore_tool_state: options.restore_tool_state,
         history_limit: options.max_persisted_undo_depth,
-        frame_counts,
-        history_counts,
-        history_present,
-        tool_state_present,
-        compressed,
-        file_version,
+        frame_counts: summary.frame_counts,
+        page_counts: summary.page_counts,
+        history_counts: summary.history_counts,
+        history_present: summary.history_present,
+        tool_state_present: summary.tool_state_present,
+        compressed: summary.compressed,
+        compact: summary.compact,
+        file_version: summary.file_version,
+        pending_migrations: summary.pending_migrations,
+        migration_backups: migration_backups(options),
     })
 }
 
+/// Reads `path` and counts what it stores; the caller holds the session lock.
+///
+/// Returns `None` when the file holds no drawings or tool state.
+pub(super) fn summarize_session_file(
+    path: &Path,
+    options: &SessionOptions,
+) -> Result<Option<SessionFileSummary>> {
+    let Some(loaded) = snapshot::load_snapshot_inner(path, options)? else {
+        return Ok(None);
+    };
+    let snapshot = loaded.snapshot;
+    let history_counts = HistoryCounts {
+        transparent: history_depth_from_board(snapshot.transparent.as_ref()),
+        whiteboard: history_depth_from_board(snapshot.whiteboard.as_ref()),
+        blackboard: history_depth_from_board(snapshot.blackboard.as_ref()),
+    };
+    Ok(Some(SessionFileSummary {
+        frame_counts: Some(FrameCounts {
+            transparent: shape_count(snapshot.transparent.as_ref()),
+            whiteboard: shape_count(snapshot.whiteboard.as_ref()),
+            blackboard: shape_count(snapshot.blackboard.as_ref()),
+        }),
+        page_counts: Some(FrameCounts {
+            transparent: snapshot
+                .transparent
+                .as_ref()
+                .map_or(0, BoardPages::page_count),
+            whiteboard: snapshot
+                .whiteboard
+                .as_ref()
+                .map_or(0, BoardPages::page_count),
+            blackboard: snapshot
+                .blackboard
+                .as_ref()
+                .map_or(0, BoardPages::page_count),
+        }),
+        history_present: history_counts.has_history(),
+        history_counts: Some(history_counts),
+        tool_state_present: snapshot.tool_state.is_some(),
+        compressed: loaded.compressed,
+        compact: loaded.compact,
+        file_version: Some(loaded.version),
+        pending_migrations: loaded.migrations,
+    }))
+}
+
+/// Lists the pre-migration backups of the session, oldest format first.
+fn migration_backups(options: &SessionOptions) -> Vec<PathBuf> {
+    let prefix = options.migration_backup_prefix();
+    let Ok(entries) = fs::read_dir(&options.base_dir) else {
+        return Vec::new();
+    };
+    let mut backups: Vec<(u32, PathBuf)> = entries
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| {
+            let path = entry.path();
+            let version = path
+                .file_name()?
+                .to_str()?
+                .strip_prefix(&prefix)?
+                .strip_suffix(".bak")?
+                .parse()
+                .ok()?;
+            path.is_file().then_some((version, path))
+        })
+        .collect();
+    backups.sort();
+    backups.into_iter().map(|(_, path)| path).collect()
+}
+
 fn remove_file_if_exists(path: &Path) -> Result<bool> {
     if path.exists() {
         fs::remove_file(path).with_context(|| format!("failed to remove {}", path.display()))?;
@@ -252,15 +346,40 @@ fn remove_matching_files(dir: &Path, prefix: &str, suffix: &str) -> Result<bool>
     Ok(removed)
 }
 
-fn history_depth_from_frame(frame: Option<&Frame>) -> HistoryDepth {
-    if let Some(frame) = frame {
-        HistoryDepth {
-            undo: frame.undo_stack_len(),
-            redo: frame.redo_stack_len(),
+/// Removes rotated backups (`<prefix>*.json.bak.<stamp>`), including per-output variants.
+fn remove_backup_generations(dir: &Path, prefix: &str) -> Result<bool> {
+    let mut removed = false;
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Ok(false);
+    };
+    for entry in entries {
+        let path = entry?.path();
+        let matches = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| name.starts_with(prefix) && name.contains(".json.bak."));
+        if matches && path.is_file() {
+            fs::remove_file(&path)
+                .with_context(|| format!("failed to remove {}", path.display()))?;
+            removed = true;
         }
-    } else {
-        HistoryDepth::default()
     }
+    Ok(removed)
+}
+
+fn shape_count(board: Option<&BoardPages>) -> usize {
+    board.map_or(0, |board| {
+        board.pages().iter().map(|page| page.shapes.len()).sum()
+    })
+}
+
+fn history_depth_from_board(board: Option<&BoardPages>) -> HistoryDepth {
+    let mut depth = HistoryDepth::default();
+    for page in board.map(BoardPages::pages).unwrap_or_default() {
+        depth.undo += page.undo_stack_len();
+        depth.redo += page.redo_stack_len();
+    }
+    depth
 }
 
 fn find_existing_variant(
@@ -319,7 +438,7 @@ fn extract_identity(name: &str, prefix: &str, suffix: &str) -> Option<String> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::draw::{Color, Frame, Shape};
+    use crate::draw::{BoardPages, Color, Frame, Shape};
     use crate::input::board_mode::BoardMode;
     use crate::session::{
         CompressionMode, SessionOptions, SessionSnapshot, ToolStateSnapshot, save_snapshot,
@@ -401,7 +520,7 @@ mod tests {
 
         let snapshot = SessionSnapshot {
             active_mode: BoardMode::Transparent,
-            transparent: Some(frame),
+            transparent: Some(BoardPages::from_pages(vec![frame], 0)),
             whiteboard: None,
             blackboard: None,
             tool_state: Some(ToolStateSnapshot {
@@ -436,6 +555,12 @@ mod tests {
         assert_eq!(counts.whiteboard, 0);
         assert_eq!(counts.blackboard, 0);
 
+        let page_counts = inspection
+            .page_counts
+            .expect("page_counts should be populated");
+        assert_eq!(page_counts.transparent, 1);
+        assert_eq!(page_counts.whiteboard, 0);
+
         let history_counts = inspection
             .history_counts
             .expect("history_counts should be populated");
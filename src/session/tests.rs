@@ -2,7 +2,7 @@ use super::*;
 use crate::config::{Action, BoardConfig, SessionConfig, SessionStorageMode};
 use crate::draw::FontDescriptor;
 use crate::draw::frame::{ShapeSnapshot, UndoAction};
-use crate::draw::{Color, Shape};
+use crate::draw::{Color, RedactMode, Shape};
 use crate::input::{ClickHighlightSettings, InputState, board_mode::BoardMode};
 use std::collections::HashMap;
 use std::fs;
@@ -84,6 +84,38 @@ fn snapshot_includes_frames_and_tool_state() {
     assert!(snapshot.tool_state.is_some());
 }
 
+#[test]
+fn snapshot_leaves_out_vanishing_ink() {
+    let mut options = SessionOptions::new(PathBuf::from("/tmp"), "display");
+    options.persist_transparent = true;
+    options.restore_tool_state = false;
+
+    let mut input = dummy_input_state();
+    let frame = input.canvas_set.active_frame_mut();
+    let id = frame.add_shape(Shape::Line {
+        x1: 0,
+        y1: 0,
+        x2: 10,
+        y2: 10,
+        color: Color {
+            r: 1.0,
+            g: 0.0,
+            b: 0.0,
+            a: 1.0,
+        },
+        thick: 2.0,
+        start_color: None,
+        end_color: None,
+    });
+    frame.set_vanishing(
+        id,
+        std::time::Instant::now() + std::time::Duration::from_secs(60),
+    );
+
+    assert!(snapshot_from_input(&input, &options).is_none());
+    assert_eq!(input.canvas_set.active_frame().shapes.len(), 1);
+}
+
 #[test]
 fn options_from_config_custom_storage() {
     let temp = tempfile::tempdir().unwrap();
@@ -240,6 +272,164 @@ fn session_roundtrip_preserves_shapes_across_frames() {
     assert_eq!(fresh_input.canvas_set.active_frame().shapes.len(), 1);
 }
 
+#[test]
+fn session_roundtrip_preserves_every_page() {
+    let temp = tempfile::tempdir().unwrap();
+    let mut options = SessionOptions::new(temp.path().to_path_buf(), "display-pages");
+    options.persist_whiteboard = true;
+
+    let mut input = dummy_input_state();
+    input.switch_board_mode(BoardMode::Whiteboard);
+    for x in [0, 10] {
+        input.canvas_set.active_frame_mut().add_shape(Shape::Line {
+            x1: x,
+            y1: 0,
+            x2: x + 5,
+            y2: 5,
+            color: Color {
+                r: 0.0,
+                g: 0.0,
+                b: 0.0,
+                a: 1.0,
+            },
+            thick: 2.0,
+            start_color: None,
+            end_color: None,
+        });
+        input.add_page();
+    }
+    assert!(input.previous_page());
+
+    let snapshot = snapshot_from_input(&input, &options).expect("snapshot present");
+    save_snapshot(&snapshot, &options).expect("save snapshot");
+
+    let loaded = load_snapshot(&options)
+        .expect("load snapshot result")
+        .expect("snapshot present");
+    let mut restored = dummy_input_state();
+    apply_snapshot(&mut restored, loaded, &options);
+
+    assert_eq!(restored.board_mode(), BoardMode::Whiteboard);
+    assert_eq!(restored.canvas_set.active_page_position(), (2, 3));
+    let pages = restored
+        .canvas_set
+        .pages(BoardMode::Whiteboard)
+        .expect("whiteboard pages restored");
+    let shape_counts: Vec<_> = pages.pages().iter().map(|page| page.shapes.len()).collect();
+    assert_eq!(shape_counts, vec![1, 1, 0]);
+}
+
+#[test]
+fn legacy_single_frame_session_loads_as_one_page() {
+    let temp = tempfile::tempdir().unwrap();
+    let mut options = SessionOptions::new(temp.path().to_path_buf(), "display-legacy");
+    options.persist_transparent = true;
+
+    fs::write(
+        options.session_file_path(),
+        r#"{
+            "version": 3,
+            "last_modified": "2024-01-01T00:00:00Z",
+            "active_mode": "transparent",
+            "transparent": {
+                "shapes": [
+                    {"Line": {"x1": 0, "y1": 0, "x2": 4, "y2": 4,
+                     "color": {"r": 1.0, "g": 0.0, "b": 0.0, "a": 1.0}, "thick": 2.0}}
+                ]
+            }
+        }"#,
+    )
+    .expect("write legacy session");
+
+    let loaded = load_snapshot(&options)
+        .expect("load snapshot result")
+        .expect("snapshot present");
+    let transparent = loaded.transparent.expect("transparent board present");
+    assert_eq!(transparent.page_count(), 1);
+    assert_eq!(transparent.active_page().shapes.len(), 1);
+}
+
+#[test]
+fn session_roundtrip_preserves_freehand_pressure_widths() {
+    let temp = tempfile::tempdir().unwrap();
+    let mut options = SessionOptions::new(temp.path().to_path_buf(), "display-pressure");
+    options.persist_transparent = true;
+
+    let mut input = dummy_input_state();
+    input
+        .canvas_set
+        .active_frame_mut()
+        .add_shape(Shape::Freehand {
+            points: vec![(0, 0), (10, 5), (20, 10)],
+            color: Color {
+                r: 0.0,
+                g: 0.0,
+                b: 1.0,
+                a: 1.0,
+            },
+            thick: 4.0,
+            per_point_colors: None,
+            per_point_widths: Some(vec![1.5, 4.0, 2.5]),
+        });
+
+    let snapshot = snapshot_from_input(&input, &options).expect("snapshot produced");
+    save_snapshot(&snapshot, &options).expect("save snapshot");
+
+    let loaded_snapshot = load_snapshot(&options)
+        .expect("load snapshot result")
+        .expect("snapshot present");
+
+    let mut fresh_input = dummy_input_state();
+    apply_snapshot(&mut fresh_input, loaded_snapshot, &options);
+
+    let frame = fresh_input.canvas_set.active_frame();
+    match &frame.shapes[0].shape {
+        Shape::Freehand {
+            per_point_widths, ..
+        } => assert_eq!(per_point_widths.as_deref(), Some(&[1.5, 4.0, 2.5][..])),
+        other => panic!("expected freehand, got {other:?}"),
+    }
+}
+
+#[test]
+fn session_stores_only_the_geometry_of_redactions() {
+    let temp = tempfile::tempdir().unwrap();
+    let mut options = SessionOptions::new(temp.path().to_path_buf(), "display-redact");
+    options.persist_transparent = true;
+
+    let redaction = Shape::Redact {
+        rect: crate::util::Rect::new(5, 6, 40, 30).unwrap(),
+        mode: RedactMode::Blur,
+    };
+    assert_eq!(
+        serde_json::to_value(&redaction).unwrap(),
+        serde_json::json!({
+            "Redact": {
+                "rect": { "x": 5, "y": 6, "width": 40, "height": 30 },
+                "mode": "blur"
+            }
+        })
+    );
+
+    let mut input = dummy_input_state();
+    input.canvas_set.active_frame_mut().add_shape(redaction);
+    let snapshot = snapshot_from_input(&input, &options).expect("snapshot produced");
+    save_snapshot(&snapshot, &options).expect("save snapshot");
+
+    let loaded_snapshot = load_snapshot(&options)
+        .expect("load snapshot result")
+        .expect("snapshot present");
+    let mut fresh_input = dummy_input_state();
+    apply_snapshot(&mut fresh_input, loaded_snapshot, &options);
+    match &fresh_input.canvas_set.active_frame().shapes[0].shape {
+        Shape::Redact { rect, mode } => {
+            assert_eq!((rect.x, rect.y, rect.width, rect.height), (5, 6, 40, 30));
+            assert_eq!(*mode, RedactMode::Blur);
+        }
+        other => panic!("expected redaction, got {other:?}"),
+    }
+}
+
 #[test]
 fn snapshot_preserves_history_only_frames() {
     let temp = tempfile::tempdir().unwrap();
@@ -499,13 +689,15 @@ fn corrupt_session_is_backed_up_and_reset() {
     let loaded = load_snapshot(&options).expect("load should not error");
     assert!(loaded.is_none());
 
-    let backup_path = options.backup_file_path();
-    let backup = fs::read(&backup_path).expect("backup file present");
+    let generations = backup_generations(&options);
+    assert_eq!(generations.len(), 1);
+    let backup = fs::read(&generations[0]).expect("backup file present");
     assert_eq!(backup, b"not json");
     assert!(
         !session_path.exists(),
         "corrupt session file should be removed after backup"
     );
+    assert_eq!(list_session_backups(&options).unwrap().len(), 1);
 }
 
 #[test]
@@ -618,8 +810,746 @@ fn load_snapshot_truncates_shapes_when_exceeding_max_shapes_per_frame() {
         .transparent
         .expect("transparent frame should be present");
     assert_eq!(
-        transparent.shapes.len(),
+        transparent.active_page().shapes.len(),
         2,
         "frame should be truncated to max_shapes_per_frame"
     );
 }
+
+fn autosave_options(temp: &tempfile::TempDir, display: &str) -> SessionOptions {
+    let mut options = SessionOptions::new(temp.path().to_path_buf(), display);
+    options.persist_transparent = true;
+    options.autosave_interval = Some(std::time::Duration::from_secs(30));
+    options.autosave_after_actions = 3;
+    options
+}
+
+fn snapshot_with_line(options: &SessionOptions) -> SessionSnapshot {
+    let mut input = dummy_input_state();
+    input.canvas_set.active_frame_mut().add_shape(Shape::Line {
+        x1: 0,
+        y1: 0,
+        x2: 10,
+        y2: 10,
+        color: Color {
+            r: 0.0,
+            g: 1.0,
+            b: 0.0,
+            a: 1.0,
+        },
+        thick: 2.0,
+        start_color: None,
+        end_color: None,
+    });
+    snapshot_from_input(&input, options).expect("snapshot produced")
+}
+
+#[test]
+fn autosave_is_due_after_action_count_or_interval() {
+    let temp = tempfile::tempdir().unwrap();
+    let options = autosave_options(&temp, "display-autosave-due");
+    let mut autosaver = Autosaver::start(&options, || {}).expect("autosave enabled");
+    let start = std::time::Instant::now();
+
+    assert!(!autosaver.is_due(0, start), "nothing changed yet");
+    assert!(!autosaver.is_due(2, start));
+    assert!(autosaver.is_due(3, start), "action threshold reached");
+
+    autosaver.save(None, &options, 3);
+    assert!(!autosaver.is_due(3, start));
+    assert!(!autosaver.is_due(4, start));
+    assert!(
+        autosaver.is_due(4, start + std::time::Duration::from_secs(31)),
+        "interval elapsed since the first unsaved edit"
+    );
+}
+
+#[test]
+fn autosave_is_due_after_deleting_a_loaded_page() {
+    let temp = tempfile::tempdir().unwrap();
+    let mut options = autosave_options(&temp, "display-autosave-delete");
+    options.autosave_after_actions = 1;
+
+    let mut input = dummy_input_state();
+    for _ in 0..2 {
+        input.canvas_set.active_frame_mut().add_shape(Shape::Line {
+            x1: 0,
+            y1: 0,
+            x2: 10,
+            y2: 10,
+            color: Color {
+                r: 0.0,
+                g: 0.0,
+                b: 0.0,
+                a: 1.0,
+            },
+            thick: 2.0,
+            start_color: None,
+            end_color: None,
+        });
+        input.add_page();
+    }
+    let snapshot = snapshot_from_input(&input, &options).expect("snapshot present");
+    save_snapshot(&snapshot, &options).expect("save snapshot");
+    let loaded = load_snapshot(&options)
+        .expect("load snapshot result")
+        .expect("snapshot present");
+    let mut restored = dummy_input_state();
+    apply_snapshot(&mut restored, loaded, &options);
+
+    let mut autosaver = Autosaver::start(&options, || {}).expect("autosave enabled");
+    let start = std::time::Instant::now();
+    assert!(!autosaver.is_due(restored.canvas_set.revision(), start));
+
+    assert!(restored.previous_page());
+    assert!(restored.delete_page());
+    assert!(
+        autosaver.is_due(restored.canvas_set.revision(), start),
+        "deleting a loaded page counts as an edit"
+    );
+}
+
+#[test]
+fn autosave_disabled_without_triggers() {
+    let temp = tempfile::tempdir().unwrap();
+    let mut options = autosave_options(&temp, "display-autosave-off");
+    options.autosave_interval = None;
+    options.autosave_after_actions = 0;
+    assert!(!options.autosave_enabled());
+    assert!(Autosaver::start(&options, || {}).is_none());
+}
+
+#[test]
+fn autosave_roundtrip_and_discard() {
+    let temp = tempfile::tempdir().unwrap();
+    let options = autosave_options(&temp, "display-autosave-io");
+    assert!(load_autosave_snapshot(&options).unwrap().is_none());
+
+    save_autosave_snapshot(&snapshot_with_line(&options), &options).expect("write autosave");
+    assert!(options.autosave_file_path().exists());
+    assert!(
+        !options.session_file_path().exists(),
+        "autosave must not touch the session file"
+    );
+
+    let restored = load_autosave_snapshot(&options)
+        .expect("read autosave")
+        .expect("autosave present");
+    let mut input = dummy_input_state();
+    apply_snapshot(&mut input, restored, &options);
+    assert_eq!(input.canvas_set.active_frame().shapes.len(), 1);
+
+    discard_autosave(&options).expect("discard autosave");
+    assert!(!options.autosave_file_path().exists());
+    discard_autosave(&options).expect("discarding twice is fine");
+}
+
+#[test]
+fn autosaver_worker_writes_on_save() {
+    let temp = tempfile::tempdir().unwrap();
+    let options = autosave_options(&temp, "display-autosave-worker");
+    let mut autosaver = Autosaver::start(&options, || {}).expect("autosave enabled");
+    autosaver.save(Some(snapshot_with_line(&options)), &options, 1);
+    // Dropping waits for queued writes.
+    drop(autosaver);
+    assert!(options.autosave_file_path().exists());
+}
+
+#[test]
+fn claim_session_detects_unclean_shutdown() {
+    let temp = tempfile::tempdir().unwrap();
+    let options = autosave_options(&temp, "display-claim");
+
+    assert!(!claim_session(&options).unwrap(), "fresh session is clean");
+    assert!(
+        !claim_session(&options).unwrap(),
+        "reclaiming our own session"
+    );
+    release_session(&options).unwrap();
+    assert_eq!(fs::read_to_string(options.lock_file_path()).unwrap(), "");
+    assert!(
+        !claim_session(&options).unwrap(),
+        "released session is clean"
+    );
+
+    // Pretend an exited process still owns the session.
+    let mut child = std::process::Command::new("true").spawn().unwrap();
+    let dead_pid = child.id();
+    child.wait().unwrap();
+    fs::write(options.lock_file_path(), dead_pid.to_string()).unwrap();
+    assert!(claim_session(&options).unwrap(), "dead owner means a crash");
+    assert_eq!(
+        fs::read_to_string(options.lock_file_path()).unwrap(),
+        std::process::id().to_string()
+    );
+}
+
+#[test]
+fn save_snapshot_keeps_session_owner() {
+    let temp = tempfile::tempdir().unwrap();
+    let options = autosave_options(&temp, "display-claim-save");
+    claim_session(&options).unwrap();
+
+    save_snapshot(&snapshot_with_line(&options), &options).expect("save snapshot");
+    assert_eq!(
+        fs::read_to_string(options.lock_file_path()).unwrap(),
+        std::process::id().to_string()
+    );
+}
+
+#[test]
+fn clear_session_removes_autosave() {
+    let temp = tempfile::tempdir().unwrap();
+    let options = autosave_options(&temp, "display-clear-autosave");
+    save_autosave_snapshot(&snapshot_with_line(&options), &options).expect("write autosave");
+
+    let outcome = clear_session(&options).expect("clear session");
+    assert!(outcome.removed_autosave);
+    assert!(!options.autosave_file_path().exists());
+}
+
+fn snapshot_with_lines(options: &SessionOptions, count: usize) -> SessionSnapshot {
+    let mut input = dummy_input_state();
+    for offset in 0..count as i32 {
+        input.canvas_set.active_frame_mut().add_shape(Shape::Line {
+            x1: offset,
+            y1: 0,
+            x2: offset,
+            y2: 10,
+            color: Color {
+                r: 0.0,
+                g: 0.0,
+                b: 1.0,
+                a: 1.0,
+            },
+            thick: 2.0,
+            start_color: None,
+            end_color: None,
+        });
+    }
+    snapshot_from_input(&input, options).expect("snapshot produced")
+}
+
+#[test]
+fn save_snapshot_rotates_timestamped_backups() {
+    let temp = tempfile::tempdir().unwrap();
+    let mut options = SessionOptions::new(temp.path().to_path_buf(), "display-rotate");
+    options.persist_transparent = true;
+    options.backup_retention = 2;
+
+    for count in 1..=4 {
+        save_snapshot(&snapshot_with_lines(&options, count), &options).expect("save snapshot");
+    }
+
+    let backups = list_session_backups(&options).expect("list backups");
+    assert_eq!(
+        backups.len(),
+        2,
+        "only backup_retention generations are kept"
+    );
+    let shapes: Vec<usize> = backups
+        .iter()
+        .map(|backup| {
+            backup
+                .summary
+                .as_ref()
+                .and_then(|summary| summary.frame_counts)
+                .expect("backup readable")
+                .transparent
+        })
+        .collect();
+    assert_eq!(shapes, vec![3, 2], "newest backup first");
+    assert_eq!(backups[0].index, 1);
+    assert!(
+        !options.backup_file_path().exists(),
+        "rotation no longer writes the single .bak file"
+    );
+
+    let inspection = inspect_session(&options).expect("inspect session");
+    assert_eq!(inspection.backup_count, 2);
+    assert_eq!(inspection.backup_path, backups[0].path);
+}
+
+#[test]
+fn restore_session_backup_replaces_session_and_keeps_it() {
+    let temp = tempfile::tempdir().unwrap();
+    let mut options = SessionOptions::new(temp.path().to_path_buf(), "display-restore");
+    options.persist_transparent = true;
+    options.backup_retention = 3;
+
+    save_snapshot(&snapshot_with_lines(&options, 5), &options).expect("save drawings");
+    // Simulate clearing the canvas, drawing a stray line and exiting.
+    save_snapshot(&snapshot_with_lines(&options, 1), &options).expect("save after clear");
+
+    let outcome = restore_session_backup(&options, 1).expect("restore newest backup");
+    assert!(outcome.previous_session_backed_up);
+    let restored = load_snapshot(&options)
+        .expect("load restored session")
+        .expect("session present");
+    let mut input = dummy_input_state();
+    apply_snapshot(&mut input, restored, &options);
+    assert_eq!(input.canvas_set.active_frame().shapes.len(), 5);
+
+    // The replaced session became the newest backup, so the restore can be undone.
+    let backups = list_session_backups(&options).expect("list backups");
+    assert_eq!(
+        backups[0]
+            .summary
+            .as_ref()
+            .and_then(|summary| summary.frame_counts)
+            .map(|counts| counts.transparent),
+        Some(1)
+    );
+
+    assert!(restore_session_backup(&options, 0).is_err());
+    assert!(restore_session_backup(&options, 99).is_err());
+}
+
+#[test]
+fn clear_session_removes_backup_generations() {
+    let temp = tempfile::tempdir().unwrap();
+    let mut options = SessionOptions::new(temp.path().to_path_buf(), "display-clear-backups");
+    options.persist_transparent = true;
+    options.backup_retention = 3;
+    save_snapshot(&snapshot_with_lines(&options, 1), &options).expect("save snapshot");
+    save_snapshot(&snapshot_with_lines(&options, 2), &options).expect("save snapshot");
+    assert_eq!(backup_generations(&options).len(), 1);
+
+    let outcome = clear_session(&options).expect("clear session");
+    assert!(outcome.removed_backup);
+    assert!(backup_generations(&options).is_empty());
+}
+
+#[test]
+fn profile_names_are_validated() {
+    for name in ["standup", "client-a", "retro_2025.q1"] {
+        assert!(
+            validate_profile_name(name).is_ok(),
+            "{name} should be valid"
+        );
+    }
+    for name in ["", ".hidden", "a/b", "..", "with space", &"x".repeat(65)] {
+        assert!(
+            validate_profile_name(name).is_err(),
+            "{name:?} should be rejected"
+        );
+    }
+}
+
+#[test]
+fn profile_sessions_are_stored_separately() {
+    let temp = tempfile::tempdir().unwrap();
+    let mut options = SessionOptions::new(temp.path().to_path_buf(), "display-profiles");
+    options.persist_transparent = true;
+    let default_options = options.clone();
+
+    apply_profile(&mut options, "standup").expect("apply profile");
+    assert_eq!(
+        options.base_dir,
+        temp.path().join("profiles").join("standup")
+    );
+    assert_eq!(profiles_dir(&options), profiles_dir(&default_options));
+    assert!(apply_profile(&mut options, "../escape").is_err());
+
+    save_snapshot(&snapshot_with_lines(&options, 2), &options).expect("save profile");
+    assert!(options.session_file_path().exists());
+    assert!(!default_options.session_file_path().exists());
+
+    let profiles = list_profiles(&default_options).expect("list profiles");
+    assert_eq!(profiles.len(), 1);
+    assert_eq!(profiles[0].name, "standup");
+    assert!(profiles[0].last_saved.is_some());
+}
+
+#[test]
+fn profiles_can_be_renamed_duplicated_and_deleted() {
+    let temp = tempfile::tempdir().unwrap();
+    let mut base = SessionOptions::new(temp.path().to_path_buf(), "display-profile-ops");
+    base.persist_transparent = true;
+    let mut standup = base.clone();
+    apply_profile(&mut standup, "standup").unwrap();
+    save_snapshot(&snapshot_with_lines(&standup, 3), &standup).expect("save profile");
+    fs::write(standup.autosave_file_path(), b"{}").unwrap();
+
+    duplicate_profile(&base, "standup", "retro").expect("duplicate profile");
+    let mut retro = base.clone();
+    apply_profile(&mut retro, "retro").unwrap();
+    assert!(retro.session_file_path().exists());
+    assert!(
+        !retro.autosave_file_path().exists(),
+        "autosaves stay with the original session"
+    );
+    assert!(duplicate_profile(&base, "standup", "retro").is_err());
+
+    rename_profile(&base, "retro", "review").expect("rename profile");
+    assert!(rename_profile(&base, "retro", "other").is_err());
+    let names: Vec<String> = list_profiles(&base)
+        .unwrap()
+        .into_iter()
+        .map(|profile| profile.name)
+        .collect();
+    assert_eq!(names, ["review", "standup"]);
+
+    delete_profile(&base, "review").expect("delete profile");
+    assert!(delete_profile(&base, "review").is_err());
+    assert_eq!(list_profiles(&base).unwrap().len(), 1);
+}
+
+#[test]
+fn document_roundtrip_keeps_every_board_history_and_background() {
+    let temp = tempfile::tempdir().unwrap();
+    let path = temp
+        .path()
+        .join(format!("explainer.{}", DOCUMENT_EXTENSION));
+    // Documents capture all boards even when the regular session persists none of them.
+    let options = document_options(10_000);
+
+    let mut input = dummy_input_state();
+    let frame = input.canvas_set.active_frame_mut();
+    let id = frame.add_shape(Shape::Line {
+        x1: 0,
+        y1: 0,
+        x2: 40,
+        y2: 40,
+        color: Color {
+            r: 1.0,
+            g: 0.0,
+            b: 0.0,
+            a: 1.0,
+        },
+        thick: 3.0,
+        start_color: None,
+        end_color: None,
+    });
+    let index = frame.find_index(id).unwrap();
+    frame.push_undo_action(
+        UndoAction::Create {
+            shapes: vec![(index, frame.shape(id).unwrap().clone())],
+        },
+        input.undo_stack_limit,
+    );
+    input.canvas_set.switch_mode(BoardMode::Blackboard);
+    input.canvas_set.active_frame_mut().add_shape(Shape::Rect {
+        x: 1,
+        y: 2,
+        w: 30,
+        h: 40,
+        fill: false,
+        color: Color {
+            r: 1.0,
+            g: 1.0,
+            b: 1.0,
+            a: 1.0,
+        },
+        thick: 2.0,
+        start_color: None,
+        end_color: None,
+    });
+
+    let background = DocumentBackground {
+        width: 2,
+        height: 1,
+        png: vec![0x89, b'P', b'N', b'G', 0, 1, 2, 254, 255],
+    };
+    let document = SessionDocument {
+        snapshot: snapshot_from_input(&input, &options).expect("snapshot produced"),
+        background: Some(background.clone()),
+    };
+    save_document(&path, &document).expect("save document");
+
+    let loaded = load_document(&path, 10_000).expect("load document");
+    assert_eq!(loaded.background, Some(background));
+    assert_eq!(loaded.snapshot.active_mode, BoardMode::Blackboard);
+
+    let mut restored = dummy_input_state();
+    apply_snapshot(&mut restored, loaded.snapshot, &options);
+    assert_eq!(restored.board_mode(), BoardMode::Blackboard);
+    assert_eq!(restored.canvas_set.active_frame().shapes.len(), 1);
+    restored.canvas_set.switch_mode(BoardMode::Transparent);
+    let frame = restored.canvas_set.active_frame_mut();
+    assert_eq!(frame.shapes.len(), 1);
+    frame.undo_last();
+    assert!(frame.shapes.is_empty(), "history travels with the document");
+}
+
+#[test]
+fn session_files_do_not_embed_a_background() {
+    let temp = tempfile::tempdir().unwrap();
+    let mut options = SessionOptions::new(temp.path().to_path_buf(), "display-document");
+    options.persist_transparent = true;
+    options.compression = CompressionMode::Off;
+    save_snapshot(&snapshot_with_lines(&options, 1), &options).expect("save snapshot");
+
+    let raw = fs::read_to_string(options.session_file_path()).unwrap();
+    assert!(!raw.contains("\"background\""));
+    // A session file is also a valid (if background-less) document.
+    let document = load_document(&options.session_file_path(), 10_000).expect("load as document");
+    assert!(document.background.is_none());
+}
+
+#[test]
+fn latest_document_picks_the_newest_file() {
+    let temp = tempfile::tempdir().unwrap();
+    assert!(latest_document(temp.path()).is_none());
+
+    let older = temp.path().join("older.wayscriber");
+    let newer = temp.path().join("newer.wayscriber");
+    fs::write(&older, b"{}").unwrap();
+    fs::write(&newer, b"{}").unwrap();
+    fs::write(temp.path().join("notes.json"), b"{}").unwrap();
+    let past = std::time::SystemTime::now() - std::time::Duration::from_secs(60);
+    fs::File::options()
+        .write(true)
+        .open(&older)
+        .unwrap()
+        .set_modified(past)
+        .unwrap();
+
+    assert_eq!(latest_document(temp.path()), Some(newer));
+}
+
+/// Session files as written by each historical format version.
+const SESSION_FIXTURES: [(u32, &str); 5] = [
+    (1, include_str!("../../tests/fixtures/session/v1.json")),
+    (2, include_str!("../../tests/fixtures/session/v2.json")),
+    (3, include_str!("../../tests/fixtures/session/v3.json")),
+    (4, include_str!("../../tests/fixtures/session/v4.json")),
+    (5, include_str!("../../tests/fixtures/session/v5.json")),
+];
+
+fn fixture_options(temp: &tempfile::TempDir, version: u32) -> SessionOptions {
+    let mut options = SessionOptions::new(
+        temp.path().to_path_buf(),
+        &format!("display-fixture-v{version}"),
+    );
+    options.persist_transparent = true;
+    options.persist_whiteboard = true;
+    options.persist_blackboard = true;
+    options.persist_history = true;
+    options.restore_tool_state = true;
+    options
+}
+
+#[test]
+fn fixture_sessions_of_every_version_load() {
+    for (version, fixture) in SESSION_FIXTURES {
+        let temp = tempfile::tempdir().unwrap();
+        let options = fixture_options(&temp, version);
+        fs::write(options.session_file_path(), fixture).unwrap();
+
+        let snapshot = load_snapshot(&options)
+            .unwrap_or_else(|err| panic!("v{version}: {err:#}"))
+            .unwrap_or_else(|| panic!("v{version}: snapshot present"));
+        match version {
+            1 => {
+                let page = snapshot.transparent.as_ref().unwrap().active_page();
+                let ids: Vec<_> = page.shapes.iter().map(|shape| shape.id).collect();
+                assert_eq!(ids, vec![1, 2]);
+                assert!(matches!(page.shapes[0].shape, Shape::Line { .. }));
+                assert!(matches!(page.shapes[1].shape, Shape::Rect { .. }));
+            }
+            2 => {
+                assert_eq!(snapshot.active_mode, BoardMode::Whiteboard);
+                let shape = &snapshot.whiteboard.as_ref().unwrap().active_page().shapes[0];
+                assert_eq!(shape.id, 4);
+                assert!(shape.locked);
+                let tool_state = snapshot.tool_state.as_ref().unwrap();
+                assert_eq!(tool_state.eraser_size, 12.0);
+                assert_eq!(tool_state.eraser_kind, crate::draw::EraserKind::Circle);
+            }
+            3 => {
+                let page = snapshot.transparent.as_ref().unwrap().active_page();
+                assert_eq!(page.undo_stack_len(), 1);
+                assert_eq!(
+                    snapshot
+                        .blackboard
+                        .as_ref()
+                        .unwrap()
+                        .active_page()
+                        .shapes
+                        .len(),
+                    1
+                );
+                let tool_state = snapshot.tool_state.as_ref().unwrap();
+                assert_eq!(tool_state.eraser_size, 24.0);
+                assert_eq!(tool_state.eraser_kind, crate::draw::EraserKind::Rect);
+            }
+            4 => {
+                let whiteboard = snapshot.whiteboard.as_ref().unwrap();
+                assert_eq!(whiteboard.page_count(), 2);
+                assert_eq!(whiteboard.active_index(), 1);
+            }
+            _ => {
+                let page = snapshot.transparent.as_ref().unwrap().active_page();
+                assert!(matches!(
+                    page.shapes[1].shape,
+                    Shape::Redact {
+                        mode: crate::draw::RedactMode::Pixelate,
+                        ..
+                    }
+                ));
+            }
+        }
+    }
+}
+
+#[test]
+fn older_sessions_are_upgraded_after_keeping_the_original() {
+    for (version, fixture) in SESSION_FIXTURES {
+        let temp = tempfile::tempdir().unwrap();
+        let options = fixture_options(&temp, version);
+        fs::write(options.session_file_path(), fixture).unwrap();
+        load_snapshot(&options).expect("load snapshot");
+
+        let rewritten: serde_json::Value =
+            serde_json::from_slice(&fs::read(options.session_file_path()).unwrap()).unwrap();
+        let backup_path = options.migration_backup_path(version);
+        let inspection = inspect_session(&options).expect("inspect");
+        assert!(inspection.pending_migrations.is_empty());
+        assert_eq!(inspection.file_version, Some(snapshot::CURRENT_VERSION));
+
+        if version == snapshot::CURRENT_VERSION {
+            assert_eq!(
+                fs::read_to_string(options.session_file_path()).unwrap(),
+                fixture
+            );
+            assert!(!backup_path.exists());
+            assert!(inspection.migration_backups.is_empty());
+            continue;
+        }
+        assert_eq!(rewritten["version"], snapshot::CURRENT_VERSION);
+        assert_eq!(fs::read_to_string(&backup_path).unwrap(), fixture);
+        assert_eq!(inspection.migration_backups, vec![backup_path.clone()]);
+
+        // The upgraded file loads without further migrations and clearing removes the original.
+        load_snapshot(&options)
+            .expect("reload")
+            .expect("snapshot present");
+        assert_eq!(fs::read_to_string(&backup_path).unwrap(), fixture);
+        assert!(clear_session(&options).unwrap().removed_backup);
+        assert!(!backup_path.exists());
+    }
+}
+
+#[test]
+fn inspect_session_reports_pending_migrations_without_rewriting() {
+    let temp = tempfile::tempdir().unwrap();
+    let options = fixture_options(&temp, 1);
+    fs::write(options.session_file_path(), SESSION_FIXTURES[0].1).unwrap();
+
+    let inspection = inspect_session(&options).expect("inspect");
+    assert_eq!(inspection.file_version, Some(1));
+    let steps: Vec<(u32, u32)> = inspection
+        .pending_migrations
+        .iter()
+        .map(|step| (step.from, step.to))
+        .collect();
+    assert_eq!(steps, vec![(1, 2), (2, 3), (3, 4), (4, 5)]);
+    assert!(inspection.migration_backups.is_empty());
+    assert_eq!(
+        fs::read_to_string(options.session_file_path()).unwrap(),
+        SESSION_FIXTURES[0].1
+    );
+}
+
+#[test]
+fn compressed_sessions_stay_compressed_when_upgraded() {
+    use flate2::{Compression, write::GzEncoder};
+    use std::io::Write;
+
+    let temp = tempfile::tempdir().unwrap();
+    let options = fixture_options(&temp, 3);
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(SESSION_FIXTURES[2].1.as_bytes()).unwrap();
+    let original = encoder.finish().unwrap();
+    fs::write(options.session_file_path(), &original).unwrap();
+
+    load_snapshot(&options)
+        .expect("load")
+        .expect("snapshot present");
+
+    let inspection = inspect_session(&options).expect("inspect");
+    assert!(inspection.compressed);
+    assert_eq!(inspection.file_version, Some(snapshot::CURRENT_VERSION));
+    assert_eq!(
+        fs::read(options.migration_backup_path(3)).unwrap(),
+        original
+    );
+}
+
+#[test]
+fn malformed_boards_report_the_parse_error() {
+    let temp = tempfile::tempdir().unwrap();
+    let options = fixture_options(&temp, 5);
+    let mut doc: serde_json::Value = serde_json::from_str(SESSION_FIXTURES[4].1).unwrap();
+    doc["transparent"]["active_page"] = serde_json::json!("first");
+    fs::write(options.session_file_path(), doc.to_string()).unwrap();
+
+    let err = snapshot::load_snapshot_inner(&options.session_file_path(), &options)
+        .err()
+        .expect("malformed board is rejected");
+    assert!(format!("{err:#}").contains("expected usize"), "{err:#}");
+}
+
+#[test]
+fn options_from_config_selects_session_format() {
+    let temp = tempfile::tempdir().unwrap();
+    let default_options =
+        options_from_config(&SessionConfig::default(), temp.path(), Some("display-1")).unwrap();
+    assert_eq!(default_options.encoding, SessionEncoding::Json);
+
+    let cfg = SessionConfig {
+        format: crate::config::SessionFormat::Compact,
+        ..SessionConfig::default()
+    };
+    let options = options_from_config(&cfg, temp.path(), Some("display-1")).unwrap();
+    assert_eq!(options.encoding, SessionEncoding::Compact);
+}
+
+#[test]
+fn compact_sessions_are_detected_when_loading() {
+    let temp = tempfile::tempdir().unwrap();
+    let mut options = SessionOptions::new(temp.path().to_path_buf(), "display-compact");
+    options.persist_transparent = true;
+    options.compression = CompressionMode::Off;
+    options.encoding = SessionEncoding::Compact;
+    save_snapshot(&snapshot_with_lines(&options, 3), &options).expect("save compact");
+
+    let raw = fs::read(options.session_file_path()).unwrap();
+    assert!(raw.starts_with(b"WSCB"));
+    let inspection = inspect_session(&options).expect("inspect");
+    assert!(inspection.compact);
+    assert!(!inspection.compressed);
+    assert_eq!(
+        inspection.frame_counts.map(|counts| counts.transparent),
+        Some(3)
+    );
+
+    // Switching back to JSON still loads the compact file, and the next save is JSON again.
+    options.encoding = SessionEncoding::Json;
+    let loaded = load_snapshot(&options)
+        .expect("load snapshot result")
+        .expect("snapshot present");
+    assert_eq!(loaded.transparent.unwrap().active_page().shapes.len(), 3);
+    save_snapshot(&snapshot_with_lines(&options, 2), &options).expect("save json");
+    assert_eq!(fs::read(options.session_file_path()).unwrap()[0], b'{');
+    assert!(!inspect_session(&options).expect("inspect").compact);
+}
+
+#[test]
+fn compact_sessions_can_be_gzip_compressed() {
+    let temp = tempfile::tempdir().unwrap();
+    let mut options = SessionOptions::new(temp.path().to_path_buf(), "display-compact-gz");
+    options.persist_transparent = true;
+    options.compression = CompressionMode::On;
+    options.encoding = SessionEncoding::Compact;
+    save_snapshot(&snapshot_with_lines(&options, 5), &options).expect("save compact");
+
+    let inspection = inspect_session(&options).expect("inspect");
+    assert!(inspection.compact);
+    assert!(inspection.compressed);
+    let loaded = load_snapshot(&options)
+        .expect("load snapshot result")
+        .expect("snapshot present");
+    assert_eq!(loaded.transparent.unwrap().active_page().shapes.len(), 5);
+}
@@ -2,7 +2,11 @@ pub mod toolbar;
 
 /// UI rendering: status bar, help overlay, visual indicators
 use crate::config::StatusPosition;
-use crate::input::{BoardMode, DrawingState, InputState, Tool, state::ContextMenuState};
+use crate::draw::RedactMode;
+use crate::input::{
+    BoardMode, DrawingState, InputState, Tool,
+    state::{CAPTURE_REGION_HANDLE_RADIUS, ContextMenuState, RegionHandle},
+};
 use std::f64::consts::{FRAC_PI_2, PI};
 
 // ============================================================================
@@ -76,6 +80,11 @@ pub fn render_status_bar(
     };
 
     // Determine tool name
+    let redact_name = match input_state.redact_mode {
+        RedactMode::Blur => "Redact (blur)",
+        RedactMode::Pixelate => "Redact (pixelate)",
+        RedactMode::Solid => "Redact (solid)",
+    };
     let tool_name = match &input_state.state {
         DrawingState::TextInput { .. } => "Text",
         DrawingState::Drawing { tool, .. } => match tool {
@@ -88,6 +97,8 @@ pub fn render_status_bar(
             Tool::Marker => "Marker",
             Tool::Highlight => "Highlight",
             Tool::Eraser => "Eraser",
+            Tool::Laser => "Laser",
+            Tool::Redact => redact_name,
         },
         DrawingState::MovingSelection { .. } => "Move",
         DrawingState::Idle => match tool {
@@ -100,6 +111,8 @@ pub fn render_status_bar(
             Tool::Marker => "Marker",
             Tool::Highlight => "Highlight",
             Tool::Eraser => "Eraser",
+            Tool::Laser => "Laser",
+            Tool::Redact => redact_name,
         },
     };
 
@@ -113,6 +126,14 @@ pub fn render_status_bar(
         BoardMode::Blackboard => "[BLACKBOARD] ",
     };
 
+    // Page indicator, only once the board has more than one page
+    let (page, page_count) = input_state.canvas_set.active_page_position();
+    let page_badge = if page_count > 1 {
+        format!("[Page {}/{}] ", page, page_count)
+    } else {
+        String::new()
+    };
+
     // Build status text with mode badge and font size
     let font_size = input_state.current_font_size;
     let highlight_badge = if input_state.click_highlight_enabled() {
@@ -125,23 +146,41 @@ pub fn render_status_bar(
     } else {
         ""
     };
+    let vanishing_badge = if input_state.vanishing_ink_enabled() {
+        " [Vanishing ink]"
+    } else {
+        ""
+    };
 
     let frozen_badge = if input_state.frozen_active() {
         "[FROZEN] "
     } else {
         ""
     };
+    let zoom_badge = input_state
+        .zoom_view()
+        .map(|view| format!("[Zoom {:.1}x] ", view.level()))
+        .unwrap_or_default();
+    let spotlight_badge = if input_state.spotlight_active() {
+        "[Spotlight] "
+    } else {
+        ""
+    };
 
     let status_text = format!(
-        "{}{}[{}] [{}px] [{}] [Text {}px]{}{}  F1=Help",
+        "{}{}{}{}{}[{}] [{}px] [{}] [Text {}px]{}{}{}  F1=Help",
         frozen_badge,
+        zoom_badge,
+        spotlight_badge,
         mode_badge,
+        page_badge,
         color_name,
         thickness as i32,
         tool_name,
         font_size as i32,
         highlight_badge,
-        highlight_tool_badge
+        highlight_tool_badge,
+        vanishing_badge
     );
 
     // Set font
@@ -250,6 +289,177 @@ pub fn render_frozen_badge(ctx: &cairo::Context, screen_width: u32, _screen_heig
     let _ = ctx.show_text(label);
 }
 
+/// Render the crash-recovery prompt shown after an unclean shutdown.
+///
+/// Dims the canvas and centers a panel asking whether to restore the autosaved drawings.
+pub fn render_recovery_prompt(ctx: &cairo::Context, screen_width: u32, screen_height: u32) {
+    let title = "Recover unsaved drawings?";
+    let body = "wayscriber did not shut down cleanly last time.";
+    let hint = "Enter / Y: restore autosave    Esc / N: discard";
+    let padding = 24.0;
+    let line_gap = 12.0;
+    let title_size = 22.0;
+    let body_size = 15.0;
+
+    ctx.set_source_rgba(0.0, 0.0, 0.0, 0.45);
+    ctx.rectangle(0.0, 0.0, screen_width as f64, screen_height as f64);
+    let _ = ctx.fill();
+
+    ctx.select_font_face("Sans", cairo::FontSlant::Normal, cairo::FontWeight::Bold);
+    ctx.set_font_size(title_size);
+    let title_extents = ctx
+        .text_extents(title)
+        .unwrap_or_else(|_| fallback_text_extents(title_size, title));
+
+    ctx.select_font_face("Sans", cairo::FontSlant::Normal, cairo::FontWeight::Normal);
+    ctx.set_font_size(body_size);
+    let body_extents = ctx
+        .text_extents(body)
+        .unwrap_or_else(|_| fallback_text_extents(body_size, body));
+    let hint_extents = ctx
+        .text_extents(hint)
+        .unwrap_or_else(|_| fallback_text_extents(body_size, hint));
+
+    let content_width = title_extents
+        .width()
+        .max(body_extents.width())
+        .max(hint_extents.width());
+    let width = content_width + padding * 2.0;
+    let height = title_size + body_size * 2.0 + line_gap * 3.0 + padding * 2.0;
+    let x = (screen_width as f64 - width) / 2.0;
+    let y = (screen_height as f64 - height) / 2.0;
+
+    ctx.set_source_rgba(0.12, 0.12, 0.15, 0.95);
+    draw_rounded_rect(ctx, x, y, width, height, 12.0);
+    let _ = ctx.fill();
+
+    let mut baseline = y + padding + title_size;
+    ctx.select_font_face("Sans", cairo::FontSlant::Normal, cairo::FontWeight::Bold);
+    ctx.set_font_size(title_size);
+    ctx.set_source_rgba(1.0, 1.0, 1.0, 1.0);
+    ctx.move_to(x + padding, baseline);
+    let _ = ctx.show_text(title);
+
+    ctx.select_font_face("Sans", cairo::FontSlant::Normal, cairo::FontWeight::Normal);
+    ctx.set_font_size(body_size);
+    baseline += line_gap + body_size;
+    ctx.set_source_rgba(0.85, 0.85, 0.88, 1.0);
+    ctx.move_to(x + padding, baseline);
+    let _ = ctx.show_text(body);
+
+    baseline += line_gap * 2.0 + body_size;
+    ctx.set_source_rgba(0.98, 0.75, 0.35, 1.0);
+    ctx.move_to(x + padding, baseline);
+    let _ = ctx.show_text(hint);
+}
+
+/// Render the in-overlay region selector used by region capture actions.
+///
+/// Dims everything outside the selection, outlines it with adjust handles, and shows the
+/// selection size next to the rectangle.
+pub fn render_capture_region(
+    ctx: &cairo::Context,
+    input_state: &InputState,
+    screen_width: u32,
+    screen_height: u32,
+) {
+    let Some(region) = input_state.capture_region() else {
+        return;
+    };
+    let width = screen_width as f64;
+    let height = screen_height as f64;
+
+    let _ = ctx.save();
+
+    // Dim the output, punching out the selection with an even-odd fill.
+    ctx.set_fill_rule(cairo::FillRule::EvenOdd);
+    ctx.rectangle(0.0, 0.0, width, height);
+    if let Some(rect) = region.rect() {
+        ctx.rectangle(
+            rect.x as f64,
+            rect.y as f64,
+            rect.width as f64,
+            rect.height as f64,
+        );
+    }
+    ctx.set_source_rgba(0.0, 0.0, 0.0, 0.45);
+    let _ = ctx.fill();
+    ctx.set_fill_rule(cairo::FillRule::Winding);
+
+    let font_size = 14.0;
+    ctx.select_font_face("Sans", cairo::FontSlant::Normal, cairo::FontWeight::Bold);
+    ctx.set_font_size(font_size);
+
+    let Some(rect) = region.rect() else {
+        let hint = "Drag to select a region • Enter to capture • Esc to cancel";
+        let extents = ctx
+            .text_extents(hint)
+            .unwrap_or_else(|_| fallback_text_extents(font_size, hint));
+        let padding = 10.0;
+        let box_w = extents.width() + padding * 2.0;
+        let box_h = extents.height() + padding * 2.0;
+        let box_x = (width - box_w) / 2.0;
+        let box_y = (height - box_h) / 2.0;
+        ctx.set_source_rgba(0.1, 0.1, 0.1, 0.85);
+        draw_rounded_rect(ctx, box_x, box_y, box_w, box_h, 8.0);
+        let _ = ctx.fill();
+        ctx.set_source_rgba(1.0, 1.0, 1.0, 1.0);
+        ctx.move_to(box_x + padding, box_y + padding + extents.height());
+        let _ = ctx.show_text(hint);
+        let _ = ctx.restore();
+        return;
+    };
+
+    let (x, y, w, h) = (
+        rect.x as f64,
+        rect.y as f64,
+        rect.width as f64,
+        rect.height as f64,
+    );
+
+    // Selection outline
+    ctx.set_source_rgba(1.0, 1.0, 1.0, 0.95);
+    ctx.set_line_width(1.5);
+    ctx.rectangle(x + 0.5, y + 0.5, w - 1.0, h - 1.0);
+    let _ = ctx.stroke();
+
+    // Adjust handles
+    let half = CAPTURE_REGION_HANDLE_RADIUS as f64;
+    for handle in RegionHandle::ALL {
+        let (hx, hy) = handle.position(&rect);
+        ctx.rectangle(hx as f64 - half, hy as f64 - half, half * 2.0, half * 2.0);
+        ctx.set_source_rgba(1.0, 1.0, 1.0, 1.0);
+        let _ = ctx.fill_preserve();
+        ctx.set_source_rgba(0.2, 0.45, 0.95, 1.0);
+        ctx.set_line_width(1.5);
+        let _ = ctx.stroke();
+    }
+
+    // Size readout above the selection (or inside it when there is no room above)
+    let label = format!("{} × {}", rect.width, rect.height);
+    let extents = ctx
+        .text_extents(&label)
+        .unwrap_or_else(|_| fallback_text_extents(font_size, &label));
+    let padding = 6.0;
+    let label_w = extents.width() + padding * 2.0;
+    let label_h = extents.height() + padding * 2.0;
+    let gap = half + 4.0;
+    let label_x = x.clamp(0.0, (width - label_w).max(0.0));
+    let label_y = if y - label_h - gap >= 0.0 {
+        y - label_h - gap
+    } else {
+        y + gap
+    };
+    ctx.set_source_rgba(0.1, 0.1, 0.1, 0.85);
+    draw_rounded_rect(ctx, label_x, label_y, label_w, label_h, 6.0);
+    let _ = ctx.fill();
+    ctx.set_source_rgba(1.0, 1.0, 1.0, 1.0);
+    ctx.move_to(label_x + padding, label_y + padding + extents.height());
+    let _ = ctx.show_text(&label);
+
+    let _ = ctx.restore();
+}
+
 /// Render help overlay showing all keybindings
 pub fn render_help_overlay(
     ctx: &cairo::Context,
@@ -294,6 +504,22 @@ pub fn render_help_overlay(
             key: "Ctrl+Shift+T",
             action: "Return to Transparent",
         },
+        Row {
+            key: "Ctrl+Alt+←/→",
+            action: "Previous/next page",
+        },
+        Row {
+            key: "Ctrl+Alt+N / D / X",
+            action: "New / duplicate / delete page",
+        },
+        Row {
+            key: "Ctrl+1",
+            action: "Zoom around pointer (scroll = level)",
+        },
+        Row {
+            key: "Ctrl+Shift+L",
+            action: "Spotlight (drag = pin region, scroll = size)",
+        },
     ];
 
     if frozen_enabled {
@@ -370,6 +596,18 @@ pub fn render_help_overlay(
                     key: "H",
                     action: "Marker tool",
                 },
+                Row {
+                    key: "L",
+                    action: "Laser pointer",
+                },
+                Row {
+                    key: "X",
+                    action: "Redact region (Shift+X = blur/pixelate/solid)",
+                },
+                Row {
+                    key: "Ctrl+Shift+V",
+                    action: "Toggle vanishing ink",
+                },
             ],
             badges: Vec::new(),
         },
@@ -485,6 +723,14 @@ pub fn render_help_overlay(
                     key: "Ctrl+Shift+S",
                     action: "Region → file",
                 },
+                Row {
+                    key: "Ctrl+Alt+C / S",
+                    action: "Full screen + drawing → clipboard / file",
+                },
+                Row {
+                    key: "Ctrl+Alt+Shift+C / S",
+                    action: "Region + drawing → clipboard / file",
+                },
                 Row {
                     key: "Ctrl+Shift+O",
                     action: "Active window (Hyprland)",
@@ -493,6 +739,22 @@ pub fn render_help_overlay(
                     key: "Ctrl+Shift+I",
                     action: "Selection (capture defaults)",
                 },
+                Row {
+                    key: "Ctrl+Shift+E",
+                    action: "Export as SVG",
+                },
+                Row {
+                    key: "Ctrl+Alt+E",
+                    action: "Export boards as PDF",
+                },
+                Row {
+                    key: "Ctrl+Shift+D",
+                    action: "Save boards as .wayscriber document",
+                },
+                Row {
+                    key: "Ctrl+O",
+                    action: "Open latest .wayscriber document",
+                },
             ],
             badges: Vec::new(),
         },
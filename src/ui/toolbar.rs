@@ -1,9 +1,10 @@
 use crate::config::KeybindingsConfig;
 use crate::draw::{Color, EraserKind, FontDescriptor};
 use crate::input::{InputState, Tool};
+use serde::{Deserialize, Serialize};
 
 /// Events emitted by the floating toolbar UI.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum ToolbarEvent {
     SelectTool(Tool),
     SetColor(Color),
@@ -170,6 +171,8 @@ pub struct ToolbarBindingHints {
     pub arrow: Option<String>,
     pub marker: Option<String>,
     pub highlight: Option<String>,
+    pub laser: Option<String>,
+    pub redact: Option<String>,
     pub eraser: Option<String>,
     pub text: Option<String>,
     pub clear: Option<String>,
@@ -187,6 +190,8 @@ impl ToolbarBindingHints {
             Tool::Arrow => self.arrow.as_deref(),
             Tool::Marker => self.marker.as_deref(),
             Tool::Highlight => self.highlight.as_deref(),
+            Tool::Laser => self.laser.as_deref(),
+            Tool::Redact => self.redact.as_deref(),
             Tool::Eraser => self.eraser.as_deref(),
             Tool::Select => None,
         }
@@ -202,6 +207,8 @@ impl ToolbarBindingHints {
             arrow: first(&kb.select_arrow_tool),
             marker: first(&kb.select_marker_tool),
             highlight: first(&kb.select_highlight_tool),
+            laser: first(&kb.select_laser_tool),
+            redact: first(&kb.select_redact_tool),
             eraser: first(&kb.select_eraser_tool),
             text: first(&kb.enter_text_mode),
             clear: first(&kb.clear_canvas),
@@ -6,6 +6,7 @@
 //! - Ellipse bounding box calculations
 
 use crate::draw::{Color, color::*};
+use serde::{Deserialize, Serialize};
 
 // ============================================================================
 // Arrowhead Geometry
@@ -131,6 +132,42 @@ pub fn name_to_color(name: &str) -> Option<Color> {
     }
 }
 
+/// Parses a `#rrggbb` or `#rrggbbaa` hex string (leading `#` optional) into a Color.
+///
+/// Used by the control socket so scripts can set arbitrary colors.
+///
+/// # Returns
+/// - `Some(Color)` if the string is a valid 6- or 8-digit hex color
+/// - `None` otherwise
+pub fn hex_to_color(hex: &str) -> Option<Color> {
+    let digits = hex.trim().trim_start_matches('#');
+    if !matches!(digits.len(), 6 | 8) || !digits.is_ascii() {
+        return None;
+    }
+    let channel = |index: usize| {
+        u8::from_str_radix(&digits[index..index + 2], 16)
+            .ok()
+            .map(|value| f64::from(value) / 255.0)
+    };
+    Some(Color {
+        r: channel(0)?,
+        g: channel(2)?,
+        b: channel(4)?,
+        a: if digits.len() == 8 { channel(6)? } else { 1.0 },
+    })
+}
+
+/// Formats a Color as a lowercase `#rrggbb` hex string (alpha is dropped).
+pub fn color_to_hex(color: &Color) -> String {
+    let channel = |v: f64| (v.clamp(0.0, 1.0) * 255.0).round() as u8;
+    format!(
+        "#{:02x}{:02x}{:02x}",
+        channel(color.r),
+        channel(color.g),
+        channel(color.b)
+    )
+}
+
 /// Maps a Color value to its human-readable name.
 ///
 /// Uses approximate matching (threshold-based) to identify colors.
@@ -184,7 +221,7 @@ pub fn clamp(val: i32, min: i32, max: i32) -> i32 {
 }
 
 /// Axis-aligned rectangle helper used for dirty region tracking.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Rect {
     pub x: i32,
     pub y: i32,
@@ -214,6 +251,11 @@ impl Rect {
         Self::new(min_x, min_y, width, height)
     }
 
+    /// Builds the rectangle spanned by two opposite corners given in any order.
+    pub fn from_corners((x1, y1): (i32, i32), (x2, y2): (i32, i32)) -> Option<Self> {
+        Self::from_min_max(x1.min(x2), y1.min(y2), x1.max(x2), y1.max(y2))
+    }
+
     // /// Expands this rectangle to include another rectangle.
     // pub fn expand_to_include(&mut self, other: Rect) {
     //     let min_x = self.x.min(other.x);
@@ -333,6 +375,16 @@ mod tests {
         assert!(name_to_color("chartreuse").is_none());
     }
 
+    #[test]
+    fn hex_colors_parse_and_format() {
+        let orange = hex_to_color("#ff8800").unwrap();
+        assert_eq!(color_to_hex(&orange), "#ff8800");
+        assert!((orange.a - 1.0).abs() < f64::EPSILON);
+        assert_eq!(hex_to_color("ffffff80").unwrap().a, 128.0 / 255.0);
+        assert!(hex_to_color("#ff88").is_none());
+        assert!(hex_to_color("#gg8800").is_none());
+    }
+
     #[test]
     fn color_to_name_matches_known_colors() {
         assert_eq!(color_to_name(&RED), "Red");
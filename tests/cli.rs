@@ -117,11 +117,13 @@ fn session_info_reports_saved_snapshot() {
             a: 1.0,
         },
         thick: 2.0,
+        start_color: None,
+        end_color: None,
     });
 
     let snapshot = wayscriber::session::SessionSnapshot {
         active_mode: wayscriber::input::BoardMode::Transparent,
-        transparent: Some(frame),
+        transparent: Some(wayscriber::draw::BoardPages::from_pages(vec![frame], 0)),
         whiteboard: None,
         blackboard: None,
         tool_state: None,
@@ -141,3 +143,43 @@ fn session_info_reports_saved_snapshot() {
         .stdout(predicate::str::contains("transparent 1"))
         .stdout(predicate::str::contains("Tool state stored: false"));
 }
+
+#[test]
+fn ctl_reports_missing_daemon_with_exit_code() {
+    let runtime = TempDir::new().unwrap();
+
+    wayscriber_cmd()
+        .env("XDG_RUNTIME_DIR", runtime.path())
+        .args(["ctl", "status", "--json"])
+        .assert()
+        .code(3)
+        .stderr(predicate::str::contains("no running wayscriber daemon"));
+}
+
+#[test]
+fn ctl_reports_rejected_commands_with_exit_code() {
+    use std::io::{BufRead, BufReader, Write};
+    use std::os::unix::net::UnixListener;
+
+    let runtime = TempDir::new().unwrap();
+    let socket_dir = runtime.path().join("wayscriber");
+    fs::create_dir_all(&socket_dir).unwrap();
+    let listener = UnixListener::bind(socket_dir.join("control.sock")).unwrap();
+    let daemon = std::thread::spawn(move || {
+        let (stream, _) = listener.accept().unwrap();
+        let mut request = String::new();
+        BufReader::new(&stream).read_line(&mut request).unwrap();
+        (&stream)
+            .write_all(b"{\"ok\":false,\"error\":\"unknown tool 'crayon'\"}\n")
+            .unwrap();
+        request
+    });
+
+    wayscriber_cmd()
+        .env("XDG_RUNTIME_DIR", runtime.path())
+        .args(["ctl", "tool", "crayon"])
+        .assert()
+        .code(1)
+        .stderr(predicate::str::contains("unknown tool 'crayon'"));
+    assert!(daemon.join().unwrap().contains("crayon"));
+}
@@ -0,0 +1,354 @@
+//! Golden-image tests for the Cairo renderer.
+//!
+//! Each case renders a fixture `Frame` offscreen through `draw::raster::render_image` and compares
+//! the result against `tests/fixtures/render/<case>.png`. Small per-channel differences are
+//! tolerated so antialiasing changes between Cairo releases do not fail the suite.
+//!
+//! After an intended rendering change, regenerate the references with
+//! `./tools/update-golden.sh` (or `WAYSCRIBER_UPDATE_GOLDEN=1 cargo test --test render_golden`)
+//! and review the PNG diff before committing. Failed comparisons leave the rendered image and a
+//! difference mask under `target/golden-failures/`.
+
+use std::fs::File;
+use std::path::PathBuf;
+
+use cairo::ImageSurface;
+use wayscriber::config::BoardConfig;
+use wayscriber::draw::raster::{render_image, write_png};
+use wayscriber::draw::{Color, EraserBrush, EraserKind, FontDescriptor, Frame, Shape};
+use wayscriber::input::BoardMode;
+
+const UPDATE_ENV: &str = "WAYSCRIBER_UPDATE_GOLDEN";
+const SIZE: (u32, u32) = (200, 140);
+
+const RED: Color = Color {
+    r: 0.9,
+    g: 0.1,
+    b: 0.1,
+    a: 1.0,
+};
+const BLUE: Color = Color {
+    r: 0.1,
+    g: 0.3,
+    b: 0.9,
+    a: 1.0,
+};
+const YELLOW_INK: Color = Color {
+    r: 1.0,
+    g: 0.85,
+    b: 0.0,
+    a: 0.4,
+};
+
+/// How far a rendered image may drift from its reference.
+#[derive(Clone, Copy)]
+struct Tolerance {
+    /// Largest difference allowed in any channel before a pixel counts as mismatched.
+    channel: u8,
+    /// Share of pixels (0.0-1.0) allowed to mismatch.
+    mismatched: f64,
+}
+
+/// Geometry only; antialiasing is the only expected source of drift.
+const STRICT: Tolerance = Tolerance {
+    channel: 8,
+    mismatched: 0.002,
+};
+
+/// Text depends on the installed fonts, so only the layout has to match closely.
+const TEXT: Tolerance = Tolerance {
+    channel: 64,
+    mismatched: 0.06,
+};
+
+fn fixtures_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/render")
+}
+
+fn failures_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("target/golden-failures")
+}
+
+fn render(frame: &Frame, mode: BoardMode) -> ImageSurface {
+    render_image(&frame.shapes, mode, SIZE, 1.0, &BoardConfig::default()).expect("render frame")
+}
+
+fn pixels(surface: &ImageSurface) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    surface
+        .with_data(|data| bytes.extend_from_slice(data))
+        .expect("image data");
+    bytes
+}
+
+/// Writes an opaque red pixel wherever two packed ARGB32 buffers disagree.
+fn write_diff_mask(name: &str, actual: &[u8], expected: &[u8], width: i32, tolerance: Tolerance) {
+    let mut mask = vec![0u8; actual.len()];
+    for ((out, a), e) in mask
+        .chunks_exact_mut(4)
+        .zip(actual.chunks_exact(4))
+        .zip(expected.chunks_exact(4))
+    {
+        if a.iter()
+            .zip(e)
+            .any(|(a, e)| a.abs_diff(*e) > tolerance.channel)
+        {
+            out.copy_from_slice(&0xffff_0000u32.to_ne_bytes());
+        }
+    }
+    let height = mask.len() as i32 / (width * 4);
+    let diff = ImageSurface::create_for_data(mask, cairo::Format::ARgb32, width, height, width * 4)
+        .expect("diff surface");
+    write_png(&diff, &failures_dir().join(format!("{name}.diff.png"))).expect("write diff mask");
+}
+
+/// Compares `actual` with the reference image for `name`, or rewrites the reference when
+/// `WAYSCRIBER_UPDATE_GOLDEN` is set.
+fn assert_golden(name: &str, actual: ImageSurface, tolerance: Tolerance) {
+    let reference = fixtures_dir().join(format!("{name}.png"));
+    if std::env::var_os(UPDATE_ENV).is_some() {
+        std::fs::create_dir_all(fixtures_dir()).expect("create fixtures dir");
+        write_png(&actual, &reference).expect("write reference image");
+        return;
+    }
+
+    let mut file = File::open(&reference).unwrap_or_else(|err| {
+        panic!(
+            "missing reference {} ({err}); run ./tools/update-golden.sh to create it",
+            reference.display()
+        )
+    });
+    let expected = ImageSurface::create_from_png(&mut file).expect("decode reference image");
+    assert_eq!(
+        (expected.width(), expected.height()),
+        (actual.width(), actual.height()),
+        "{name}: reference image has a different size"
+    );
+
+    // PNG decoding always yields ARGB32 with a tightly packed stride for these widths, but compare
+    // row by row so a different stride cannot cause false mismatches.
+    let width = actual.width() as usize * 4;
+    let (actual_px, expected_px) = (pixels(&actual), pixels(&expected));
+    let rows = |bytes: &[u8], stride: i32| -> Vec<u8> {
+        bytes
+            .chunks(stride as usize)
+            .flat_map(|row| row[..width].to_vec())
+            .collect()
+    };
+    let actual_px = rows(&actual_px, actual.stride());
+    let expected_px = rows(&expected_px, expected.stride());
+
+    let total = actual_px.len() / 4;
+    let mismatched = actual_px
+        .chunks_exact(4)
+        .zip(expected_px.chunks_exact(4))
+        .filter(|(a, e)| {
+            a.iter()
+                .zip(*e)
+                .any(|(a, e)| a.abs_diff(*e) > tolerance.channel)
+        })
+        .count();
+    let ratio = mismatched as f64 / total as f64;
+    if ratio <= tolerance.mismatched {
+        return;
+    }
+
+    std::fs::create_dir_all(failures_dir()).expect("create failures dir");
+    let actual_path = failures_dir().join(format!("{name}.actual.png"));
+    write_png(&actual, &actual_path).expect("write rendered image");
+    write_diff_mask(name, &actual_px, &expected_px, actual.width(), tolerance);
+    panic!(
+        "{name}: {mismatched} of {total} pixels ({:.2}%) differ from {} (allowed {:.2}%); \
+         see {} and the matching .diff.png",
+        ratio * 100.0,
+        reference.display(),
+        tolerance.mismatched * 100.0,
+        actual_path.display()
+    );
+}
+
+fn frame(shapes: impl IntoIterator<Item = Shape>) -> Frame {
+    let mut frame = Frame::new();
+    for shape in shapes {
+        frame.add_shape(shape);
+    }
+    frame
+}
+
+#[test]
+fn outlines_and_fills() {
+    let frame = frame([
+        Shape::Rect {
+            x: 12,
+            y: 12,
+            w: 70,
+            h: 50,
+            fill: false,
+            color: RED,
+            thick: 4.0,
+            start_color: None,
+            end_color: None,
+        },
+        Shape::Rect {
+            x: 100,
+            y: 20,
+            w: 40,
+            h: 30,
+            fill: true,
+            color: BLUE,
+            thick: 2.0,
+            start_color: None,
+            end_color: None,
+        },
+        Shape::Ellipse {
+            cx: 60,
+            cy: 100,
+            rx: 40,
+            ry: 22,
+            fill: false,
+            color: BLUE,
+            thick: 3.0,
+            start_color: None,
+            end_color: None,
+        },
+        Shape::Line {
+            x1: 110,
+            y1: 120,
+            x2: 190,
+            y2: 70,
+            color: RED,
+            thick: 6.0,
+            start_color: Some(RED),
+            end_color: Some(BLUE),
+        },
+    ]);
+    assert_golden(
+        "outlines_and_fills",
+        render(&frame, BoardMode::Whiteboard),
+        STRICT,
+    );
+}
+
+#[test]
+fn arrowheads() {
+    let arrow =
+        |(x1, y1): (i32, i32), (x2, y2): (i32, i32), length: f64, angle: f64| Shape::Arrow {
+            x1,
+            y1,
+            x2,
+            y2,
+            color: RED,
+            thick: 3.0,
+            arrow_length: length,
+            arrow_angle: angle,
+            start_color: None,
+            end_color: None,
+        };
+    let frame = frame([
+        arrow((180, 20), (20, 20), 20.0, 30.0),
+        arrow((20, 60), (180, 60), 12.0, 45.0),
+        arrow((100, 130), (40, 80), 24.0, 20.0),
+        // Short shafts cap the head at 30% of their length.
+        arrow((170, 100), (160, 95), 30.0, 30.0),
+    ]);
+    assert_golden("arrowheads", render(&frame, BoardMode::Whiteboard), STRICT);
+}
+
+#[test]
+fn freehand_with_pressure_and_marker() {
+    let points: Vec<(i32, i32)> = (0..40).map(|i| (10 + i * 4, 30 + (i % 8) * 3)).collect();
+    let widths = (0..40).map(|i| 1.0 + f64::from(i) * 0.25).collect();
+    let marker: Vec<(i32, i32)> = (0..30).map(|i| (20 + i * 5, 90 + (i % 5))).collect();
+    let frame = frame([
+        Shape::Freehand {
+            points,
+            color: BLUE,
+            thick: 3.0,
+            per_point_colors: None,
+            per_point_widths: Some(widths),
+        },
+        Shape::MarkerStroke {
+            points: marker.clone(),
+            color: YELLOW_INK,
+            thick: 18.0,
+            per_point_colors: None,
+        },
+        // Overlapping marker strokes darken where they cross.
+        Shape::MarkerStroke {
+            points: marker.iter().map(|&(x, y)| (x, y + 10)).collect(),
+            color: YELLOW_INK,
+            thick: 18.0,
+            per_point_colors: None,
+        },
+    ]);
+    assert_golden(
+        "freehand_with_pressure_and_marker",
+        render(&frame, BoardMode::Whiteboard),
+        STRICT,
+    );
+}
+
+#[test]
+fn eraser_replay_on_boards() {
+    let filled = Shape::Rect {
+        x: 20,
+        y: 20,
+        w: 160,
+        h: 100,
+        fill: true,
+        color: BLUE,
+        thick: 2.0,
+        start_color: None,
+        end_color: None,
+    };
+    let eraser = |kind| Shape::EraserStroke {
+        points: (0..20).map(|i| (30 + i * 7, 40 + i * 3)).collect(),
+        brush: EraserBrush { size: 14.0, kind },
+    };
+    let frame_with = |kind| frame([filled.clone(), eraser(kind)]);
+
+    // The transparent board punches holes; boards paint their background back.
+    assert_golden(
+        "eraser_transparent",
+        render(&frame_with(EraserKind::Circle), BoardMode::Transparent),
+        STRICT,
+    );
+    assert_golden(
+        "eraser_blackboard",
+        render(&frame_with(EraserKind::Rect), BoardMode::Blackboard),
+        STRICT,
+    );
+}
+
+#[test]
+fn text_with_background_box() {
+    let frame = frame([
+        Shape::Text {
+            x: 16,
+            y: 50,
+            text: "Golden".to_string(),
+            color: RED,
+            size: 28.0,
+            font_descriptor: FontDescriptor::new(
+                "Sans".to_string(),
+                "bold".to_string(),
+                "normal".to_string(),
+            ),
+            background_enabled: true,
+        },
+        Shape::Text {
+            x: 16,
+            y: 110,
+            text: "plain text".to_string(),
+            color: BLUE,
+            size: 20.0,
+            font_descriptor: FontDescriptor::default(),
+            background_enabled: false,
+        },
+    ]);
+    assert_golden(
+        "text_with_background_box",
+        render(&frame, BoardMode::Whiteboard),
+        TEXT,
+    );
+}
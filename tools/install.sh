@@ -105,7 +105,7 @@ echo "   # Autostart wayscriber daemon"
 echo "   exec-once = $INSTALL_DIR/$BINARY_NAME --daemon"
 echo ""
 echo "   # Toggle overlay with Super+D"
-echo "   bind = SUPER, D, exec, pkill -SIGUSR1 $BINARY_NAME"
+echo "   bind = SUPER, D, exec, $BINARY_NAME ctl toggle"
 echo ""
 
 # Setup autostart options
@@ -192,12 +192,12 @@ case $REPLY in
             read -p "Add Super+D keybind to Hyprland config? (y/n) " -n 1 -r
             echo ""
             if [[ $REPLY =~ ^[Yy]$ ]]; then
-                if grep -q "pkill -SIGUSR1 $BINARY_NAME" "$HYPR_CONFIG"; then
+                if grep -q -e "pkill -SIGUSR1 $BINARY_NAME" -e "$BINARY_NAME ctl toggle" "$HYPR_CONFIG"; then
                     echo "⚠️  Keybind already configured"
                 else
                     echo "" >> "$HYPR_CONFIG"
                     echo "# wayscriber toggle keybind" >> "$HYPR_CONFIG"
-                    echo "bind = SUPER, D, exec, pkill -SIGUSR1 $BINARY_NAME" >> "$HYPR_CONFIG"
+                    echo "bind = SUPER, D, exec, $BINARY_NAME ctl toggle" >> "$HYPR_CONFIG"
                     echo "✅ Keybind added to Hyprland config"
                     echo ""
                     echo "Reload Hyprland: hyprctl reload"
@@ -216,7 +216,7 @@ case $REPLY in
                 echo "" >> "$HYPR_CONFIG"
                 echo "# wayscriber - Screen annotation tool" >> "$HYPR_CONFIG"
                 echo "exec-once = $INSTALL_DIR/$BINARY_NAME --daemon" >> "$HYPR_CONFIG"
-                echo "bind = SUPER, D, exec, pkill -SIGUSR1 $BINARY_NAME" >> "$HYPR_CONFIG"
+                echo "bind = SUPER, D, exec, $BINARY_NAME ctl toggle" >> "$HYPR_CONFIG"
                 echo "✅ Added to Hyprland config"
             fi
             echo ""
@@ -226,7 +226,7 @@ case $REPLY in
             echo "⚠️  Hyprland config not found at $HYPR_CONFIG"
             echo "Add these lines manually to your Hyprland config:"
             echo "  exec-once = $INSTALL_DIR/$BINARY_NAME --daemon"
-            echo "  bind = SUPER, D, exec, pkill -SIGUSR1 $BINARY_NAME"
+            echo "  bind = SUPER, D, exec, $BINARY_NAME ctl toggle"
         fi
         ;;
 
@@ -0,0 +1,11 @@
+#!/bin/bash
+# Regenerate the reference images used by the golden-image rendering tests.
+set -euo pipefail
+
+SCRIPT_DIR="$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)"
+PROJECT_ROOT="$(cd "$SCRIPT_DIR/.." && pwd)"
+
+echo "Rendering reference images into tests/fixtures/render..."
+(cd "$PROJECT_ROOT" && WAYSCRIBER_UPDATE_GOLDEN=1 cargo test --test render_golden)
+(cd "$PROJECT_ROOT" && git status --short -- tests/fixtures/render)
+echo "✅ References updated. Review the changed PNGs before committing."